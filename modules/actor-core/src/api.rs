@@ -23,7 +23,11 @@ pub mod metrics;
 pub mod process;
 /// Receive timeout handling
 pub mod receive_timeout;
+/// Routers spreading messages over pools and groups of actors.
+pub mod routing;
 /// Supervision strategies and failure handling.
 pub mod supervision;
 #[cfg(any(test, feature = "test-support"))]
 pub mod test_support;
+/// Runtime timer drivers backing actor timers.
+pub mod timer_driver;
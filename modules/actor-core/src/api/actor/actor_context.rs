@@ -1,4 +1,4 @@
-use alloc::boxed::Box;
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
 use core::{future::Future, marker::PhantomData, time::Duration};
 
 use cellex_utils_core_rs::{
@@ -19,6 +19,7 @@ use crate::{
       actor_failure::ActorFailure,
       actor_ref::{ActorRef, PriorityActorRef},
       ask::{ask_with_timeout, create_ask_handles, AskError, AskFuture, AskResult, AskTimeoutFuture},
+      behavior::TimerScheduler,
       message_metadata_responder::MessageMetadataResponder,
       props::Props,
     },
@@ -71,6 +72,8 @@ where
   pub(super) inner:      &'r mut InternalActorContext<'ctx, MailboxOf<AR>>,
   pub(super) metadata:   Option<MessageMetadata<MailboxConcurrencyOf<AR>>>,
   pub(super) extensions: Extensions,
+  pub(super) unstashed:  VecDeque<(U, Option<MessageMetadata<MailboxConcurrencyOf<AR>>>)>,
+  pub(super) timers:     Vec<TimerScheduler<U, AR>>,
   pub(super) _marker:    PhantomData<U>,
 }
 
@@ -88,7 +91,7 @@ where
 {
   pub(crate) fn new(inner: &'r mut InternalActorContext<'ctx, MailboxOf<AR>>) -> Self {
     let extensions = inner.extensions();
-    Self { inner, metadata: None, extensions, _marker: PhantomData }
+    Self { inner, metadata: None, extensions, unstashed: VecDeque::new(), timers: Vec::new(), _marker: PhantomData }
   }
 }
 
@@ -138,7 +141,14 @@ where
     metadata: MessageMetadata<MailboxConcurrencyOf<AR>>,
   ) -> Self {
     let extensions = inner.extensions();
-    Self { inner, metadata: Some(metadata), extensions, _marker: PhantomData }
+    Self {
+      inner,
+      metadata: Some(metadata),
+      extensions,
+      unstashed: VecDeque::new(),
+      timers: Vec::new(),
+      _marker: PhantomData,
+    }
   }
 
   /// Returns the shared extension registry.
@@ -266,6 +276,59 @@ where
     self.inner.send_envelope_to_self(envelope)
   }
 
+  /// Watches `target`: once it stops, [`Signal::Terminated`](crate::api::actor::Signal::Terminated)
+  /// carrying its ID is delivered
+  /// through the signal handler of the current behavior. Watching an actor that already stopped
+  /// delivers the signal right away.
+  ///
+  /// # Errors
+  /// Returns [`QueueError`] when the signal for a stopped target cannot be queued.
+  pub fn watch<V>(&mut self, target: &ActorRef<V, AR>) -> Result<(), QueueError<PriorityEnvelope<AnyMessage>>>
+  where
+    V: Element, {
+    self.watch_target(target, None)
+  }
+
+  /// Watches `target` like [`ActorContext::watch`], delivering `message` as a user message
+  /// instead of the terminated signal once it stops.
+  ///
+  /// # Errors
+  /// Returns [`QueueError`] when the message for a stopped target cannot be queued.
+  pub fn watch_with<V>(
+    &mut self,
+    target: &ActorRef<V, AR>,
+    message: U,
+  ) -> Result<(), QueueError<PriorityEnvelope<AnyMessage>>>
+  where
+    V: Element, {
+    self.watch_target(target, Some(AnyMessage::new(MessageEnvelope::user(message))))
+  }
+
+  /// Stops watching `target`; no termination notification is delivered for it afterwards.
+  pub fn unwatch<V>(&mut self, target: &ActorRef<V, AR>)
+  where
+    V: Element, {
+    self.inner.unwatch(&target.pid_slot());
+    // A target that already stopped has no watcher left to forget.
+    let _ = target.send_system(SystemMessage::Unwatch(self.actor_id()));
+  }
+
+  fn watch_target<V>(
+    &mut self,
+    target: &ActorRef<V, AR>,
+    message: Option<AnyMessage>,
+  ) -> Result<(), QueueError<PriorityEnvelope<AnyMessage>>>
+  where
+    V: Element, {
+    self.inner.watch(target.pid_slot(), message);
+    if target.send_system(SystemMessage::Watch(self.actor_id())).is_err() {
+      if let Some(actor) = target.pid().and_then(|pid| pid.path().last()) {
+        return self.send_system_to_self(SystemMessage::Terminated(actor));
+      }
+    }
+    Ok(())
+  }
+
   /// Reports a failure to the guardian using the supervision channel.
   ///
   /// # Errors
@@ -34,6 +34,13 @@ impl DefaultBehaviorFailure {
     }
   }
 
+  /// Attaches debug details, e.g. when rebuilding a failure received from another node.
+  #[must_use]
+  pub fn with_debug_details(mut self, debug: impl Into<String>) -> Self {
+    self.debug = Some(debug.into());
+    self
+  }
+
   /// Returns optional debug details if available.
   #[must_use]
   pub fn debug_details(&self) -> Option<&str> {
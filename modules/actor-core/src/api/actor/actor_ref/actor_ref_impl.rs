@@ -81,7 +81,6 @@ where
   }
 
   /// Returns the PID slot associated with this reference.
-  #[allow(dead_code)]
   pub(crate) fn pid_slot(&self) -> ArcShared<RwLock<Option<Pid>>> {
     self.pid_slot.clone()
   }
@@ -103,6 +102,13 @@ where
     self.current_pid()
   }
 
+  /// Returns the number of messages waiting in the mailbox of the referenced actor, or `None`
+  /// when the mailbox cannot tell.
+  #[must_use]
+  pub fn mailbox_len(&self) -> Option<usize> {
+    self.inner.mailbox_len()
+  }
+
   fn current_pid_from_slot(pid_slot: &ArcShared<RwLock<Option<Pid>>>) -> Option<Pid> {
     pid_slot.read().clone()
   }
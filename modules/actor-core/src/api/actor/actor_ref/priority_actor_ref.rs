@@ -123,6 +123,13 @@ where
     self.sender.try_send_mailbox(envelope)
   }
 
+  /// Returns the number of messages waiting in the mailbox, or `None` when the mailbox cannot
+  /// tell.
+  #[must_use]
+  pub fn mailbox_len(&self) -> Option<usize> {
+    self.sender.len_opt()
+  }
+
   /// Returns the raw producer handle kept by the reference.
   #[must_use]
   pub const fn sender(&self) -> &MF::Producer<PriorityEnvelope<M>> {
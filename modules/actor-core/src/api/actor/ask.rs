@@ -32,8 +32,13 @@ where
   AskTimeoutFuture::new(future, timeout)
 }
 
-/// Creates a Future and responder pair for the `ask` pattern (internal API).
-pub(crate) fn create_ask_handles<Resp, Mode>() -> (AskFuture<Resp>, MessageSender<Resp, Mode>)
+/// Creates a Future and responder pair for the `ask` pattern.
+///
+/// The responder completes the future when it receives a user message of type `Resp`. Layers
+/// that route replies themselves (such as remote transports) use this to back their own `ask`
+/// APIs.
+#[must_use]
+pub fn create_ask_handles<Resp, Mode>() -> (AskFuture<Resp>, MessageSender<Resp, Mode>)
 where
   Resp: Element,
   Mode: MetadataStorageMode, {
@@ -4,22 +4,34 @@ use core::{
   task::{Context, Poll},
 };
 
-use cellex_utils_core_rs::sync::ArcShared;
+use cellex_utils_core_rs::sync::{shared::SharedBound, ArcShared};
 
 use super::{
   ask_error::AskError,
-  shared::{AskShared, STATE_CANCELLED, STATE_PENDING, STATE_READY, STATE_RESPONDER_DROPPED},
+  shared::{AskShared, DropHookFn, STATE_CANCELLED, STATE_PENDING, STATE_READY, STATE_RESPONDER_DROPPED},
   AskResult,
 };
 
 /// Future that awaits a response from an `ask` operation.
 pub struct AskFuture<Resp> {
   pub(super) shared: ArcShared<AskShared<Resp>>,
+  cancel_hook:       Option<ArcShared<DropHookFn>>,
 }
 
 impl<Resp> AskFuture<Resp> {
   pub(super) const fn new(shared: ArcShared<AskShared<Resp>>) -> Self {
-    Self { shared }
+    Self { shared, cancel_hook: None }
+  }
+
+  /// Runs `hook` when the future is dropped before the response arrives, including when a
+  /// timeout gives up on it, so that layers routing replies themselves can release the
+  /// responder.
+  #[must_use]
+  pub fn with_cancel_hook<F>(mut self, hook: F) -> Self
+  where
+    F: Fn() + SharedBound + 'static, {
+    self.cancel_hook = Some(ArcShared::new(hook).into_dyn(|hook| hook as &DropHookFn));
+    self
   }
 }
 
@@ -54,7 +66,11 @@ impl<Resp> Future for AskFuture<Resp> {
 
 impl<Resp> Drop for AskFuture<Resp> {
   fn drop(&mut self) {
-    let _ = self.shared.cancel();
+    if self.shared.cancel() {
+      if let Some(hook) = self.cancel_hook.take() {
+        hook();
+      }
+    }
   }
 }
 
@@ -18,9 +18,12 @@ mod behavior_state;
 mod behaviors;
 mod dyn_supervisor;
 mod fixed_directive_supervisor;
+mod stash_buffer;
+mod stash_overflow_error;
 mod supervise_builder;
 mod supervisor_strategy;
 mod supervisor_strategy_config;
+mod timer_scheduler;
 
 pub use actor_adapter::ActorAdapter;
 pub(crate) use behavior_directive::BehaviorDirective;
@@ -31,11 +34,15 @@ pub use behaviors::Behaviors;
 pub(crate) use dyn_supervisor::DynSupervisor;
 #[allow(unused_imports)]
 pub(crate) use fixed_directive_supervisor::FixedDirectiveSupervisor;
+pub use stash_buffer::StashBuffer;
+pub use stash_overflow_error::StashOverflowError;
 #[allow(unused_imports)]
 pub(crate) use supervise_builder::SuperviseBuilder;
 #[allow(unused_imports)]
 pub use supervisor_strategy::SupervisorStrategy;
 pub use supervisor_strategy_config::SupervisorStrategyConfig;
+pub use timer_scheduler::TimerScheduler;
+pub(crate) use timer_scheduler::TimerTick;
 
 pub(super) type ReceiveFn<U, R> = dyn for<'r, 'ctx> FnMut(&mut ActorContext<'r, 'ctx, U, R>, U) -> Result<BehaviorDirective<U, R>, ActorFailure>
   + 'static;
@@ -1,15 +1,15 @@
-use alloc::boxed::Box;
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
 use core::mem;
 
 use cellex_utils_core_rs::{collections::Element, sync::ArcShared};
 
-use super::{Behavior, BehaviorDirective, Signal, SignalFn, SupervisorStrategyConfig, SystemHandlerFn};
+use super::{Behavior, BehaviorDirective, Signal, SignalFn, SupervisorStrategyConfig, SystemHandlerFn, TimerScheduler};
 use crate::{
   api::{
     actor::{actor_context::ActorContext, actor_failure::ActorFailure},
     actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxQueueOf, MailboxSignalOf},
     mailbox::messages::SystemMessage,
-    messaging::MetadataStorageMode,
+    messaging::{MessageMetadata, MetadataStorageMode},
   },
   shared::{
     mailbox::messages::PriorityEnvelope,
@@ -24,10 +24,15 @@ where
   U: Element,
   AR: ActorRuntime + 'static,
   MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
-  MailboxSignalOf<AR>: Clone, {
+  MailboxSignalOf<AR>: Clone,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode, {
   behavior_factory:          ArcShared<dyn Fn() -> Behavior<U, AR> + 'static>,
   pub(super) behavior:       Behavior<U, AR>,
   pub(super) system_handler: Option<Box<SystemHandlerFn<U, AR>>>,
+  started:                   bool,
+  timers:                    Vec<TimerScheduler<U, AR>>,
+  /// Unstashed messages not handled yet, kept across failures.
+  unstashed:                 VecDeque<(U, Option<MessageMetadata<MailboxConcurrencyOf<AR>>>)>,
 }
 
 impl<U, AR> ActorAdapter<U, AR>
@@ -50,6 +55,9 @@ where
       behavior_factory,
       behavior,
       system_handler: system_handler.map(|h| Box::new(h) as Box<SystemHandlerFn<U, AR>>),
+      started: false,
+      timers: Vec::new(),
+      unstashed: VecDeque::new(),
     }
   }
 
@@ -58,10 +66,17 @@ where
   /// # Errors
   /// Returns [`ActorFailure`] when behavior transitions fail or remain in setup state.
   pub fn handle_user(&mut self, ctx: &mut ActorContext<'_, '_, U, AR>, message: U) -> Result<(), ActorFailure> {
-    self.ensure_initialized(ctx)?;
-    while matches!(self.behavior, Behavior::Setup { .. }) {
-      self.ensure_initialized(ctx)?;
+    self.ensure_started(ctx)?;
+    // A timer cancelled or replaced since it sent the message no longer wants it handled.
+    let tick = ctx.metadata.as_mut().and_then(MessageMetadata::take_timer_tick);
+    if tick.is_none_or(|tick| tick.claim()) {
+      // Messages a failure left unstashed are still handled before this one.
+      self.unstashed.push_back((message, ctx.metadata.take()));
     }
+    self.replay_unstashed(ctx)
+  }
+
+  fn receive(&mut self, ctx: &mut ActorContext<'_, '_, U, AR>, message: U) -> Result<(), ActorFailure> {
     match &mut self.behavior {
       | Behavior::Receive(state) => match state.handle(ctx, message)? {
         | BehaviorDirective::Same => {},
@@ -84,15 +99,19 @@ where
     ctx: &mut ActorContext<'_, '_, U, AR>,
     message: SystemMessage,
   ) -> Result<(), ActorFailure> {
-    self.ensure_initialized(ctx)?;
-    while matches!(self.behavior, Behavior::Setup { .. }) {
-      self.ensure_initialized(ctx)?;
-    }
-    if matches!(message, SystemMessage::Stop) {
-      self.transition(Behavior::stopped(), ctx)?;
-    } else if matches!(message, SystemMessage::Restart) {
-      self.behavior = (self.behavior_factory)();
-      self.ensure_initialized(ctx)?;
+    self.ensure_started(ctx)?;
+    match &message {
+      | SystemMessage::Stop => self.transition(Behavior::stopped(), ctx)?,
+      | SystemMessage::Restart => {
+        self.handle_signal(ctx, Signal::PreRestart)?;
+        self.cancel_timers();
+        self.behavior = (self.behavior_factory)();
+        self.ensure_initialized(ctx)?;
+        self.handle_signal(ctx, Signal::PostRestart)?;
+      },
+      | SystemMessage::Terminated(actor) => self.handle_signal(ctx, Signal::Terminated(*actor))?,
+      | SystemMessage::Failure(info) => self.handle_signal(ctx, Signal::ChildFailed(info.clone()))?,
+      | _ => {},
     }
     if matches!(self.behavior, Behavior::Setup { .. }) {
       return Err(ActorFailure::from_message("behavior remained in setup state"));
@@ -100,7 +119,7 @@ where
     if let Some(handler) = self.system_handler.as_mut() {
       handler(ctx, message);
     }
-    Ok(())
+    self.replay_unstashed(ctx)
   }
 
   /// Creates a SystemMessage mapper for Guardian/Scheduler.
@@ -114,6 +133,32 @@ where
     self.behavior.supervisor_config()
   }
 
+  /// Handles the messages unstashed so far, ahead of the mailbox.
+  ///
+  /// When one of them fails, it is dropped with the failure while the others stay queued, and are
+  /// replayed once the actor resumes or restarts.
+  fn replay_unstashed(&mut self, ctx: &mut ActorContext<'_, '_, U, AR>) -> Result<(), ActorFailure> {
+    self.unstashed.append(&mut ctx.unstashed);
+    while let Some((message, metadata)) = self.unstashed.pop_front() {
+      ctx.metadata = metadata;
+      let result = self.receive(ctx, message);
+      self.unstashed.append(&mut ctx.unstashed);
+      result?;
+    }
+    Ok(())
+  }
+
+  /// Signals PreStart on the first activation of the actor, ahead of the message activating it.
+  /// Every spawned actor is activated right away by the watch request of its parent.
+  fn ensure_started(&mut self, ctx: &mut ActorContext<'_, '_, U, AR>) -> Result<(), ActorFailure> {
+    self.ensure_initialized(ctx)?;
+    if !self.started {
+      self.started = true;
+      self.handle_signal(ctx, Signal::PreStart)?;
+    }
+    Ok(())
+  }
+
   fn ensure_initialized(&mut self, ctx: &mut ActorContext<'_, '_, U, AR>) -> Result<(), ActorFailure> {
     while matches!(self.behavior, Behavior::Setup { .. }) {
       let (init, signal) = match mem::replace(&mut self.behavior, Behavior::stopped()) {
@@ -127,9 +172,17 @@ where
       let next_behavior = if let Some(init) = init { init(ctx)? } else { Behavior::stopped() };
       self.behavior = next_behavior.attach_signal_arc(next_signal);
     }
+    self.timers.append(&mut ctx.timers);
     Ok(())
   }
 
+  /// Cancels the timers started by the current incarnation of the behavior.
+  fn cancel_timers(&mut self) {
+    for timers in self.timers.drain(..) {
+      timers.cancel_all();
+    }
+  }
+
   fn current_signal_handler(&self) -> Option<ArcShared<SignalFn<U, AR>>> {
     match &self.behavior {
       | Behavior::Receive(state) => state.signal_handler(),
@@ -138,7 +191,6 @@ where
     }
   }
 
-  #[allow(dead_code)]
   fn handle_signal(&mut self, ctx: &mut ActorContext<'_, '_, U, AR>, signal: Signal) -> Result<(), ActorFailure> {
     if let Some(handler) = self.current_signal_handler() {
       match handler(ctx, signal) {
@@ -154,6 +206,7 @@ where
     self.behavior = next;
     self.ensure_initialized(ctx)?;
     if matches!(self.behavior, Behavior::Stopped) {
+      self.cancel_timers();
       let mut handler = self.current_signal_handler();
       if handler.is_none() {
         handler = previous_handler;
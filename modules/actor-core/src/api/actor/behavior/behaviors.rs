@@ -1,13 +1,16 @@
-use cellex_utils_core_rs::collections::Element;
+use cellex_utils_core_rs::{collections::Element, sync::shared::SharedBound};
 
-use super::{Behavior, BehaviorDirective};
+use super::{Behavior, BehaviorDirective, StashBuffer, TimerScheduler};
 use crate::{
   api::{
     actor::{actor_context::ActorContext, actor_failure::ActorFailure, behavior::supervise_builder::SuperviseBuilder},
-    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxQueueOf, MailboxSignalOf},
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
     messaging::MetadataStorageMode,
   },
-  shared::{mailbox::messages::PriorityEnvelope, messaging::AnyMessage},
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
 };
 
 /// Behavior DSL builder.
@@ -96,4 +99,36 @@ impl Behaviors {
     F: for<'r, 'ctx> Fn(&mut ActorContext<'r, 'ctx, U, AR>) -> Result<Behavior<U, AR>, ActorFailure> + 'static, {
     Behavior::setup(init)
   }
+
+  /// Generates Behavior with a fresh [`StashBuffer`] holding up to `capacity` messages.
+  ///
+  /// The buffer is created anew whenever the behavior is set up, so a restart drops the messages
+  /// stashed before it.
+  pub fn with_stash<U, AR, F>(capacity: usize, factory: F) -> Behavior<U, AR>
+  where
+    U: Element,
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode,
+    F: Fn(StashBuffer<U, AR>) -> Behavior<U, AR> + 'static, {
+    Behavior::setup(move |_| Ok(factory(StashBuffer::new(capacity))))
+  }
+
+  /// Generates Behavior with a [`TimerScheduler`] sending messages to the actor itself.
+  ///
+  /// The scheduler is created anew whenever the behavior is set up; the timers started through it
+  /// are cancelled when the actor stops or restarts.
+  pub fn with_timers<U, AR, F>(factory: F) -> Behavior<U, AR>
+  where
+    U: Element,
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone + SharedBound + 'static,
+    MailboxSignalOf<AR>: Clone + SharedBound + 'static,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode + SharedBound,
+    F: Fn(TimerScheduler<U, AR>) -> Behavior<U, AR> + 'static, {
+    Behavior::setup(move |ctx| Ok(factory(TimerScheduler::new(ctx))))
+  }
 }
@@ -0,0 +1,112 @@
+use alloc::collections::VecDeque;
+
+use cellex_utils_core_rs::{
+  collections::Element,
+  sync::{sync_mutex_like::SyncMutexLike, ArcShared},
+};
+
+use super::StashOverflowError;
+use crate::{
+  api::{
+    actor::actor_context::ActorContext,
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::{MessageMetadata, MetadataStorageMode},
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
+};
+
+type StashedMessages<U, AR> = VecDeque<(U, Option<MessageMetadata<MailboxConcurrencyOf<AR>>>)>;
+
+/// Bounded buffer holding messages an actor is not ready to handle yet.
+///
+/// Obtained from [`Behaviors::with_stash`](super::Behaviors::with_stash). Stashed messages keep the
+/// metadata they arrived with, so replies still reach their senders once they are unstashed.
+/// [`StashBuffer::unstash_all`] hands them back to the actor, which handles them right after the
+/// current message with its current behavior, ahead of anything waiting in the mailbox.
+pub struct StashBuffer<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static, {
+  messages: ArcShared<AR::SyncMutex<StashedMessages<U, AR>>>,
+  capacity: usize,
+}
+
+impl<U, AR> Clone for StashBuffer<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static,
+{
+  fn clone(&self) -> Self {
+    Self { messages: self.messages.clone(), capacity: self.capacity }
+  }
+}
+
+impl<U, AR> StashBuffer<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+  MailboxSignalOf<AR>: Clone,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode,
+{
+  /// Creates an empty buffer holding up to `capacity` messages.
+  #[must_use]
+  pub fn new(capacity: usize) -> Self {
+    Self { messages: ArcShared::new(AR::SyncMutex::new(VecDeque::new())), capacity }
+  }
+
+  /// Returns how many messages the buffer holds at most.
+  #[must_use]
+  pub const fn capacity(&self) -> usize {
+    self.capacity
+  }
+
+  /// Returns how many messages are stashed.
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.messages.lock().len()
+  }
+
+  /// Returns whether no message is stashed.
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.messages.lock().is_empty()
+  }
+
+  /// Returns whether the buffer cannot take any more messages.
+  #[must_use]
+  pub fn is_full(&self) -> bool {
+    self.len() >= self.capacity
+  }
+
+  /// Stashes `message`, moving the metadata of the message being handled by `ctx` along with it.
+  ///
+  /// # Errors
+  /// Returns [`StashOverflowError`] carrying `message` back when the buffer is full; the metadata
+  /// then stays with `ctx`.
+  pub fn stash(&self, ctx: &mut ActorContext<'_, '_, U, AR>, message: U) -> Result<(), StashOverflowError<U>> {
+    let mut messages = self.messages.lock();
+    if messages.len() >= self.capacity {
+      return Err(StashOverflowError::new(message, self.capacity));
+    }
+    // The metadata moves rather than being cloned: dropping a responder handle settles its ask.
+    messages.push_back((message, ctx.metadata.take()));
+    Ok(())
+  }
+
+  /// Hands every stashed message back to the actor, in stashing order. They are handled once the
+  /// current message is done, before any message waiting in the mailbox, by the behavior the actor
+  /// holds at that point.
+  pub fn unstash_all(&self, ctx: &mut ActorContext<'_, '_, U, AR>) {
+    ctx.unstashed.extend(self.messages.lock().drain(..));
+  }
+
+  /// Drops every stashed message.
+  pub fn clear(&self) {
+    self.messages.lock().clear();
+  }
+}
@@ -0,0 +1,39 @@
+use core::fmt;
+
+/// Error returned when a message is stashed into a full [`StashBuffer`](super::StashBuffer).
+///
+/// The rejected message is handed back so the actor can drop it, reply to its sender or fail.
+#[derive(Debug)]
+pub struct StashOverflowError<U> {
+  message:  U,
+  capacity: usize,
+}
+
+impl<U> StashOverflowError<U> {
+  pub(crate) const fn new(message: U, capacity: usize) -> Self {
+    Self { message, capacity }
+  }
+
+  /// Returns the capacity of the buffer that rejected the message.
+  #[must_use]
+  pub const fn capacity(&self) -> usize {
+    self.capacity
+  }
+
+  /// Returns the rejected message.
+  #[must_use]
+  pub const fn message(&self) -> &U {
+    &self.message
+  }
+
+  /// Consumes the error and returns the rejected message.
+  pub fn into_message(self) -> U {
+    self.message
+  }
+}
+
+impl<U> fmt::Display for StashOverflowError<U> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "stash buffer is full (capacity {})", self.capacity)
+  }
+}
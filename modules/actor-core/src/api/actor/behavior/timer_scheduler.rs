@@ -0,0 +1,221 @@
+use alloc::{collections::BTreeMap, string::String};
+use core::{fmt, time::Duration};
+
+use cellex_utils_core_rs::{
+  collections::Element,
+  sync::{shared::SharedBound, ArcShared},
+  timing::deadline_timer::DeadlineTimerKey,
+};
+use spin::Mutex;
+
+use crate::{
+  api::{
+    actor::actor_context::ActorContext,
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::{MessageMetadata, MessageSender, MetadataStorageMode},
+    timer_driver::{timer_driver_extension_id, TimerDriverExtension, TimerDriverShared, TimerMode, TimerTaskFn},
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::{AnyMessage, MessageEnvelope},
+  },
+};
+
+/// Timer started through a [`TimerScheduler`], as seen by the driver.
+struct ActiveTimer {
+  driver_key: DeadlineTimerKey,
+  generation: u64,
+}
+
+/// Timers of one scheduler, keyed by the name they were started with.
+#[derive(Default)]
+struct TimerTable {
+  timers:          BTreeMap<String, ActiveTimer>,
+  next_generation: u64,
+}
+
+impl TimerTable {
+  /// Returns whether the timer running under `key` is still the one started as `generation`.
+  fn is_current(&self, key: &str, generation: u64) -> bool {
+    self.timers.get(key).is_some_and(|timer| timer.generation == generation)
+  }
+
+  /// Claims a tick of the timer started as `generation`, forgetting single-shot timers once their
+  /// tick is handled.
+  fn claim(&mut self, key: &str, generation: u64, mode: TimerMode) -> bool {
+    if !self.is_current(key, generation) {
+      return false;
+    }
+    if mode == TimerMode::Single {
+      self.timers.remove(key);
+    }
+    true
+  }
+}
+
+/// Mark of a message sent by a timer, checked once the message is taken out of the mailbox.
+#[derive(Clone)]
+pub(crate) struct TimerTick {
+  timers:     ArcShared<Mutex<TimerTable>>,
+  key:        String,
+  generation: u64,
+  mode:       TimerMode,
+}
+
+impl TimerTick {
+  /// Returns whether the message is still due, that is whether its timer was neither cancelled
+  /// nor replaced since the message was sent.
+  pub(crate) fn claim(&self) -> bool {
+    self.timers.lock().claim(&self.key, self.generation, self.mode)
+  }
+}
+
+impl fmt::Debug for TimerTick {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("TimerTick").field("key", &self.key).field("generation", &self.generation).finish()
+  }
+}
+
+/// Keyed timers sending messages to the actor that owns them.
+///
+/// Obtained from [`Behaviors::with_timers`](super::Behaviors::with_timers). Starting a timer under
+/// a key that is already in use replaces the previous timer, and every timer is cancelled when the
+/// actor stops or restarts. Timers are driven by the
+/// [`TimerDriver`](crate::api::timer_driver::TimerDriver) of the actor runtime; without one,
+/// starting a timer has no effect. A message still in the mailbox when its timer is cancelled or
+/// replaced is dropped before the actor handles it.
+pub struct TimerScheduler<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+  driver: Option<TimerDriverShared>,
+  target: MessageSender<U, MailboxConcurrencyOf<AR>>,
+  timers: ArcShared<Mutex<TimerTable>>,
+}
+
+impl<U, AR> Clone for TimerScheduler<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode,
+{
+  fn clone(&self) -> Self {
+    Self {
+      driver: self.driver.clone(),
+      target: MessageSender::new(self.target.internal()),
+      timers: self.timers.clone(),
+    }
+  }
+}
+
+impl<U, AR> TimerScheduler<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone + SharedBound + 'static,
+  MailboxSignalOf<AR>: Clone + SharedBound + 'static,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode + SharedBound,
+{
+  /// Creates a scheduler for the actor behind `ctx`, whose timers are cancelled once it stops or
+  /// restarts.
+  pub(crate) fn new(ctx: &mut ActorContext<'_, '_, U, AR>) -> Self {
+    let driver = ctx.extension::<TimerDriverExtension, _, _>(timer_driver_extension_id(), |ext| ext.driver());
+    let scheduler =
+      Self { driver, target: ctx.self_dispatcher(), timers: ArcShared::new(Mutex::new(TimerTable::default())) };
+    ctx.timers.push(scheduler.clone());
+    scheduler
+  }
+
+  /// Sends `message` once, after `delay`.
+  ///
+  /// Returns `false` when the actor runtime provides no timer driver.
+  pub fn start_single_timer(&self, key: impl Into<String>, message: U, delay: Duration) -> bool {
+    let message = Mutex::new(Some(message));
+    self.start(key.into(), delay, TimerMode::Single, move || message.lock().take())
+  }
+
+  /// Sends `message` every `interval`, catching up with ticks that were delayed.
+  ///
+  /// Returns `false` when the actor runtime provides no timer driver.
+  pub fn start_timer_at_fixed_rate(&self, key: impl Into<String>, message: U, interval: Duration) -> bool
+  where
+    U: Clone, {
+    self.start(key.into(), interval, TimerMode::FixedRate(interval), move || Some(message.clone()))
+  }
+
+  /// Sends `message` repeatedly, waiting `delay` after each tick before the next one.
+  ///
+  /// Returns `false` when the actor runtime provides no timer driver.
+  pub fn start_timer_with_fixed_delay(&self, key: impl Into<String>, message: U, delay: Duration) -> bool
+  where
+    U: Clone, {
+    self.start(key.into(), delay, TimerMode::FixedDelay(delay), move || Some(message.clone()))
+  }
+
+  fn start<F>(&self, key: String, delay: Duration, mode: TimerMode, next_message: F) -> bool
+  where
+    F: Fn() -> Option<U> + SharedBound + 'static, {
+    let Some(driver) = self.driver.as_ref() else {
+      return false;
+    };
+    // The table stays locked until the new timer is recorded, so that its first tick cannot be
+    // mistaken for a stale one.
+    let mut table = self.timers.lock();
+    if let Some(previous) = table.timers.remove(&key) {
+      driver.cancel(previous.driver_key);
+    }
+    let generation = table.next_generation;
+    table.next_generation = table.next_generation.wrapping_add(1);
+
+    let timers = self.timers.clone();
+    let target = MessageSender::<U, MailboxConcurrencyOf<AR>>::new(self.target.internal());
+    let name = key.clone();
+    let task = ArcShared::new(move || {
+      if !timers.lock().is_current(&name, generation) {
+        return;
+      }
+      if let Some(message) = next_message() {
+        let tick = TimerTick { timers: timers.clone(), key: name.clone(), generation, mode };
+        let metadata = MessageMetadata::<MailboxConcurrencyOf<AR>>::new().with_timer_tick(tick);
+        let _ = target.dispatch_envelope(MessageEnvelope::user_with_metadata(message, metadata));
+      }
+    })
+    .into_dyn(|task| task as &TimerTaskFn);
+    let driver_key = driver.start(delay, mode, task);
+    table.timers.insert(key, ActiveTimer { driver_key, generation });
+    true
+  }
+}
+
+impl<U, AR> TimerScheduler<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode,
+{
+  /// Returns whether a timer is running under `key`.
+  #[must_use]
+  pub fn is_timer_active(&self, key: &str) -> bool {
+    self.timers.lock().timers.contains_key(key)
+  }
+
+  /// Cancels the timer running under `key`, if any.
+  pub fn cancel(&self, key: &str) {
+    let removed = self.timers.lock().timers.remove(key);
+    if let (Some(driver), Some(timer)) = (self.driver.as_ref(), removed) {
+      driver.cancel(timer.driver_key);
+    }
+  }
+
+  /// Cancels every timer of this scheduler.
+  pub fn cancel_all(&self) {
+    let timers = core::mem::take(&mut self.timers.lock().timers);
+    if let Some(driver) = self.driver.as_ref() {
+      for timer in timers.into_values() {
+        driver.cancel(timer.driver_key);
+      }
+    }
+  }
+}
@@ -1,6 +1,21 @@
+use crate::api::{actor::ActorId, failure::FailureInfo};
+
 /// Actor lifecycle signals.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///
+/// Signals are `Clone` but not `Copy`, as [`Signal::ChildFailed`] carries the failure.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Signal {
+  /// Signal sent once the actor is spawned and its behavior initialized, before any message is
+  /// handled.
+  PreStart,
   /// Signal sent after the actor stops.
   PostStop,
+  /// Signal sent to the failed behavior before the supervisor restarts it.
+  PreRestart,
+  /// Signal sent to the fresh behavior once a restart rebuilt it.
+  PostRestart,
+  /// Signal sent when an actor watched through `ActorContext::watch` stops.
+  Terminated(ActorId),
+  /// Signal sent to the parent when the supervisor stops a child because of a failure.
+  ChildFailed(FailureInfo),
 }
@@ -40,17 +40,19 @@ use crate::{
   api::{
     actor::{
       actor_context::ActorContext,
+      actor_failure::ActorFailure,
       actor_ref::ActorRef,
       ask::{ask_with_timeout, AskError},
       behavior::{Behavior, Behaviors},
       message_adapter_ref::MessageAdapterRef,
       props::Props,
       signal::Signal,
-      ActorId,
+      ActorId, ActorPath,
     },
     actor_runtime::{ActorRuntime, GenericActorRuntime, MailboxQueueOf, MailboxSignalOf},
     actor_system::{GenericActorSystem, GenericActorSystemConfig},
     extensions::{next_extension_id, Extension, ExtensionId},
+    failure::FailureInfo,
     mailbox::messages::SystemMessage,
     messaging::{MessageMetadata, MessageSender},
     test_support::TestMailboxFactory,
@@ -420,7 +422,9 @@ fn test_supervise_stop_on_failure() {
   let failures: StdArc<Mutex<Vec<String>>> = StdArc::new(Mutex::new(Vec::new()));
   let failures_clone = StdArc::clone(&failures);
   let listener = FailureEventListener::new(move |event: FailureEvent| {
-    let FailureEvent::RootEscalated(info) = event;
+    let FailureEvent::RootEscalated(info) = event else {
+      return;
+    };
     failures_clone.lock().unwrap().push(info.description().into_owned());
   });
 
@@ -1059,6 +1063,7 @@ fn test_receive_signal_post_stop() {
       .receive_signal(move |_, signal| {
         match signal {
           | Signal::PostStop => signals_cell.borrow_mut().push("post_stop"),
+          | _ => {},
         }
         Behaviors::same()
       })
@@ -1074,6 +1079,343 @@ fn test_receive_signal_post_stop() {
   assert_eq!(signals.borrow().as_slice(), &["post_stop"]);
 }
 
+#[test]
+fn test_watch_delivers_terminated_signal_for_any_watched_actor() {
+  let mailbox_factory = TestMailboxFactory::unbounded();
+  let actor_runtime = GenericActorRuntime::new(mailbox_factory);
+  let mut system: GenericActorSystem<u32, _, AlwaysRestart> =
+    GenericActorSystem::new_with_actor_runtime(actor_runtime, GenericActorSystemConfig::default());
+  let mut root = system.root_context();
+
+  let target = root.spawn(Props::new(|_, _: u32| Ok(()))).expect("spawn target");
+  let target_id = target.pid().and_then(|pid| pid.path().last()).expect("target id");
+  let terminated: Rc<RefCell<Vec<ActorId>>> = Rc::new(RefCell::new(Vec::new()));
+
+  let props = Props::with_behavior({
+    let target = target.clone();
+    let terminated = terminated.clone();
+    move || {
+      let target = target.clone();
+      let terminated = terminated.clone();
+      Behaviors::receive(move |ctx: &mut ActorContext<'_, '_, u32, _>, msg: u32| {
+        if msg == 1 {
+          ctx.watch(&target).expect("watch");
+        } else {
+          ctx.unwatch(&target);
+        }
+        Ok(Behaviors::same())
+      })
+      .receive_signal(move |_, signal| {
+        if let Signal::Terminated(actor) = signal {
+          terminated.borrow_mut().push(actor);
+        }
+        Behaviors::same()
+      })
+    }
+  });
+  let watcher = root.spawn(props).expect("spawn watcher");
+
+  watcher.tell(1).expect("tell watch");
+  system.run_until_idle().expect("run watch");
+  target.send_system(SystemMessage::Stop).expect("send stop");
+  system.run_until_idle().expect("run stop");
+  assert_eq!(terminated.borrow().as_slice(), &[target_id]);
+
+  // Watching an actor that already stopped reports it right away.
+  watcher.tell(1).expect("tell watch");
+  system.run_until_idle().expect("run watch");
+  assert_eq!(terminated.borrow().as_slice(), &[target_id, target_id]);
+}
+
+#[test]
+fn test_watch_queued_behind_a_stop_still_delivers_terminated() {
+  let mailbox_factory = TestMailboxFactory::unbounded();
+  let actor_runtime = GenericActorRuntime::new(mailbox_factory);
+  let mut system: GenericActorSystem<u32, _, AlwaysRestart> =
+    GenericActorSystem::new_with_actor_runtime(actor_runtime, GenericActorSystemConfig::default());
+  let mut root = system.root_context();
+
+  let target = root.spawn(Props::new(|_, _: u32| Ok(()))).expect("spawn target");
+  let target_id = target.pid().and_then(|pid| pid.path().last()).expect("target id");
+  system.run_until_idle().expect("run start");
+  let terminated: Rc<RefCell<Vec<ActorId>>> = Rc::new(RefCell::new(Vec::new()));
+
+  let props = Props::with_behavior({
+    let terminated = terminated.clone();
+    move || {
+      let target = target.clone();
+      let terminated = terminated.clone();
+      Behaviors::receive(move |ctx: &mut ActorContext<'_, '_, u32, _>, _: u32| {
+        // The stop outranks the watch request queued right after it.
+        target.send_system(SystemMessage::Stop).expect("send stop");
+        ctx.watch(&target).expect("watch");
+        Ok(Behaviors::same())
+      })
+      .receive_signal(move |_, signal| {
+        if let Signal::Terminated(actor) = signal {
+          terminated.borrow_mut().push(actor);
+        }
+        Behaviors::same()
+      })
+    }
+  });
+  let watcher = system.root_context().spawn(props).expect("spawn watcher");
+
+  watcher.tell(1).expect("tell");
+  system.run_until_idle().expect("run");
+  assert_eq!(terminated.borrow().as_slice(), &[target_id]);
+}
+
+#[test]
+fn test_watch_with_delivers_custom_message_until_unwatched() {
+  let mailbox_factory = TestMailboxFactory::unbounded();
+  let actor_runtime = GenericActorRuntime::new(mailbox_factory);
+  let mut system: GenericActorSystem<u32, _, AlwaysRestart> =
+    GenericActorSystem::new_with_actor_runtime(actor_runtime, GenericActorSystemConfig::default());
+
+  let received: Rc<RefCell<Vec<u32>>> = Rc::new(RefCell::new(Vec::new()));
+  let terminated: Rc<RefCell<Vec<ActorId>>> = Rc::new(RefCell::new(Vec::new()));
+
+  let props = Props::with_behavior({
+    let received = received.clone();
+    let terminated = terminated.clone();
+    move || {
+      let received = received.clone();
+      let terminated = terminated.clone();
+      Behaviors::receive(move |ctx: &mut ActorContext<'_, '_, u32, _>, msg: u32| {
+        received.borrow_mut().push(msg);
+        let child = match msg {
+          | 1 => {
+            let child = ctx.spawn_child(Props::new(|_, _: u32| Ok(())));
+            ctx.watch_with(&child, 100).expect("watch child");
+            child
+          },
+          | 2 => {
+            let child = ctx.spawn_child(Props::new(|_, _: u32| Ok(())));
+            ctx.watch(&child).expect("watch child");
+            ctx.unwatch(&child);
+            child
+          },
+          | _ => return Ok(Behaviors::same()),
+        };
+        child.send_system(SystemMessage::Stop).expect("stop child");
+        Ok(Behaviors::same())
+      })
+      .receive_signal(move |_, signal| {
+        if let Signal::Terminated(actor) = signal {
+          terminated.borrow_mut().push(actor);
+        }
+        Behaviors::same()
+      })
+    }
+  });
+
+  let watcher = system.root_context().spawn(props).expect("spawn watcher");
+  watcher.tell(1).expect("tell");
+  system.run_until_idle().expect("run");
+  watcher.tell(2).expect("tell");
+  system.run_until_idle().expect("run");
+
+  assert_eq!(received.borrow().as_slice(), &[1, 100, 2]);
+  assert!(terminated.borrow().is_empty(), "unwatched children must not be reported");
+}
+
+#[test]
+fn test_pre_start_is_signalled_before_any_message_arrives() {
+  let mailbox_factory = TestMailboxFactory::unbounded();
+  let actor_runtime = GenericActorRuntime::new(mailbox_factory);
+  let mut system: GenericActorSystem<u32, _, AlwaysRestart> =
+    GenericActorSystem::new_with_actor_runtime(actor_runtime, GenericActorSystemConfig::default());
+
+  let signals: Rc<RefCell<Vec<Signal>>> = Rc::new(RefCell::new(Vec::new()));
+  let props = Props::with_behavior({
+    let signals = signals.clone();
+    move || {
+      let signals = signals.clone();
+      Behaviors::receive(|_, _: u32| Ok(Behaviors::same())).receive_signal(move |_, signal| {
+        signals.borrow_mut().push(signal);
+        Behaviors::same()
+      })
+    }
+  });
+  let _actor_ref = system.root_context().spawn(props).expect("spawn actor");
+
+  system.run_until_idle().expect("run");
+  assert_eq!(signals.borrow().as_slice(), &[Signal::PreStart]);
+}
+
+#[test]
+fn test_receive_signal_observes_start_restart_and_child_failure() {
+  let mailbox_factory = TestMailboxFactory::unbounded();
+  let actor_runtime = GenericActorRuntime::new(mailbox_factory);
+  let mut system: GenericActorSystem<u32, _, AlwaysRestart> =
+    GenericActorSystem::new_with_actor_runtime(actor_runtime, GenericActorSystemConfig::default());
+
+  let signals: Rc<RefCell<Vec<Signal>>> = Rc::new(RefCell::new(Vec::new()));
+  let props = Props::with_behavior({
+    let signals = signals.clone();
+    move || {
+      let signals = signals.clone();
+      Behaviors::receive(
+        |_, msg: u32| {
+          if msg == 0 {
+            Err(ActorFailure::from_message("boom"))
+          } else {
+            Ok(Behaviors::same())
+          }
+        },
+      )
+      .receive_signal(move |_, signal| {
+        signals.borrow_mut().push(signal);
+        Behaviors::same()
+      })
+    }
+  });
+  let actor_ref = system.root_context().spawn(props).expect("spawn actor");
+
+  actor_ref.tell(1).expect("tell");
+  system.run_until_idle().expect("run");
+  assert_eq!(signals.borrow().as_slice(), &[Signal::PreStart]);
+
+  actor_ref.tell(0).expect("tell failure");
+  system.run_until_idle().expect("run restart");
+  assert_eq!(signals.borrow().as_slice(), &[Signal::PreStart, Signal::PreRestart, Signal::PostRestart]);
+
+  let failure = FailureInfo::new(ActorId(42), ActorPath::new(), ActorFailure::from_message("child"));
+  actor_ref.send_system(SystemMessage::Failure(failure.clone())).expect("send failure");
+  system.run_until_idle().expect("run failure");
+  assert_eq!(signals.borrow().last(), Some(&Signal::ChildFailed(failure)));
+}
+
+#[test]
+fn test_with_stash_replays_messages_before_the_mailbox_with_their_senders() {
+  let mailbox_factory = TestMailboxFactory::unbounded();
+  let actor_runtime = GenericActorRuntime::new(mailbox_factory);
+  let mut system: GenericActorSystem<u32, _, AlwaysRestart> =
+    GenericActorSystem::new_with_actor_runtime(actor_runtime, GenericActorSystemConfig::default());
+
+  let handled: Rc<RefCell<Vec<u32>>> = Rc::new(RefCell::new(Vec::new()));
+  let props = Props::with_behavior({
+    let handled = handled.clone();
+    move || {
+      let handled = handled.clone();
+      Behaviors::with_stash(8, move |stash| {
+        let handled = handled.clone();
+        Behaviors::receive(move |ctx: &mut ActorContext<'_, '_, u32, _>, msg: u32| {
+          if msg != 0 {
+            stash.stash(ctx, msg).map_err(ActorFailure::from_error)?;
+            return Ok(Behaviors::same());
+          }
+          stash.unstash_all(ctx);
+          let handled = handled.clone();
+          Ok(Behaviors::transition(Behaviors::receive(move |ctx: &mut ActorContext<'_, '_, u32, _>, msg: u32| {
+            handled.borrow_mut().push(msg);
+            if let Some(responder) = ctx.message_metadata().and_then(|metadata| metadata.responder_as::<u32>()) {
+              responder.dispatch_user(msg * 2).expect("respond");
+            }
+            Ok(Behaviors::same())
+          })))
+        })
+      })
+    }
+  });
+
+  let (actor_ref, first, second) = {
+    let mut root = system.root_context();
+    let actor_ref = root.spawn(props).expect("spawn actor");
+    let first = root.request_future::<u32, u32>(&actor_ref, 1).expect("request first");
+    let second = root.request_future::<u32, u32>(&actor_ref, 2).expect("request second");
+    (actor_ref, first, second)
+  };
+  actor_ref.tell(0).expect("tell ready");
+  actor_ref.tell(3).expect("tell after ready");
+  system.run_until_idle().expect("run");
+
+  assert_eq!(handled.borrow().as_slice(), &[1, 2, 3]);
+  assert_eq!(block_on(first).expect("first response"), 2);
+  assert_eq!(block_on(second).expect("second response"), 4);
+}
+
+#[test]
+fn test_unstashed_messages_survive_a_failure_while_replaying() {
+  let mailbox_factory = TestMailboxFactory::unbounded();
+  let actor_runtime = GenericActorRuntime::new(mailbox_factory);
+  let mut system: GenericActorSystem<u32, _, AlwaysRestart> =
+    GenericActorSystem::new_with_actor_runtime(actor_runtime, GenericActorSystemConfig::default());
+
+  let handled: Rc<RefCell<Vec<u32>>> = Rc::new(RefCell::new(Vec::new()));
+  let props = Props::with_behavior({
+    let handled = handled.clone();
+    move || {
+      let handled = handled.clone();
+      Behaviors::with_stash(8, move |stash| {
+        let handled = handled.clone();
+        Behaviors::receive(move |ctx: &mut ActorContext<'_, '_, u32, _>, msg: u32| {
+          if msg != 0 {
+            stash.stash(ctx, msg).map_err(ActorFailure::from_error)?;
+            return Ok(Behaviors::same());
+          }
+          stash.unstash_all(ctx);
+          let handled = handled.clone();
+          Ok(Behaviors::transition(Behaviors::receive(move |_, msg: u32| {
+            handled.borrow_mut().push(msg);
+            if msg == 2 {
+              return Err(ActorFailure::from_message("replay failed"));
+            }
+            Ok(Behaviors::same())
+          })))
+        })
+      })
+    }
+  });
+
+  let actor_ref = system.root_context().spawn(props).expect("spawn actor");
+  for msg in [1, 2, 3, 0] {
+    actor_ref.tell(msg).expect("tell");
+  }
+  system.run_until_idle().expect("run");
+  assert_eq!(handled.borrow().as_slice(), &[1, 2]);
+
+  // The restarted actor stashes the message left over by the failure again.
+  actor_ref.tell(0).expect("tell ready again");
+  system.run_until_idle().expect("run after restart");
+  assert_eq!(handled.borrow().as_slice(), &[1, 2, 3]);
+}
+
+#[test]
+fn test_stash_buffer_hands_back_messages_once_full() {
+  let mailbox_factory = TestMailboxFactory::unbounded();
+  let actor_runtime = GenericActorRuntime::new(mailbox_factory);
+  let mut system: GenericActorSystem<u32, _, AlwaysRestart> =
+    GenericActorSystem::new_with_actor_runtime(actor_runtime, GenericActorSystemConfig::default());
+
+  let rejected: Rc<RefCell<Vec<(u32, usize)>>> = Rc::new(RefCell::new(Vec::new()));
+  let props = Props::with_behavior({
+    let rejected = rejected.clone();
+    move || {
+      let rejected = rejected.clone();
+      Behaviors::with_stash(2, move |stash| {
+        let rejected = rejected.clone();
+        Behaviors::receive(move |ctx: &mut ActorContext<'_, '_, u32, _>, msg: u32| {
+          if let Err(overflow) = stash.stash(ctx, msg) {
+            rejected.borrow_mut().push((*overflow.message(), overflow.capacity()));
+          }
+          assert!(stash.len() <= stash.capacity());
+          Ok(Behaviors::same())
+        })
+      })
+    }
+  });
+
+  let actor_ref = system.root_context().spawn(props).expect("spawn actor");
+  for msg in 1..=4 {
+    actor_ref.tell(msg).expect("tell");
+  }
+  system.run_until_idle().expect("run");
+
+  assert_eq!(rejected.borrow().as_slice(), &[(3, 2), (4, 2)]);
+}
+
 fn noop_waker() -> Waker {
   fn clone(_: *const ()) -> RawWaker {
     noop_raw_waker()
@@ -9,6 +9,7 @@ use crate::{
     failure::failure_event_stream::FailureEventListener,
     metrics::MetricsSinkShared,
     receive_timeout::{ReceiveTimeoutSchedulerFactoryProviderShared, ReceiveTimeoutSchedulerFactoryShared},
+    timer_driver::TimerDriverShared,
   },
   internal::mailbox::PriorityMailboxSpawnerHandle,
   shared::{
@@ -35,6 +36,7 @@ pub type MailboxConcurrencyOf<R> = <MailboxOf<R> as MailboxFactory>::Concurrency
 /// This trait provides a facade over a [`MailboxFactory`], adding actor-system-level features such
 /// as:
 /// - Receive timeout configuration
+/// - Timer driver for actor timers
 /// - Failure event listeners and escalation handlers
 /// - Metrics integration
 /// - Scheduler builder configuration
@@ -90,6 +92,22 @@ pub trait ActorRuntime: Clone {
   where
     Self: Sized;
 
+  /// Returns the timer driver backing actor timers, if the runtime provides one.
+  ///
+  /// Defaults to `None`, leaving actors unable to start timers.
+  fn timer_driver_shared_opt(&self) -> Option<TimerDriverShared> {
+    None
+  }
+
+  /// Overrides the timer driver backing actor timers.
+  ///
+  /// Defaults to returning `self` unchanged, for runtimes that cannot hold a timer driver.
+  fn with_timer_driver_shared_opt(self, _driver: Option<TimerDriverShared>) -> Self
+  where
+    Self: Sized, {
+    self
+  }
+
   /// Returns the root failure event listener configured for the runtime.
   fn root_failure_event_listener_opt(&self) -> Option<FailureEventListener>;
 
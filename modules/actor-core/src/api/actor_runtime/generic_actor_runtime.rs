@@ -13,6 +13,7 @@ use crate::{
       NoopReceiveTimeoutSchedulerFactoryProvider, ReceiveTimeoutSchedulerFactoryProviderShared,
       ReceiveTimeoutSchedulerFactoryShared,
     },
+    timer_driver::TimerDriverShared,
   },
   internal::{mailbox::PriorityMailboxSpawnerHandle, GenericActorRuntimeState},
   shared::{
@@ -37,6 +38,7 @@ where
     Option<ReceiveTimeoutSchedulerFactoryShared<AnyMessage, BundleMailbox<MF>>>,
   receive_timeout_scheduler_factory_provider_shared_opt:
     Option<ReceiveTimeoutSchedulerFactoryProviderShared<BundleMailbox<MF>>>,
+  timer_driver_shared_opt: Option<TimerDriverShared>,
   root_failure_event_listener_opt: Option<FailureEventListener>,
   root_escalation_failure_event_handler_opt: Option<FailureEventHandler>,
   metrics_sink_opt: Option<MetricsSinkShared>,
@@ -57,6 +59,7 @@ where
       receive_timeout_scheduler_factory_provider_shared_opt: Some(ReceiveTimeoutSchedulerFactoryProviderShared::new(
         NoopReceiveTimeoutSchedulerFactoryProvider,
       )),
+      timer_driver_shared_opt: None,
       root_failure_event_listener_opt: None,
       root_escalation_failure_event_handler_opt: None,
       metrics_sink_opt: None,
@@ -135,6 +138,19 @@ where
     self.receive_timeout_scheduler_factory_provider_shared_opt.as_ref().map(|driver| driver.build_factory())
   }
 
+  /// Returns the timer driver backing actor timers, if any.
+  #[must_use]
+  pub fn timer_driver_shared_opt(&self) -> Option<TimerDriverShared> {
+    self.timer_driver_shared_opt.clone()
+  }
+
+  /// Sets the timer driver backing actor timers and returns the updated bundle.
+  #[must_use]
+  pub fn with_timer_driver_shared_opt(mut self, driver: Option<TimerDriverShared>) -> Self {
+    self.timer_driver_shared_opt = driver;
+    self
+  }
+
   /// Returns the configured root failure event listener.
   #[must_use]
   pub fn root_failure_event_listener_opt(&self) -> Option<FailureEventListener> {
@@ -261,6 +277,14 @@ where
     GenericActorRuntime::with_receive_timeout_scheduler_factory_provider_shared_opt(self, driver)
   }
 
+  fn timer_driver_shared_opt(&self) -> Option<TimerDriverShared> {
+    GenericActorRuntime::timer_driver_shared_opt(self)
+  }
+
+  fn with_timer_driver_shared_opt(self, driver: Option<TimerDriverShared>) -> Self {
+    GenericActorRuntime::with_timer_driver_shared_opt(self, driver)
+  }
+
   fn root_failure_event_listener_opt(&self) -> Option<FailureEventListener> {
     GenericActorRuntime::root_failure_event_listener_opt(self)
   }
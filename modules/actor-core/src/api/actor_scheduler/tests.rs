@@ -1056,6 +1056,7 @@ fn scheduler_root_event_listener_broadcasts() {
     | crate::api::failure::FailureEvent::RootEscalated(info) => {
       received_clone.lock().unwrap().push(info.clone());
     },
+    | _ => {},
   }));
 
   scheduler.set_root_event_listener(Some(hub.listener()));
@@ -22,6 +22,7 @@ use crate::{
       pid::{NodeId, SystemId},
       process_registry::ProcessRegistry,
     },
+    timer_driver::{timer_driver_extension_id, TimerDriverExtension},
   },
   internal::actor_system::{InternalActorSystem, InternalGenericActorSystemConfig},
   shared::{mailbox::messages::PriorityEnvelope, messaging::AnyMessage},
@@ -77,6 +78,11 @@ where
       let extension = ArcShared::new(SerializerRegistryExtension::new());
       extensions_handle.register(extension);
     }
+    if let Some(driver) = actor_runtime.timer_driver_shared_opt() {
+      if extensions_handle.get(timer_driver_extension_id()).is_none() {
+        extensions_handle.register(ArcShared::new(TimerDriverExtension::new(driver)));
+      }
+    }
     let extensions = extensions_handle;
 
     let receive_timeout_scheduler_factory_shared_opt = config
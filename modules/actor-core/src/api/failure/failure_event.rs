@@ -1,9 +1,19 @@
 use super::FailureInfo;
+use crate::api::process::pid::NodeId;
 
 /// Failure event. Represents a failure that occurred within the actor system.
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub enum FailureEvent {
   /// Failure escalated to root actor.
   /// Used when no further escalation is possible.
   RootEscalated(FailureInfo),
+  /// The failure detector stopped receiving heartbeats from a remote node.
+  NodeUnreachable(NodeId),
+  /// A node previously reported unreachable answers heartbeats again.
+  NodeReachable(NodeId),
+  /// A node stayed unreachable past the quarantine timeout and will not be reconnected.
+  NodeQuarantined(NodeId),
+  /// A cluster member was downed, e.g. by a split-brain resolver ending a network partition.
+  NodeDowned(NodeId),
 }
@@ -189,6 +189,12 @@ where
           #[allow(clippy::redundant_closure)]
           let envelope = PriorityEnvelope::from_system(SystemMessage::Stop).map(move |sys| map_clone(sys));
           record.control_ref.try_send_envelope_mailbox(envelope).map_err(|error| QueueError::from(error))?;
+          if let Some((parent_ref, parent_map)) = record.watcher.and_then(|watcher| self.child_route(watcher)) {
+            #[allow(clippy::redundant_closure)]
+            let envelope =
+              PriorityEnvelope::from_system(SystemMessage::Failure(failure)).map(move |sys| parent_map(sys));
+            let _ = parent_ref.try_send_envelope_mailbox(envelope);
+          }
           Ok(None)
         } else {
           Ok(Some(failure))
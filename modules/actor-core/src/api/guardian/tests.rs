@@ -63,18 +63,19 @@ fn guardian_sends_restart_message() {
   assert_eq!(channel, PriorityChannel::Control);
 }
 
-#[test]
-fn guardian_sends_stop_message() {
-  struct AlwaysStop;
-  impl<MF> GuardianStrategy<MF> for AlwaysStop
-  where
-    MF: MailboxFactory,
-  {
-    fn decide(&mut self, _actor: ActorId, _error: &dyn BehaviorFailure) -> SupervisorDirective {
-      SupervisorDirective::Stop
-    }
+struct AlwaysStop;
+
+impl<MF> GuardianStrategy<MF> for AlwaysStop
+where
+  MF: MailboxFactory,
+{
+  fn decide(&mut self, _actor: ActorId, _error: &dyn BehaviorFailure) -> SupervisorDirective {
+    SupervisorDirective::Stop
   }
+}
 
+#[test]
+fn guardian_sends_stop_message() {
   let (mailbox, sender) = TestMailboxFactory::unbounded().build_default_mailbox::<PriorityEnvelope<AnyMessage>>();
   let ref_control: PriorityActorRef<AnyMessage, TestMailboxFactory> = PriorityActorRef::new(sender);
 
@@ -92,6 +93,31 @@ fn guardian_sends_stop_message() {
   assert_eq!(extract_system(envelope.into_parts().0), SystemMessage::Stop);
 }
 
+#[test]
+fn guardian_reports_stopped_child_failure_to_parent() {
+  let factory = TestMailboxFactory::unbounded();
+  let (parent_mailbox, parent_sender) = factory.build_default_mailbox::<PriorityEnvelope<AnyMessage>>();
+  let (child_mailbox, child_sender) = factory.build_default_mailbox::<PriorityEnvelope<AnyMessage>>();
+
+  let mut guardian: Guardian<TestMailboxFactory, AlwaysStop> = Guardian::new(AlwaysStop);
+  let (parent_id, parent_path) =
+    guardian.register_child(PriorityActorRef::new(parent_sender), system_mapper(), None, &ActorPath::new()).unwrap();
+  let (child_id, _path) = guardian
+    .register_child(PriorityActorRef::new(child_sender), system_mapper(), Some(parent_id), &parent_path)
+    .unwrap();
+  let _ = child_mailbox.try_dequeue().unwrap().unwrap();
+
+  assert!(guardian.notify_failure(child_id, ActorFailure::from_message("panic")).unwrap().is_none());
+
+  let envelope = child_mailbox.try_dequeue().unwrap().unwrap();
+  assert_eq!(extract_system(envelope.into_parts().0), SystemMessage::Stop);
+  let envelope = parent_mailbox.try_dequeue().unwrap().unwrap();
+  match extract_system(envelope.into_parts().0) {
+    | SystemMessage::Failure(info) => assert_eq!(info.actor, child_id),
+    | other => panic!("unexpected system message: {other:?}"),
+  }
+}
+
 #[test]
 fn guardian_emits_unwatch_on_remove() {
   let (mailbox, sender) = TestMailboxFactory::unbounded().build_default_mailbox::<PriorityEnvelope<AnyMessage>>();
@@ -7,11 +7,14 @@ mod tests;
 
 /// Control message types inspired by protoactor-go's `SystemMessage` catalogue.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum SystemMessage {
   /// Start watching another actor.
   Watch(ActorId),
   /// Stop watching another actor.
   Unwatch(ActorId),
+  /// Notify that a watched actor terminated.
+  Terminated(ActorId),
   /// Instruct the actor to stop.
   Stop,
   /// Notify of a failure occurrence.
@@ -33,7 +36,7 @@ impl SystemMessage {
   #[must_use]
   pub const fn priority(&self) -> i8 {
     match self {
-      | SystemMessage::Watch(_) | SystemMessage::Unwatch(_) => DEFAULT_PRIORITY + 5,
+      | SystemMessage::Watch(_) | SystemMessage::Unwatch(_) | SystemMessage::Terminated(_) => DEFAULT_PRIORITY + 5,
       | SystemMessage::Stop => DEFAULT_PRIORITY + 10,
       | SystemMessage::Failure(_) => DEFAULT_PRIORITY + 12,
       | SystemMessage::Restart => DEFAULT_PRIORITY + 11,
@@ -15,6 +15,7 @@ fn priority_table_matches_expected_values() {
   let expectations = [
     (SystemMessage::Watch(ActorId(1)), base + 5),
     (SystemMessage::Unwatch(ActorId(1)), base + 5),
+    (SystemMessage::Terminated(ActorId(1)), base + 5),
     (SystemMessage::Stop, base + 10),
     (SystemMessage::Failure(failure_info.clone()), base + 12),
     (SystemMessage::Restart, base + 11),
@@ -167,6 +167,11 @@ where
     <QueueMailboxProducer<SQ, UQ, S>>::try_send_mailbox(self, message)
   }
 
+  fn len_opt(&self) -> Option<usize> {
+    let len = <QueueMailboxProducer<SQ, UQ, S>>::len::<M>(self);
+    (!len.is_limitless()).then(|| len.to_usize())
+  }
+
   fn set_metrics_sink(&mut self, sink: Option<MetricsSinkShared>) {
     let queue_sink = sink.clone();
     self.core.apply_queue_metrics_sink::<M>(queue_sink);
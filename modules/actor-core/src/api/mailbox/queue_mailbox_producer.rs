@@ -1,6 +1,9 @@
 use cellex_utils_core_rs::{
   collections::{
-    queue::backend::{OfferOutcome, QueueError},
+    queue::{
+      backend::{OfferOutcome, QueueError},
+      QueueSize,
+    },
     Element,
   },
   sync::shared::SharedBound,
@@ -66,6 +69,16 @@ impl<SQ, UQ, S> QueueMailboxProducer<SQ, UQ, S> {
     self.core.user_queue()
   }
 
+  /// Returns the current queue length, limitless when a queue cannot tell.
+  #[must_use]
+  pub fn len<M>(&self) -> QueueSize
+  where
+    SQ: SystemMailboxLane<M>,
+    UQ: MailboxQueue<M>,
+    M: Element, {
+    self.core.len::<M>()
+  }
+
   /// Assigns a metrics sink for enqueue instrumentation.
   pub fn set_metrics_sink<M>(&mut self, sink: Option<MetricsSinkShared>)
   where
@@ -1,7 +1,10 @@
+use alloc::boxed::Box;
+
 use cellex_utils_core_rs::collections::Element;
 
 use crate::{
   api::{
+    actor::behavior::TimerTick,
     mailbox::ThreadSafe,
     messaging::{MessageSender, MetadataStorageMode},
     process::pid::Pid,
@@ -118,6 +121,18 @@ where
       && self.inner.responder.is_none()
       && self.inner.sender_pid().is_none()
       && self.inner.responder_pid().is_none()
+      && self.inner.timer_tick.is_none()
+  }
+
+  /// Marks the message as a tick of the timer described by `tick`.
+  pub(crate) fn with_timer_tick(mut self, tick: TimerTick) -> Self {
+    self.inner.timer_tick = Some(Box::new(tick));
+    self
+  }
+
+  /// Takes the timer tick the message was marked with, if any.
+  pub(crate) const fn take_timer_tick(&mut self) -> Option<Box<TimerTick>> {
+    self.inner.timer_tick.take()
   }
 }
 
@@ -1,3 +1,4 @@
+mod batch_flush_reason;
 mod metrics_event;
 mod metrics_sink;
 mod metrics_sink_shared;
@@ -6,6 +7,7 @@ mod null_suspension_clock;
 mod suspension_clock;
 mod suspension_clock_shared;
 
+pub use batch_flush_reason::BatchFlushReason;
 pub use metrics_event::MetricsEvent;
 pub use metrics_sink::MetricsSink;
 pub use metrics_sink_shared::MetricsSinkShared;
@@ -0,0 +1,12 @@
+/// Trigger that caused a batch of outbound messages to be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchFlushReason {
+  /// The batch reached its maximum number of messages.
+  MaxFrames,
+  /// The batch reached its maximum size in bytes.
+  MaxBytes,
+  /// The oldest message of the batch waited for the configured linger time.
+  Linger,
+  /// The batch was flushed on request, for instance before disconnecting.
+  Explicit,
+}
@@ -1,3 +1,5 @@
+use super::BatchFlushReason;
+
 /// Metrics events emitted by the actor runtime.
 ///
 /// The variants currently cover high-level categories; payloads can be extended in later phases.
@@ -55,4 +57,13 @@ pub enum MetricsEvent {
   TelemetryInvoked,
   /// Duration, in nanoseconds, spent executing telemetry handlers.
   TelemetryLatencyNanos(u64),
+  /// A batch of outbound remote messages was written to a connection.
+  OutboundBatchFlushed {
+    /// Number of messages in the batch.
+    frames: usize,
+    /// Encoded size of the messages, in bytes.
+    bytes:  usize,
+    /// What triggered the write.
+    reason: BatchFlushReason,
+  },
 }
@@ -1,8 +1,10 @@
 mod process_registry_impl;
 mod process_resolution;
+mod process_termination_listener;
 
 #[cfg(test)]
 mod tests;
 
 pub use process_registry_impl::ProcessRegistry;
 pub use process_resolution::ProcessResolution;
+pub use process_termination_listener::ProcessTerminationListener;
@@ -3,6 +3,7 @@
 use alloc::{
   collections::BTreeMap,
   string::{String, ToString},
+  vec::Vec,
 };
 
 use cellex_utils_core_rs::sync::ArcShared;
@@ -13,7 +14,7 @@ use crate::api::{
   process::{
     dead_letter::{DeadLetter, DeadLetterHub, DeadLetterListener, DeadLetterReason},
     pid::{NodeId, Pid, SystemId},
-    process_registry::ProcessResolution,
+    process_registry::{ProcessResolution, ProcessTerminationListener},
   },
 };
 
@@ -23,13 +24,20 @@ pub struct ProcessRegistry<P, M> {
   node:         Option<NodeId>,
   processes:    RwLock<BTreeMap<String, ArcShared<P>>>,
   dead_letters: RwLock<DeadLetterHub<M>>,
+  terminations: RwLock<Vec<ArcShared<ProcessTerminationListener>>>,
 }
 
 impl<P, M> ProcessRegistry<P, M> {
   /// Creates a new process registry for the given system/node combination.
   #[must_use]
   pub const fn new(system: SystemId, node: Option<NodeId>) -> Self {
-    Self { system, node, processes: RwLock::new(BTreeMap::new()), dead_letters: RwLock::new(DeadLetterHub::new()) }
+    Self {
+      system,
+      node,
+      processes: RwLock::new(BTreeMap::new()),
+      dead_letters: RwLock::new(DeadLetterHub::new()),
+      terminations: RwLock::new(Vec::new()),
+    }
   }
 
   fn pid_key(pid: &Pid) -> String {
@@ -60,8 +68,21 @@ impl<P, M> ProcessRegistry<P, M> {
   }
 
   /// Removes a process entry.
+  ///
+  /// Termination listeners are notified when an entry was actually removed.
   pub fn deregister(&self, pid: &Pid) {
-    self.processes.write().remove(&Self::pid_key(pid));
+    let removed = self.processes.write().remove(&Self::pid_key(pid)).is_some();
+    if removed {
+      let listeners = self.terminations.read().clone();
+      for listener in listeners {
+        listener(pid);
+      }
+    }
+  }
+
+  /// Subscribes a listener notified whenever a registered process is removed.
+  pub fn subscribe_terminations(&self, listener: ArcShared<ProcessTerminationListener>) {
+    self.terminations.write().push(listener);
   }
 
   /// Resolves a PID to a process handle, remote indicator, or unresolved.
@@ -0,0 +1,11 @@
+//! Process termination listener type.
+
+use crate::api::process::pid::Pid;
+
+/// Listener invoked with the PID of every process removed from the registry.
+#[cfg(target_has_atomic = "ptr")]
+pub type ProcessTerminationListener = dyn Fn(&Pid) + Send + Sync + 'static;
+
+/// Listener invoked with the PID of every process removed from the registry.
+#[cfg(not(target_has_atomic = "ptr"))]
+pub type ProcessTerminationListener = dyn Fn(&Pid) + 'static;
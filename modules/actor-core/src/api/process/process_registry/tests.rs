@@ -1,13 +1,13 @@
 #![allow(clippy::disallowed_types)]
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::expect_used)]
-use alloc::sync::Arc;
+use alloc::{sync::Arc, vec::Vec};
 use core::sync::atomic::{AtomicBool, Ordering};
 
 use cellex_utils_core_rs::sync::ArcShared;
 use spin::Mutex;
 
-use super::{ProcessRegistry, ProcessResolution};
+use super::{ProcessRegistry, ProcessResolution, ProcessTerminationListener};
 use crate::api::{
   actor::{ActorId, ActorPath},
   process::{
@@ -81,3 +81,22 @@ fn publishes_dead_letter_when_remote() {
   assert!(result.is_none());
   assert!(matches!(observed.lock().as_ref(), Some(DeadLetterReason::NetworkUnreachable)));
 }
+
+#[test]
+fn notifies_termination_listeners_on_deregister() {
+  let registry: ProcessRegistry<u32, i32> = ProcessRegistry::new(SystemId::new("sys"), None);
+  let pid = registry.register_local(sample_path(), ArcShared::new(10));
+
+  let observed = Arc::new(Mutex::new(Vec::new()));
+  let observed_clone = Arc::clone(&observed);
+  let listener = ArcShared::new(move |pid: &Pid| {
+    observed_clone.lock().push(pid.clone());
+  })
+  .into_dyn(|f| f as &ProcessTerminationListener);
+  registry.subscribe_terminations(listener);
+
+  registry.deregister(&pid);
+  registry.deregister(&pid);
+
+  assert_eq!(observed.lock().as_slice(), &[pid]);
+}
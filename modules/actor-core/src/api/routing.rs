@@ -0,0 +1,25 @@
+use crate::api::actor::Props;
+
+mod fnv_hasher;
+mod group_router;
+mod pool_resizer;
+mod pool_router;
+mod router_message;
+mod router_state;
+mod routing_cursor;
+mod routing_logic;
+
+#[cfg(test)]
+mod tests;
+
+pub use group_router::GroupRouter;
+pub use pool_resizer::PoolResizer;
+pub use pool_router::PoolRouter;
+pub use router_message::RouterMessage;
+pub use routing_logic::RoutingLogic;
+
+/// Function giving the consistent-hashing key of a message.
+pub type HashKeyFn<U> = dyn Fn(&U) -> u64 + 'static;
+
+/// Function building the props of a pool routee.
+pub(crate) type RouteePropsFn<U, AR> = dyn Fn() -> Props<U, AR> + 'static;
@@ -0,0 +1,36 @@
+use core::hash::{Hash, Hasher};
+
+const OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+const PRIME: u64 = 0x0000_0100_0000_01B3;
+
+/// FNV-1a hasher, giving routing keys that do not change between runs or targets.
+pub(crate) struct FnvHasher {
+  state: u64,
+}
+
+impl FnvHasher {
+  pub(crate) const fn new() -> Self {
+    Self { state: OFFSET_BASIS }
+  }
+
+  /// Hashes `value` from a fresh state.
+  pub(crate) fn hash_of<T>(value: &T) -> u64
+  where
+    T: Hash + ?Sized, {
+    let mut hasher = Self::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+  }
+}
+
+impl Hasher for FnvHasher {
+  fn finish(&self) -> u64 {
+    self.state
+  }
+
+  fn write(&mut self, bytes: &[u8]) {
+    for byte in bytes {
+      self.state = (self.state ^ u64::from(*byte)).wrapping_mul(PRIME);
+    }
+  }
+}
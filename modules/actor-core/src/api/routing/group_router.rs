@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+
+use cellex_utils_core_rs::{collections::Element, sync::shared::SharedBound};
+
+use super::{router_state::RouterState, RouterMessage, RoutingLogic};
+use crate::{
+  api::{
+    actor::{actor_ref::ActorRef, behavior::Behaviors, Props},
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::MetadataStorageMode,
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
+};
+
+/// Builds the [`Props`] of a router over actors that already exist.
+///
+/// The router watches its routees and drops each of them once it stops, but never stops them
+/// itself. [`RouterMessage::AddRoutee`] and [`RouterMessage::RemoveRoutee`] change the routees at
+/// runtime.
+pub struct GroupRouter<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+  MailboxSignalOf<AR>: Clone,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+  routees: Vec<ActorRef<U, AR>>,
+  logic:   RoutingLogic<U>,
+}
+
+impl<U, AR> GroupRouter<U, AR>
+where
+  U: Element + Clone,
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone + SharedBound + 'static,
+  MailboxSignalOf<AR>: Clone + SharedBound + 'static,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode + SharedBound,
+{
+  /// Creates a round-robin router over `routees`.
+  pub fn new<I>(routees: I) -> Self
+  where
+    I: IntoIterator<Item = ActorRef<U, AR>>, {
+    Self { routees: routees.into_iter().collect(), logic: RoutingLogic::default() }
+  }
+
+  /// Picks routees with `logic` instead.
+  #[must_use]
+  pub fn with_logic(mut self, logic: RoutingLogic<U>) -> Self {
+    self.logic = logic;
+    self
+  }
+
+  /// Returns the routees the router starts with.
+  #[must_use]
+  pub fn routees(&self) -> &[ActorRef<U, AR>] {
+    &self.routees
+  }
+
+  /// Returns how routees are picked.
+  #[must_use]
+  pub const fn logic(&self) -> &RoutingLogic<U> {
+    &self.logic
+  }
+
+  /// Returns the props of the router actor.
+  #[must_use]
+  pub fn props(&self) -> Props<RouterMessage<U, AR>, AR> {
+    let routees = self.routees.clone();
+    let logic = self.logic.clone();
+    Props::with_behavior(move || {
+      let routees = routees.clone();
+      let logic = logic.clone();
+      Behaviors::setup(move |ctx| {
+        let mut router = RouterState::new(logic.clone(), None, None);
+        for actor in &routees {
+          router.add(ctx, actor.clone(), false);
+        }
+        Ok(router.into_behavior())
+      })
+    })
+  }
+}
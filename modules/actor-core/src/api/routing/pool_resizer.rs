@@ -0,0 +1,91 @@
+/// Grows and shrinks a pool router with the load of its routees.
+///
+/// Every [`Self::messages_per_resize`] routed messages, starting with the first one, the pool gains
+/// a routee when every routee is busy and loses one when none is, staying within the bounds. A
+/// routee is busy when at least [`Self::pressure_threshold`] messages wait in its mailbox.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolResizer {
+  lower_bound:         usize,
+  upper_bound:         usize,
+  pressure_threshold:  usize,
+  messages_per_resize: usize,
+}
+
+impl PoolResizer {
+  /// Creates a resizer keeping between `lower_bound` and `upper_bound` routees, which counts a
+  /// routee as busy as soon as one message waits for it and resizes every 10 messages.
+  #[must_use]
+  pub const fn new(lower_bound: usize, upper_bound: usize) -> Self {
+    let upper_bound = if upper_bound < lower_bound { lower_bound } else { upper_bound };
+    Self { lower_bound, upper_bound, pressure_threshold: 1, messages_per_resize: 10 }
+  }
+
+  /// Counts a routee as busy once `pressure_threshold` messages wait in its mailbox instead.
+  #[must_use]
+  pub const fn with_pressure_threshold(mut self, pressure_threshold: usize) -> Self {
+    self.pressure_threshold = if pressure_threshold == 0 { 1 } else { pressure_threshold };
+    self
+  }
+
+  /// Resizes every `messages_per_resize` routed messages instead.
+  #[must_use]
+  pub const fn with_messages_per_resize(mut self, messages_per_resize: usize) -> Self {
+    self.messages_per_resize = if messages_per_resize == 0 { 1 } else { messages_per_resize };
+    self
+  }
+
+  /// Returns the fewest routees the pool keeps.
+  #[must_use]
+  pub const fn lower_bound(&self) -> usize {
+    self.lower_bound
+  }
+
+  /// Returns the most routees the pool keeps.
+  #[must_use]
+  pub const fn upper_bound(&self) -> usize {
+    self.upper_bound
+  }
+
+  /// Returns how many waiting messages make a routee busy.
+  #[must_use]
+  pub const fn pressure_threshold(&self) -> usize {
+    self.pressure_threshold
+  }
+
+  /// Returns how many routed messages go by between two resizes.
+  #[must_use]
+  pub const fn messages_per_resize(&self) -> usize {
+    self.messages_per_resize
+  }
+
+  /// Returns `size` brought within the bounds.
+  pub(crate) const fn clamp(&self, size: usize) -> usize {
+    if size < self.lower_bound {
+      self.lower_bound
+    } else if size > self.upper_bound {
+      self.upper_bound
+    } else {
+      size
+    }
+  }
+
+  /// Returns whether the pool resizes before routing its `routed`-th message, counted from zero.
+  pub(crate) const fn is_due(&self, routed: usize) -> bool {
+    routed.is_multiple_of(self.messages_per_resize)
+  }
+
+  /// Returns the number of routees the pool should have, given the length of the mailbox of each
+  /// of its routees.
+  pub(crate) fn target_size(&self, mailbox_lens: &[usize]) -> usize {
+    let size = mailbox_lens.len();
+    let busy = mailbox_lens.iter().filter(|len| **len >= self.pressure_threshold).count();
+    let target = if busy == size {
+      size + 1
+    } else if busy == 0 {
+      size.saturating_sub(1)
+    } else {
+      size
+    };
+    self.clamp(target)
+  }
+}
@@ -0,0 +1,108 @@
+use cellex_utils_core_rs::{
+  collections::Element,
+  sync::{shared::SharedBound, ArcShared},
+};
+
+use super::{router_state::RouterState, PoolResizer, RouteePropsFn, RouterMessage, RoutingLogic};
+use crate::{
+  api::{
+    actor::{behavior::Behaviors, Props},
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::MetadataStorageMode,
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
+};
+
+/// Builds the [`Props`] of a router spawning its routees as children.
+///
+/// The router spawns its routees when it starts, and stops once all of them stopped on their own.
+/// [`RouterMessage::AdjustPoolSize`] spawns or stops routees at runtime, and a [`PoolResizer`]
+/// does so with the load of the routees.
+pub struct PoolRouter<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+  MailboxSignalOf<AR>: Clone,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+  size:         usize,
+  routee_props: ArcShared<RouteePropsFn<U, AR>>,
+  logic:        RoutingLogic<U>,
+  resizer:      Option<PoolResizer>,
+}
+
+impl<U, AR> PoolRouter<U, AR>
+where
+  U: Element + Clone,
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone + SharedBound + 'static,
+  MailboxSignalOf<AR>: Clone + SharedBound + 'static,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode + SharedBound,
+{
+  /// Creates a round-robin router over `size` routees spawned from the props `routee_props`
+  /// returns.
+  pub fn new<F>(size: usize, routee_props: F) -> Self
+  where
+    F: Fn() -> Props<U, AR> + 'static, {
+    let routee_props = ArcShared::new(routee_props).into_dyn(|props| props as &RouteePropsFn<U, AR>);
+    Self { size, routee_props, logic: RoutingLogic::default(), resizer: None }
+  }
+
+  /// Picks routees with `logic` instead.
+  #[must_use]
+  pub fn with_logic(mut self, logic: RoutingLogic<U>) -> Self {
+    self.logic = logic;
+    self
+  }
+
+  /// Resizes the pool with `resizer`, which also brings the initial size within its bounds.
+  #[must_use]
+  pub const fn with_resizer(mut self, resizer: PoolResizer) -> Self {
+    self.resizer = Some(resizer);
+    self
+  }
+
+  /// Returns the number of routees spawned when the router starts.
+  #[must_use]
+  pub const fn size(&self) -> usize {
+    match &self.resizer {
+      | Some(resizer) => resizer.clamp(self.size),
+      | None => self.size,
+    }
+  }
+
+  /// Returns how routees are picked.
+  #[must_use]
+  pub const fn logic(&self) -> &RoutingLogic<U> {
+    &self.logic
+  }
+
+  /// Returns the resizer of the pool, if any.
+  #[must_use]
+  pub const fn resizer(&self) -> Option<PoolResizer> {
+    self.resizer
+  }
+
+  /// Returns the props of the router actor.
+  #[must_use]
+  pub fn props(&self) -> Props<RouterMessage<U, AR>, AR> {
+    let size = self.size();
+    let routee_props = self.routee_props.clone();
+    let logic = self.logic.clone();
+    let resizer = self.resizer;
+    Props::with_behavior(move || {
+      let routee_props = routee_props.clone();
+      let logic = logic.clone();
+      Behaviors::setup(move |ctx| {
+        let mut router = RouterState::new(logic.clone(), Some(routee_props.clone()), resizer);
+        router.spawn(ctx, size);
+        Ok(router.into_behavior())
+      })
+    })
+  }
+}
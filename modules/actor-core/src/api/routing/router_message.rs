@@ -0,0 +1,62 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use cellex_utils_core_rs::collections::Element;
+
+use crate::{
+  api::{
+    actor::actor_ref::ActorRef,
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::{MessageSender, MetadataStorageMode},
+    process::pid::Pid,
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
+};
+
+/// Message handled by a router built by [`PoolRouter`](super::PoolRouter) or
+/// [`GroupRouter`](super::GroupRouter).
+pub enum RouterMessage<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+  MailboxSignalOf<AR>: Clone,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+  /// Routes the message to the routees picked by the routing logic, keeping its sender and
+  /// responder.
+  Route(U),
+  /// Routes to `actor` as well. The router watches it and drops it once it stops.
+  AddRoutee(ActorRef<U, AR>),
+  /// Stops routing to the routee with this PID. Routees spawned by a pool router are stopped.
+  RemoveRoutee(Pid),
+  /// Spawns as many routees as a positive value, or stops as many as a negative one. Only pool
+  /// routers change their size; group routers ignore it.
+  AdjustPoolSize(isize),
+  /// Replies with the PIDs of the current routees, as built by
+  /// [`ActorRef::ask_with`](crate::api::actor::actor_ref::ActorRef::ask_with).
+  GetRoutees(MessageSender<Vec<Pid>, MailboxConcurrencyOf<AR>>),
+}
+
+impl<U, AR> fmt::Debug for RouterMessage<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+  MailboxSignalOf<AR>: Clone,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode,
+{
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      | Self::Route(message) => f.debug_tuple("Route").field(message).finish(),
+      | Self::AddRoutee(actor) => f.debug_tuple("AddRoutee").field(&actor.pid()).finish(),
+      | Self::RemoveRoutee(pid) => f.debug_tuple("RemoveRoutee").field(pid).finish(),
+      | Self::AdjustPoolSize(delta) => f.debug_tuple("AdjustPoolSize").field(delta).finish(),
+      | Self::GetRoutees(_) => f.write_str("GetRoutees(..)"),
+    }
+  }
+}
@@ -0,0 +1,226 @@
+use alloc::{format, vec::Vec};
+
+use cellex_utils_core_rs::{
+  collections::Element,
+  sync::{shared::SharedBound, ArcShared},
+};
+use spin::Mutex;
+
+use super::{
+  fnv_hasher::FnvHasher, routing_cursor::RoutingCursor, PoolResizer, RouteePropsFn, RouterMessage, RoutingLogic,
+};
+use crate::{
+  api::{
+    actor::{
+      actor_context::ActorContext,
+      actor_ref::ActorRef,
+      behavior::{Behavior, BehaviorDirective, Behaviors},
+      Signal,
+    },
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    mailbox::messages::SystemMessage,
+    messaging::MetadataStorageMode,
+    process::pid::Pid,
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
+};
+
+/// Actor a router routes to.
+struct Routee<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+  MailboxSignalOf<AR>: Clone,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+  actor:   ActorRef<U, AR>,
+  /// Identity of the routee for consistent hashing.
+  key:     u64,
+  /// Whether the router spawned the routee, and stops it when it stops routing to it.
+  spawned: bool,
+}
+
+/// State of a running router actor.
+pub(crate) struct RouterState<U, AR>
+where
+  U: Element,
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+  MailboxSignalOf<AR>: Clone,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+  logic:   RoutingLogic<U>,
+  cursor:  RoutingCursor,
+  routees: Vec<Routee<U, AR>>,
+  /// Props of the routees of a pool router; group routers spawn no routee.
+  pool:    Option<ArcShared<RouteePropsFn<U, AR>>>,
+  resizer: Option<PoolResizer>,
+  routed:  usize,
+}
+
+impl<U, AR> RouterState<U, AR>
+where
+  U: Element + Clone,
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone + SharedBound + 'static,
+  MailboxSignalOf<AR>: Clone + SharedBound + 'static,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode + SharedBound,
+{
+  /// Creates a router without routees, which spawns routees from `pool` when it is set.
+  pub(crate) const fn new(
+    logic: RoutingLogic<U>,
+    pool: Option<ArcShared<RouteePropsFn<U, AR>>>,
+    resizer: Option<PoolResizer>,
+  ) -> Self {
+    Self { logic, cursor: RoutingCursor::new(), routees: Vec::new(), pool, resizer, routed: 0 }
+  }
+
+  /// Turns the router into the behavior of the router actor.
+  pub(crate) fn into_behavior(self) -> Behavior<RouterMessage<U, AR>, AR> {
+    let state = ArcShared::new(Mutex::new(self));
+    let signal_state = state.clone();
+    Behavior::receive(move |ctx, message| Ok(state.lock().handle(ctx, message)))
+      .receive_signal(move |ctx, signal| signal_state.lock().handle_signal(ctx, &signal))
+  }
+
+  /// Routes to `actor` too, watching it so that it is dropped once it stops.
+  pub(crate) fn add(
+    &mut self,
+    ctx: &mut ActorContext<'_, '_, RouterMessage<U, AR>, AR>,
+    actor: ActorRef<U, AR>,
+    spawned: bool,
+  ) {
+    let pid = actor.pid();
+    if pid.is_some() && self.routees.iter().any(|routee| routee.actor.pid() == pid) {
+      return;
+    }
+    let key = pid.map_or_else(|| FnvHasher::hash_of(&self.routees.len()), |pid| FnvHasher::hash_of(&pid));
+    // A routee that already stopped is reported through the terminated signal.
+    let _ = ctx.watch(&actor);
+    self.routees.push(Routee { actor, key, spawned });
+  }
+
+  /// Spawns `count` routees from the pool props; group routers spawn none.
+  pub(crate) fn spawn(&mut self, ctx: &mut ActorContext<'_, '_, RouterMessage<U, AR>, AR>, count: usize) {
+    let Some(props) = self.pool.clone() else {
+      return;
+    };
+    for _ in 0..count {
+      let actor = ctx.spawn_child(props());
+      self.add(ctx, actor, true);
+    }
+  }
+
+  fn handle(
+    &mut self,
+    ctx: &mut ActorContext<'_, '_, RouterMessage<U, AR>, AR>,
+    message: RouterMessage<U, AR>,
+  ) -> BehaviorDirective<RouterMessage<U, AR>, AR> {
+    match message {
+      | RouterMessage::Route(message) => self.route(ctx, &message),
+      | RouterMessage::AddRoutee(actor) => self.add(ctx, actor, false),
+      | RouterMessage::RemoveRoutee(pid) => {
+        if let Some(index) = self.routees.iter().position(|routee| routee.actor.pid().as_ref() == Some(&pid)) {
+          self.remove(ctx, index);
+        }
+      },
+      | RouterMessage::AdjustPoolSize(delta) if delta > 0 => self.spawn(ctx, delta.unsigned_abs()),
+      | RouterMessage::AdjustPoolSize(delta) => self.shrink(ctx, delta.unsigned_abs()),
+      | RouterMessage::GetRoutees(reply_to) => {
+        let pids: Vec<Pid> = self.routees.iter().filter_map(|routee| routee.actor.pid()).collect();
+        // A requester that went away no longer waits for the reply.
+        let _ = reply_to.dispatch_user(pids);
+      },
+    }
+    Behaviors::same()
+  }
+
+  fn handle_signal(
+    &mut self,
+    ctx: &mut ActorContext<'_, '_, RouterMessage<U, AR>, AR>,
+    signal: &Signal,
+  ) -> BehaviorDirective<RouterMessage<U, AR>, AR> {
+    let actor_id = match signal {
+      | Signal::Terminated(actor_id) => actor_id,
+      | Signal::PreRestart => {
+        // The restarted router sets its routees up anew, so the spawned ones must not outlive it.
+        while let Some(index) = self.routees.len().checked_sub(1) {
+          self.remove(ctx, index);
+        }
+        return Behaviors::same();
+      },
+      | _ => return Behaviors::same(),
+    };
+    self.routees.retain(|routee| routee.actor.pid().and_then(|pid| pid.path().last()) != Some(*actor_id));
+    // A pool router has nothing left to do once all of its routees stopped on their own.
+    if self.pool.is_some() && self.routees.is_empty() {
+      Behaviors::stopped()
+    } else {
+      Behaviors::same()
+    }
+  }
+
+  fn route(&mut self, ctx: &mut ActorContext<'_, '_, RouterMessage<U, AR>, AR>, message: &U) {
+    self.resize(ctx);
+    let routees = &self.routees;
+    let selected = self.logic.select(
+      message,
+      &mut self.cursor,
+      routees.len(),
+      |index| routees[index].key,
+      |index| routees[index].actor.mailbox_len(),
+    );
+    if selected.is_empty() {
+      ctx.log().warn(|| format!("router has no routee for {:?}", message));
+    }
+    for index in selected {
+      // Messages a routee cannot take are reported as dead letters by its reference.
+      let _ = ctx.forward(&self.routees[index].actor, message.clone());
+    }
+  }
+
+  fn resize(&mut self, ctx: &mut ActorContext<'_, '_, RouterMessage<U, AR>, AR>) {
+    let Some(resizer) = self.resizer else {
+      return;
+    };
+    let due = resizer.is_due(self.routed);
+    self.routed = self.routed.wrapping_add(1);
+    if !due {
+      return;
+    }
+    // Routees whose mailbox cannot tell its length count as idle.
+    let mailbox_lens: Vec<usize> =
+      self.routees.iter().map(|routee| routee.actor.mailbox_len().unwrap_or_default()).collect();
+    let size = mailbox_lens.len();
+    let target = resizer.target_size(&mailbox_lens);
+    if target > size {
+      self.spawn(ctx, target - size);
+    } else {
+      self.shrink(ctx, size - target);
+    }
+  }
+
+  /// Stops up to `count` of the spawned routees, the most recent first.
+  fn shrink(&mut self, ctx: &mut ActorContext<'_, '_, RouterMessage<U, AR>, AR>, count: usize) {
+    for _ in 0..count {
+      let Some(index) = self.routees.iter().rposition(|routee| routee.spawned) else {
+        return;
+      };
+      self.remove(ctx, index);
+    }
+  }
+
+  fn remove(&mut self, ctx: &mut ActorContext<'_, '_, RouterMessage<U, AR>, AR>, index: usize) {
+    let routee = self.routees.remove(index);
+    ctx.unwatch(&routee.actor);
+    if routee.spawned {
+      // A routee that already stopped has nothing left to stop.
+      let _ = routee.actor.send_system(SystemMessage::Stop);
+    }
+  }
+}
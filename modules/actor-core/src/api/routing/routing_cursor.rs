@@ -0,0 +1,30 @@
+const DEFAULT_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Where a router stands in its routing logic, carried from one message to the next.
+pub(crate) struct RoutingCursor {
+  turn: usize,
+  rng:  u64,
+}
+
+impl RoutingCursor {
+  pub(crate) const fn new() -> Self {
+    Self { turn: 0, rng: DEFAULT_SEED }
+  }
+
+  /// Returns the index of the routee whose turn it is among `len`, and moves on to the next one.
+  pub(crate) const fn next_turn(&mut self, len: usize) -> usize {
+    let index = self.turn % len;
+    self.turn = index + 1;
+    index
+  }
+
+  /// Returns a pseudo-random index below `len`.
+  pub(crate) const fn next_random(&mut self, len: usize) -> usize {
+    let mut x = self.rng;
+    x ^= x >> 12;
+    x ^= x << 25;
+    x ^= x >> 27;
+    self.rng = x;
+    (x.wrapping_mul(0x2545_F491_4F6C_DD1D) % len as u64) as usize
+  }
+}
@@ -0,0 +1,120 @@
+use core::{fmt, hash::Hash, ops::Range};
+
+use cellex_utils_core_rs::sync::ArcShared;
+
+use super::{fnv_hasher::FnvHasher, routing_cursor::RoutingCursor, HashKeyFn};
+
+/// How a router picks the routees receiving a message.
+#[derive(Default)]
+pub enum RoutingLogic<U> {
+  /// One routee receives each message, chosen in turn.
+  #[default]
+  RoundRobin,
+  /// One routee receives each message, chosen pseudo-randomly.
+  Random,
+  /// Every routee receives each message.
+  Broadcast,
+  /// Messages with the same key go to the same routee for as long as it is routed to.
+  ///
+  /// Routees are picked by rendezvous hashing, so adding or removing a routee only moves the keys
+  /// that it gains or loses.
+  ConsistentHashing(ArcShared<HashKeyFn<U>>),
+  /// One routee receives each message: the one with the fewest messages waiting in its mailbox,
+  /// the first one on ties.
+  ///
+  /// Routees whose mailbox cannot tell its length, such as remote ones, take turns instead unless
+  /// another routee has an empty mailbox.
+  SmallestMailbox,
+}
+
+impl<U> RoutingLogic<U> {
+  /// Creates a consistent-hashing logic routing by the key `extract` returns for each message.
+  #[must_use]
+  pub fn consistent_hashing<K, F>(extract: F) -> Self
+  where
+    K: Hash,
+    F: Fn(&U) -> K + 'static, {
+    let hash_key = ArcShared::new(move |message: &U| FnvHasher::hash_of(&extract(message)));
+    Self::ConsistentHashing(hash_key.into_dyn(|hash_key| hash_key as &HashKeyFn<U>))
+  }
+
+  /// Picks the routees receiving `message` among `len` routees, as a range of their indices.
+  /// `key` gives the identity of the routee at an index, and `mailbox_len` the number of messages
+  /// waiting for it, if known.
+  pub(crate) fn select<K, L>(
+    &self,
+    message: &U,
+    cursor: &mut RoutingCursor,
+    len: usize,
+    key: K,
+    mailbox_len: L,
+  ) -> Range<usize>
+  where
+    K: Fn(usize) -> u64,
+    L: Fn(usize) -> Option<usize>, {
+    if len == 0 {
+      return 0..0;
+    }
+    let index = match self {
+      | Self::RoundRobin => cursor.next_turn(len),
+      | Self::Random => cursor.next_random(len),
+      | Self::Broadcast => return 0..len,
+      | Self::ConsistentHashing(hash_key) => {
+        let message_key = hash_key(message);
+        (0..len).max_by_key(|index| rendezvous_score(message_key, key(*index))).unwrap_or_default()
+      },
+      | Self::SmallestMailbox => smallest_mailbox(cursor, len, mailbox_len),
+    };
+    index..index + 1
+  }
+}
+
+/// Index of the routee with the fewest known messages waiting, or of the next routee whose mailbox
+/// length is unknown when no known mailbox is empty.
+fn smallest_mailbox<L>(cursor: &mut RoutingCursor, len: usize, mailbox_len: L) -> usize
+where
+  L: Fn(usize) -> Option<usize>, {
+  let smallest = (0..len).filter_map(|index| mailbox_len(index).map(|waiting| (waiting, index))).min();
+  if let Some((0, index)) = smallest {
+    return index;
+  }
+  let unknown = (0..len).filter(|index| mailbox_len(*index).is_none()).count();
+  if unknown == 0 {
+    return smallest.map(|(_, index)| index).unwrap_or_default();
+  }
+  let turn = cursor.next_turn(unknown);
+  (0..len).filter(|index| mailbox_len(*index).is_none()).nth(turn).unwrap_or_default()
+}
+
+/// Weight of the routee identified by `routee` for `key`; the heaviest routee takes the key.
+const fn rendezvous_score(key: u64, routee: u64) -> u64 {
+  // SplitMix64 finalizer, spreading nearby keys over the whole range.
+  let mut x = key ^ routee.rotate_left(32);
+  x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+  x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+  x ^ (x >> 31)
+}
+
+impl<U> Clone for RoutingLogic<U> {
+  fn clone(&self) -> Self {
+    match self {
+      | Self::RoundRobin => Self::RoundRobin,
+      | Self::Random => Self::Random,
+      | Self::Broadcast => Self::Broadcast,
+      | Self::ConsistentHashing(hash_key) => Self::ConsistentHashing(hash_key.clone()),
+      | Self::SmallestMailbox => Self::SmallestMailbox,
+    }
+  }
+}
+
+impl<U> fmt::Debug for RoutingLogic<U> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      | Self::RoundRobin => f.write_str("RoundRobin"),
+      | Self::Random => f.write_str("Random"),
+      | Self::Broadcast => f.write_str("Broadcast"),
+      | Self::ConsistentHashing(_) => f.write_str("ConsistentHashing(..)"),
+      | Self::SmallestMailbox => f.write_str("SmallestMailbox"),
+    }
+  }
+}
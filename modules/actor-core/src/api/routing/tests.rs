@@ -0,0 +1,112 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Range;
+
+use super::{fnv_hasher::FnvHasher, routing_cursor::RoutingCursor, PoolResizer, RoutingLogic};
+
+fn select(logic: &RoutingLogic<u32>, cursor: &mut RoutingCursor, message: u32, keys: &[u64]) -> Range<usize> {
+  logic.select(&message, cursor, keys.len(), |index| keys[index], |_| Some(0))
+}
+
+#[test]
+fn round_robin_takes_routees_in_turn() {
+  let logic = RoutingLogic::RoundRobin;
+  let mut cursor = RoutingCursor::new();
+  let keys = [1, 2, 3];
+
+  let picked: Vec<Range<usize>> = (0..4).map(|message| select(&logic, &mut cursor, message, &keys)).collect();
+
+  assert_eq!(picked, vec![0..1, 1..2, 2..3, 0..1]);
+  assert!(select(&logic, &mut cursor, 0, &[]).is_empty());
+}
+
+#[test]
+fn random_picks_every_routee_over_time() {
+  let logic = RoutingLogic::Random;
+  let mut cursor = RoutingCursor::new();
+  let keys = [1, 2, 3, 4];
+  let mut hits = [0_usize; 4];
+
+  for message in 0..200 {
+    let picked = select(&logic, &mut cursor, message, &keys);
+    assert_eq!(picked.len(), 1);
+    hits[picked.start] += 1;
+  }
+
+  assert!(hits.iter().all(|count| *count > 0), "{hits:?}");
+}
+
+#[test]
+fn broadcast_picks_every_routee() {
+  let logic = RoutingLogic::Broadcast;
+  let mut cursor = RoutingCursor::new();
+
+  assert_eq!(select(&logic, &mut cursor, 0, &[1, 2, 3]), 0..3);
+}
+
+#[test]
+fn consistent_hashing_keeps_keys_on_their_routee() {
+  let logic = RoutingLogic::consistent_hashing(|message: &u32| message % 10);
+  let mut cursor = RoutingCursor::new();
+  let keys: Vec<u64> = (0..4_u32).map(|routee| FnvHasher::hash_of(&routee)).collect();
+
+  let before: Vec<usize> = (0..10).map(|message| select(&logic, &mut cursor, message, &keys).start).collect();
+  let again: Vec<usize> = (10..20).map(|message| select(&logic, &mut cursor, message, &keys).start).collect();
+  assert_eq!(before, again);
+  assert!(before.iter().any(|index| *index != before[0]), "{before:?}");
+
+  // Dropping the last routee only moves the keys it owned.
+  let after: Vec<usize> = (0..10).map(|message| select(&logic, &mut cursor, message, &keys[..3]).start).collect();
+  for (old, new) in before.iter().zip(&after) {
+    if *old != 3 {
+      assert_eq!(old, new);
+    }
+  }
+}
+
+#[test]
+fn smallest_mailbox_picks_the_least_loaded_routee() {
+  let logic = RoutingLogic::SmallestMailbox;
+  let mut cursor = RoutingCursor::new();
+  let lens = [Some(3), Some(1), Some(4), Some(1)];
+
+  assert_eq!(logic.select(&0, &mut cursor, lens.len(), |_| 0, |index| lens[index]), 1..2);
+}
+
+#[test]
+fn smallest_mailbox_takes_turns_among_routees_of_unknown_load() {
+  let logic = RoutingLogic::SmallestMailbox;
+  let mut cursor = RoutingCursor::new();
+  let lens = [Some(2), None, Some(1), None];
+
+  let picked: Vec<Range<usize>> =
+    (0..3).map(|_| logic.select(&0, &mut cursor, lens.len(), |_| 0, |index| lens[index])).collect();
+  assert_eq!(picked, vec![1..2, 3..4, 1..2]);
+
+  let lens = [Some(2), None, Some(0), None];
+  assert_eq!(logic.select(&0, &mut cursor, lens.len(), |_| 0, |index| lens[index]), 2..3);
+}
+
+#[test]
+fn pool_resizer_follows_the_pressure_within_its_bounds() {
+  let resizer = PoolResizer::new(1, 3).with_pressure_threshold(2);
+
+  assert_eq!(resizer.target_size(&[2, 5]), 3);
+  assert_eq!(resizer.target_size(&[2, 5, 9]), 3);
+  assert_eq!(resizer.target_size(&[2, 0]), 2);
+  assert_eq!(resizer.target_size(&[1, 0]), 1);
+  assert_eq!(resizer.target_size(&[0]), 1);
+  assert_eq!(resizer.target_size(&[]), 1);
+  assert_eq!(resizer.target_size(&[0, 0, 0, 0, 0]), 3);
+}
+
+#[test]
+fn pool_resizer_resizes_every_few_messages() {
+  let resizer = PoolResizer::new(2, 4).with_messages_per_resize(3);
+
+  let due: Vec<bool> = (0..7).map(|routed| resizer.is_due(routed)).collect();
+
+  assert_eq!(due, vec![true, false, false, true, false, false, true]);
+  assert_eq!(resizer.clamp(0), 2);
+  assert_eq!(resizer.clamp(9), 4);
+  assert_eq!(PoolResizer::new(5, 1).upper_bound(), 5);
+}
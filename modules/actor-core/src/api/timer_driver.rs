@@ -0,0 +1,21 @@
+mod base;
+mod timer_driver_extension;
+mod timer_driver_shared;
+mod timer_mode;
+
+pub use base::TimerDriver;
+use cellex_utils_core_rs::sync::ArcShared;
+pub use timer_driver_extension::{timer_driver_extension_id, TimerDriverExtension};
+pub use timer_driver_shared::TimerDriverShared;
+pub use timer_mode::TimerMode;
+
+#[cfg(target_has_atomic = "ptr")]
+/// Callback run by a [`TimerDriver`] every time a timer fires.
+pub type TimerTaskFn = dyn Fn() + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+/// Callback run by a [`TimerDriver`] every time a timer fires.
+pub type TimerTaskFn = dyn Fn();
+
+/// Shared handle to the callback of a timer.
+pub type TimerTask = ArcShared<TimerTaskFn>;
@@ -0,0 +1,19 @@
+use core::time::Duration;
+
+use cellex_utils_core_rs::{sync::shared::SharedBound, timing::deadline_timer::DeadlineTimerKey};
+
+use crate::api::timer_driver::{TimerMode, TimerTask};
+
+/// Runtime-provided driver that runs timer tasks once their deadline passes.
+///
+/// Implementations sit on top of a runtime-specific
+/// [`DeadlineTimer`](cellex_utils_core_rs::timing::deadline_timer::DeadlineTimer) and re-arm
+/// periodic timers according to their [`TimerMode`], so that actor-level timers such as
+/// [`TimerScheduler`](crate::api::actor::behavior::TimerScheduler) stay independent of the runtime.
+pub trait TimerDriver: SharedBound {
+  /// Starts a timer that runs `task` after `delay`, returning the key used to cancel it.
+  fn start(&self, delay: Duration, mode: TimerMode, task: TimerTask) -> DeadlineTimerKey;
+
+  /// Cancels the timer identified by `key`. Unknown or already fired keys are ignored.
+  fn cancel(&self, key: DeadlineTimerKey);
+}
@@ -0,0 +1,57 @@
+use core::{any::Any, sync::atomic::Ordering};
+
+use portable_atomic::AtomicI32;
+
+use crate::api::{
+  extensions::{next_extension_id, Extension, ExtensionId},
+  timer_driver::TimerDriverShared,
+};
+
+static TIMER_DRIVER_EXTENSION_ID: AtomicI32 = AtomicI32::new(-1);
+
+/// Returns the reserved extension identifier for the timer driver.
+#[must_use]
+pub fn timer_driver_extension_id() -> ExtensionId {
+  let current = TIMER_DRIVER_EXTENSION_ID.load(Ordering::SeqCst);
+  if current >= 0 {
+    return current;
+  }
+  let new_id = next_extension_id();
+  match TIMER_DRIVER_EXTENSION_ID.compare_exchange(-1, new_id, Ordering::SeqCst, Ordering::SeqCst) {
+    | Ok(_) => new_id,
+    | Err(existing) => existing,
+  }
+}
+
+/// Extension that exposes the runtime's [`TimerDriver`](crate::api::timer_driver::TimerDriver)
+/// to actors.
+///
+/// The actor system installs it when the actor runtime provides a driver.
+pub struct TimerDriverExtension {
+  id:     ExtensionId,
+  driver: TimerDriverShared,
+}
+
+impl TimerDriverExtension {
+  /// Creates an extension wrapping `driver`.
+  #[must_use]
+  pub fn new(driver: TimerDriverShared) -> Self {
+    Self { id: timer_driver_extension_id(), driver }
+  }
+
+  /// Returns the wrapped driver.
+  #[must_use]
+  pub fn driver(&self) -> TimerDriverShared {
+    self.driver.clone()
+  }
+}
+
+impl Extension for TimerDriverExtension {
+  fn extension_id(&self) -> ExtensionId {
+    self.id
+  }
+
+  fn as_any(&self) -> &dyn Any {
+    self
+  }
+}
@@ -0,0 +1,51 @@
+use cellex_utils_core_rs::sync::ArcShared;
+
+use crate::api::timer_driver::TimerDriver;
+
+/// Shared wrapper around a [`TimerDriver`] implementation.
+pub struct TimerDriverShared {
+  inner: ArcShared<dyn TimerDriver>,
+}
+
+impl TimerDriverShared {
+  /// Creates a new shared driver from a concrete driver value.
+  #[must_use]
+  pub fn new<D>(driver: D) -> Self
+  where
+    D: TimerDriver + 'static, {
+    let shared = ArcShared::new(driver);
+    Self { inner: shared.into_dyn(|inner| inner as &dyn TimerDriver) }
+  }
+
+  /// Wraps an existing shared driver.
+  #[must_use]
+  pub const fn from_shared(inner: ArcShared<dyn TimerDriver>) -> Self {
+    Self { inner }
+  }
+
+  /// Consumes the wrapper and returns the underlying shared handle.
+  #[must_use]
+  pub fn into_shared(self) -> ArcShared<dyn TimerDriver> {
+    self.inner
+  }
+
+  /// Returns the inner shared handle.
+  #[must_use]
+  pub const fn as_shared(&self) -> &ArcShared<dyn TimerDriver> {
+    &self.inner
+  }
+}
+
+impl Clone for TimerDriverShared {
+  fn clone(&self) -> Self {
+    Self { inner: self.inner.clone() }
+  }
+}
+
+impl core::ops::Deref for TimerDriverShared {
+  type Target = dyn TimerDriver;
+
+  fn deref(&self) -> &Self::Target {
+    &*self.inner
+  }
+}
@@ -0,0 +1,23 @@
+use core::time::Duration;
+
+/// How a timer is re-armed after it fires.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimerMode {
+  /// Fires once.
+  Single,
+  /// Fires at every interval, measured from the previous deadline so that late ticks catch up.
+  FixedRate(Duration),
+  /// Fires at every interval, measured from the moment the previous tick fired.
+  FixedDelay(Duration),
+}
+
+impl TimerMode {
+  /// Returns the interval between ticks, or `None` for a single-shot timer.
+  #[must_use]
+  pub const fn interval(&self) -> Option<Duration> {
+    match self {
+      | TimerMode::Single => None,
+      | TimerMode::FixedRate(interval) | TimerMode::FixedDelay(interval) => Some(*interval),
+    }
+  }
+}
@@ -27,7 +27,7 @@ use crate::{
     supervision::supervisor::Supervisor,
   },
   internal::{
-    actor_context::{ChildSpawnSpec, InternalActorContext},
+    actor_context::{ChildSpawnSpec, InternalActorContext, WatchedActor},
     mailbox::PriorityMailboxSpawnerHandle,
   },
   shared::{
@@ -46,6 +46,7 @@ where
   actor_id: ActorId,
   map_system: MapSystemShared<AnyMessage>,
   watchers: Vec<ActorId>,
+  watching: Vec<WatchedActor>,
   actor_path: ActorPath,
   pid: Pid,
   mailbox_factory: MF,
@@ -98,6 +99,7 @@ where
       actor_id,
       map_system,
       watchers,
+      watching: Vec::new(),
       actor_path,
       pid,
       mailbox_factory,
@@ -199,10 +201,34 @@ where
     }
     self.receive_timeout_scheduler_opt = None;
     self.receive_timeout_scheduler_factory_shared_opt = None;
+    // Watch requests queued behind the stop would be dropped with the mailbox; their watchers are
+    // notified along with the others.
+    self.collect_queued_watchers();
     self.mailbox.close();
+    self.collect_queued_watchers();
     self.process_registry.with_ref(|registry| registry.deregister(&self.pid));
     let _ = guardian.remove_child(self.actor_id);
-    self.watchers.clear();
+    for watcher in core::mem::take(&mut self.watchers) {
+      self.notify_terminated(guardian, watcher);
+    }
+    self.watching.clear();
+  }
+
+  fn collect_queued_watchers(&mut self) {
+    while let Ok(Some(envelope)) = MailboxConsumer::try_dequeue(&self.mailbox) {
+      if let Some(SystemMessage::Watch(watcher)) = envelope.system_message() {
+        if !self.watchers.contains(watcher) {
+          self.watchers.push(*watcher);
+        }
+      }
+    }
+  }
+
+  fn notify_terminated(&self, guardian: &Guardian<MF, Strat>, watcher: ActorId) {
+    if let Some((control_ref, map_system)) = guardian.child_route(watcher) {
+      let envelope = PriorityEnvelope::from_system(SystemMessage::Terminated(self.actor_id)).map(&*map_system);
+      let _ = control_ref.try_send_envelope_mailbox(envelope);
+    }
   }
 
   pub(super) const fn should_mark_stop_for_message() -> bool {
@@ -338,6 +364,13 @@ where
     let mut outcome = ActorInvokeOutcome::new();
     let mut processed = 0;
     for envelope in envelopes.into_iter() {
+      if self.stopped {
+        // A watch request dequeued along with the stop still learns of the termination.
+        if let Some(SystemMessage::Watch(watcher)) = envelope.system_message() {
+          self.notify_terminated(guardian, *watcher);
+        }
+        continue;
+      }
       if self.is_suspended() && envelope.system_message().is_none() {
         self.pending_user_envelopes.push_back(envelope);
         continue;
@@ -404,7 +437,7 @@ where
 
   pub(super) fn dispatch_envelope(
     &mut self,
-    envelope: PriorityEnvelope<AnyMessage>,
+    mut envelope: PriorityEnvelope<AnyMessage>,
     guardian: &mut Guardian<MF, Strat>,
     new_children: &mut Vec<ActorCell<MF, Strat>>,
     escalations: &mut Vec<FailureInfo>,
@@ -424,6 +457,22 @@ where
     }
 
     match envelope.system_message() {
+      | Some(SystemMessage::Watch(watcher)) => {
+        if !self.watchers.contains(watcher) {
+          self.watchers.push(*watcher);
+        }
+      },
+      | Some(SystemMessage::Unwatch(watcher)) => self.watchers.retain(|id| id != watcher),
+      | Some(SystemMessage::Terminated(actor)) => {
+        // Notifications of actors no longer watched are dropped, as are notifications sent to a
+        // parent that never watched its child.
+        let Some(index) = self.watching.iter().position(|watched| watched.actor_id() == Some(*actor)) else {
+          return Ok(());
+        };
+        if let Some(message) = self.watching.remove(index).into_message() {
+          envelope = PriorityEnvelope::new(message, envelope.priority());
+        }
+      },
       | Some(SystemMessage::Suspend) => {
         self.transition_to_suspended();
         if !outcome.is_set() {
@@ -501,6 +550,7 @@ where
       self.pid.clone(),
       self.process_registry.clone(),
       &mut self.watchers,
+      &mut self.watching,
       receive_timeout,
       self.extensions.clone(),
     );
@@ -6,6 +6,7 @@ use crate::{
   api::{
     actor::{actor_context::ActorContext, actor_failure::ActorFailure, ActorHandlerFn},
     actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    mailbox::messages::SystemMessage,
     messaging::MetadataStorageMode,
   },
   internal::actor_context::InternalActorContext,
@@ -55,8 +56,14 @@ where
   MailboxSignalOf<AR>: Clone,
   MailboxConcurrencyOf<AR>: MetadataStorageMode, {
   InternalProps::new(options, map_system, move |ctx, message| {
-    let Ok(envelope) = message.downcast::<MessageEnvelope<U>>() else {
-      return Err(ActorFailure::from_message("unexpected message type delivered to typed handler"));
+    // System messages delivered without knowledge of `U` (e.g. from the remote layer) arrive
+    // type-erased and are routed to the system handler as well.
+    let envelope = match message.downcast::<MessageEnvelope<U>>() {
+      | Ok(envelope) => envelope,
+      | Err(message) => match message.downcast::<SystemMessage>() {
+        | Ok(system) => MessageEnvelope::System(system),
+        | Err(_) => return Err(ActorFailure::from_message("unexpected message type delivered to typed handler")),
+      },
     };
     match envelope {
       | MessageEnvelope::User(user) => {
@@ -1,5 +1,7 @@
 mod child_spawn_spec;
 mod internal_actor_context;
+mod watched_actor;
 
 pub(crate) use child_spawn_spec::ChildSpawnSpec;
 pub use internal_actor_context::InternalActorContext;
+pub(crate) use watched_actor::WatchedActor;
@@ -12,7 +12,11 @@ use crate::{
     receive_timeout::ReceiveTimeoutScheduler,
     supervision::supervisor::Supervisor,
   },
-  internal::{actor::InternalProps, actor_context::ChildSpawnSpec, mailbox::PriorityMailboxSpawnerHandle},
+  internal::{
+    actor::InternalProps,
+    actor_context::{ChildSpawnSpec, WatchedActor},
+    mailbox::PriorityMailboxSpawnerHandle,
+  },
   shared::{
     mailbox::{messages::PriorityEnvelope, MailboxFactory, MailboxOptions, MailboxProducer},
     messaging::{AnyMessage, MapSystemShared},
@@ -40,6 +44,7 @@ where
   pid:              Pid,
   process_registry: ActorProcessRegistryShared<MF>,
   watchers:         &'a mut Vec<ActorId>,
+  watching:         &'a mut Vec<WatchedActor>,
   current_priority: Option<i8>,
   receive_timeout:  Option<&'a RefCell<Box<dyn ReceiveTimeoutScheduler>>>,
   extensions:       Extensions,
@@ -63,6 +68,7 @@ where
     pid: Pid,
     process_registry: ActorProcessRegistryShared<MF>,
     watchers: &'a mut Vec<ActorId>,
+    watching: &'a mut Vec<WatchedActor>,
     receive_timeout: Option<&'a RefCell<Box<dyn ReceiveTimeoutScheduler>>>,
     extensions: Extensions,
   ) -> Self {
@@ -78,6 +84,7 @@ where
       pid,
       process_registry,
       watchers,
+      watching,
       current_priority: None,
       receive_timeout,
       extensions,
@@ -154,6 +161,18 @@ where
     }
   }
 
+  /// Starts watching the actor whose PID is supplied through `target`, replacing any previous
+  /// watch of it. `message` is delivered instead of the terminated signal when provided.
+  pub(crate) fn watch(&mut self, target: ArcShared<RwLock<Option<Pid>>>, message: Option<AnyMessage>) {
+    self.unwatch(&target);
+    self.watching.push(WatchedActor::new(target, message));
+  }
+
+  /// Stops watching the actor whose PID is supplied through `target`.
+  pub(crate) fn unwatch(&mut self, target: &ArcShared<RwLock<Option<Pid>>>) {
+    self.watching.retain(|watched| !watched.is_target(target));
+  }
+
   /// Returns an actor reference to the actor itself.
   pub(crate) fn self_ref(&self) -> PriorityActorRef<AnyMessage, MF>
   where
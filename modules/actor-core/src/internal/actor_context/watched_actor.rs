@@ -0,0 +1,42 @@
+use cellex_utils_core_rs::sync::ArcShared;
+use spin::RwLock;
+
+use crate::{
+  api::{actor::ActorId, process::pid::Pid},
+  shared::messaging::AnyMessage,
+};
+
+/// Actor watched by the running actor, with the message to deliver once it terminates.
+pub(crate) struct WatchedActor {
+  /// Slot holding the PID of the watched actor, filled once a freshly spawned child is registered.
+  target:  ArcShared<RwLock<Option<Pid>>>,
+  /// Message delivered instead of the terminated signal, if any.
+  message: Option<AnyMessage>,
+}
+
+impl WatchedActor {
+  /// Creates an entry for the actor behind `target`.
+  pub(crate) const fn new(target: ArcShared<RwLock<Option<Pid>>>, message: Option<AnyMessage>) -> Self {
+    Self { target, message }
+  }
+
+  /// Returns the ID of the watched actor, if its PID is known yet.
+  pub(crate) fn actor_id(&self) -> Option<ActorId> {
+    self.target.read().as_ref().and_then(|pid| pid.path().last())
+  }
+
+  /// Returns whether `target` designates the watched actor.
+  pub(crate) fn is_target(&self, target: &ArcShared<RwLock<Option<Pid>>>) -> bool {
+    if core::ptr::eq(&*self.target, &**target) {
+      return true;
+    }
+    let watched = self.target.read();
+    let other = target.read();
+    matches!((watched.as_ref(), other.as_ref()), (Some(watched), Some(other)) if watched == other)
+  }
+
+  /// Consumes the entry, returning the message to deliver instead of the terminated signal.
+  pub(crate) fn into_message(self) -> Option<AnyMessage> {
+    self.message
+  }
+}
@@ -1,5 +1,8 @@
+use alloc::boxed::Box;
+
 use crate::{
   api::{
+    actor::behavior::TimerTick,
     mailbox::{MailboxConcurrency, ThreadSafe},
     process::pid::Pid,
   },
@@ -13,6 +16,7 @@ pub(crate) struct InternalMessageMetadata<C: MailboxConcurrency = ThreadSafe> {
   pub(crate) responder:     Option<InternalMessageSender<C>>,
   pub(crate) sender_pid:    Option<Pid>,
   pub(crate) responder_pid: Option<Pid>,
+  pub(crate) timer_tick:    Option<Box<TimerTick>>,
 }
 
 impl<C> InternalMessageMetadata<C>
@@ -26,7 +30,7 @@ where
   /// * `responder` - Responder's dispatcher (optional)
   #[allow(dead_code)]
   pub const fn new(sender: Option<InternalMessageSender<C>>, responder: Option<InternalMessageSender<C>>) -> Self {
-    Self { sender, responder, sender_pid: None, responder_pid: None }
+    Self { sender, responder, sender_pid: None, responder_pid: None, timer_tick: None }
   }
 
   /// Gets a reference to the sender's dispatcher.
@@ -109,6 +113,6 @@ where
   C: MailboxConcurrency,
 {
   fn default() -> Self {
-    Self { sender: None, responder: None, sender_pid: None, responder_pid: None }
+    Self { sender: None, responder: None, sender_pid: None, responder_pid: None, timer_tick: None }
   }
 }
@@ -19,6 +19,12 @@ where
     self.try_send(message).map_err(MailboxError::from_queue_error)
   }
 
+  /// Returns the number of messages waiting in the mailbox. Default: `None`, for producers that
+  /// cannot tell.
+  fn len_opt(&self) -> Option<usize> {
+    None
+  }
+
   /// Injects a metrics sink for enqueue instrumentation. Default: no-op.
   fn set_metrics_sink(&mut self, _sink: Option<MetricsSinkShared>) {}
 
@@ -59,6 +59,7 @@ extern crate alloc;
 mod default_mailbox;
 #[cfg(feature = "embedded_arc")]
 mod default_priority_mailbox;
+mod manual_timer_driver;
 #[cfg(feature = "embassy_executor")]
 mod receive_timeout;
 /// Runtime driver for failure event handling in embedded environments.
@@ -81,6 +82,7 @@ mod embassy_dispatcher;
 pub use scheduler::{embassy_scheduler_builder, EmbassyActorRuntimeExt, EmbassyScheduler};
 /// Local mailbox implementation for embedded environments.
 pub mod local_mailbox;
+pub use manual_timer_driver::ManualTimerDriver;
 pub use spawn::ImmediateSpawner;
 pub use timer::ImmediateTimer;
 
@@ -0,0 +1,128 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{
+  task::{Context, Poll, Waker},
+  time::Duration,
+};
+
+use cellex_actor_core_rs::api::timer_driver::{TimerDriver, TimerMode, TimerTask};
+use cellex_utils_core_rs::{
+  sync::ArcShared,
+  timing::deadline_timer::{DeadlineTimer, DeadlineTimerKey, DeadlineTimerKeyAllocator, TimerDeadline},
+};
+use cellex_utils_embedded_rs::timing::ManualDeadlineTimer;
+use spin::Mutex;
+
+#[cfg(test)]
+mod tests;
+
+struct Entry {
+  timer_key: DeadlineTimerKey,
+  deadline:  Duration,
+  mode:      TimerMode,
+  task:      TimerTask,
+}
+
+struct DriverState {
+  timer:   ManualDeadlineTimer<DeadlineTimerKey>,
+  entries: BTreeMap<DeadlineTimerKey, Entry>,
+  now:     Duration,
+}
+
+/// Software-driven `TimerDriver` for environments without a timer task.
+///
+/// Time only moves forward through [`Self::advance`], typically called from a tick interrupt or
+/// the main loop, which runs the tasks of the timers that came due on the calling thread. Clones
+/// share the same timers, so one clone can be installed on the actor runtime while another keeps
+/// advancing the clock.
+#[derive(Clone)]
+pub struct ManualTimerDriver {
+  allocator: ArcShared<DeadlineTimerKeyAllocator>,
+  state:     ArcShared<Mutex<DriverState>>,
+}
+
+impl ManualTimerDriver {
+  /// Creates a driver with no timers, whose clock starts at zero.
+  #[must_use]
+  pub fn new() -> Self {
+    Self {
+      allocator: ArcShared::new(DeadlineTimerKeyAllocator::new()),
+      state:     ArcShared::new(Mutex::new(DriverState {
+        timer:   ManualDeadlineTimer::new(),
+        entries: BTreeMap::new(),
+        now:     Duration::ZERO,
+      })),
+    }
+  }
+
+  /// Moves the clock forward by `elapsed` and runs the tasks of every timer that came due, in
+  /// deadline order. Fixed-rate timers fire once for each interval that went by.
+  pub fn advance(&self, elapsed: Duration) {
+    let mut due = Vec::new();
+    {
+      let mut state = self.state.lock();
+      state.now += elapsed;
+      state.timer.advance(elapsed);
+      let mut cx = Context::from_waker(Waker::noop());
+      while let Poll::Ready(Ok(expired)) = state.timer.poll_expired(&mut cx) {
+        let key = expired.item;
+        let now = state.now;
+        let Some(entry) = state.entries.get_mut(&key) else {
+          continue;
+        };
+        due.push(entry.task.clone());
+        let next_delay = match entry.mode {
+          | TimerMode::Single => None,
+          | TimerMode::FixedRate(interval) => {
+            entry.deadline += interval;
+            Some(entry.deadline.saturating_sub(now))
+          },
+          | TimerMode::FixedDelay(interval) => {
+            entry.deadline = now + interval;
+            Some(interval)
+          },
+        };
+        match next_delay {
+          | Some(delay) => {
+            if let Ok(timer_key) = state.timer.insert(key, TimerDeadline::from(delay)) {
+              if let Some(entry) = state.entries.get_mut(&key) {
+                entry.timer_key = timer_key;
+              }
+            }
+          },
+          | None => {
+            state.entries.remove(&key);
+          },
+        }
+      }
+    }
+    // Tasks run unlocked so that they may start or cancel timers themselves.
+    for task in due {
+      task();
+    }
+  }
+}
+
+impl Default for ManualTimerDriver {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl TimerDriver for ManualTimerDriver {
+  fn start(&self, delay: Duration, mode: TimerMode, task: TimerTask) -> DeadlineTimerKey {
+    let key = self.allocator.allocate();
+    let mut state = self.state.lock();
+    if let Ok(timer_key) = state.timer.insert(key, TimerDeadline::from(delay)) {
+      let deadline = state.now + delay;
+      state.entries.insert(key, Entry { timer_key, deadline, mode, task });
+    }
+    key
+  }
+
+  fn cancel(&self, key: DeadlineTimerKey) {
+    let mut state = self.state.lock();
+    if let Some(entry) = state.entries.remove(&key) {
+      let _ = state.timer.cancel(entry.timer_key);
+    }
+  }
+}
@@ -0,0 +1,53 @@
+use alloc::vec;
+
+use cellex_actor_core_rs::api::timer_driver::TimerTaskFn;
+
+use super::*;
+
+type FiredLog = ArcShared<Mutex<Vec<&'static str>>>;
+
+fn record(log: &FiredLog, label: &'static str) -> TimerTask {
+  let log = log.clone();
+  ArcShared::new(move || log.lock().push(label)).into_dyn(|task| task as &TimerTaskFn)
+}
+
+#[test]
+fn manual_timer_driver_fires_due_timers_on_advance() {
+  let driver = ManualTimerDriver::new();
+  let log: FiredLog = ArcShared::new(Mutex::new(Vec::new()));
+
+  driver.start(Duration::from_millis(10), TimerMode::Single, record(&log, "single"));
+  let cancelled = driver.start(Duration::from_millis(10), TimerMode::Single, record(&log, "cancelled"));
+  driver.cancel(cancelled);
+
+  driver.advance(Duration::from_millis(9));
+  assert!(log.lock().is_empty());
+
+  driver.advance(Duration::from_millis(1));
+  driver.advance(Duration::from_millis(20));
+  assert_eq!(*log.lock(), vec!["single"]);
+}
+
+#[test]
+fn manual_timer_driver_catches_up_fixed_rate_but_not_fixed_delay() {
+  let driver = ManualTimerDriver::new();
+  let log: FiredLog = ArcShared::new(Mutex::new(Vec::new()));
+
+  let rate =
+    driver.start(Duration::from_millis(10), TimerMode::FixedRate(Duration::from_millis(10)), record(&log, "rate"));
+  driver.start(Duration::from_millis(10), TimerMode::FixedDelay(Duration::from_millis(10)), record(&log, "delay"));
+
+  driver.advance(Duration::from_millis(30));
+  let count = |label: &str| log.lock().iter().filter(|fired| **fired == label).count();
+  assert_eq!(count("rate"), 3);
+  assert_eq!(count("delay"), 1);
+
+  driver.advance(Duration::from_millis(10));
+  assert_eq!(count("rate"), 4);
+  assert_eq!(count("delay"), 2);
+
+  driver.cancel(rate);
+  driver.advance(Duration::from_millis(10));
+  assert_eq!(count("rate"), 4);
+  assert_eq!(count("delay"), 3);
+}
@@ -65,6 +65,8 @@ fn embedded_failure_event_hub_broadcasts() {
 
   assert_eq!(received.lock().unwrap().len(), 1);
   let guard = received.lock().unwrap();
-  let FailureEvent::RootEscalated(recorded) = &guard[0];
+  let FailureEvent::RootEscalated(recorded) = &guard[0] else {
+    panic!("root escalation expected");
+  };
   assert_eq!(recorded.actor, info.actor);
 }
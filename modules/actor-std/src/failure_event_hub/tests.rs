@@ -37,9 +37,7 @@ fn hub_forwards_events_to_subscribers() {
 
   let events = storage.lock().unwrap_or_else(|err| err.into_inner());
   assert_eq!(events.len(), 1);
-  match &events[0] {
-    | FailureEvent::RootEscalated(info) => assert_eq!(info.description().as_ref(), "boom"),
-  }
+  assert!(matches!(&events[0], FailureEvent::RootEscalated(info) if info.description().as_ref() == "boom"));
 }
 
 #[test]
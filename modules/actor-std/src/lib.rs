@@ -69,6 +69,7 @@ mod timer;
 pub mod tokio_mailbox;
 /// Tokio priority mailbox implementation.
 pub mod tokio_priority_mailbox;
+mod tokio_timer_driver;
 
 #[cfg(test)]
 mod tests;
@@ -77,13 +78,18 @@ use cellex_actor_core_rs::api::actor_runtime::GenericActorRuntime;
 pub use runtime_driver::TokioSystemHandle;
 pub use spawn::TokioSpawner;
 pub use timer::TokioTimer;
+pub use tokio_timer_driver::TokioTimerDriver;
 
 /// Default actor runtime preset for Tokio environments.
 pub type TokioActorRuntime = GenericActorRuntime<tokio_mailbox::TokioMailboxFactory>;
 
-/// Builds the default Tokio-oriented actor runtime preset.
-#[must_use]
-pub fn tokio_actor_runtime() -> TokioActorRuntime {
+/// Builds the default Tokio-oriented actor runtime preset on the Tokio runtime of the calling
+/// context.
+///
+/// # Errors
+/// Returns [`TryCurrentError`](tokio::runtime::TryCurrentError) when called outside a Tokio
+/// runtime.
+pub fn tokio_actor_runtime() -> Result<TokioActorRuntime, tokio::runtime::TryCurrentError> {
   use scheduler::TokioActorRuntimeExt;
 
   GenericActorRuntime::new(tokio_mailbox::TokioMailboxFactory).with_tokio_scheduler()
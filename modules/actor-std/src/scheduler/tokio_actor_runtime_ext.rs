@@ -1,23 +1,37 @@
 use cellex_actor_core_rs::api::{
   actor_runtime::GenericActorRuntime, receive_timeout::ReceiveTimeoutSchedulerFactoryProviderShared,
+  timer_driver::TimerDriverShared,
 };
+use tokio::runtime::TryCurrentError;
 
 use crate::{
   receive_timeout::TokioReceiveTimeoutDriver, scheduler::tokio_scheduler::tokio_scheduler_builder,
-  tokio_mailbox::TokioMailboxFactory,
+  tokio_mailbox::TokioMailboxFactory, TokioTimerDriver,
 };
 
-/// Extension trait that installs Tokio-specific scheduler and timeout settings on
+/// Extension trait that installs Tokio-specific scheduler, timeout and timer settings on
 /// [`GenericActorRuntime`].
 pub trait TokioActorRuntimeExt {
-  /// Replaces the scheduler with the Tokio-backed implementation.
-  fn with_tokio_scheduler(self) -> GenericActorRuntime<TokioMailboxFactory>;
+  /// Replaces the scheduler, receive-timeout driver and timer driver with the Tokio-backed
+  /// implementations.
+  ///
+  /// The timer driver runs on the Tokio runtime of the calling context.
+  ///
+  /// # Errors
+  /// Returns [`TryCurrentError`] when called outside a Tokio runtime.
+  fn with_tokio_scheduler(self) -> Result<GenericActorRuntime<TokioMailboxFactory>, TryCurrentError>;
 }
 
 impl TokioActorRuntimeExt for GenericActorRuntime<TokioMailboxFactory> {
-  fn with_tokio_scheduler(self) -> GenericActorRuntime<TokioMailboxFactory> {
-    self.with_scheduler_builder(tokio_scheduler_builder()).with_receive_timeout_scheduler_factory_provider_shared_opt(
-      Some(ReceiveTimeoutSchedulerFactoryProviderShared::new(TokioReceiveTimeoutDriver::new())),
+  fn with_tokio_scheduler(self) -> Result<GenericActorRuntime<TokioMailboxFactory>, TryCurrentError> {
+    let timer_driver = TokioTimerDriver::current()?;
+    Ok(
+      self
+        .with_scheduler_builder(tokio_scheduler_builder())
+        .with_receive_timeout_scheduler_factory_provider_shared_opt(Some(
+          ReceiveTimeoutSchedulerFactoryProviderShared::new(TokioReceiveTimeoutDriver::new()),
+        ))
+        .with_timer_driver_shared_opt(Some(TimerDriverShared::new(timer_driver))),
     )
   }
 }
@@ -4,7 +4,12 @@ use std::sync::{Arc, Mutex};
 use cellex_actor_core_rs::{
   actor_loop,
   api::{
-    actor::{actor_context::ActorContext, ActorId, ChildNaming, Props, Spawn},
+    actor::{
+      actor_context::ActorContext,
+      actor_ref::ActorRef,
+      behavior::{Behaviors, TimerScheduler},
+      ActorId, ChildNaming, Props, Signal, Spawn,
+    },
     actor_runtime::GenericActorRuntime,
     actor_scheduler::ActorSchedulerSpawnContext,
     actor_system::{GenericActorSystem, GenericActorSystemConfig},
@@ -15,14 +20,19 @@ use cellex_actor_core_rs::{
       process_registry::ProcessRegistry,
     },
     receive_timeout::ReceiveTimeoutSchedulerFactoryShared,
+    routing::{GroupRouter, PoolResizer, PoolRouter, RouterMessage, RoutingLogic},
     supervision::supervisor::NoopSupervisor,
+    timer_driver::{TimerDriver, TimerDriverShared, TimerMode, TimerTask, TimerTaskFn},
   },
   shared::{
     mailbox::MailboxOptions,
     messaging::{AnyMessage, MapSystemShared, MessageEnvelope},
   },
 };
-use cellex_utils_core_rs::sync::{ArcShared, StateCell};
+use cellex_utils_core_rs::{
+  sync::{ArcShared, StateCell},
+  timing::deadline_timer::DeadlineTimerKey,
+};
 use cellex_utils_std_rs::sync::ArcStateCell;
 use spin::RwLock;
 
@@ -40,6 +50,43 @@ enum Message {
   System(SystemMessage),
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum TimerMessage {
+  Tick,
+  Once,
+  Stop,
+}
+
+/// Timer driver running its timers only when told to, as if their deadlines had just passed.
+#[derive(Clone, Default)]
+struct OnDemandTimerDriver {
+  tasks: Arc<Mutex<Vec<TimerTask>>>,
+}
+
+impl OnDemandTimerDriver {
+  fn fire_all(&self) {
+    let tasks = self.tasks.lock().unwrap_or_else(|err| err.into_inner()).clone();
+    for task in tasks {
+      task();
+    }
+  }
+}
+
+impl TimerDriver for OnDemandTimerDriver {
+  fn start(&self, _delay: Duration, _mode: TimerMode, task: TimerTask) -> DeadlineTimerKey {
+    let mut tasks = self.tasks.lock().unwrap_or_else(|err| err.into_inner());
+    tasks.push(task);
+    DeadlineTimerKey::from_raw(tasks.len() as u64)
+  }
+
+  fn cancel(&self, _key: DeadlineTimerKey) {}
+}
+
+type TimerSchedulerSlot = Arc<Mutex<Option<TimerScheduler<TimerMessage, TokioActorRuntime>>>>;
+type RouterSystem = GenericActorSystem<RouterMessage<u32, TokioActorRuntime>, TokioActorRuntime>;
+type RouterRef = ActorRef<RouterMessage<u32, TokioActorRuntime>, TokioActorRuntime>;
+type RoutedLog = Arc<Mutex<Vec<(ActorId, u32)>>>;
+
 async fn run_test_actor_loop_updates_state() -> TestResult {
   let (mailbox, sender) = TokioMailbox::new(8);
   let mailbox = Arc::new(mailbox);
@@ -157,7 +204,7 @@ async fn typed_actor_system_handles_user_messages_multi_thread() -> TestResult {
 
 #[tokio::test]
 async fn tokio_scheduler_builder_dispatches() -> TestResult {
-  let bundle: TokioActorRuntime = tokio_actor_runtime();
+  let bundle: TokioActorRuntime = tokio_actor_runtime().map_err(|err| format!("bundle: {err}"))?;
   let mailbox_factory = bundle.mailbox_factory().clone();
   let mut scheduler = tokio_scheduler_builder().build(mailbox_factory.clone(), Extensions::new());
 
@@ -194,14 +241,23 @@ async fn tokio_scheduler_builder_dispatches() -> TestResult {
 }
 
 #[test]
-fn tokio_bundle_sets_default_receive_timeout_factory() {
-  let bundle: TokioActorRuntime = tokio_actor_runtime();
+fn tokio_bundle_sets_default_receive_timeout_factory() -> TestResult {
+  let runtime = tokio::runtime::Builder::new_current_thread().build().map_err(|err| format!("runtime: {err}"))?;
+  let _guard = runtime.enter();
+  let bundle: TokioActorRuntime = tokio_actor_runtime().map_err(|err| format!("bundle: {err}"))?;
   let factory_from_bundle = bundle.receive_timeout_scheduler_factory_shared();
   let factory_from_driver = bundle.receive_timeout_scheduler_factory_provider_shared_opt();
   assert!(
     factory_from_bundle.is_some() || factory_from_driver.is_some(),
     "Tokio バンドルは ReceiveTimeout ドライバまたはファクトリを提供する想定"
   );
+  assert!(bundle.timer_driver_shared_opt().is_some());
+  Ok(())
+}
+
+#[test]
+fn tokio_bundle_requires_a_tokio_runtime() {
+  assert!(tokio_actor_runtime().is_err());
 }
 
 #[tokio::test(flavor = "current_thread")]
@@ -213,3 +269,345 @@ async fn receive_timeout_triggers() -> TestResult {
 async fn receive_timeout_triggers_multi_thread() -> TestResult {
   run_receive_timeout_triggers().await
 }
+
+async fn run_timers_deliver_messages_until_the_actor_stops() -> TestResult {
+  let mut system: GenericActorSystem<TimerMessage, _> = GenericActorSystem::new_with_actor_runtime(
+    tokio_actor_runtime().map_err(|err| format!("runtime: {err}"))?,
+    GenericActorSystemConfig::default(),
+  );
+
+  let log: Arc<Mutex<Vec<TimerMessage>>> = Arc::new(Mutex::new(Vec::new()));
+  let slot: TimerSchedulerSlot = Arc::new(Mutex::new(None));
+  let props = Props::with_behavior({
+    let log = log.clone();
+    let slot = slot.clone();
+    move || {
+      let log = log.clone();
+      let slot = slot.clone();
+      Behaviors::with_timers(move |timers| {
+        timers.start_timer_at_fixed_rate("tick", TimerMessage::Tick, Duration::from_millis(5));
+        timers.start_single_timer("once", TimerMessage::Once, Duration::from_millis(5));
+        *slot.lock().unwrap_or_else(|err| err.into_inner()) = Some(timers);
+        let log = log.clone();
+        Behaviors::receive(move |_: &mut ActorContext<'_, '_, TimerMessage, TokioActorRuntime>, msg: TimerMessage| {
+          let stop = msg == TimerMessage::Stop;
+          log.lock().unwrap_or_else(|err| err.into_inner()).push(msg);
+          Ok(if stop { Behaviors::stopped() } else { Behaviors::same() })
+        })
+      })
+    }
+  });
+
+  let mut root = system.root_context();
+  let actor_ref = root.spawn(props).map_err(|err| format!("spawn timer actor: {:?}", err))?;
+  let count = |message: &TimerMessage| {
+    log.lock().unwrap_or_else(|err| err.into_inner()).iter().filter(|logged| *logged == message).count()
+  };
+
+  while count(&TimerMessage::Tick) < 3 {
+    tokio::time::timeout(Duration::from_secs(1), root.dispatch_next())
+      .await
+      .map_err(|_| "timed out waiting for ticks".to_string())?
+      .map_err(|err| format!("dispatch tick: {:?}", err))?;
+  }
+  let timers = slot.lock().unwrap_or_else(|err| err.into_inner()).clone().ok_or("timers were not set up")?;
+  assert_eq!(count(&TimerMessage::Once), 1);
+  assert!(!timers.is_timer_active("once"));
+  assert!(timers.is_timer_active("tick"));
+
+  actor_ref.tell(TimerMessage::Stop).map_err(|err| format!("tell stop: {:?}", err))?;
+  while count(&TimerMessage::Stop) == 0 {
+    tokio::time::timeout(Duration::from_secs(1), root.dispatch_next())
+      .await
+      .map_err(|_| "timed out waiting for stop".to_string())?
+      .map_err(|err| format!("dispatch stop: {:?}", err))?;
+  }
+  assert!(!timers.is_timer_active("tick"));
+  Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn timers_deliver_messages_until_the_actor_stops() -> TestResult {
+  run_timers_deliver_messages_until_the_actor_stops().await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn timers_deliver_messages_until_the_actor_stops_multi_thread() -> TestResult {
+  run_timers_deliver_messages_until_the_actor_stops().await
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn tokio_timer_driver_repeats_periodic_timers_and_honours_cancellation() -> TestResult {
+  let driver = TokioTimerDriver::current().map_err(|err| format!("current runtime: {err}"))?;
+  let fired = |log: &Arc<Mutex<Vec<&'static str>>>, label: &'static str| -> TimerTask {
+    let log = log.clone();
+    ArcShared::new(move || {
+      log.lock().unwrap_or_else(|err| err.into_inner()).push(label);
+    })
+    .into_dyn(|task| task as &TimerTaskFn)
+  };
+  let log: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(Vec::new()));
+
+  driver.start(Duration::from_millis(10), TimerMode::FixedRate(Duration::from_millis(10)), fired(&log, "rate"));
+  driver.start(Duration::from_millis(10), TimerMode::FixedDelay(Duration::from_millis(10)), fired(&log, "delay"));
+  let single = driver.start(Duration::from_millis(10), TimerMode::Single, fired(&log, "single"));
+  let cancelled = driver.start(Duration::from_millis(5), TimerMode::Single, fired(&log, "cancelled"));
+  driver.cancel(cancelled);
+
+  tokio::time::sleep(Duration::from_millis(55)).await;
+  driver.cancel(single);
+
+  let log = log.lock().unwrap_or_else(|err| err.into_inner()).clone();
+  assert!(log.iter().filter(|label| **label == "rate").count() >= 2);
+  assert!(log.iter().filter(|label| **label == "delay").count() >= 2);
+  assert_eq!(log.iter().filter(|label| **label == "single").count(), 1);
+  assert!(!log.contains(&"cancelled"));
+  Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn timer_messages_left_in_the_mailbox_are_dropped_once_their_timer_is_cancelled() -> TestResult {
+  let driver = OnDemandTimerDriver::default();
+  let actor_runtime = tokio_actor_runtime()
+    .map_err(|err| format!("runtime: {err}"))?
+    .with_timer_driver_shared_opt(Some(TimerDriverShared::new(driver.clone())));
+  let mut system: GenericActorSystem<TimerMessage, _> =
+    GenericActorSystem::new_with_actor_runtime(actor_runtime, GenericActorSystemConfig::default());
+
+  let log: Arc<Mutex<Vec<TimerMessage>>> = Arc::new(Mutex::new(Vec::new()));
+  let slot: TimerSchedulerSlot = Arc::new(Mutex::new(None));
+  let props = Props::with_behavior({
+    let log = log.clone();
+    let slot = slot.clone();
+    move || {
+      let log = log.clone();
+      let slot = slot.clone();
+      Behaviors::with_timers(move |timers| {
+        timers.start_timer_at_fixed_rate("tick", TimerMessage::Tick, Duration::from_millis(5));
+        *slot.lock().unwrap_or_else(|err| err.into_inner()) = Some(timers);
+        let log = log.clone();
+        Behaviors::receive(move |_: &mut ActorContext<'_, '_, TimerMessage, TokioActorRuntime>, msg: TimerMessage| {
+          log.lock().unwrap_or_else(|err| err.into_inner()).push(msg);
+          Ok(Behaviors::same())
+        })
+      })
+    }
+  });
+  let actor_ref = system.root_context().spawn(props).map_err(|err| format!("spawn timer actor: {:?}", err))?;
+  system.run_until_idle().map_err(|err| format!("set up: {:?}", err))?;
+  let logged = |log: &Arc<Mutex<Vec<TimerMessage>>>| log.lock().unwrap_or_else(|err| err.into_inner()).clone();
+
+  driver.fire_all();
+  driver.fire_all();
+  let timers = slot.lock().unwrap_or_else(|err| err.into_inner()).clone().ok_or("timers were not set up")?;
+  timers.cancel("tick");
+  system.run_until_idle().map_err(|err| format!("drain after cancel: {:?}", err))?;
+  assert!(logged(&log).is_empty(), "ticks of a cancelled timer must not be handled");
+
+  timers.start_timer_at_fixed_rate("tick", TimerMessage::Tick, Duration::from_millis(5));
+  driver.fire_all();
+  system.run_until_idle().map_err(|err| format!("run restarted timer: {:?}", err))?;
+  assert_eq!(logged(&log), vec![TimerMessage::Tick]);
+
+  driver.fire_all();
+  actor_ref.send_system(SystemMessage::Restart).map_err(|err| format!("send restart: {:?}", err))?;
+  system.run_until_idle().map_err(|err| format!("drain after restart: {:?}", err))?;
+  assert_eq!(logged(&log), vec![TimerMessage::Tick], "ticks sent before a restart must not be handled");
+  Ok(())
+}
+
+#[test]
+fn tokio_timer_driver_runs_on_its_runtime_when_started_from_any_thread() -> TestResult {
+  assert!(TokioTimerDriver::current().is_err(), "no Tokio runtime runs on the test thread");
+
+  let runtime = tokio::runtime::Builder::new_multi_thread()
+    .worker_threads(1)
+    .enable_time()
+    .build()
+    .map_err(|err| format!("build runtime: {err}"))?;
+  let driver = TokioTimerDriver::new(runtime.handle().clone());
+  let (fired_tx, fired_rx) = std::sync::mpsc::channel();
+  let task = ArcShared::new(move || {
+    let _ = fired_tx.send(());
+  })
+  .into_dyn(|task| task as &TimerTaskFn);
+
+  driver.start(Duration::from_millis(1), TimerMode::Single, task);
+
+  fired_rx.recv_timeout(Duration::from_secs(1)).map_err(|err| format!("timer did not fire: {err}"))
+}
+
+fn logging_worker(log: &RoutedLog) -> Props<u32, TokioActorRuntime> {
+  let log = log.clone();
+  Props::new(move |ctx: &mut ActorContext<'_, '_, u32, TokioActorRuntime>, msg: u32| {
+    log.lock().unwrap_or_else(|err| err.into_inner()).push((ctx.actor_id(), msg));
+    Ok(())
+  })
+}
+
+fn counted_worker(live: &Arc<Mutex<usize>>) -> Props<u32, TokioActorRuntime> {
+  let live = live.clone();
+  Props::with_behavior(move || {
+    let live = live.clone();
+    Behaviors::receive(|_, _: u32| Ok(Behaviors::same())).receive_signal(move |_, signal| {
+      let mut live = live.lock().unwrap_or_else(|err| err.into_inner());
+      match signal {
+        | Signal::PreStart => *live += 1,
+        | Signal::PostStop => *live -= 1,
+        | _ => {},
+      }
+      Behaviors::same()
+    })
+  })
+}
+
+fn spawn_router(
+  system: &mut RouterSystem,
+  props: Props<RouterMessage<u32, TokioActorRuntime>, TokioActorRuntime>,
+) -> TestResult<RouterRef> {
+  let router = system.root_context().spawn(props).map_err(|err| format!("spawn router: {:?}", err))?;
+  system.run_until_idle().map_err(|err| format!("start router: {:?}", err))?;
+  Ok(router)
+}
+
+fn route(system: &mut RouterSystem, router: &RouterRef, messages: impl IntoIterator<Item = u32>) -> TestResult {
+  for message in messages {
+    router.tell(RouterMessage::Route(message)).map_err(|err| format!("route {message}: {:?}", err))?;
+  }
+  system.run_until_idle().map_err(|err| format!("run router: {:?}", err))
+}
+
+async fn routees(system: &mut RouterSystem, router: &RouterRef) -> TestResult<Vec<Pid>> {
+  let routees = router.ask_with(RouterMessage::GetRoutees).map_err(|err| format!("ask routees: {:?}", err))?;
+  system.run_until_idle().map_err(|err| format!("run router: {:?}", err))?;
+  routees.await.map_err(|err| format!("await routees: {:?}", err))
+}
+
+#[tokio::test]
+async fn pool_router_routes_in_turn_and_follows_management_messages() -> TestResult {
+  let mut system: RouterSystem = GenericActorSystem::new_with_actor_runtime(
+    tokio_actor_runtime().map_err(|err| format!("runtime: {err}"))?,
+    GenericActorSystemConfig::default(),
+  );
+  let log: RoutedLog = Arc::new(Mutex::new(Vec::new()));
+  let props = PoolRouter::new(3, {
+    let log = log.clone();
+    move || logging_worker(&log)
+  })
+  .props();
+  let router = spawn_router(&mut system, props)?;
+
+  route(&mut system, &router, 0..6)?;
+  let routed = log.lock().unwrap_or_else(|err| err.into_inner()).clone();
+  assert_eq!(routed.len(), 6);
+  let worker_of = |message: u32| routed.iter().find(|(_, routed)| *routed == message).map(|(worker, _)| *worker);
+  for message in 0..3 {
+    assert_eq!(worker_of(message), worker_of(message + 3));
+  }
+  assert_ne!(worker_of(0), worker_of(1));
+  assert_ne!(worker_of(1), worker_of(2));
+  assert_ne!(worker_of(0), worker_of(2));
+
+  router.tell(RouterMessage::AdjustPoolSize(2)).map_err(|err| format!("grow: {:?}", err))?;
+  let grown = routees(&mut system, &router).await?;
+  assert_eq!(grown.len(), 5);
+
+  router.tell(RouterMessage::RemoveRoutee(grown[0].clone())).map_err(|err| format!("remove: {:?}", err))?;
+  router.tell(RouterMessage::AdjustPoolSize(-3)).map_err(|err| format!("shrink: {:?}", err))?;
+  let shrunk = routees(&mut system, &router).await?;
+  assert_eq!(shrunk, grown[1..2].to_vec());
+  Ok(())
+}
+
+#[tokio::test]
+async fn pool_router_stops_its_routees_when_restarted() -> TestResult {
+  let mut system: RouterSystem = GenericActorSystem::new_with_actor_runtime(
+    tokio_actor_runtime().map_err(|err| format!("runtime: {err}"))?,
+    GenericActorSystemConfig::default(),
+  );
+  let live = Arc::new(Mutex::new(0_usize));
+  let props = PoolRouter::new(3, {
+    let live = live.clone();
+    move || counted_worker(&live)
+  })
+  .props();
+  let router = spawn_router(&mut system, props)?;
+  let before = routees(&mut system, &router).await?;
+  assert_eq!(*live.lock().unwrap_or_else(|err| err.into_inner()), 3);
+
+  router.send_system(SystemMessage::Restart).map_err(|err| format!("restart router: {:?}", err))?;
+  system.run_until_idle().map_err(|err| format!("run restart: {:?}", err))?;
+
+  let after = routees(&mut system, &router).await?;
+  assert_eq!(*live.lock().unwrap_or_else(|err| err.into_inner()), 3);
+  assert_eq!(after.len(), 3);
+  assert!(after.iter().all(|pid| !before.contains(pid)), "the restarted router spawns a new pool");
+  Ok(())
+}
+
+#[tokio::test]
+async fn pool_router_broadcasts_and_resizes_with_the_load() -> TestResult {
+  let mut system: RouterSystem = GenericActorSystem::new_with_actor_runtime(
+    tokio_actor_runtime().map_err(|err| format!("runtime: {err}"))?,
+    GenericActorSystemConfig::default(),
+  );
+  let log: RoutedLog = Arc::new(Mutex::new(Vec::new()));
+  let props = PoolRouter::new(5, {
+    let log = log.clone();
+    move || logging_worker(&log)
+  })
+  .with_logic(RoutingLogic::Broadcast)
+  .with_resizer(PoolResizer::new(1, 3))
+  .props();
+  let router = spawn_router(&mut system, props)?;
+  assert_eq!(routees(&mut system, &router).await?.len(), 3);
+
+  // No routee has any message waiting, so the pool shrinks before routing.
+  route(&mut system, &router, [7])?;
+  let routed = log.lock().unwrap_or_else(|err| err.into_inner()).clone();
+  assert_eq!(routed.len(), 2);
+  assert!(routed.iter().all(|(_, message)| *message == 7));
+  assert_ne!(routed[0].0, routed[1].0);
+  assert_eq!(routees(&mut system, &router).await?.len(), 2);
+  Ok(())
+}
+
+#[tokio::test]
+async fn group_router_prefers_the_smallest_mailbox_and_takes_new_routees() -> TestResult {
+  let mut workers: GenericActorSystem<u32, TokioActorRuntime> = GenericActorSystem::new_with_actor_runtime(
+    tokio_actor_runtime().map_err(|err| format!("runtime: {err}"))?,
+    GenericActorSystemConfig::default(),
+  );
+  let log: RoutedLog = Arc::new(Mutex::new(Vec::new()));
+  let (first, second, busy) = {
+    let mut root = workers.root_context();
+    let mut spawn = || root.spawn(logging_worker(&log)).map_err(|err| format!("spawn worker: {:?}", err));
+    (spawn()?, spawn()?, spawn()?)
+  };
+  let mut system: RouterSystem = GenericActorSystem::new_with_actor_runtime(
+    tokio_actor_runtime().map_err(|err| format!("runtime: {err}"))?,
+    GenericActorSystemConfig::default(),
+  );
+  let props = GroupRouter::new([first.clone(), busy.clone()]).with_logic(RoutingLogic::SmallestMailbox).props();
+  let router = spawn_router(&mut system, props)?;
+  router.tell(RouterMessage::AddRoutee(second.clone())).map_err(|err| format!("add: {:?}", err))?;
+  system.run_until_idle().map_err(|err| format!("run router: {:?}", err))?;
+  workers.run_until_idle().map_err(|err| format!("start workers: {:?}", err))?;
+  for message in 0..5 {
+    busy.tell(message).map_err(|err| format!("fill busy worker: {:?}", err))?;
+  }
+
+  // The workers do not run, so their mailboxes keep every routed message.
+  route(&mut system, &router, 10..14)?;
+  assert_eq!((first.mailbox_len(), second.mailbox_len(), busy.mailbox_len()), (Some(2), Some(2), Some(5)));
+
+  let pid = busy.pid().ok_or("busy worker has no pid")?;
+  router.tell(RouterMessage::RemoveRoutee(pid)).map_err(|err| format!("remove: {:?}", err))?;
+  let remaining = routees(&mut system, &router).await?;
+  assert_eq!(remaining, vec![first.pid().ok_or("no pid")?, second.pid().ok_or("no pid")?]);
+
+  // Routees removed from a group keep running.
+  workers.run_until_idle().map_err(|err| format!("run workers: {:?}", err))?;
+  assert_eq!(log.lock().unwrap_or_else(|err| err.into_inner()).len(), 9);
+  Ok(())
+}
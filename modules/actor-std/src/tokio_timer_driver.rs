@@ -0,0 +1,137 @@
+use core::time::Duration;
+use std::collections::HashMap;
+
+use cellex_actor_core_rs::api::timer_driver::{TimerDriver, TimerMode, TimerTask};
+use cellex_utils_core_rs::timing::deadline_timer::{
+  DeadlineTimer, DeadlineTimerKey, DeadlineTimerKeyAllocator, TimerDeadline,
+};
+use cellex_utils_std_rs::timing::TokioDeadlineTimer;
+use futures::future::poll_fn;
+use spin::Mutex;
+use tokio::{
+  runtime::{Handle, TryCurrentError},
+  sync::mpsc::{error::SendError, unbounded_channel, UnboundedReceiver, UnboundedSender},
+  time::Instant,
+};
+
+enum Command {
+  Start { key: DeadlineTimerKey, delay: Duration, mode: TimerMode, task: TimerTask },
+  Cancel(DeadlineTimerKey),
+}
+
+struct Entry {
+  timer_key: DeadlineTimerKey,
+  deadline:  Instant,
+  mode:      TimerMode,
+  task:      TimerTask,
+}
+
+/// `TimerDriver` implementation for the Tokio runtime.
+///
+/// Timers are kept in a `TokioDeadlineTimer` owned by a background task, spawned on the runtime
+/// the driver was created for the first time a timer is started. Commands reach the task through a
+/// channel, so starting and cancelling timers never blocks the calling actor, whichever thread it
+/// runs on.
+pub struct TokioTimerDriver {
+  handle:    Handle,
+  allocator: DeadlineTimerKeyAllocator,
+  commands:  Mutex<Option<UnboundedSender<Command>>>,
+}
+
+impl TokioTimerDriver {
+  /// Creates a driver spawning its background task on `handle`.
+  #[must_use]
+  pub const fn new(handle: Handle) -> Self {
+    Self { handle, allocator: DeadlineTimerKeyAllocator::new(), commands: Mutex::new(None) }
+  }
+
+  /// Creates a driver bound to the Tokio runtime of the calling context.
+  ///
+  /// # Errors
+  /// Returns [`TryCurrentError`] when called outside a Tokio runtime.
+  pub fn current() -> Result<Self, TryCurrentError> {
+    Handle::try_current().map(Self::new)
+  }
+
+  fn send(&self, command: Command) {
+    let mut commands = self.commands.lock();
+    let command = match commands.as_ref() {
+      | Some(sender) => match sender.send(command) {
+        | Ok(()) => return,
+        // The task is gone, as the runtime is shutting down; the new one is dropped along with it.
+        | Err(SendError(command)) => command,
+      },
+      | None => command,
+    };
+    let (sender, receiver) = unbounded_channel();
+    self.handle.spawn(run_driver(receiver));
+    let _ = sender.send(command);
+    *commands = Some(sender);
+  }
+}
+
+impl TimerDriver for TokioTimerDriver {
+  fn start(&self, delay: Duration, mode: TimerMode, task: TimerTask) -> DeadlineTimerKey {
+    let key = self.allocator.allocate();
+    self.send(Command::Start { key, delay, mode, task });
+    key
+  }
+
+  fn cancel(&self, key: DeadlineTimerKey) {
+    if let Some(sender) = self.commands.lock().as_ref() {
+      let _ = sender.send(Command::Cancel(key));
+    }
+  }
+}
+
+async fn run_driver(mut commands: UnboundedReceiver<Command>) {
+  let mut timer = TokioDeadlineTimer::new();
+  let mut entries: HashMap<DeadlineTimerKey, Entry> = HashMap::new();
+
+  loop {
+    tokio::select! {
+      command = commands.recv() => {
+        match command {
+          | Some(Command::Start { key, delay, mode, task }) => {
+            if let Ok(timer_key) = timer.insert(key, TimerDeadline::from(delay)) {
+              entries.insert(key, Entry { timer_key, deadline: Instant::now() + delay, mode, task });
+            }
+          },
+          | Some(Command::Cancel(key)) => {
+            if let Some(entry) = entries.remove(&key) {
+              let _ = timer.cancel(entry.timer_key);
+            }
+          },
+          | None => break,
+        }
+      }
+      expired = poll_fn(|cx| timer.poll_expired(cx)), if !entries.is_empty() => {
+        let Ok(expired) = expired else {
+          continue;
+        };
+        let key = expired.item;
+        let Some(entry) = entries.get_mut(&key) else {
+          continue;
+        };
+        (entry.task)();
+        let next_delay = match entry.mode {
+          | TimerMode::Single => None,
+          | TimerMode::FixedRate(interval) => {
+            entry.deadline += interval;
+            Some(entry.deadline.saturating_duration_since(Instant::now()))
+          },
+          | TimerMode::FixedDelay(interval) => {
+            entry.deadline = Instant::now() + interval;
+            Some(interval)
+          },
+        };
+        match next_delay.and_then(|delay| timer.insert(key, TimerDeadline::from(delay)).ok()) {
+          | Some(timer_key) => entry.timer_key = timer_key,
+          | None => {
+            entries.remove(&key);
+          },
+        }
+      }
+    }
+  }
+}
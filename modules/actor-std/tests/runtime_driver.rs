@@ -12,7 +12,7 @@ use cellex_actor_std_rs::{
 
 async fn run_tokio_actor_system_processes_messages(worker_count: NonZeroUsize) {
   let failure_hub = FailureEventHub::new();
-  let actor_runtime: TokioActorRuntime = tokio_actor_runtime();
+  let actor_runtime: TokioActorRuntime = tokio_actor_runtime().expect("tokio runtime");
   let config = GenericActorSystemConfig::default()
     .with_failure_event_listener_opt(Some(failure_hub.listener()))
     .with_ready_queue_worker_count_opt(Some(worker_count));
@@ -54,7 +54,7 @@ async fn run_tokio_system_handle_can_be_aborted(worker_count: NonZeroUsize) {
   tokio::task::LocalSet::new()
     .run_until(async move {
       let failure_hub = FailureEventHub::new();
-      let actor_runtime: TokioActorRuntime = tokio_actor_runtime();
+      let actor_runtime: TokioActorRuntime = tokio_actor_runtime().expect("tokio runtime");
       let config = GenericActorSystemConfig::default()
         .with_failure_event_listener_opt(Some(failure_hub.listener()))
         .with_ready_queue_worker_count_opt(Some(worker_count));
@@ -0,0 +1,28 @@
+mod consistency;
+mod data_error;
+mod g_counter;
+mod lww_register;
+mod or_map;
+mod or_set;
+mod pending_read;
+mod pending_write;
+mod pn_counter;
+mod replicated_data;
+mod replicated_value;
+mod replicator;
+mod replicator_message;
+mod replicator_state;
+
+#[cfg(test)]
+mod tests;
+
+pub use consistency::Consistency;
+pub use data_error::DataError;
+pub use g_counter::GCounter;
+pub use lww_register::LwwRegister;
+pub use or_map::OrMap;
+pub use or_set::OrSet;
+pub use pn_counter::PnCounter;
+pub use replicated_data::ReplicatedData;
+pub use replicated_value::ReplicatedValue;
+pub use replicator::{ReadResult, Replicator, WriteResult};
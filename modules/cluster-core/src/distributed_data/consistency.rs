@@ -0,0 +1,23 @@
+/// Number of replicas a read or write waits for before completing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Consistency {
+  /// Only the local replica; other members learn about writes through gossip.
+  #[default]
+  Local,
+  /// A majority of the members that are joining, up or leaving, the local node included.
+  Majority,
+  /// Every member that is joining, up or leaving.
+  All,
+}
+
+impl Consistency {
+  /// Returns the number of replicas, the local one included, required out of `available`.
+  #[must_use]
+  pub const fn required(self, available: usize) -> usize {
+    match self {
+      | Self::Local => 1,
+      | Self::Majority => available / 2 + 1,
+      | Self::All => available,
+    }
+  }
+}
@@ -0,0 +1,12 @@
+/// Errors reported by reads and writes of replicated data.
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum DataError {
+  /// Fewer members than the consistency level requires can take part in the request.
+  #[error("{required} replicas required but only {available} available")]
+  NotEnoughReplicas {
+    /// Replicas required by the consistency level, the local one included.
+    required:  usize,
+    /// Members currently joining, up or leaving, the local node included.
+    available: usize,
+  },
+}
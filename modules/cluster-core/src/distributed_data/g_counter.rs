@@ -0,0 +1,67 @@
+use alloc::{
+  collections::BTreeMap,
+  string::{String, ToString},
+};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use cellex_remote_core_rs::codec::{RemoteCodecError, WireReader, WireWriter};
+
+use super::{replicated_value::put_len, ReplicatedData};
+
+/// Grow-only counter.
+///
+/// Each node counts its own increments; the value is the sum over all nodes, and merging keeps
+/// the highest count seen for each node.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GCounter {
+  counts: BTreeMap<String, u64>,
+}
+
+impl GCounter {
+  /// Creates a counter at zero.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { counts: BTreeMap::new() }
+  }
+
+  /// Adds `delta` to the count of `node`.
+  pub fn increment(&mut self, node: &NodeId, delta: u64) {
+    let count = self.counts.entry(node.to_string()).or_default();
+    *count = count.saturating_add(delta);
+  }
+
+  /// Returns the sum of the increments of every node.
+  #[must_use]
+  pub fn value(&self) -> u64 {
+    self.counts.values().fold(0, |sum, count| sum.saturating_add(*count))
+  }
+}
+
+impl ReplicatedData for GCounter {
+  fn merge(&self, other: &Self) -> Self {
+    let mut counts = self.counts.clone();
+    for (node, count) in &other.counts {
+      let known = counts.entry(node.clone()).or_default();
+      *known = (*known).max(*count);
+    }
+    Self { counts }
+  }
+
+  fn encode(&self, writer: &mut WireWriter) -> Result<(), RemoteCodecError> {
+    put_len(writer, self.counts.len())?;
+    for (node, count) in &self.counts {
+      writer.put_str(node)?;
+      writer.put_u64(*count);
+    }
+    Ok(())
+  }
+
+  fn decode(reader: &mut WireReader<'_>) -> Result<Self, RemoteCodecError> {
+    let mut counts = BTreeMap::new();
+    for _ in 0..reader.u32()? {
+      let node = reader.string()?;
+      counts.insert(node, reader.u64()?);
+    }
+    Ok(Self { counts })
+  }
+}
@@ -0,0 +1,76 @@
+use alloc::string::{String, ToString};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use cellex_remote_core_rs::codec::{RemoteCodecError, WireReader, WireWriter};
+
+use super::{ReplicatedData, ReplicatedValue};
+
+/// Register holding the value written last.
+///
+/// Each write carries a timestamp, and merging keeps the value with the highest timestamp; the
+/// node that wrote it breaks ties. Timestamps come from the caller, typically a wall clock in
+/// milliseconds, and are raised past the current one so that a local write always wins over the
+/// value it replaces.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LwwRegister<V>
+where
+  V: ReplicatedValue + Default, {
+  value:     V,
+  timestamp: u64,
+  node:      String,
+}
+
+impl<V> LwwRegister<V>
+where
+  V: ReplicatedValue + Default,
+{
+  /// Creates a register holding the default value, older than any write.
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Writes `value` on behalf of `node` at `timestamp`.
+  pub fn set(&mut self, node: &NodeId, value: V, timestamp: u64) {
+    self.timestamp = timestamp.max(self.timestamp.saturating_add(1));
+    self.node = node.to_string();
+    self.value = value;
+  }
+
+  /// Returns the value written last.
+  #[must_use]
+  pub const fn value(&self) -> &V {
+    &self.value
+  }
+
+  /// Returns the timestamp of the last write, or 0 while the register was never written.
+  #[must_use]
+  pub const fn timestamp(&self) -> u64 {
+    self.timestamp
+  }
+}
+
+impl<V> ReplicatedData for LwwRegister<V>
+where
+  V: ReplicatedValue + Default,
+{
+  fn merge(&self, other: &Self) -> Self {
+    if (other.timestamp, &other.node, &other.value) > (self.timestamp, &self.node, &self.value) {
+      other.clone()
+    } else {
+      self.clone()
+    }
+  }
+
+  fn encode(&self, writer: &mut WireWriter) -> Result<(), RemoteCodecError> {
+    writer.put_u64(self.timestamp);
+    writer.put_str(&self.node)?;
+    self.value.encode_value(writer)
+  }
+
+  fn decode(reader: &mut WireReader<'_>) -> Result<Self, RemoteCodecError> {
+    let timestamp = reader.u64()?;
+    let node = reader.string()?;
+    Ok(Self { value: V::decode_value(reader)?, timestamp, node })
+  }
+}
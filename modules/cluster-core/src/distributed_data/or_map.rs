@@ -0,0 +1,121 @@
+use alloc::collections::BTreeMap;
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use cellex_remote_core_rs::codec::{RemoteCodecError, WireReader, WireWriter};
+
+use super::{replicated_value::put_len, OrSet, ReplicatedData, ReplicatedValue};
+
+/// Map from keys to replicated data, with the keys kept in an [`OrSet`].
+///
+/// Values of the same key are merged with their own [`ReplicatedData::merge`]; an update
+/// concurrent with a remove of the same key wins, as for the elements of an [`OrSet`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrMap<K, V>
+where
+  K: ReplicatedValue,
+  V: ReplicatedData, {
+  keys:   OrSet<K>,
+  values: BTreeMap<K, V>,
+}
+
+impl<K, V> Default for OrMap<K, V>
+where
+  K: ReplicatedValue,
+  V: ReplicatedData,
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<K, V> OrMap<K, V>
+where
+  K: ReplicatedValue,
+  V: ReplicatedData,
+{
+  /// Creates an empty map.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { keys: OrSet::new(), values: BTreeMap::new() }
+  }
+
+  /// Applies `modify` to the value of `key` on behalf of `node`, starting from the default value
+  /// when the key is absent.
+  pub fn update<F>(&mut self, node: &NodeId, key: K, modify: F)
+  where
+    F: FnOnce(&mut V), {
+    self.keys.add(node, key.clone());
+    modify(self.values.entry(key).or_default());
+  }
+
+  /// Removes `key`, returning `true` when it was present.
+  pub fn remove(&mut self, key: &K) -> bool {
+    self.values.remove(key);
+    self.keys.remove(key)
+  }
+
+  /// Returns the value of `key`, if present.
+  #[must_use]
+  pub fn get(&self, key: &K) -> Option<&V> {
+    self.values.get(key)
+  }
+
+  /// Iterates over the entries in key order.
+  pub fn entries(&self) -> impl Iterator<Item = (&K, &V)> {
+    self.values.iter()
+  }
+
+  /// Returns the number of entries.
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.values.len()
+  }
+
+  /// Returns `true` when the map holds no entry.
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.values.is_empty()
+  }
+}
+
+impl<K, V> ReplicatedData for OrMap<K, V>
+where
+  K: ReplicatedValue,
+  V: ReplicatedData,
+{
+  fn merge(&self, other: &Self) -> Self {
+    let keys = self.keys.merge(&other.keys);
+    let values = keys
+      .elements()
+      .map(|key| {
+        let value = match (self.values.get(key), other.values.get(key)) {
+          | (Some(ours), Some(theirs)) => ours.merge(theirs),
+          | (Some(value), None) | (None, Some(value)) => value.clone(),
+          | (None, None) => V::default(),
+        };
+        (key.clone(), value)
+      })
+      .collect();
+    Self { keys, values }
+  }
+
+  fn encode(&self, writer: &mut WireWriter) -> Result<(), RemoteCodecError> {
+    self.keys.encode(writer)?;
+    put_len(writer, self.values.len())?;
+    for (key, value) in &self.values {
+      key.encode_value(writer)?;
+      value.encode(writer)?;
+    }
+    Ok(())
+  }
+
+  fn decode(reader: &mut WireReader<'_>) -> Result<Self, RemoteCodecError> {
+    let keys = OrSet::decode(reader)?;
+    let mut values = BTreeMap::new();
+    for _ in 0..reader.u32()? {
+      let key = K::decode_value(reader)?;
+      values.insert(key, V::decode(reader)?);
+    }
+    Ok(Self { keys, values })
+  }
+}
@@ -0,0 +1,161 @@
+use alloc::{
+  collections::BTreeMap,
+  string::{String, ToString},
+};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use cellex_remote_core_rs::codec::{RemoteCodecError, WireReader, WireWriter};
+
+use super::{replicated_value::put_len, ReplicatedData, ReplicatedValue};
+
+type Dots = BTreeMap<String, u64>;
+
+/// Observed-remove set, where an add concurrent with a remove of the same element wins.
+///
+/// Every add is tagged with a dot, the adding node and its next sequence number, and the set
+/// keeps a version vector of the dots it has seen. A remove drops the dots the local replica
+/// observed, so merging discards an element only when the other replica has seen its dots and
+/// dropped them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrSet<E>
+where
+  E: ReplicatedValue, {
+  clock:    Dots,
+  elements: BTreeMap<E, Dots>,
+}
+
+impl<E> Default for OrSet<E>
+where
+  E: ReplicatedValue,
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<E> OrSet<E>
+where
+  E: ReplicatedValue,
+{
+  /// Creates an empty set.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { clock: BTreeMap::new(), elements: BTreeMap::new() }
+  }
+
+  /// Adds `element` on behalf of `node`.
+  pub fn add(&mut self, node: &NodeId, element: E) {
+    let node = node.to_string();
+    let sequence = self.clock.get(&node).copied().unwrap_or(0) + 1;
+    self.clock.insert(node.clone(), sequence);
+    self.elements.insert(element, BTreeMap::from([(node, sequence)]));
+  }
+
+  /// Removes `element`, returning `true` when it was present.
+  pub fn remove(&mut self, element: &E) -> bool {
+    self.elements.remove(element).is_some()
+  }
+
+  /// Returns `true` when the set holds `element`.
+  #[must_use]
+  pub fn contains(&self, element: &E) -> bool {
+    self.elements.contains_key(element)
+  }
+
+  /// Iterates over the elements in order.
+  pub fn elements(&self) -> impl Iterator<Item = &E> {
+    self.elements.keys()
+  }
+
+  /// Returns the number of elements.
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.elements.len()
+  }
+
+  /// Returns `true` when the set holds no element.
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.elements.is_empty()
+  }
+}
+
+impl<E> ReplicatedData for OrSet<E>
+where
+  E: ReplicatedValue,
+{
+  fn merge(&self, other: &Self) -> Self {
+    let mut elements = BTreeMap::new();
+    let keys = self.elements.keys().chain(other.elements.keys());
+    for element in keys {
+      if elements.contains_key(element) {
+        continue;
+      }
+      let ours = self.elements.get(element);
+      let theirs = other.elements.get(element);
+      let mut dots = Dots::new();
+      for (node, sequence) in ours.into_iter().flatten() {
+        if theirs.is_some_and(|dots| dots.get(node) == Some(sequence)) || !covers(&other.clock, node, *sequence) {
+          dots.insert(node.clone(), *sequence);
+        }
+      }
+      for (node, sequence) in theirs.into_iter().flatten() {
+        if !covers(&self.clock, node, *sequence) {
+          let kept = dots.entry(node.clone()).or_default();
+          *kept = (*kept).max(*sequence);
+        }
+      }
+      if !dots.is_empty() {
+        elements.insert(element.clone(), dots);
+      }
+    }
+    let mut clock = self.clock.clone();
+    for (node, sequence) in &other.clock {
+      let known = clock.entry(node.clone()).or_default();
+      *known = (*known).max(*sequence);
+    }
+    Self { clock, elements }
+  }
+
+  fn encode(&self, writer: &mut WireWriter) -> Result<(), RemoteCodecError> {
+    put_dots(writer, &self.clock)?;
+    put_len(writer, self.elements.len())?;
+    for (element, dots) in &self.elements {
+      element.encode_value(writer)?;
+      put_dots(writer, dots)?;
+    }
+    Ok(())
+  }
+
+  fn decode(reader: &mut WireReader<'_>) -> Result<Self, RemoteCodecError> {
+    let clock = dots(reader)?;
+    let mut elements = BTreeMap::new();
+    for _ in 0..reader.u32()? {
+      let element = E::decode_value(reader)?;
+      elements.insert(element, dots(reader)?);
+    }
+    Ok(Self { clock, elements })
+  }
+}
+
+fn covers(clock: &Dots, node: &str, sequence: u64) -> bool {
+  clock.get(node).is_some_and(|seen| *seen >= sequence)
+}
+
+fn put_dots(writer: &mut WireWriter, dots: &Dots) -> Result<(), RemoteCodecError> {
+  put_len(writer, dots.len())?;
+  for (node, sequence) in dots {
+    writer.put_str(node)?;
+    writer.put_u64(*sequence);
+  }
+  Ok(())
+}
+
+fn dots(reader: &mut WireReader<'_>) -> Result<Dots, RemoteCodecError> {
+  let mut dots = Dots::new();
+  for _ in 0..reader.u32()? {
+    let node = reader.string()?;
+    dots.insert(node, reader.u64()?);
+  }
+  Ok(dots)
+}
@@ -0,0 +1,18 @@
+use alloc::{collections::BTreeSet, string::String};
+
+use cellex_actor_core_rs::api::{mailbox::ThreadSafe, messaging::MessageSender};
+
+use super::{ReadResult, ReplicatedData};
+
+/// Read waiting for the replicas of other members.
+pub(crate) struct PendingRead<D>
+where
+  D: ReplicatedData, {
+  pub(crate) key:       String,
+  pub(crate) required:  usize,
+  /// Members that answered, the local node excluded.
+  pub(crate) replied:   BTreeSet<String>,
+  /// Merge of the local replica and the answers so far.
+  pub(crate) value:     Option<D>,
+  pub(crate) responder: MessageSender<ReadResult<D>, ThreadSafe>,
+}
@@ -0,0 +1,14 @@
+use alloc::{collections::BTreeSet, string::String};
+
+use cellex_actor_core_rs::api::{mailbox::ThreadSafe, messaging::MessageSender};
+
+use super::WriteResult;
+
+/// Write waiting for acknowledgements from other members.
+pub(crate) struct PendingWrite {
+  pub(crate) key:       String,
+  pub(crate) required:  usize,
+  /// Members that acknowledged the write, the local node excluded.
+  pub(crate) acked:     BTreeSet<String>,
+  pub(crate) responder: MessageSender<WriteResult, ThreadSafe>,
+}
@@ -0,0 +1,52 @@
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use cellex_remote_core_rs::codec::{RemoteCodecError, WireReader, WireWriter};
+
+use super::{GCounter, ReplicatedData};
+
+/// Counter supporting increments and decrements, kept as a pair of [`GCounter`]s.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PnCounter {
+  increments: GCounter,
+  decrements: GCounter,
+}
+
+impl PnCounter {
+  /// Creates a counter at zero.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { increments: GCounter::new(), decrements: GCounter::new() }
+  }
+
+  /// Adds `delta` on behalf of `node`.
+  pub fn increment(&mut self, node: &NodeId, delta: u64) {
+    self.increments.increment(node, delta);
+  }
+
+  /// Subtracts `delta` on behalf of `node`.
+  pub fn decrement(&mut self, node: &NodeId, delta: u64) {
+    self.decrements.increment(node, delta);
+  }
+
+  /// Returns the increments minus the decrements of every node, saturated to the `i64` range.
+  #[must_use]
+  pub fn value(&self) -> i64 {
+    let value = i128::from(self.increments.value()) - i128::from(self.decrements.value());
+    i64::try_from(value).unwrap_or(if value < 0 { i64::MIN } else { i64::MAX })
+  }
+}
+
+impl ReplicatedData for PnCounter {
+  fn merge(&self, other: &Self) -> Self {
+    Self { increments: self.increments.merge(&other.increments), decrements: self.decrements.merge(&other.decrements) }
+  }
+
+  fn encode(&self, writer: &mut WireWriter) -> Result<(), RemoteCodecError> {
+    self.increments.encode(writer)?;
+    self.decrements.encode(writer)
+  }
+
+  fn decode(reader: &mut WireReader<'_>) -> Result<Self, RemoteCodecError> {
+    let increments = GCounter::decode(reader)?;
+    Ok(Self { increments, decrements: GCounter::decode(reader)? })
+  }
+}
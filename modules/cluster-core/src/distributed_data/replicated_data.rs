@@ -0,0 +1,28 @@
+use core::fmt::Debug;
+
+use cellex_remote_core_rs::codec::{RemoteCodecError, WireReader, WireWriter};
+use cellex_utils_core_rs::sync::shared::SharedBound;
+
+/// Conflict-free replicated data type.
+///
+/// Replicas are updated independently and converge by merging: `merge` must be commutative,
+/// associative and idempotent, so replicas that received the same updates hold equal values
+/// whatever the order in which the updates and merges happened. The default value is the empty
+/// data a key holds before its first update.
+pub trait ReplicatedData: Clone + Default + PartialEq + Debug + SharedBound + 'static {
+  /// Returns the combination of both replicas.
+  #[must_use]
+  fn merge(&self, other: &Self) -> Self;
+
+  /// Writes the data with the remote wire layout.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError`] when the data is too large to be encoded.
+  fn encode(&self, writer: &mut WireWriter) -> Result<(), RemoteCodecError>;
+
+  /// Reads data written by [`ReplicatedData::encode`].
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError`] when the bytes do not describe valid data.
+  fn decode(reader: &mut WireReader<'_>) -> Result<Self, RemoteCodecError>;
+}
@@ -0,0 +1,69 @@
+use alloc::string::String;
+use core::fmt::Debug;
+
+use cellex_remote_core_rs::codec::{RemoteCodecError, WireReader, WireWriter};
+use cellex_utils_core_rs::sync::shared::SharedBound;
+
+/// Plain value held by replicated data: an element of an [`OrSet`](super::OrSet), a key of an
+/// [`OrMap`](super::OrMap) or the value of an [`LwwRegister`](super::LwwRegister).
+pub trait ReplicatedValue: Clone + Ord + Debug + SharedBound + 'static {
+  /// Writes the value with the remote wire layout.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError`] when the value is too large to be encoded.
+  fn encode_value(&self, writer: &mut WireWriter) -> Result<(), RemoteCodecError>;
+
+  /// Reads a value written by [`ReplicatedValue::encode_value`].
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError`] when the bytes do not describe a valid value.
+  fn decode_value(reader: &mut WireReader<'_>) -> Result<Self, RemoteCodecError>;
+}
+
+impl ReplicatedValue for String {
+  fn encode_value(&self, writer: &mut WireWriter) -> Result<(), RemoteCodecError> {
+    writer.put_str(self)
+  }
+
+  fn decode_value(reader: &mut WireReader<'_>) -> Result<Self, RemoteCodecError> {
+    reader.string()
+  }
+}
+
+impl ReplicatedValue for u64 {
+  fn encode_value(&self, writer: &mut WireWriter) -> Result<(), RemoteCodecError> {
+    writer.put_u64(*self);
+    Ok(())
+  }
+
+  fn decode_value(reader: &mut WireReader<'_>) -> Result<Self, RemoteCodecError> {
+    reader.u64()
+  }
+}
+
+impl ReplicatedValue for i64 {
+  fn encode_value(&self, writer: &mut WireWriter) -> Result<(), RemoteCodecError> {
+    writer.put_u64(u64::from_be_bytes(self.to_be_bytes()));
+    Ok(())
+  }
+
+  fn decode_value(reader: &mut WireReader<'_>) -> Result<Self, RemoteCodecError> {
+    Ok(Self::from_be_bytes(reader.u64()?.to_be_bytes()))
+  }
+}
+
+impl ReplicatedValue for bool {
+  fn encode_value(&self, writer: &mut WireWriter) -> Result<(), RemoteCodecError> {
+    writer.put_bool(*self);
+    Ok(())
+  }
+
+  fn decode_value(reader: &mut WireReader<'_>) -> Result<Self, RemoteCodecError> {
+    reader.bool()
+  }
+}
+
+pub(crate) fn put_len(writer: &mut WireWriter, len: usize) -> Result<(), RemoteCodecError> {
+  writer.put_u32(u32::try_from(len).map_err(|_| RemoteCodecError::FrameTooLarge(len))?);
+  Ok(())
+}
@@ -0,0 +1,478 @@
+use alloc::{
+  collections::{BTreeMap, BTreeSet},
+  format,
+  string::{String, ToString},
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::{
+      ask::{create_ask_handles, AskFuture},
+      ActorPath,
+    },
+    mailbox::ThreadSafe,
+    process::pid::{NodeId, Pid, PidTag},
+  },
+  shared::mailbox::MailboxFactory,
+};
+use cellex_remote_core_rs::{
+  codec::{RemoteCodecError, RemoteDelivery, RemotePayloadFrame, WireReader, WireWriter},
+  delivery::{RemoteFrameInterceptor, RemoteInboundDispatcher, RemoteProcessRegistry},
+  outbound::RemoteOutbound,
+  transport::{RemoteTransport, TransportError},
+};
+use cellex_serialization_core_rs::message::SerializedMessage;
+use cellex_utils_core_rs::{
+  collections::queue::priority::DEFAULT_PRIORITY,
+  sync::{shared::SharedBound, ArcShared},
+};
+use spin::RwLock;
+
+use super::{
+  pending_read::PendingRead, pending_write::PendingWrite, replicator_message::ReplicatorMessage,
+  replicator_state::ReplicatorState, Consistency, DataError, ReplicatedData,
+};
+use crate::{membership::ClusterMembership, partition::stable_hash, CLUSTER_SERIALIZER_ID};
+
+const REPLICATOR_TAG_PREFIX: &str = "ddata:";
+const MESSAGE_TYPE_NAME: &str = "cellex.cluster.ReplicatorMessage";
+
+/// Outcome of a write through [`Replicator::update`].
+pub type WriteResult = Result<(), DataError>;
+
+/// Outcome of a read through [`Replicator::read`]: the merged replicas, or `None` when no replica
+/// knows the key.
+pub type ReadResult<D> = Result<Option<D>, DataError>;
+
+/// Replicated key-value store of conflict-free replicated data.
+///
+/// Each member keeps a replica of every key of the replicators sharing its name. Updates apply to
+/// the local replica; every [`Replicator::tick`] gossips a digest of the local entries to a few
+/// other members, which answer with the entries whose digest differs, and both sides merge what
+/// they receive, so replicas converge within a few rounds.
+///
+/// Reads and writes take a [`Consistency`]. Beyond [`Consistency::Local`], a write is also sent
+/// to the other members and completes once enough of them acknowledged it, and a read asks the
+/// other members for their replica and completes with the merge of enough answers. Requests are
+/// repeated to the members that did not answer on each tick, and fail with
+/// [`DataError::NotEnoughReplicas`] once too few members remain to complete them. Callers
+/// bound the wait with `ask_with_timeout`.
+pub struct Replicator<T, D>
+where
+  T: RemoteTransport,
+  D: ReplicatedData, {
+  membership: ClusterMembership<T>,
+  name:       String,
+  state:      ArcShared<RwLock<ReplicatorState<D>>>,
+  cursor:     ArcShared<RwLock<usize>>,
+}
+
+impl<T, D> Clone for Replicator<T, D>
+where
+  T: RemoteTransport,
+  D: ReplicatedData,
+{
+  fn clone(&self) -> Self {
+    Self {
+      membership: self.membership.clone(),
+      name:       self.name.clone(),
+      state:      self.state.clone(),
+      cursor:     self.cursor.clone(),
+    }
+  }
+}
+
+impl<T, D> Replicator<T, D>
+where
+  T: RemoteTransport,
+  D: ReplicatedData,
+{
+  /// Creates the replicator `name` over the members of `membership`. Replicators replicate with
+  /// the replicators of the same name on other members, which must hold the same data type.
+  #[must_use]
+  pub fn new(membership: ClusterMembership<T>, name: &str) -> Self {
+    Self {
+      membership,
+      name: name.into(),
+      state: ArcShared::new(RwLock::new(ReplicatorState::default())),
+      cursor: ArcShared::new(RwLock::new(0)),
+    }
+  }
+
+  /// Returns the membership the data is replicated over.
+  #[must_use]
+  pub const fn membership(&self) -> &ClusterMembership<T> {
+    &self.membership
+  }
+
+  /// Returns the outbound side used to reach other members.
+  #[must_use]
+  pub const fn outbound(&self) -> &RemoteOutbound<T> {
+    self.membership.outbound()
+  }
+
+  /// Returns the name shared by the replicators of the same data.
+  #[must_use]
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the local replica of `key`, if any.
+  #[must_use]
+  pub fn get(&self, key: &str) -> Option<D> {
+    self.state.read().entries.get(key).cloned()
+  }
+
+  /// Returns the keys with a local replica, in order.
+  #[must_use]
+  pub fn keys(&self) -> Vec<String> {
+    self.state.read().entries.keys().cloned().collect()
+  }
+
+  /// Applies `modify` to the local replica of `key`, starting from the default value when the
+  /// key is absent, and returns a future completed once `consistency` is reached. `modify`
+  /// receives the local node to record the update on behalf of.
+  pub fn update<F>(&self, key: &str, consistency: Consistency, modify: F) -> AskFuture<WriteResult>
+  where
+    F: FnOnce(&mut D, &NodeId), {
+    let (future, responder) = create_ask_handles::<WriteResult, ThreadSafe>();
+    let data = {
+      let mut state = self.state.write();
+      let data = state.entries.entry(key.into()).or_default();
+      modify(data, self.membership.local());
+      data.clone()
+    };
+    let peers = self.peers();
+    let required = consistency.required(peers.len() + 1);
+    if required <= 1 {
+      let _ = responder.dispatch_user(Ok(()));
+      return future;
+    }
+    if required > peers.len() + 1 {
+      let _ = responder.dispatch_user(Err(DataError::NotEnoughReplicas { required, available: peers.len() + 1 }));
+      return future;
+    }
+    let id = {
+      let mut state = self.state.write();
+      state.next_id += 1;
+      let id = state.next_id;
+      state.writes.insert(id, PendingWrite { key: key.into(), required, acked: BTreeSet::new(), responder });
+      id
+    };
+    if let Ok(data) = encode(&data) {
+      for node in &peers {
+        self.send(node, &ReplicatorMessage::Write { id, key: key.into(), data: data.clone() });
+      }
+    }
+    future
+  }
+
+  /// Reads `key` and returns a future completed with the merge of the local replica and of the
+  /// replicas of other members once `consistency` is reached.
+  #[must_use]
+  pub fn read(&self, key: &str, consistency: Consistency) -> AskFuture<ReadResult<D>> {
+    let (future, responder) = create_ask_handles::<ReadResult<D>, ThreadSafe>();
+    let value = self.get(key);
+    let peers = self.peers();
+    let required = consistency.required(peers.len() + 1);
+    if required <= 1 {
+      let _ = responder.dispatch_user(Ok(value));
+      return future;
+    }
+    if required > peers.len() + 1 {
+      let _ = responder.dispatch_user(Err(DataError::NotEnoughReplicas { required, available: peers.len() + 1 }));
+      return future;
+    }
+    let id = {
+      let mut state = self.state.write();
+      state.next_id += 1;
+      let id = state.next_id;
+      state.reads.insert(id, PendingRead { key: key.into(), required, replied: BTreeSet::new(), value, responder });
+      id
+    };
+    for node in &peers {
+      self.send(node, &ReplicatorMessage::Read { id, key: key.into() });
+    }
+    future
+  }
+
+  /// Fails the requests that too few members remain to complete, repeats the others to the
+  /// members that did not answer yet, and gossips the entry digests to the next members.
+  pub fn tick(&self) {
+    let peers = self.peers();
+    self.expire(peers.len() + 1);
+    self.repeat(&peers);
+    if peers.is_empty() {
+      return;
+    }
+    let count = self.membership.config().gossip_fanout().min(peers.len());
+    let targets: Vec<NodeId> = {
+      let mut cursor = self.cursor.write();
+      let start = *cursor % peers.len();
+      *cursor = start + count;
+      peers.iter().cycle().skip(start).take(count).cloned().collect()
+    };
+    let status = self.status();
+    for node in &targets {
+      self.send(node, &status);
+    }
+  }
+
+  /// Handles the messages of the replicators of the same name, returning every other delivery.
+  #[must_use]
+  pub fn handle_frame(&self, delivery: RemoteDelivery) -> Option<RemoteDelivery> {
+    let RemoteDelivery { target, frame } = delivery;
+    let ours =
+      target.tag().and_then(|tag| tag.0.strip_prefix(REPLICATOR_TAG_PREFIX)).is_some_and(|name| name == self.name);
+    let RemotePayloadFrame::User { serialized } = &frame.payload else {
+      return Some(RemoteDelivery::new(target, frame));
+    };
+    if !ours
+      || serialized.serializer_id != CLUSTER_SERIALIZER_ID
+      || serialized.type_name.as_deref() != Some(MESSAGE_TYPE_NAME)
+    {
+      return Some(RemoteDelivery::new(target, frame));
+    }
+    // Malformed messages are dropped; gossip and request repetition make up for them.
+    if let (Ok(message), Some(sender)) =
+      (ReplicatorMessage::decode(&serialized.payload), frame.reply_to.as_ref().and_then(Pid::node))
+    {
+      self.handle_message(message, sender);
+    }
+    None
+  }
+
+  fn handle_message(&self, message: ReplicatorMessage, sender: &NodeId) {
+    match message {
+      | ReplicatorMessage::Status(digests) => {
+        let theirs: BTreeMap<String, u64> = digests.into_iter().collect();
+        let (delta, behind) = {
+          let state = self.state.read();
+          let delta: Vec<(String, Vec<u8>)> = state
+            .entries
+            .iter()
+            .filter_map(|(key, data)| {
+              let encoded = encode(data).ok()?;
+              (theirs.get(key) != Some(&stable_hash(&[&encoded]))).then(|| (key.clone(), encoded))
+            })
+            .collect();
+          let behind = theirs.iter().any(|(key, theirs)| {
+            state
+              .entries
+              .get(key)
+              .and_then(|data| encode(data).ok())
+              .is_none_or(|ours| stable_hash(&[&ours]) != *theirs)
+          });
+          (delta, behind)
+        };
+        if !delta.is_empty() {
+          self.send(sender, &ReplicatorMessage::Delta(delta));
+        }
+        if behind {
+          self.send(sender, &self.status());
+        }
+      },
+      | ReplicatorMessage::Delta(entries) => {
+        for (key, data) in entries {
+          if let Ok(data) = decode::<D>(&data) {
+            self.merge(&key, &data);
+          }
+        }
+      },
+      | ReplicatorMessage::Write { id, key, data } => {
+        if let Ok(data) = decode::<D>(&data) {
+          self.merge(&key, &data);
+          self.send(sender, &ReplicatorMessage::WriteAck { id });
+        }
+      },
+      | ReplicatorMessage::WriteAck { id } => {
+        let mut state = self.state.write();
+        let complete = state.writes.get_mut(&id).is_some_and(|write| {
+          write.acked.insert(sender.to_string());
+          write.acked.len() + 1 >= write.required
+        });
+        if !complete {
+          return;
+        }
+        if let Some(write) = state.writes.remove(&id) {
+          drop(state);
+          let _ = write.responder.dispatch_user(Ok(()));
+        }
+      },
+      | ReplicatorMessage::Read { id, key } => {
+        let data = self.get(&key).and_then(|data| encode(&data).ok());
+        self.send(sender, &ReplicatorMessage::ReadResult { id, data });
+      },
+      | ReplicatorMessage::ReadResult { id, data } => {
+        let data = data.and_then(|data| decode::<D>(&data).ok());
+        let mut state = self.state.write();
+        let Some(read) = state.reads.get_mut(&id) else {
+          return;
+        };
+        read.replied.insert(sender.to_string());
+        if let Some(data) = data {
+          read.value = Some(read.value.as_ref().map_or_else(|| data.clone(), |value| value.merge(&data)));
+        }
+        if read.replied.len() + 1 < read.required {
+          return;
+        }
+        if let Some(read) = state.reads.remove(&id) {
+          drop(state);
+          // The answers also repair the local replica.
+          if let Some(value) = &read.value {
+            self.merge(&read.key, value);
+          }
+          let _ = read.responder.dispatch_user(Ok(read.value));
+        }
+      },
+    }
+  }
+
+  fn merge(&self, key: &str, data: &D) {
+    let mut state = self.state.write();
+    let merged = state.entries.get(key).map_or_else(|| data.clone(), |known| known.merge(data));
+    state.entries.insert(key.into(), merged);
+  }
+
+  /// Fails the requests requiring more than the `available` replicas.
+  fn expire(&self, available: usize) {
+    let (writes, reads) = {
+      let mut state = self.state.write();
+      let writes: Vec<u64> =
+        state.writes.iter().filter(|(_, write)| write.required > available).map(|(id, _)| *id).collect();
+      let reads: Vec<u64> =
+        state.reads.iter().filter(|(_, read)| read.required > available).map(|(id, _)| *id).collect();
+      (
+        writes.iter().filter_map(|id| state.writes.remove(id)).collect::<Vec<_>>(),
+        reads.iter().filter_map(|id| state.reads.remove(id)).collect::<Vec<_>>(),
+      )
+    };
+    for write in writes {
+      let _ = write.responder.dispatch_user(Err(DataError::NotEnoughReplicas { required: write.required, available }));
+    }
+    for read in reads {
+      let _ = read.responder.dispatch_user(Err(DataError::NotEnoughReplicas { required: read.required, available }));
+    }
+  }
+
+  /// Sends the pending requests again to the `peers` that did not answer them.
+  fn repeat(&self, peers: &[NodeId]) {
+    let messages: Vec<(NodeId, ReplicatorMessage)> = {
+      let state = self.state.read();
+      let writes = state.writes.iter().filter_map(|(id, write)| {
+        let data = encode(state.entries.get(&write.key)?).ok()?;
+        Some(
+          peers
+            .iter()
+            .filter(|node| !write.acked.contains(&node.to_string()))
+            .map(|node| {
+              (node.clone(), ReplicatorMessage::Write { id: *id, key: write.key.clone(), data: data.clone() })
+            })
+            .collect::<Vec<_>>(),
+        )
+      });
+      let reads = state.reads.iter().map(|(id, read)| {
+        peers
+          .iter()
+          .filter(|node| !read.replied.contains(&node.to_string()))
+          .map(|node| (node.clone(), ReplicatorMessage::Read { id: *id, key: read.key.clone() }))
+          .collect::<Vec<_>>()
+      });
+      writes.chain(reads).flatten().collect()
+    };
+    for (node, message) in &messages {
+      self.send(node, message);
+    }
+  }
+
+  fn peers(&self) -> Vec<NodeId> {
+    let local = self.membership.local();
+    self
+      .membership
+      .members()
+      .into_iter()
+      .filter(|member| member.status().is_active() && member.node() != local)
+      .map(|member| member.node().clone())
+      .collect()
+  }
+
+  fn status(&self) -> ReplicatorMessage {
+    ReplicatorMessage::Status(
+      self
+        .state
+        .read()
+        .entries
+        .iter()
+        .filter_map(|(key, data)| Some((key.clone(), stable_hash(&[&encode(data).ok()?]))))
+        .collect(),
+    )
+  }
+
+  fn send(&self, node: &NodeId, message: &ReplicatorMessage) {
+    let Ok(payload) = message.encode() else {
+      return;
+    };
+    let serialized = SerializedMessage::new(CLUSTER_SERIALIZER_ID, payload).with_type_name(MESSAGE_TYPE_NAME);
+    // Lost messages are made up for by the next gossip round or request repetition.
+    let _ = self.outbound().send_control(
+      &self.replicator_pid(node),
+      RemotePayloadFrame::User { serialized },
+      DEFAULT_PRIORITY,
+      Some(self.replicator_pid(self.membership.local())),
+    );
+  }
+
+  fn replicator_pid(&self, node: &NodeId) -> Pid {
+    Pid::new(self.outbound().system().clone(), ActorPath::new())
+      .with_node(node.clone())
+      .with_tag(PidTag::new(format!("{REPLICATOR_TAG_PREFIX}{}", self.name)))
+  }
+}
+
+impl<T, D> Replicator<T, D>
+where
+  T: RemoteTransport + 'static,
+  D: ReplicatedData,
+  Self: SharedBound,
+{
+  /// Handles the messages of the replicators of the same name arriving through `dispatcher`.
+  #[must_use]
+  pub fn attach<MF>(&self, dispatcher: RemoteInboundDispatcher<MF>) -> RemoteInboundDispatcher<MF>
+  where
+    MF: MailboxFactory, {
+    let replicator = self.clone();
+    dispatcher
+      .with_interceptor(RemoteFrameInterceptor::new(move |delivery: RemoteDelivery| replicator.handle_frame(delivery)))
+  }
+
+  /// Starts listening on the local node with the membership and the replicator attached to
+  /// inbound delivery.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the transport cannot listen on the local node.
+  pub fn start<MF>(&self, registry: ArcShared<RemoteProcessRegistry<MF>>) -> Result<(), TransportError>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    let dispatcher = self.attach(self.membership.attach(self.outbound().inbound_dispatcher(registry)));
+    self.outbound().endpoints().start(dispatcher)
+  }
+}
+
+fn encode<D>(data: &D) -> Result<Vec<u8>, RemoteCodecError>
+where
+  D: ReplicatedData, {
+  let mut writer = WireWriter::new();
+  data.encode(&mut writer)?;
+  Ok(writer.into_bytes())
+}
+
+fn decode<D>(bytes: &[u8]) -> Result<D, RemoteCodecError>
+where
+  D: ReplicatedData, {
+  let mut reader = WireReader::new(bytes);
+  let data = D::decode(&mut reader)?;
+  reader.finish()?;
+  Ok(data)
+}
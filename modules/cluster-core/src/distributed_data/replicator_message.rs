@@ -0,0 +1,118 @@
+use alloc::{string::String, vec::Vec};
+
+use cellex_remote_core_rs::codec::{RemoteCodecError, WireReader, WireWriter};
+
+use super::replicated_value::put_len;
+
+const STATUS: u8 = 0;
+const DELTA: u8 = 1;
+const WRITE: u8 = 2;
+const WRITE_ACK: u8 = 3;
+const READ: u8 = 4;
+const READ_RESULT: u8 = 5;
+
+/// Messages exchanged between the replicators of the same name on different members. Data is
+/// carried encoded, as written by [`ReplicatedData::encode`](super::ReplicatedData::encode).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ReplicatorMessage {
+  /// Digest of every entry known to the sender.
+  Status(Vec<(String, u64)>),
+  /// Entries whose digest differs from the receiver's, or that the receiver lacks.
+  Delta(Vec<(String, Vec<u8>)>),
+  /// Request to merge an entry and acknowledge it.
+  Write { id: u64, key: String, data: Vec<u8> },
+  /// Acknowledgement of a write.
+  WriteAck { id: u64 },
+  /// Request for the receiver's replica of an entry.
+  Read { id: u64, key: String },
+  /// Answer to a read, without data when the receiver does not know the entry.
+  ReadResult { id: u64, data: Option<Vec<u8>> },
+}
+
+impl ReplicatorMessage {
+  pub(crate) fn encode(&self) -> Result<Vec<u8>, RemoteCodecError> {
+    let mut writer = WireWriter::new();
+    match self {
+      | Self::Status(digests) => {
+        writer.put_u8(STATUS);
+        put_len(&mut writer, digests.len())?;
+        for (key, digest) in digests {
+          writer.put_str(key)?;
+          writer.put_u64(*digest);
+        }
+      },
+      | Self::Delta(entries) => {
+        writer.put_u8(DELTA);
+        put_len(&mut writer, entries.len())?;
+        for (key, data) in entries {
+          writer.put_str(key)?;
+          writer.put_bytes(data)?;
+        }
+      },
+      | Self::Write { id, key, data } => {
+        writer.put_u8(WRITE);
+        writer.put_u64(*id);
+        writer.put_str(key)?;
+        writer.put_bytes(data)?;
+      },
+      | Self::WriteAck { id } => {
+        writer.put_u8(WRITE_ACK);
+        writer.put_u64(*id);
+      },
+      | Self::Read { id, key } => {
+        writer.put_u8(READ);
+        writer.put_u64(*id);
+        writer.put_str(key)?;
+      },
+      | Self::ReadResult { id, data } => {
+        writer.put_u8(READ_RESULT);
+        writer.put_u64(*id);
+        writer.put_bool(data.is_some());
+        if let Some(data) = data {
+          writer.put_bytes(data)?;
+        }
+      },
+    }
+    Ok(writer.into_bytes())
+  }
+
+  pub(crate) fn decode(bytes: &[u8]) -> Result<Self, RemoteCodecError> {
+    let mut reader = WireReader::new(bytes);
+    let message = match reader.u8()? {
+      | STATUS => {
+        let mut digests = Vec::new();
+        for _ in 0..reader.u32()? {
+          let key = reader.string()?;
+          digests.push((key, reader.u64()?));
+        }
+        Self::Status(digests)
+      },
+      | DELTA => {
+        let mut entries = Vec::new();
+        for _ in 0..reader.u32()? {
+          let key = reader.string()?;
+          entries.push((key, reader.bytes()?));
+        }
+        Self::Delta(entries)
+      },
+      | WRITE => {
+        let id = reader.u64()?;
+        let key = reader.string()?;
+        Self::Write { id, key, data: reader.bytes()? }
+      },
+      | WRITE_ACK => Self::WriteAck { id: reader.u64()? },
+      | READ => {
+        let id = reader.u64()?;
+        Self::Read { id, key: reader.string()? }
+      },
+      | READ_RESULT => {
+        let id = reader.u64()?;
+        let data = if reader.bool()? { Some(reader.bytes()?) } else { None };
+        Self::ReadResult { id, data }
+      },
+      | other => return Err(RemoteCodecError::UnknownTag(other)),
+    };
+    reader.finish()?;
+    Ok(message)
+  }
+}
@@ -0,0 +1,22 @@
+use alloc::{collections::BTreeMap, string::String};
+
+use super::{pending_read::PendingRead, pending_write::PendingWrite, ReplicatedData};
+
+/// Local replicas of a replicator and the requests waiting for other members.
+pub(crate) struct ReplicatorState<D>
+where
+  D: ReplicatedData, {
+  pub(crate) entries: BTreeMap<String, D>,
+  pub(crate) next_id: u64,
+  pub(crate) writes:  BTreeMap<u64, PendingWrite>,
+  pub(crate) reads:   BTreeMap<u64, PendingRead<D>>,
+}
+
+impl<D> Default for ReplicatorState<D>
+where
+  D: ReplicatedData,
+{
+  fn default() -> Self {
+    Self { entries: BTreeMap::new(), next_id: 0, writes: BTreeMap::new(), reads: BTreeMap::new() }
+  }
+}
@@ -0,0 +1,266 @@
+extern crate std;
+
+use std::{
+  format,
+  string::{String, ToString},
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor_runtime::GenericActorRuntime,
+  actor_system::{GenericActorSystem, GenericActorSystemConfig},
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  process::pid::NodeId,
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_remote_core_rs::{
+  codec::{WireReader, WireWriter},
+  endpoint::EndpointManager,
+  loopback::{LoopbackNetwork, LoopbackTransport},
+  outbound::RemoteOutbound,
+};
+use cellex_utils_core_rs::sync::ArcShared;
+use futures::executor::block_on;
+
+use super::{
+  replicator_message::ReplicatorMessage, Consistency, DataError, GCounter, LwwRegister, OrMap, OrSet, PnCounter,
+  ReplicatedData, Replicator,
+};
+use crate::membership::{ClusterMembership, MembershipConfig};
+
+type TestResult<T = ()> = Result<T, String>;
+type TestSystem = GenericActorSystem<String, TokioActorRuntime>;
+
+struct TestNode {
+  _system: TestSystem,
+  counter: Replicator<LoopbackTransport, GCounter>,
+}
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn started(network: &LoopbackNetwork, port: u16) -> TestResult<TestNode> {
+  let system_config = GenericActorSystemConfig::default().with_node_id(node(port));
+  let system = GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), system_config);
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(network.transport(&node(port)), system_id, node(port)));
+  let outbound = system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      RemoteOutbound::new(endpoints, extension)
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())?;
+  let membership = ClusterMembership::new(outbound, MembershipConfig::new().with_seed_node(node(2551)));
+  let counter = Replicator::new(membership, "counters");
+  counter.start(system.process_registry()).map_err(|err| format!("start: {err}"))?;
+  Ok(TestNode { _system: system, counter })
+}
+
+fn cluster(network: &LoopbackNetwork) -> TestResult<[TestNode; 3]> {
+  let nodes = [started(network, 2551)?, started(network, 2552)?, started(network, 2553)?];
+  for test_node in &nodes {
+    test_node.counter.membership().join();
+  }
+  for _ in 0..4 {
+    for test_node in &nodes {
+      test_node.counter.membership().tick();
+    }
+    network.flush();
+  }
+  Ok(nodes)
+}
+
+fn round_trip<D: ReplicatedData>(data: &D) -> TestResult<D> {
+  let mut writer = WireWriter::new();
+  data.encode(&mut writer).map_err(|err| format!("encode: {err}"))?;
+  let bytes = writer.into_bytes();
+  let mut reader = WireReader::new(&bytes);
+  let decoded = D::decode(&mut reader).map_err(|err| format!("decode: {err}"))?;
+  reader.finish().map_err(|err| format!("finish: {err}"))?;
+  Ok(decoded)
+}
+
+fn assert_merge_laws<D: ReplicatedData>(a: &D, b: &D, c: &D) {
+  assert_eq!(a.merge(b), b.merge(a));
+  assert_eq!(a.merge(b).merge(c), a.merge(&b.merge(c)));
+  assert_eq!(a.merge(a), *a);
+}
+
+#[test]
+fn counters_merge_per_node_contributions() -> TestResult {
+  let mut a = PnCounter::new();
+  let mut b = PnCounter::new();
+  a.increment(&node(2551), 5);
+  b.increment(&node(2552), 3);
+  b.decrement(&node(2552), 10);
+  let c = b.clone();
+  b.increment(&node(2552), 1);
+
+  assert_merge_laws(&a, &b, &c);
+  assert_eq!(a.merge(&b).value(), -1);
+  assert_eq!(a.merge(&b).merge(&c).value(), -1);
+  assert_eq!(round_trip(&a.merge(&b))?, a.merge(&b));
+
+  let mut grow = GCounter::new();
+  grow.increment(&node(2551), u64::MAX);
+  grow.increment(&node(2551), 1);
+  assert_eq!(grow.value(), u64::MAX);
+  Ok(())
+}
+
+#[test]
+fn lww_register_keeps_the_latest_write() -> TestResult {
+  let mut a = LwwRegister::<String>::new();
+  let mut b = LwwRegister::<String>::new();
+  a.set(&node(2551), "first".into(), 10);
+  b.set(&node(2552), "second".into(), 20);
+  let mut c = a.clone();
+  c.set(&node(2553), "tie".into(), 20);
+
+  assert_merge_laws(&a, &b, &c);
+  assert_eq!(a.merge(&b).value(), "second");
+  // Ties are broken by node, so every replica picks the same value.
+  assert_eq!(b.merge(&c).value(), "tie");
+  // A write never goes back in time relative to what the register holds.
+  a.set(&node(2551), "late".into(), 5);
+  assert_eq!(a.timestamp(), 11);
+  assert_eq!(round_trip(&b)?, b);
+  Ok(())
+}
+
+#[test]
+fn or_set_lets_concurrent_adds_win_over_removes() -> TestResult {
+  let mut a = OrSet::<String>::new();
+  a.add(&node(2551), "apple".into());
+  a.add(&node(2551), "pear".into());
+  let mut b = a.clone();
+  b.remove(&"apple".to_string());
+  b.remove(&"pear".to_string());
+  a.add(&node(2551), "apple".into());
+  let mut c = OrSet::new();
+  c.add(&node(2553), "plum".to_string());
+
+  assert_merge_laws(&a, &b, &c);
+  let merged = a.merge(&b);
+  assert_eq!(merged.elements().cloned().collect::<Vec<_>>(), vec!["apple".to_string()]);
+  assert!(!merged.contains(&"pear".to_string()));
+  assert_eq!(round_trip(&merged.merge(&c))?, merged.merge(&c));
+  Ok(())
+}
+
+#[test]
+fn or_map_merges_the_values_of_surviving_keys() -> TestResult {
+  let mut a = OrMap::<String, GCounter>::new();
+  a.update(&node(2551), "hits".into(), |counter| counter.increment(&node(2551), 2));
+  let mut b = a.clone();
+  b.update(&node(2552), "hits".into(), |counter| counter.increment(&node(2552), 3));
+  b.update(&node(2552), "misses".into(), |counter| counter.increment(&node(2552), 1));
+  a.update(&node(2551), "gone".into(), |counter| counter.increment(&node(2551), 1));
+  let mut c = a.clone();
+  c.remove(&"gone".to_string());
+
+  assert_merge_laws(&a, &b, &c);
+  let merged = a.merge(&b).merge(&c);
+  let values: Vec<(String, u64)> = merged.entries().map(|(key, value)| (key.clone(), value.value())).collect();
+  assert_eq!(values, vec![("hits".into(), 5), ("misses".into(), 1)]);
+  assert_eq!(round_trip(&merged)?, merged);
+  Ok(())
+}
+
+#[test]
+fn replicator_messages_round_trip() -> TestResult {
+  let messages = [
+    ReplicatorMessage::Status(vec![("a".into(), 1)]),
+    ReplicatorMessage::Delta(vec![("a".into(), vec![1, 2])]),
+    ReplicatorMessage::Write { id: 1, key: "a".into(), data: vec![3] },
+    ReplicatorMessage::WriteAck { id: 1 },
+    ReplicatorMessage::Read { id: 2, key: "a".into() },
+    ReplicatorMessage::ReadResult { id: 2, data: None },
+  ];
+  for message in messages {
+    let bytes = message.encode().map_err(|err| format!("encode: {err}"))?;
+    assert_eq!(ReplicatorMessage::decode(&bytes).map_err(|err| format!("decode: {err}"))?, message);
+  }
+  Ok(())
+}
+
+#[test]
+fn local_updates_converge_through_gossip() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let nodes = cluster(&network)?;
+  for (index, test_node) in nodes.iter().enumerate() {
+    let delta = u64::try_from(index).unwrap_or(0) + 1;
+    let written =
+      test_node.counter.update("visits", Consistency::Local, |counter, node| counter.increment(node, delta));
+    assert_eq!(block_on(written).map_err(|err| format!("ask: {err:?}"))?, Ok(()));
+    assert_eq!(test_node.counter.get("visits").map(|counter| counter.value()), Some(delta));
+  }
+
+  for _ in 0..4 {
+    for test_node in &nodes {
+      test_node.counter.tick();
+    }
+    network.flush();
+  }
+  for test_node in &nodes {
+    assert_eq!(test_node.counter.keys(), vec!["visits".to_string()]);
+    assert_eq!(test_node.counter.get("visits").map(|counter| counter.value()), Some(6));
+  }
+  Ok(())
+}
+
+#[test]
+fn majority_and_all_requests_wait_for_other_replicas() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let [first, second, third] = cluster(&network)?;
+
+  let written = first.counter.update("orders", Consistency::All, |counter, node| counter.increment(node, 4));
+  network.flush();
+  assert_eq!(block_on(written).map_err(|err| format!("ask: {err:?}"))?, Ok(()));
+  assert_eq!(third.counter.get("orders").map(|counter| counter.value()), Some(4));
+
+  let local = second.counter.update("orders", Consistency::Local, |counter, node| counter.increment(node, 1));
+  assert_eq!(block_on(local).map_err(|err| format!("ask: {err:?}"))?, Ok(()));
+  let read = third.counter.read("orders", Consistency::Majority);
+  network.flush();
+  let value = block_on(read).map_err(|err| format!("ask: {err:?}"))?;
+  // A majority of three includes one of the other members; both merge into at least the write.
+  assert!(value.is_ok_and(|counter| counter.is_some_and(|counter| counter.value() >= 4)));
+
+  let read = third.counter.read("orders", Consistency::All);
+  network.flush();
+  let value = block_on(read).map_err(|err| format!("ask: {err:?}"))?;
+  assert_eq!(value.map(|counter| counter.map(|counter| counter.value())), Ok(Some(5)));
+  // The read repaired the local replica.
+  assert_eq!(third.counter.get("orders").map(|counter| counter.value()), Some(5));
+
+  let missing = first.counter.read("unknown", Consistency::All);
+  network.flush();
+  assert_eq!(block_on(missing).map_err(|err| format!("ask: {err:?}"))?, Ok(None));
+  Ok(())
+}
+
+#[test]
+fn unanswered_requests_are_repeated_and_fail_once_replicas_are_missing() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let [first, _second, third] = cluster(&network)?;
+  network.partition(&node(2551), &node(2553));
+
+  let repeated = first.counter.update("orders", Consistency::All, |counter, node| counter.increment(node, 1));
+  network.flush();
+  network.heal(&node(2551), &node(2553));
+  first.counter.tick();
+  network.flush();
+  assert_eq!(block_on(repeated).map_err(|err| format!("ask: {err:?}"))?, Ok(()));
+  assert_eq!(third.counter.get("orders").map(|counter| counter.value()), Some(1));
+
+  network.partition(&node(2551), &node(2553));
+  let failed = first.counter.read("orders", Consistency::All);
+  network.flush();
+  first.counter.membership().down(&node(2553));
+  first.counter.tick();
+  let failed = block_on(failed).map_err(|err| format!("ask: {err:?}"))?;
+  assert_eq!(failed, Err(DataError::NotEnoughReplicas { required: 3, available: 2 }));
+  Ok(())
+}
@@ -0,0 +1,11 @@
+mod downing_strategy;
+mod resolver_state;
+mod split_brain_resolver;
+mod split_brain_resolver_config;
+
+#[cfg(test)]
+mod tests;
+
+pub use downing_strategy::DowningStrategy;
+pub use split_brain_resolver::SplitBrainResolver;
+pub use split_brain_resolver_config::SplitBrainResolverConfig;
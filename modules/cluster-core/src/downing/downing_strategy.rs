@@ -0,0 +1,79 @@
+use alloc::{string::ToString, vec::Vec};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use crate::membership::Member;
+
+/// Rule deciding which side of a network partition keeps running and which side is downed.
+///
+/// Every side of the partition applies the same rule to its own view, in which the members it
+/// reaches form one side and the members it does not reach the other, so the sides agree on the
+/// outcome without talking to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DowningStrategy {
+  /// The side with more members survives. On a tie, the side with the lowest node address
+  /// survives.
+  KeepMajority,
+  /// A side survives when it has at least `quorum_size` members. When both sides reach the
+  /// quorum, which the quorum size should rule out, every member is downed.
+  StaticQuorum {
+    /// Minimum number of members of a surviving side.
+    quorum_size: usize,
+  },
+  /// The side with the oldest member survives.
+  KeepOldest {
+    /// Downs the oldest member instead when it is alone on its side.
+    down_if_alone: bool,
+  },
+  /// Every member is downed.
+  DownAll,
+}
+
+impl DowningStrategy {
+  /// Returns the members to down, given the members the deciding node reaches, itself included,
+  /// and the members it does not reach. Nothing is downed while every member is reachable.
+  #[must_use]
+  pub fn decide(&self, reachable: &[Member], unreachable: &[Member]) -> Vec<NodeId> {
+    if unreachable.is_empty() {
+      return Vec::new();
+    }
+    let keep_reachable = match *self {
+      | Self::KeepMajority => match reachable.len().cmp(&unreachable.len()) {
+        | core::cmp::Ordering::Greater => true,
+        | core::cmp::Ordering::Less => false,
+        | core::cmp::Ordering::Equal => {
+          let lowest = |members: &[Member]| members.iter().map(|member| member.node().to_string()).min();
+          lowest(reachable) < lowest(unreachable)
+        },
+      },
+      | Self::StaticQuorum { quorum_size } => {
+        if unreachable.len() >= quorum_size && reachable.len() >= quorum_size {
+          return nodes(reachable.iter().chain(unreachable));
+        }
+        reachable.len() >= quorum_size
+      },
+      | Self::KeepOldest { down_if_alone } => {
+        let oldest = reachable
+          .iter()
+          .map(|member| (member, true))
+          .chain(unreachable.iter().map(|member| (member, false)))
+          .min_by_key(|(member, _)| (member.up_number(), member.node().to_string()));
+        let oldest_reachable = oldest.is_some_and(|(_, reachable)| reachable);
+        let (oldest_side, other_side) =
+          if oldest_reachable { (reachable.len(), unreachable.len()) } else { (unreachable.len(), reachable.len()) };
+        let oldest_survives = !(down_if_alone && oldest_side == 1 && other_side > 0);
+        oldest_reachable == oldest_survives
+      },
+      | Self::DownAll => return nodes(reachable.iter().chain(unreachable)),
+    };
+    if keep_reachable {
+      nodes(unreachable.iter())
+    } else {
+      nodes(reachable.iter())
+    }
+  }
+}
+
+fn nodes<'a>(members: impl Iterator<Item = &'a Member>) -> Vec<NodeId> {
+  members.map(|member| member.node().clone()).collect()
+}
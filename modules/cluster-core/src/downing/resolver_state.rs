@@ -0,0 +1,16 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::time::Duration;
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use crate::membership::MemberStatus;
+
+/// Reachability reported by the failure detector and the view the stable-after delay runs for.
+#[derive(Default)]
+pub(crate) struct ResolverState {
+  pub(crate) unreachable: BTreeMap<String, NodeId>,
+  /// Members taking part in the decision, with their status, as last seen.
+  pub(crate) view:        Vec<(String, MemberStatus)>,
+  /// Time of the last change to the view or to the reported reachability.
+  pub(crate) changed_at:  Duration,
+}
@@ -0,0 +1,159 @@
+use alloc::{
+  string::{String, ToString},
+  vec::Vec,
+};
+use core::time::Duration;
+
+use cellex_actor_core_rs::api::{
+  failure::{failure_event_stream::FailureEventListener, FailureEvent},
+  process::pid::NodeId,
+};
+use cellex_remote_core_rs::{failure_detector::HeartbeatClock, transport::RemoteTransport};
+use cellex_utils_core_rs::sync::{shared::SharedBound, ArcShared};
+use spin::RwLock;
+
+use super::{resolver_state::ResolverState, SplitBrainResolverConfig};
+use crate::membership::{ClusterMembership, Member, MemberStatus};
+
+/// Automatic downing of the members on the losing side of a network partition.
+///
+/// The resolver learns which members the local node cannot reach from the reachability events
+/// of the failure detector, fed through [`SplitBrainResolver::failure_listener`]. Once the up and
+/// leaving members and their reachability have stayed unchanged for the configured stable-after
+/// delay, the first reachable member in node order applies the [`DowningStrategy`] on each
+/// [`SplitBrainResolver::tick`] and downs the members on the losing side, which may be its own.
+/// Every downed member is published as [`FailureEvent::NodeDowned`].
+///
+/// [`DowningStrategy`]: super::DowningStrategy
+pub struct SplitBrainResolver<T>
+where
+  T: RemoteTransport, {
+  membership: ClusterMembership<T>,
+  config:     SplitBrainResolverConfig,
+  clock:      ArcShared<HeartbeatClock>,
+  state:      ArcShared<RwLock<ResolverState>>,
+  listeners:  Vec<FailureEventListener>,
+}
+
+impl<T> Clone for SplitBrainResolver<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self {
+      membership: self.membership.clone(),
+      config:     self.config,
+      clock:      self.clock.clone(),
+      state:      self.state.clone(),
+      listeners:  self.listeners.clone(),
+    }
+  }
+}
+
+impl<T> SplitBrainResolver<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates a resolver downing members of `membership`, with `clock` as the time source.
+  #[must_use]
+  pub fn new<C>(membership: ClusterMembership<T>, config: SplitBrainResolverConfig, clock: C) -> Self
+  where
+    C: Fn() -> Duration + SharedBound + 'static, {
+    let clock = ArcShared::new(clock).into_dyn(|f| f as &HeartbeatClock);
+    let state = ResolverState { changed_at: clock(), ..ResolverState::default() };
+    Self { membership, config, clock, state: ArcShared::new(RwLock::new(state)), listeners: Vec::new() }
+  }
+
+  /// Publishes downing decisions to `listener`, typically the listener of a `FailureEventStream`
+  /// or of a [`ClusterFailureBridge`](crate::ClusterFailureBridge).
+  #[must_use]
+  pub fn with_failure_listener(mut self, listener: FailureEventListener) -> Self {
+    self.listeners.push(listener);
+    self
+  }
+
+  /// Returns the membership the resolver downs members of.
+  #[must_use]
+  pub const fn membership(&self) -> &ClusterMembership<T> {
+    &self.membership
+  }
+
+  /// Returns the resolver settings.
+  #[must_use]
+  pub const fn config(&self) -> &SplitBrainResolverConfig {
+    &self.config
+  }
+
+  /// Returns the nodes the failure detector currently reports unreachable.
+  #[must_use]
+  pub fn unreachable(&self) -> Vec<NodeId> {
+    self.state.read().unreachable.values().cloned().collect()
+  }
+
+  /// Records a reachability change reported by the failure detector. Other events are ignored.
+  pub fn observe(&self, event: &FailureEvent) {
+    let mut state = self.state.write();
+    let changed = match event {
+      | FailureEvent::NodeUnreachable(node) | FailureEvent::NodeQuarantined(node) => {
+        state.unreachable.insert(node.to_string(), node.clone()).is_none()
+      },
+      | FailureEvent::NodeReachable(node) => state.unreachable.remove(&node.to_string()).is_some(),
+      | _ => false,
+    };
+    if changed {
+      state.changed_at = (self.clock)();
+    }
+  }
+
+  /// Downs the members on the losing side once the partition has been stable long enough and the
+  /// local node decides for its side.
+  pub fn tick(&self) {
+    let now = (self.clock)();
+    let local = self.membership.local().clone();
+    let members: Vec<Member> = self
+      .membership
+      .members()
+      .into_iter()
+      .filter(|member| matches!(member.status(), MemberStatus::Up | MemberStatus::Leaving))
+      .collect();
+    let decision = {
+      let mut state = self.state.write();
+      let reported = state.unreachable.clone();
+      let is_unreachable =
+        |member: &Member| member.node() != &local && reported.contains_key(&member.node().to_string());
+      let mut view: Vec<(String, MemberStatus)> =
+        members.iter().map(|member| (member.node().to_string(), member.status())).collect();
+      view.sort();
+      if view != state.view {
+        state.view = view;
+        state.changed_at = now;
+      }
+      let (unreachable, reachable): (Vec<Member>, Vec<Member>) = members.into_iter().partition(is_unreachable);
+      let decides = reachable.iter().map(|member| member.node().to_string()).min() == Some(local.to_string());
+      if unreachable.is_empty() || !decides || now.saturating_sub(state.changed_at) < self.config.stable_after() {
+        return;
+      }
+      self.config.strategy().decide(&reachable, &unreachable)
+    };
+    for node in decision {
+      self.membership.down(&node);
+      for listener in &self.listeners {
+        listener(FailureEvent::NodeDowned(node.clone()));
+      }
+    }
+  }
+}
+
+impl<T> SplitBrainResolver<T>
+where
+  T: RemoteTransport + 'static,
+  Self: SharedBound,
+{
+  /// Returns a listener recording the reachability events it receives, to be passed to the
+  /// failure detector, e.g. through `RemoteHeartbeat::with_failure_listener`.
+  #[must_use]
+  pub fn failure_listener(&self) -> FailureEventListener {
+    let resolver = self.clone();
+    FailureEventListener::new(move |event: FailureEvent| resolver.observe(&event))
+  }
+}
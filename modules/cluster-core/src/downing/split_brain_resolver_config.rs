@@ -0,0 +1,46 @@
+use core::time::Duration;
+
+use super::DowningStrategy;
+
+const DEFAULT_STABLE_AFTER: Duration = Duration::from_secs(20);
+
+/// Settings of the [`SplitBrainResolver`](super::SplitBrainResolver).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplitBrainResolverConfig {
+  strategy:     DowningStrategy,
+  stable_after: Duration,
+}
+
+impl SplitBrainResolverConfig {
+  /// Creates settings deciding with `strategy` once the membership and reachability have been
+  /// stable for 20 seconds.
+  #[must_use]
+  pub const fn new(strategy: DowningStrategy) -> Self {
+    Self { strategy, stable_after: DEFAULT_STABLE_AFTER }
+  }
+
+  /// Sets how long the membership and reachability must stay unchanged before deciding.
+  #[must_use]
+  pub const fn with_stable_after(mut self, stable_after: Duration) -> Self {
+    self.stable_after = stable_after;
+    self
+  }
+
+  /// Returns the strategy deciding which side of a partition survives.
+  #[must_use]
+  pub const fn strategy(&self) -> DowningStrategy {
+    self.strategy
+  }
+
+  /// Returns how long the membership and reachability must stay unchanged before deciding.
+  #[must_use]
+  pub const fn stable_after(&self) -> Duration {
+    self.stable_after
+  }
+}
+
+impl Default for SplitBrainResolverConfig {
+  fn default() -> Self {
+    Self::new(DowningStrategy::KeepMajority)
+  }
+}
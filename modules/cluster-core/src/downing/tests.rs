@@ -0,0 +1,226 @@
+extern crate std;
+
+use core::time::Duration;
+use std::{
+  format,
+  string::{String, ToString},
+  sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+  },
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor_runtime::GenericActorRuntime,
+  actor_system::{GenericActorSystem, GenericActorSystemConfig},
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  failure::{failure_event_stream::FailureEventListener, FailureEvent},
+  process::pid::NodeId,
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_remote_core_rs::{
+  endpoint::EndpointManager,
+  loopback::{LoopbackNetwork, LoopbackTransport},
+  outbound::RemoteOutbound,
+};
+use cellex_utils_core_rs::sync::ArcShared;
+
+use super::{DowningStrategy, SplitBrainResolver, SplitBrainResolverConfig};
+use crate::membership::{ClusterMembership, Member, MemberStatus, MembershipConfig};
+
+type TestResult<T = ()> = Result<T, String>;
+type TestSystem = GenericActorSystem<String, TokioActorRuntime>;
+type Downed = Arc<Mutex<Vec<u16>>>;
+
+const STABLE_AFTER: Duration = Duration::from_secs(20);
+
+struct TestNode {
+  _system:  TestSystem,
+  resolver: SplitBrainResolver<LoopbackTransport>,
+  now:      Arc<AtomicU64>,
+  downed:   Downed,
+}
+
+impl TestNode {
+  fn membership(&self) -> &ClusterMembership<LoopbackTransport> {
+    self.resolver.membership()
+  }
+
+  fn advance(&self, by: Duration) {
+    self.now.fetch_add(u64::try_from(by.as_millis()).unwrap_or(u64::MAX), Ordering::SeqCst);
+  }
+
+  fn unreachable(&self, ports: &[u16]) {
+    for port in ports {
+      self.resolver.observe(&FailureEvent::NodeUnreachable(node(*port)));
+    }
+  }
+
+  fn status_of(&self, port: u16) -> Option<MemberStatus> {
+    self.membership().member(&node(port)).map(|member| member.status())
+  }
+
+  fn downed(&self) -> Vec<u16> {
+    core::mem::take(&mut *self.downed.lock().unwrap_or_else(|err| err.into_inner()))
+  }
+}
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn up(port: u16, up_number: u64) -> Member {
+  Member::new(node(port)).with_status(MemberStatus::Up).with_up_number(up_number)
+}
+
+fn ports(nodes: &[NodeId]) -> Vec<u16> {
+  nodes.iter().filter_map(NodeId::port).collect()
+}
+
+fn started(network: &LoopbackNetwork, port: u16, strategy: DowningStrategy) -> TestResult<TestNode> {
+  let system_config = GenericActorSystemConfig::default().with_node_id(node(port));
+  let system = GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), system_config);
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(network.transport(&node(port)), system_id, node(port)));
+  let outbound = system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      RemoteOutbound::new(endpoints, extension)
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())?;
+  let membership = ClusterMembership::new(outbound, MembershipConfig::new().with_seed_node(node(2551)));
+  membership.start(system.process_registry()).map_err(|err| format!("start: {err}"))?;
+  let now = Arc::new(AtomicU64::new(0));
+  let clock = now.clone();
+  let downed: Downed = Arc::new(Mutex::new(Vec::new()));
+  let recorded = downed.clone();
+  let config = SplitBrainResolverConfig::new(strategy).with_stable_after(STABLE_AFTER);
+  let resolver =
+    SplitBrainResolver::new(membership, config, move || Duration::from_millis(clock.load(Ordering::SeqCst)))
+      .with_failure_listener(FailureEventListener::new(move |event: FailureEvent| {
+        if let FailureEvent::NodeDowned(node) = event {
+          recorded.lock().unwrap_or_else(|err| err.into_inner()).extend(node.port());
+        }
+      }));
+  Ok(TestNode { _system: system, resolver, now, downed })
+}
+
+fn cluster(network: &LoopbackNetwork, strategy: DowningStrategy) -> TestResult<[TestNode; 3]> {
+  let nodes = [started(network, 2551, strategy)?, started(network, 2552, strategy)?, started(network, 2553, strategy)?];
+  for test_node in &nodes {
+    test_node.membership().join();
+  }
+  for _ in 0..4 {
+    for test_node in &nodes {
+      test_node.membership().tick();
+    }
+    network.flush();
+  }
+  Ok(nodes)
+}
+
+#[test]
+fn keep_majority_keeps_the_larger_side_and_breaks_ties_by_lowest_address() {
+  let strategy = DowningStrategy::KeepMajority;
+  assert_eq!(ports(&strategy.decide(&[up(2551, 1), up(2552, 2)], &[up(2553, 3)])), vec![2553]);
+  assert_eq!(ports(&strategy.decide(&[up(2553, 3)], &[up(2551, 1), up(2552, 2)])), vec![2553]);
+  assert_eq!(ports(&strategy.decide(&[up(2552, 1)], &[up(2551, 2)])), vec![2552]);
+  assert_eq!(ports(&strategy.decide(&[up(2551, 2)], &[up(2552, 1)])), vec![2552]);
+  assert!(strategy.decide(&[up(2551, 1), up(2552, 2)], &[]).is_empty());
+}
+
+#[test]
+fn static_quorum_keeps_the_side_reaching_the_quorum() {
+  let strategy = DowningStrategy::StaticQuorum { quorum_size: 2 };
+  assert_eq!(ports(&strategy.decide(&[up(2551, 1), up(2552, 2)], &[up(2553, 3)])), vec![2553]);
+  assert_eq!(ports(&strategy.decide(&[up(2553, 3)], &[up(2551, 1), up(2552, 2)])), vec![2553]);
+  let everyone = strategy.decide(&[up(2551, 1), up(2552, 2)], &[up(2553, 3), up(2554, 4)]);
+  assert_eq!(ports(&everyone), vec![2551, 2552, 2553, 2554]);
+  let alone = DowningStrategy::StaticQuorum { quorum_size: 3 };
+  assert_eq!(ports(&alone.decide(&[up(2551, 1), up(2552, 2)], &[up(2553, 3)])), vec![2551, 2552]);
+}
+
+#[test]
+fn keep_oldest_keeps_the_side_of_the_oldest_member_unless_it_is_alone() {
+  let strategy = DowningStrategy::KeepOldest { down_if_alone: false };
+  assert_eq!(ports(&strategy.decide(&[up(2553, 1)], &[up(2551, 2), up(2552, 3)])), vec![2551, 2552]);
+  assert_eq!(ports(&strategy.decide(&[up(2551, 2), up(2552, 3)], &[up(2553, 1)])), vec![2551, 2552]);
+
+  let strategy = DowningStrategy::KeepOldest { down_if_alone: true };
+  assert_eq!(ports(&strategy.decide(&[up(2553, 1)], &[up(2551, 2), up(2552, 3)])), vec![2553]);
+  assert_eq!(ports(&strategy.decide(&[up(2551, 2), up(2552, 3)], &[up(2553, 1)])), vec![2553]);
+  assert_eq!(ports(&strategy.decide(&[up(2551, 1), up(2552, 2)], &[up(2553, 3)])), vec![2553]);
+}
+
+#[test]
+fn down_all_downs_every_member() {
+  let decided = DowningStrategy::DownAll.decide(&[up(2551, 1), up(2552, 2)], &[up(2553, 3)]);
+  assert_eq!(ports(&decided), vec![2551, 2552, 2553]);
+}
+
+#[test]
+fn majority_side_downs_the_unreachable_member_once_the_partition_is_stable() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let [first, second, _third] = cluster(&network, DowningStrategy::KeepMajority)?;
+  first.unreachable(&[2553]);
+  second.unreachable(&[2553]);
+  first.resolver.tick();
+  second.resolver.tick();
+
+  first.advance(STABLE_AFTER - Duration::from_secs(1));
+  second.advance(STABLE_AFTER - Duration::from_secs(1));
+  first.resolver.tick();
+  assert_eq!(first.status_of(2553), Some(MemberStatus::Up));
+
+  first.advance(Duration::from_secs(1));
+  second.advance(Duration::from_secs(1));
+  first.resolver.tick();
+  second.resolver.tick();
+  assert_eq!(first.status_of(2553), Some(MemberStatus::Down));
+  assert_eq!(first.downed(), vec![2553]);
+  // Only the first reachable member in node order decides for its side.
+  assert_eq!((second.status_of(2553), second.downed()), (Some(MemberStatus::Up), vec![]));
+
+  first.advance(STABLE_AFTER);
+  first.resolver.tick();
+  assert!(first.downed().is_empty());
+  Ok(())
+}
+
+#[test]
+fn minority_side_downs_itself() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let [_first, _second, third] = cluster(&network, DowningStrategy::KeepMajority)?;
+  third.unreachable(&[2551, 2552]);
+  third.resolver.tick();
+  third.advance(STABLE_AFTER);
+  third.resolver.tick();
+
+  assert_eq!(third.status_of(2553), Some(MemberStatus::Down));
+  assert_eq!(third.status_of(2551), Some(MemberStatus::Up));
+  assert_eq!(third.downed(), vec![2553]);
+  Ok(())
+}
+
+#[test]
+fn reachability_changes_restart_the_stable_after_delay() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let [first, _second, _third] = cluster(&network, DowningStrategy::KeepMajority)?;
+  first.unreachable(&[2553]);
+  first.resolver.tick();
+  first.advance(STABLE_AFTER - Duration::from_secs(1));
+  first.resolver.observe(&FailureEvent::NodeReachable(node(2553)));
+  first.resolver.tick();
+  assert!(first.resolver.unreachable().is_empty());
+
+  first.unreachable(&[2552]);
+  first.advance(STABLE_AFTER - Duration::from_secs(1));
+  first.resolver.tick();
+  assert!(first.downed().is_empty());
+  first.advance(Duration::from_secs(1));
+  first.resolver.tick();
+  assert_eq!(first.downed(), vec![2552]);
+  assert_eq!(first.status_of(2553), Some(MemberStatus::Up));
+  Ok(())
+}
@@ -0,0 +1,15 @@
+mod cluster_event;
+mod cluster_event_hub;
+mod cluster_event_hub_state;
+mod cluster_event_listener;
+mod cluster_event_subscription;
+mod cluster_state;
+
+#[cfg(test)]
+mod tests;
+
+pub use cluster_event::ClusterEvent;
+pub use cluster_event_hub::ClusterEventHub;
+pub use cluster_event_listener::ClusterEventListener;
+pub use cluster_event_subscription::ClusterEventSubscription;
+pub use cluster_state::ClusterState;
@@ -0,0 +1,29 @@
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use super::ClusterState;
+use crate::membership::Member;
+
+/// Change of the cluster as observed by the local node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClusterEvent {
+  /// Snapshot of the cluster, delivered to every subscriber before any other event.
+  CurrentState(ClusterState),
+  /// A node asked to join the cluster.
+  MemberJoined(Member),
+  /// The leader admitted a member to the cluster.
+  MemberUp(Member),
+  /// A member announced that it leaves the cluster.
+  MemberLeft(Member),
+  /// The leader acknowledged the departure of a leaving member.
+  MemberExited(Member),
+  /// A member was declared dead.
+  MemberDowned(Member),
+  /// A member is no longer part of the cluster.
+  MemberRemoved(Member),
+  /// The failure detector no longer reaches a node.
+  Unreachable(NodeId),
+  /// The failure detector reaches a node again.
+  Reachable(NodeId),
+  /// The leader moved to another member, or no member is up or leaving anymore.
+  LeaderChanged(Option<NodeId>),
+}
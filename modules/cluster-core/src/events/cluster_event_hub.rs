@@ -0,0 +1,138 @@
+use cellex_actor_core_rs::api::failure::{failure_event_stream::FailureEventListener, FailureEvent};
+use cellex_remote_core_rs::transport::RemoteTransport;
+use cellex_utils_core_rs::sync::ArcShared;
+use spin::RwLock;
+
+use super::{
+  cluster_event_hub_state::ClusterEventHubState, ClusterEvent, ClusterEventListener, ClusterEventSubscription,
+  ClusterState,
+};
+use crate::membership::{
+  ClusterMembership, MemberStatus, MembershipChange, MembershipListener, MembershipSubscription,
+};
+
+/// Typed bus distributing [`ClusterEvent`]s to subscribed listeners.
+///
+/// The hub follows the listener/subscription pattern of `FailureEventHub`: events are published
+/// through [`ClusterEventHub::listener`] and delivered to every listener registered with
+/// [`ClusterEventHub::subscribe`] until its subscription is dropped. The hub also keeps the
+/// [`ClusterState`] resulting from the events published so far, hands it to each new subscriber
+/// as [`ClusterEvent::CurrentState`], and derives [`ClusterEvent::LeaderChanged`] from the member
+/// events. Events that do not change the state are not delivered.
+///
+/// Deliveries are serialized, so a new subscriber receives its snapshot before any event published
+/// afterwards. Listeners therefore must not publish on or subscribe to the hub calling them.
+///
+/// Membership changes reach the hub through [`ClusterEventHub::track`], reachability changes
+/// through [`ClusterEventHub::failure_listener`].
+#[derive(Clone)]
+pub struct ClusterEventHub {
+  state:    ArcShared<RwLock<ClusterEventHubState>>,
+  delivery: ArcShared<RwLock<()>>,
+}
+
+impl ClusterEventHub {
+  /// Creates a hub without listeners or known members.
+  #[must_use]
+  pub fn new() -> Self {
+    Self {
+      state:    ArcShared::new(RwLock::new(ClusterEventHubState::default())),
+      delivery: ArcShared::new(RwLock::new(())),
+    }
+  }
+
+  /// Returns the cluster state built from the events published so far.
+  #[must_use]
+  pub fn state(&self) -> ClusterState {
+    self.state.read().snapshot()
+  }
+
+  /// Applies `event` to the cluster state and delivers it when it changed the state.
+  pub fn publish(&self, event: ClusterEvent) {
+    let _delivery = self.delivery.write();
+    let (events, listeners) = {
+      let mut state = self.state.write();
+      (state.apply(event), state.listeners())
+    };
+    for event in &events {
+      for listener in &listeners {
+        listener.notify(event);
+      }
+    }
+  }
+
+  /// Returns a listener publishing the events it receives on the hub.
+  #[must_use]
+  pub fn listener(&self) -> ClusterEventListener {
+    let hub = self.clone();
+    ClusterEventListener::new(move |event: &ClusterEvent| hub.publish(event.clone()))
+  }
+
+  /// Registers `listener`, which first receives the current state and then every event published
+  /// from now on.
+  #[must_use]
+  pub fn subscribe(&self, listener: ClusterEventListener) -> ClusterEventSubscription {
+    let first = listener.clone();
+    // Holding the delivery guard keeps concurrent events from overtaking the snapshot.
+    let _delivery = self.delivery.write();
+    let (id, snapshot) = {
+      let mut state = self.state.write();
+      (state.add(listener), state.snapshot())
+    };
+    first.notify(&ClusterEvent::CurrentState(snapshot));
+    ClusterEventSubscription::new(self.state.clone(), id)
+  }
+
+  /// Returns a membership listener publishing each membership change as the matching member
+  /// event.
+  #[must_use]
+  pub fn membership_listener(&self) -> MembershipListener {
+    let hub = self.clone();
+    MembershipListener::new(move |change: &MembershipChange| hub.publish(member_event(change)))
+  }
+
+  /// Returns a listener publishing the reachability events of the failure detector, to be passed
+  /// e.g. to `RemoteHeartbeat::with_failure_listener` or subscribed to the hub of a
+  /// [`ClusterFailureBridge`](crate::ClusterFailureBridge). Quarantined nodes are reported
+  /// unreachable; other failure events are ignored.
+  #[must_use]
+  pub fn failure_listener(&self) -> FailureEventListener {
+    let hub = self.clone();
+    FailureEventListener::new(move |event: FailureEvent| match event {
+      | FailureEvent::NodeUnreachable(node) | FailureEvent::NodeQuarantined(node) => {
+        hub.publish(ClusterEvent::Unreachable(node));
+      },
+      | FailureEvent::NodeReachable(node) => hub.publish(ClusterEvent::Reachable(node)),
+      | _ => {},
+    })
+  }
+
+  /// Takes the current members of `membership` as the known state, without publishing them, and
+  /// publishes its changes from now on until the returned subscription is dropped.
+  #[must_use]
+  pub fn track<T>(&self, membership: &ClusterMembership<T>) -> MembershipSubscription
+  where
+    T: RemoteTransport, {
+    let subscription = membership.subscribe(self.membership_listener());
+    self.state.write().seed(membership.members());
+    subscription
+  }
+}
+
+impl Default for ClusterEventHub {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+fn member_event(change: &MembershipChange) -> ClusterEvent {
+  let member = change.member().clone();
+  match change.status() {
+    | MemberStatus::Joining => ClusterEvent::MemberJoined(member),
+    | MemberStatus::Up => ClusterEvent::MemberUp(member),
+    | MemberStatus::Leaving => ClusterEvent::MemberLeft(member),
+    | MemberStatus::Exiting => ClusterEvent::MemberExited(member),
+    | MemberStatus::Down => ClusterEvent::MemberDowned(member),
+    | MemberStatus::Removed => ClusterEvent::MemberRemoved(member),
+  }
+}
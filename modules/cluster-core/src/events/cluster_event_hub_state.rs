@@ -0,0 +1,101 @@
+use alloc::{
+  collections::BTreeMap,
+  string::{String, ToString},
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use super::{ClusterEvent, ClusterEventListener, ClusterState};
+use crate::membership::{Member, MemberStatus};
+
+/// Listeners of a hub, keyed by subscription id, and the cluster state built from the events
+/// published so far.
+#[derive(Default)]
+pub(crate) struct ClusterEventHubState {
+  next_id:     u64,
+  listeners:   Vec<(u64, ClusterEventListener)>,
+  members:     BTreeMap<(String, u64), Member>,
+  unreachable: BTreeMap<String, NodeId>,
+  leader:      Option<NodeId>,
+}
+
+impl ClusterEventHubState {
+  pub(crate) fn add(&mut self, listener: ClusterEventListener) -> u64 {
+    self.next_id += 1;
+    self.listeners.push((self.next_id, listener));
+    self.next_id
+  }
+
+  pub(crate) fn remove(&mut self, id: u64) {
+    self.listeners.retain(|(entry_id, _)| *entry_id != id);
+  }
+
+  pub(crate) fn listeners(&self) -> Vec<ClusterEventListener> {
+    self.listeners.iter().map(|(_, listener)| listener.clone()).collect()
+  }
+
+  pub(crate) fn snapshot(&self) -> ClusterState {
+    ClusterState::new(
+      self.members.values().cloned().collect(),
+      self.unreachable.values().cloned().collect(),
+      self.leader.clone(),
+    )
+  }
+
+  /// Replaces the known members without publishing anything.
+  pub(crate) fn seed(&mut self, members: Vec<Member>) {
+    self.members = members.into_iter().map(|member| ((member.node().to_string(), member.uid()), member)).collect();
+    self.leader = self.elect();
+  }
+
+  /// Applies `event` and returns the events to deliver: nothing when the event changes nothing,
+  /// otherwise the event followed by the leader change it caused, if any.
+  pub(crate) fn apply(&mut self, event: ClusterEvent) -> Vec<ClusterEvent> {
+    let changed = match &event {
+      | ClusterEvent::CurrentState(_) => false,
+      | ClusterEvent::MemberJoined(member)
+      | ClusterEvent::MemberUp(member)
+      | ClusterEvent::MemberLeft(member)
+      | ClusterEvent::MemberExited(member)
+      | ClusterEvent::MemberDowned(member) => {
+        self.members.insert((member.node().to_string(), member.uid()), member.clone()).as_ref() != Some(member)
+      },
+      | ClusterEvent::MemberRemoved(member) => {
+        let node = member.node().to_string();
+        let removed = self.members.remove(&(node.clone(), member.uid())).is_some();
+        // Another incarnation of the node may still be a member.
+        if !self.members.keys().any(|(other, _)| *other == node) {
+          self.unreachable.remove(&node);
+        }
+        removed
+      },
+      | ClusterEvent::Unreachable(node) => self.unreachable.insert(node.to_string(), node.clone()).is_none(),
+      | ClusterEvent::Reachable(node) => self.unreachable.remove(&node.to_string()).is_some(),
+      | ClusterEvent::LeaderChanged(leader) => {
+        let changed = self.leader != *leader;
+        self.leader.clone_from(leader);
+        changed
+      },
+    };
+    if !changed {
+      return Vec::new();
+    }
+    let elected = if matches!(event, ClusterEvent::LeaderChanged(_)) { self.leader.clone() } else { self.elect() };
+    let mut events = vec![event];
+    if elected != self.leader {
+      self.leader.clone_from(&elected);
+      events.push(ClusterEvent::LeaderChanged(elected));
+    }
+    events
+  }
+
+  fn elect(&self) -> Option<NodeId> {
+    self
+      .members
+      .values()
+      .find(|member| matches!(member.status(), MemberStatus::Up | MemberStatus::Leaving))
+      .map(|member| member.node().clone())
+  }
+}
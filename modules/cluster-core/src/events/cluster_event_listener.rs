@@ -0,0 +1,33 @@
+use cellex_utils_core_rs::sync::{shared::SharedBound, ArcShared};
+
+use super::ClusterEvent;
+
+#[cfg(target_has_atomic = "ptr")]
+type ClusterEventListenerFn = dyn Fn(&ClusterEvent) + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type ClusterEventListenerFn = dyn Fn(&ClusterEvent);
+
+/// Callback receiving the events published on a [`ClusterEventHub`](super::ClusterEventHub).
+///
+/// Actors observe the cluster by subscribing a listener that forwards each event to their
+/// reference.
+#[derive(Clone)]
+pub struct ClusterEventListener {
+  inner: ArcShared<ClusterEventListenerFn>,
+}
+
+impl ClusterEventListener {
+  /// Creates a listener from a closure.
+  #[must_use]
+  pub fn new<F>(f: F) -> Self
+  where
+    F: Fn(&ClusterEvent) + SharedBound + 'static, {
+    Self { inner: ArcShared::new(f).into_dyn(|func| func as &ClusterEventListenerFn) }
+  }
+
+  /// Hands `event` to the listener.
+  pub fn notify(&self, event: &ClusterEvent) {
+    (self.inner)(event);
+  }
+}
@@ -0,0 +1,23 @@
+use cellex_utils_core_rs::sync::ArcShared;
+use spin::RwLock;
+
+use super::cluster_event_hub_state::ClusterEventHubState;
+
+/// Subscription handle returned by
+/// [`ClusterEventHub::subscribe`](super::ClusterEventHub::subscribe). Unsubscribes on drop.
+pub struct ClusterEventSubscription {
+  state: ArcShared<RwLock<ClusterEventHubState>>,
+  id:    u64,
+}
+
+impl ClusterEventSubscription {
+  pub(crate) const fn new(state: ArcShared<RwLock<ClusterEventHubState>>, id: u64) -> Self {
+    Self { state, id }
+  }
+}
+
+impl Drop for ClusterEventSubscription {
+  fn drop(&mut self) {
+    self.state.write().remove(self.id);
+  }
+}
@@ -0,0 +1,37 @@
+use alloc::vec::Vec;
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use crate::membership::Member;
+
+/// Snapshot of the members, their reachability and the leader, as known by the local node.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClusterState {
+  members:     Vec<Member>,
+  unreachable: Vec<NodeId>,
+  leader:      Option<NodeId>,
+}
+
+impl ClusterState {
+  pub(crate) const fn new(members: Vec<Member>, unreachable: Vec<NodeId>, leader: Option<NodeId>) -> Self {
+    Self { members, unreachable, leader }
+  }
+
+  /// Returns the members that have not been removed, in node order.
+  #[must_use]
+  pub fn members(&self) -> &[Member] {
+    &self.members
+  }
+
+  /// Returns the nodes the failure detector currently reports unreachable, in node order.
+  #[must_use]
+  pub fn unreachable(&self) -> &[NodeId] {
+    &self.unreachable
+  }
+
+  /// Returns the leader, if any member is up or leaving.
+  #[must_use]
+  pub const fn leader(&self) -> Option<&NodeId> {
+    self.leader.as_ref()
+  }
+}
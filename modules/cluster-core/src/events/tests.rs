@@ -0,0 +1,246 @@
+extern crate std;
+
+use core::time::Duration;
+use std::{
+  format,
+  string::{String, ToString},
+  sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc, Mutex,
+  },
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor_runtime::GenericActorRuntime,
+  actor_system::{GenericActorSystem, GenericActorSystemConfig},
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  failure::FailureEvent,
+  process::pid::NodeId,
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_remote_core_rs::{
+  endpoint::EndpointManager,
+  loopback::{LoopbackNetwork, LoopbackTransport},
+  outbound::RemoteOutbound,
+};
+use cellex_utils_core_rs::sync::ArcShared;
+
+use super::{ClusterEvent, ClusterEventHub, ClusterEventListener};
+use crate::membership::{ClusterMembership, Member, MemberStatus, MembershipChange, MembershipConfig};
+
+type TestResult<T = ()> = Result<T, String>;
+type TestSystem = GenericActorSystem<String, TokioActorRuntime>;
+type Events = Arc<Mutex<Vec<String>>>;
+
+struct TestNode {
+  _system:    TestSystem,
+  membership: ClusterMembership<LoopbackTransport>,
+}
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn member(port: u16, status: MemberStatus) -> Member {
+  Member::new(node(port)).with_status(status).with_up_number(u64::from(port))
+}
+
+fn started(network: &LoopbackNetwork, port: u16) -> TestResult<TestNode> {
+  let system_config = GenericActorSystemConfig::default().with_node_id(node(port));
+  let system = GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), system_config);
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(network.transport(&node(port)), system_id, node(port)));
+  let outbound = system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      RemoteOutbound::new(endpoints, extension)
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())?;
+  let membership = ClusterMembership::new(outbound, MembershipConfig::new().with_seed_node(node(2551)));
+  membership.start(system.process_registry()).map_err(|err| format!("start: {err}"))?;
+  Ok(TestNode { _system: system, membership })
+}
+
+fn port(node: &NodeId) -> u16 {
+  node.port().unwrap_or(0)
+}
+
+fn describe(event: &ClusterEvent) -> String {
+  match event {
+    | ClusterEvent::CurrentState(state) => {
+      let members: Vec<String> =
+        state.members().iter().map(|member| format!("{}:{:?}", port(member.node()), member.status())).collect();
+      let unreachable: Vec<u16> = state.unreachable().iter().map(port).collect();
+      format!("state {members:?} unreachable {unreachable:?} leader {:?}", state.leader().map(port))
+    },
+    | ClusterEvent::MemberJoined(member) => format!("joined {}", port(member.node())),
+    | ClusterEvent::MemberUp(member) => format!("up {}", port(member.node())),
+    | ClusterEvent::MemberLeft(member) => format!("left {}", port(member.node())),
+    | ClusterEvent::MemberExited(member) => format!("exited {}", port(member.node())),
+    | ClusterEvent::MemberDowned(member) => format!("downed {}", port(member.node())),
+    | ClusterEvent::MemberRemoved(member) => format!("removed {}", port(member.node())),
+    | ClusterEvent::Unreachable(node) => format!("unreachable {}", port(node)),
+    | ClusterEvent::Reachable(node) => format!("reachable {}", port(node)),
+    | ClusterEvent::LeaderChanged(leader) => format!("leader {:?}", leader.as_ref().map(port)),
+  }
+}
+
+fn recorder() -> (ClusterEventListener, Events) {
+  let events: Events = Arc::new(Mutex::new(Vec::new()));
+  let recorded = events.clone();
+  let listener = ClusterEventListener::new(move |event: &ClusterEvent| {
+    recorded.lock().unwrap_or_else(|err| err.into_inner()).push(describe(event));
+  });
+  (listener, events)
+}
+
+fn taken(events: &Events) -> Vec<String> {
+  core::mem::take(&mut *events.lock().unwrap_or_else(|err| err.into_inner()))
+}
+
+fn strings(expected: &[&str]) -> Vec<String> {
+  expected.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn subscribers_receive_the_current_state_then_every_change() {
+  let hub = ClusterEventHub::new();
+  let membership = hub.membership_listener();
+  let failures = hub.failure_listener();
+  membership.notify(&MembershipChange::new(member(2552, MemberStatus::Up), Some(MemberStatus::Joining)));
+
+  let (listener, events) = recorder();
+  let subscription = hub.subscribe(listener);
+  assert_eq!(taken(&events), strings(&["state [\"2552:Up\"] unreachable [] leader Some(2552)"]));
+
+  membership.notify(&MembershipChange::new(member(2551, MemberStatus::Joining), None));
+  membership.notify(&MembershipChange::new(member(2551, MemberStatus::Up), Some(MemberStatus::Joining)));
+  failures(FailureEvent::NodeUnreachable(node(2552)));
+  failures(FailureEvent::NodeQuarantined(node(2552)));
+  failures(FailureEvent::NodeReachable(node(2552)));
+  failures(FailureEvent::NodeDowned(node(2552)));
+  membership.notify(&MembershipChange::new(member(2551, MemberStatus::Leaving), Some(MemberStatus::Up)));
+  membership.notify(&MembershipChange::new(member(2551, MemberStatus::Exiting), Some(MemberStatus::Leaving)));
+  assert_eq!(
+    taken(&events),
+    strings(&[
+      "joined 2551",
+      "up 2551",
+      "leader Some(2551)",
+      "unreachable 2552",
+      "reachable 2552",
+      "left 2551",
+      "exited 2551",
+      "leader Some(2552)",
+    ])
+  );
+
+  failures(FailureEvent::NodeUnreachable(node(2551)));
+  membership.notify(&MembershipChange::new(member(2551, MemberStatus::Removed), Some(MemberStatus::Exiting)));
+  membership.notify(&MembershipChange::new(member(2552, MemberStatus::Down), Some(MemberStatus::Up)));
+  assert_eq!(taken(&events), strings(&["unreachable 2551", "removed 2551", "downed 2552", "leader None"]));
+  assert_eq!(hub.state().members(), &[member(2552, MemberStatus::Down)]);
+  assert!(hub.state().unreachable().is_empty());
+
+  drop(subscription);
+  hub.listener().notify(&ClusterEvent::Unreachable(node(2552)));
+  assert!(taken(&events).is_empty());
+  assert_eq!(hub.state().unreachable(), &[node(2552)]);
+}
+
+#[test]
+fn concurrent_events_never_overtake_the_snapshot_of_a_new_subscriber() -> TestResult {
+  let hub = ClusterEventHub::new();
+  let publisher = hub.listener();
+  let published = Arc::new(AtomicU64::new(0));
+  let running = published.clone();
+  let done = Arc::new(AtomicBool::new(false));
+  let stop = done.clone();
+  let worker = std::thread::spawn(move || {
+    let mut unreachable = true;
+    while !stop.load(Ordering::Acquire) {
+      let event = if unreachable { ClusterEvent::Unreachable(node(2552)) } else { ClusterEvent::Reachable(node(2552)) };
+      publisher.notify(&event);
+      running.fetch_add(1, Ordering::Release);
+      unreachable = !unreachable;
+    }
+  });
+  while published.load(Ordering::Acquire) == 0 {
+    std::thread::yield_now();
+  }
+
+  // Listeners dwell on the snapshot so that an event racing it would be recorded first.
+  let subscriber = std::thread::current().id();
+  let recorded: Vec<_> = (0..20)
+    .map(|_| {
+      let (recorder, events) = recorder();
+      let listener = ClusterEventListener::new(move |event: &ClusterEvent| {
+        if std::thread::current().id() == subscriber {
+          std::thread::sleep(Duration::from_millis(1));
+        }
+        recorder.notify(event);
+      });
+      (hub.subscribe(listener), events)
+    })
+    .collect();
+  done.store(true, Ordering::Release);
+  worker.join().map_err(|_| "publisher panicked".to_string())?;
+
+  for (_, events) in &recorded {
+    let first = taken(events).into_iter().next().ok_or_else(|| "snapshot expected".to_string())?;
+    assert!(first.starts_with("state"), "{first}");
+  }
+  Ok(())
+}
+
+#[test]
+fn removing_a_previous_incarnation_keeps_the_new_one() {
+  let hub = ClusterEventHub::new();
+  let membership = hub.membership_listener();
+  membership.notify(&MembershipChange::new(member(2552, MemberStatus::Up), None));
+  let restarted = Member::new(node(2552)).with_uid(2);
+  membership.notify(&MembershipChange::new(restarted.clone(), None));
+
+  membership.notify(&MembershipChange::new(member(2552, MemberStatus::Down), Some(MemberStatus::Up)));
+  membership.notify(&MembershipChange::new(member(2552, MemberStatus::Removed), Some(MemberStatus::Down)));
+
+  assert_eq!(hub.state().members(), &[restarted]);
+}
+
+#[test]
+fn tracked_membership_changes_reach_late_and_early_subscribers() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let first = started(&network, 2551)?;
+  let second = started(&network, 2552)?;
+  let hub = ClusterEventHub::new();
+  let _tracking = hub.track(&first.membership);
+  let (early, early_events) = recorder();
+  let _early = hub.subscribe(early);
+
+  for test_node in [&first, &second] {
+    test_node.membership.join();
+  }
+  for _ in 0..4 {
+    first.membership.tick();
+    second.membership.tick();
+    network.flush();
+  }
+  let events = taken(&early_events);
+  assert_eq!(events.first().map(String::as_str), Some("state [\"2551:Joining\"] unreachable [] leader None"));
+  for expected in ["up 2551", "leader Some(2551)", "joined 2552", "up 2552"] {
+    assert!(events.iter().any(|event| event == expected), "missing {expected} in {events:?}");
+  }
+
+  let (late, late_events) = recorder();
+  let _late = hub.subscribe(late);
+  assert_eq!(taken(&late_events), strings(&["state [\"2551:Up\", \"2552:Up\"] unreachable [] leader Some(2551)"]));
+
+  second.membership.leave();
+  for _ in 0..4 {
+    first.membership.tick();
+    second.membership.tick();
+    network.flush();
+  }
+  assert_eq!(taken(&late_events), strings(&["left 2552", "exited 2552", "removed 2552"]));
+  Ok(())
+}
@@ -1,6 +1,15 @@
 //! Core implementation of cluster coordination functionality.
 //!
-//! Provides placeholder APIs for integrating `FailureEventStream` into future cluster services.
+//! Provides gossip-based cluster membership through [`membership::ClusterMembership`] on top of
+//! the remote layer, virtual actors activated on demand through
+//! [`virtual_actor::VirtualActors`] and placed by a [`partition::PartitionStrategy`], cluster
+//! singletons through [`singleton::ClusterSingleton`], sharded entities through
+//! [`sharding::ClusterSharding`], distributed publish/subscribe through
+//! [`pubsub::DistributedPubSub`], split-brain resolution through
+//! [`downing::SplitBrainResolver`], a typed stream of cluster events through
+//! [`events::ClusterEventHub`], replicated conflict-free data through
+//! [`distributed_data::Replicator`], cluster-aware routers through [`routing::ClusterGroupRouter`]
+//! and [`routing::ClusterPoolRouter`], together with integration points for `FailureEventStream`.
 
 #![deny(missing_docs)]
 #![cfg_attr(test, allow(clippy::unwrap_used, clippy::expect_used, clippy::disallowed_types))]
@@ -57,15 +66,41 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+/// Conflict-free replicated data kept consistent across the members of a cluster by gossip.
+pub mod distributed_data;
+/// Automatic downing of the members on the losing side of a network partition.
+pub mod downing;
+/// Typed stream of the membership, reachability and leader changes of a cluster.
+pub mod events;
+/// Gossip-based membership of the nodes forming a cluster.
+pub mod membership;
+/// Deterministic placement of identities on the members of a cluster.
+pub mod partition;
+/// Topic-based publish/subscribe spanning the members of a cluster.
+pub mod pubsub;
+/// Routers spreading messages over routees on the members of a cluster.
+pub mod routing;
+/// Entities spread over the cluster in shards allocated by a coordinator on the oldest member.
+pub mod sharding;
+/// Actors running exactly once in the cluster, on its oldest member.
+pub mod singleton;
+/// Virtual actors addressed by kind and identity, activated on demand and passivated when idle.
+pub mod virtual_actor;
+
 use cellex_actor_core_rs::api::failure::{
   failure_event_stream::{FailureEventListener, FailureEventStream},
   FailureEvent,
 };
 use cellex_remote_core_rs::RemoteFailureNotifier;
+use cellex_serialization_core_rs::SerializerId;
 
 #[cfg(test)]
 mod tests;
 
+/// Serializer id marking payloads encoded by the cluster's own protocols, such as membership
+/// gossip. It lies in the range reserved for framework serializers.
+pub const CLUSTER_SERIALIZER_ID: SerializerId = SerializerId::new(16);
+
 /// Bridge connecting cluster and remote failure notifications.
 ///
 /// Propagates local failure events to remote nodes, enabling cluster-wide sharing of failure
@@ -117,8 +152,7 @@ where
   ///
   /// * `event` - The failure event to distribute
   pub fn fan_out(&self, event: FailureEvent) {
-    let FailureEvent::RootEscalated(info) = &event;
-    self.remote_notifier.dispatch(info.clone());
+    self.remote_notifier.dispatch_event(event.clone());
     self.hub.listener()(event);
   }
 }
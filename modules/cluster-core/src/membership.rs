@@ -0,0 +1,21 @@
+mod cluster_membership;
+mod member;
+mod member_status;
+mod membership_change;
+mod membership_config;
+mod membership_listener;
+mod membership_listeners;
+mod membership_subscription;
+mod membership_table;
+
+#[cfg(test)]
+mod tests;
+
+pub use cluster_membership::ClusterMembership;
+pub use member::Member;
+pub use member_status::MemberStatus;
+pub use membership_change::MembershipChange;
+pub use membership_config::MembershipConfig;
+pub use membership_listener::MembershipListener;
+pub use membership_subscription::MembershipSubscription;
+pub use membership_table::MembershipTable;
@@ -0,0 +1,353 @@
+use alloc::{string::ToString, vec::Vec};
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::ActorPath,
+    process::pid::{NodeId, Pid, PidTag},
+  },
+  shared::mailbox::MailboxFactory,
+};
+use cellex_remote_core_rs::{
+  codec::{RemoteDelivery, RemotePayloadFrame},
+  delivery::{RemoteFrameInterceptor, RemoteInboundDispatcher, RemoteProcessRegistry},
+  outbound::RemoteOutbound,
+  transport::{RemoteTransport, TransportError},
+};
+use cellex_serialization_core_rs::message::SerializedMessage;
+use cellex_utils_core_rs::{
+  collections::queue::priority::DEFAULT_PRIORITY,
+  sync::{shared::SharedBound, ArcShared},
+};
+use spin::RwLock;
+
+use super::{
+  membership_listeners::MembershipListeners, Member, MemberStatus, MembershipChange, MembershipConfig,
+  MembershipListener, MembershipSubscription, MembershipTable,
+};
+use crate::CLUSTER_SERIALIZER_ID;
+
+const MEMBERSHIP_TAG: &str = "membership";
+const GOSSIP_TYPE_NAME: &str = "cellex.cluster.MembershipGossip";
+
+/// Gossip-based membership of the local node in the cluster.
+///
+/// Every [`ClusterMembership::tick`] sends the local [`MembershipTable`] to a few other members,
+/// or to the seed nodes while the local node knows no other member. Receivers merge the table
+/// into theirs and answer with their own table when the sender missed something, so tables
+/// converge within a few rounds.
+///
+/// The leader, the first member in node order that is up or leaving, moves the other members
+/// through their lifecycle on each tick: joining members are admitted as up, leaving members
+/// exit, and exiting or downed members are removed. Each step takes one tick, which leaves the
+/// previous one time to spread.
+///
+/// A node restarted on the same address joins with a new uid (see [`MembershipConfig::with_uid`]).
+/// The leader downs its previous incarnation and admits the new one once the previous one is
+/// removed.
+pub struct ClusterMembership<T>
+where
+  T: RemoteTransport, {
+  outbound:  RemoteOutbound<T>,
+  config:    MembershipConfig,
+  table:     ArcShared<RwLock<MembershipTable>>,
+  listeners: ArcShared<RwLock<MembershipListeners>>,
+  cursor:    ArcShared<RwLock<usize>>,
+}
+
+impl<T> Clone for ClusterMembership<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self {
+      outbound:  self.outbound.clone(),
+      config:    self.config.clone(),
+      table:     self.table.clone(),
+      listeners: self.listeners.clone(),
+      cursor:    self.cursor.clone(),
+    }
+  }
+}
+
+impl<T> ClusterMembership<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates the membership of the local node, which starts as joining.
+  #[must_use]
+  pub fn new(outbound: RemoteOutbound<T>, config: MembershipConfig) -> Self {
+    let mut table = MembershipTable::new();
+    let _ = table.update(Member::new(outbound.node().clone()).with_uid(config.uid()));
+    Self {
+      outbound,
+      config,
+      table: ArcShared::new(RwLock::new(table)),
+      listeners: ArcShared::new(RwLock::new(MembershipListeners::default())),
+      cursor: ArcShared::new(RwLock::new(0)),
+    }
+  }
+
+  /// Returns the outbound side used to gossip.
+  #[must_use]
+  pub const fn outbound(&self) -> &RemoteOutbound<T> {
+    &self.outbound
+  }
+
+  /// Returns the membership settings.
+  #[must_use]
+  pub const fn config(&self) -> &MembershipConfig {
+    &self.config
+  }
+
+  /// Returns the local node.
+  #[must_use]
+  pub fn local(&self) -> &NodeId {
+    self.outbound.node()
+  }
+
+  /// Returns a copy of the local membership table.
+  #[must_use]
+  pub fn table(&self) -> MembershipTable {
+    self.table.read().clone()
+  }
+
+  /// Returns the members that have not been removed, in node order.
+  #[must_use]
+  pub fn members(&self) -> Vec<Member> {
+    self.table.read().members().filter(|member| member.status() != MemberStatus::Removed).cloned().collect()
+  }
+
+  /// Returns the local view of the latest incarnation of `node`, if known.
+  #[must_use]
+  pub fn member(&self, node: &NodeId) -> Option<Member> {
+    self.table.read().get(node).cloned()
+  }
+
+  /// Returns the current leader, if any member is up.
+  #[must_use]
+  pub fn leader(&self) -> Option<NodeId> {
+    self.table.read().leader().map(|member| member.node().clone())
+  }
+
+  /// Returns the member that has been up the longest, i.e. the one with the lowest up number.
+  #[must_use]
+  pub fn oldest(&self) -> Option<NodeId> {
+    self
+      .table
+      .read()
+      .members()
+      .filter(|member| member.status() == MemberStatus::Up)
+      .min_by_key(|member| (member.up_number(), member.node().to_string()))
+      .map(|member| member.node().clone())
+  }
+
+  /// Registers `listener` for every change applied to the local table from now on.
+  #[must_use]
+  pub fn subscribe(&self, listener: MembershipListener) -> MembershipSubscription {
+    let id = self.listeners.write().add(listener);
+    MembershipSubscription::new(self.listeners.clone(), id)
+  }
+
+  /// Announces the local node to the seed nodes. Unanswered announcements are repeated by
+  /// [`ClusterMembership::tick`] until another member is known.
+  pub fn join(&self) {
+    for seed in self.seeds() {
+      self.gossip(&seed);
+    }
+  }
+
+  /// Marks the local node as leaving; the leader then moves it to exiting and removes it.
+  pub fn leave(&self) {
+    let local = Member::new(self.local().clone()).with_uid(self.config.uid());
+    self.transition(local.with_status(MemberStatus::Leaving));
+  }
+
+  /// Declares the latest incarnation of `node` dead; the leader then removes it.
+  pub fn down(&self, node: &NodeId) {
+    let member = self.member(node).unwrap_or_else(|| Member::new(node.clone()));
+    self.transition(member.with_status(MemberStatus::Down));
+  }
+
+  /// Applies the leader actions when the local node leads, then gossips the local table.
+  pub fn tick(&self) {
+    if self.is_leader() {
+      self.lead();
+    }
+    let peers = self.peers();
+    let targets = if peers.is_empty() { self.seeds() } else { self.next_peers(&peers) };
+    for node in &targets {
+      self.gossip(node);
+    }
+  }
+
+  /// Merges membership gossip into the local table, returning every other delivery.
+  pub fn handle_frame(&self, delivery: RemoteDelivery) -> Option<RemoteDelivery> {
+    let RemoteDelivery { target, frame } = delivery;
+    let RemotePayloadFrame::User { serialized } = &frame.payload else {
+      return Some(RemoteDelivery::new(target, frame));
+    };
+    if serialized.serializer_id != CLUSTER_SERIALIZER_ID || serialized.type_name.as_deref() != Some(GOSSIP_TYPE_NAME) {
+      return Some(RemoteDelivery::new(target, frame));
+    }
+    // Malformed gossip is dropped; the next round carries the same information.
+    if let Ok(remote) = MembershipTable::decode(&serialized.payload) {
+      let (changes, stale) = {
+        let mut table = self.table.write();
+        let changes = table.merge(&remote);
+        (changes, !table.is_covered_by(&remote))
+      };
+      self.publish(&changes);
+      if let Some(sender) = frame.reply_to.as_ref().and_then(Pid::node).filter(|_| stale) {
+        self.gossip(sender);
+      }
+    }
+    None
+  }
+
+  fn is_leader(&self) -> bool {
+    let local = self.local();
+    match self.table.read().leader() {
+      | Some(leader) => leader.node() == local && leader.uid() == self.config.uid(),
+      // Without any member up, the first seed node bootstraps the cluster.
+      | None => self.config.seed_nodes().first().is_none_or(|seed| seed == local),
+    }
+  }
+
+  fn lead(&self) {
+    let changes = {
+      let mut table = self.table.write();
+      let mut up_number = table.members().map(Member::up_number).max().unwrap_or(0);
+      let moves: Vec<Member> = table
+        .members()
+        .filter_map(|member| {
+          let replaced = table.incarnations(member.node()).any(|other| {
+            other.status() == MemberStatus::Joining
+              && (member.status() != MemberStatus::Joining || member.uid() < other.uid())
+          });
+          let next = match member.status() {
+            | status if replaced && status < MemberStatus::Down => MemberStatus::Down,
+            | MemberStatus::Joining => {
+              // The previous incarnation of the node must be gone before the new one is admitted.
+              let alone = table
+                .incarnations(member.node())
+                .all(|other| other.uid() == member.uid() || other.status() == MemberStatus::Removed);
+              if !alone {
+                return None;
+              }
+              up_number += 1;
+              return Some(member.clone().with_status(MemberStatus::Up).with_up_number(up_number));
+            },
+            | MemberStatus::Leaving => MemberStatus::Exiting,
+            | MemberStatus::Exiting | MemberStatus::Down => MemberStatus::Removed,
+            | MemberStatus::Up | MemberStatus::Removed => return None,
+          };
+          Some(member.clone().with_status(next))
+        })
+        .collect();
+      moves.into_iter().filter_map(|member| table.update(member)).collect::<Vec<_>>()
+    };
+    self.publish(&changes);
+  }
+
+  fn transition(&self, member: Member) {
+    let change = self.table.write().update(member);
+    if let Some(change) = change {
+      self.publish(core::slice::from_ref(&change));
+    }
+  }
+
+  fn publish(&self, changes: &[MembershipChange]) {
+    if changes.is_empty() {
+      return;
+    }
+    for change in changes {
+      let node = change.member().node();
+      // A newer incarnation of the node may already use the connection.
+      let replaced = self.member(node).is_some_and(|member| member.status() != MemberStatus::Removed);
+      if change.status() == MemberStatus::Removed && node != self.local() && !replaced {
+        self.outbound.endpoints().disconnect(node);
+      }
+    }
+    let listeners = self.listeners.read().snapshot();
+    for change in changes {
+      for listener in &listeners {
+        listener.notify(change);
+      }
+    }
+  }
+
+  fn seeds(&self) -> Vec<NodeId> {
+    self.config.seed_nodes().iter().filter(|seed| *seed != self.local()).cloned().collect()
+  }
+
+  fn peers(&self) -> Vec<NodeId> {
+    let local = self.local();
+    let mut peers = self
+      .table
+      .read()
+      .members()
+      .filter(|member| member.status().is_active() && member.node() != local)
+      .map(|member| member.node().clone())
+      .collect::<Vec<_>>();
+    // Incarnations of one node are adjacent in the table.
+    peers.dedup();
+    peers
+  }
+
+  fn next_peers(&self, peers: &[NodeId]) -> Vec<NodeId> {
+    let count = self.config.gossip_fanout().min(peers.len());
+    let mut cursor = self.cursor.write();
+    let start = *cursor % peers.len();
+    *cursor = start + count;
+    peers.iter().cycle().skip(start).take(count).cloned().collect()
+  }
+
+  fn gossip(&self, node: &NodeId) {
+    let Ok(payload) = self.table.read().encode() else {
+      return;
+    };
+    let serialized = SerializedMessage::new(CLUSTER_SERIALIZER_ID, payload).with_type_name(GOSSIP_TYPE_NAME);
+    let reply_to = self.membership_pid(self.local());
+    // Lost gossip is repeated by the next round.
+    let _ = self.outbound.send_control(
+      &self.membership_pid(node),
+      RemotePayloadFrame::User { serialized },
+      DEFAULT_PRIORITY,
+      Some(reply_to),
+    );
+  }
+
+  fn membership_pid(&self, node: &NodeId) -> Pid {
+    Pid::new(self.outbound.system().clone(), ActorPath::new())
+      .with_node(node.clone())
+      .with_tag(PidTag::new(MEMBERSHIP_TAG))
+  }
+}
+
+impl<T> ClusterMembership<T>
+where
+  T: RemoteTransport + 'static,
+  Self: SharedBound,
+{
+  /// Merges membership gossip arriving through `dispatcher`.
+  #[must_use]
+  pub fn attach<MF>(&self, dispatcher: RemoteInboundDispatcher<MF>) -> RemoteInboundDispatcher<MF>
+  where
+    MF: MailboxFactory, {
+    let membership = self.clone();
+    dispatcher
+      .with_interceptor(RemoteFrameInterceptor::new(move |delivery: RemoteDelivery| membership.handle_frame(delivery)))
+  }
+
+  /// Starts listening on the local node with the membership attached to inbound delivery.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the transport cannot listen on the local node.
+  pub fn start<MF>(&self, registry: ArcShared<RemoteProcessRegistry<MF>>) -> Result<(), TransportError>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    let dispatcher = self.attach(self.outbound.inbound_dispatcher(registry));
+    self.outbound.endpoints().start(dispatcher)
+  }
+}
@@ -0,0 +1,76 @@
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use super::MemberStatus;
+
+/// One incarnation of a node of the cluster as seen by the membership table.
+///
+/// A node restarted on the same address comes back as a new member, told apart from the previous
+/// incarnation by its uid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+  node:      NodeId,
+  uid:       u64,
+  status:    MemberStatus,
+  up_number: u64,
+}
+
+impl Member {
+  /// Creates a member for `node` with uid 0 that is still joining.
+  #[must_use]
+  pub const fn new(node: NodeId) -> Self {
+    Self { node, uid: 0, status: MemberStatus::Joining, up_number: 0 }
+  }
+
+  /// Sets the uid of the incarnation of the node.
+  #[must_use]
+  pub const fn with_uid(mut self, uid: u64) -> Self {
+    self.uid = uid;
+    self
+  }
+
+  /// Returns the node of the member.
+  #[must_use]
+  pub const fn node(&self) -> &NodeId {
+    &self.node
+  }
+
+  /// Returns the uid telling this incarnation of the node apart from the others.
+  #[must_use]
+  pub const fn uid(&self) -> u64 {
+    self.uid
+  }
+
+  /// Returns the lifecycle state of the member.
+  #[must_use]
+  pub const fn status(&self) -> MemberStatus {
+    self.status
+  }
+
+  /// Returns the order in which the leader admitted the member, starting at 1, or 0 while the
+  /// member has not been admitted yet. Lower numbers denote older members.
+  #[must_use]
+  pub const fn up_number(&self) -> u64 {
+    self.up_number
+  }
+
+  pub(crate) const fn with_status(mut self, status: MemberStatus) -> Self {
+    self.status = status;
+    self
+  }
+
+  pub(crate) const fn with_up_number(mut self, up_number: u64) -> Self {
+    self.up_number = up_number;
+    self
+  }
+
+  /// Combines two views of the same incarnation: the most advanced status wins, and the lowest
+  /// admission number wins should two leaders have admitted the member concurrently.
+  pub(crate) fn merge(&self, other: &Self) -> Self {
+    let up_number = match (self.up_number, other.up_number) {
+      | (0, theirs) => theirs,
+      | (ours, 0) => ours,
+      | (ours, theirs) => ours.min(theirs),
+    };
+    Self { node: self.node.clone(), uid: self.uid, status: self.status.max(other.status), up_number }
+  }
+}
@@ -0,0 +1,54 @@
+/// Lifecycle state of a cluster member.
+///
+/// A member only moves forward through the states in declaration order, so when two views of the
+/// same member disagree the later state wins:
+///
+/// `Joining` → `Up` → `Leaving` → `Exiting` → `Removed`, with `Down` reachable from any state
+/// before `Removed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MemberStatus {
+  /// The node asked to join and waits for the leader to admit it.
+  Joining,
+  /// The node is a full member of the cluster.
+  Up,
+  /// The node announced that it leaves the cluster.
+  Leaving,
+  /// The leader acknowledged the departure; the node hands over its work and shuts down.
+  Exiting,
+  /// The node was declared dead and will be removed without handing anything over.
+  Down,
+  /// The node is no longer part of the cluster. Removed members are kept as tombstones so that
+  /// stale gossip cannot bring them back.
+  Removed,
+}
+
+impl MemberStatus {
+  /// Returns `true` while the member takes part in gossip, i.e. until it exits or is downed.
+  #[must_use]
+  pub const fn is_active(self) -> bool {
+    matches!(self, Self::Joining | Self::Up | Self::Leaving)
+  }
+
+  pub(crate) const fn code(self) -> u8 {
+    match self {
+      | Self::Joining => 0,
+      | Self::Up => 1,
+      | Self::Leaving => 2,
+      | Self::Exiting => 3,
+      | Self::Down => 4,
+      | Self::Removed => 5,
+    }
+  }
+
+  pub(crate) const fn from_code(code: u8) -> Option<Self> {
+    match code {
+      | 0 => Some(Self::Joining),
+      | 1 => Some(Self::Up),
+      | 2 => Some(Self::Leaving),
+      | 3 => Some(Self::Exiting),
+      | 4 => Some(Self::Down),
+      | 5 => Some(Self::Removed),
+      | _ => None,
+    }
+  }
+}
@@ -0,0 +1,35 @@
+use super::{Member, MemberStatus};
+
+/// Transition of one member observed by the local membership table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MembershipChange {
+  member:   Member,
+  previous: Option<MemberStatus>,
+}
+
+impl MembershipChange {
+  /// Creates a change moving `member` out of `previous`, or adding it when `previous` is `None`.
+  #[must_use]
+  pub const fn new(member: Member, previous: Option<MemberStatus>) -> Self {
+    Self { member, previous }
+  }
+
+  /// Returns the member after the change.
+  #[must_use]
+  pub const fn member(&self) -> &Member {
+    &self.member
+  }
+
+  /// Returns the status of the member before the change, or `None` for a member seen for the
+  /// first time.
+  #[must_use]
+  pub const fn previous(&self) -> Option<MemberStatus> {
+    self.previous
+  }
+
+  /// Returns the status of the member after the change.
+  #[must_use]
+  pub const fn status(&self) -> MemberStatus {
+    self.member.status()
+  }
+}
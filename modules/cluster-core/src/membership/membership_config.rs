@@ -0,0 +1,71 @@
+use alloc::vec::Vec;
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+/// Settings of a [`ClusterMembership`](super::ClusterMembership).
+///
+/// Seed nodes are the contact points a node joins through. The first seed node bootstraps the
+/// cluster when it finds no member that is already up, so every node should list the same seed
+/// nodes in the same order.
+///
+/// The uid tells the local node apart from earlier incarnations on the same address: a node
+/// restarted with the uid it had before cannot rejoin once the cluster removed it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MembershipConfig {
+  seed_nodes:    Vec<NodeId>,
+  gossip_fanout: usize,
+  uid:           u64,
+}
+
+impl MembershipConfig {
+  /// Creates a configuration without seed nodes and with uid 0, gossiping to 3 members per round.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { seed_nodes: Vec::new(), gossip_fanout: 3, uid: 0 }
+  }
+
+  /// Adds `node` to the seed nodes.
+  #[must_use]
+  pub fn with_seed_node(mut self, node: NodeId) -> Self {
+    self.seed_nodes.push(node);
+    self
+  }
+
+  /// Sets how many members receive the membership table in each gossip round.
+  #[must_use]
+  pub const fn with_gossip_fanout(mut self, gossip_fanout: usize) -> Self {
+    self.gossip_fanout = gossip_fanout;
+    self
+  }
+
+  /// Sets the uid of the local incarnation, typically its start time or a random number.
+  #[must_use]
+  pub const fn with_uid(mut self, uid: u64) -> Self {
+    self.uid = uid;
+    self
+  }
+
+  /// Returns the seed nodes in the order they were added.
+  #[must_use]
+  pub fn seed_nodes(&self) -> &[NodeId] {
+    &self.seed_nodes
+  }
+
+  /// Returns how many members receive the membership table in each gossip round.
+  #[must_use]
+  pub const fn gossip_fanout(&self) -> usize {
+    self.gossip_fanout
+  }
+
+  /// Returns the uid of the local incarnation.
+  #[must_use]
+  pub const fn uid(&self) -> u64 {
+    self.uid
+  }
+}
+
+impl Default for MembershipConfig {
+  fn default() -> Self {
+    Self::new()
+  }
+}
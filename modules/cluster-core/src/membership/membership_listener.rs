@@ -0,0 +1,33 @@
+use cellex_utils_core_rs::sync::{shared::SharedBound, ArcShared};
+
+use super::MembershipChange;
+
+#[cfg(target_has_atomic = "ptr")]
+type MembershipListenerFn = dyn Fn(&MembershipChange) + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type MembershipListenerFn = dyn Fn(&MembershipChange);
+
+/// Callback receiving the membership changes applied by the local node.
+///
+/// Actors observe the membership by subscribing a listener that forwards each change to their
+/// reference.
+#[derive(Clone)]
+pub struct MembershipListener {
+  inner: ArcShared<MembershipListenerFn>,
+}
+
+impl MembershipListener {
+  /// Creates a listener from a closure.
+  #[must_use]
+  pub fn new<F>(f: F) -> Self
+  where
+    F: Fn(&MembershipChange) + SharedBound + 'static, {
+    Self { inner: ArcShared::new(f).into_dyn(|func| func as &MembershipListenerFn) }
+  }
+
+  /// Hands `change` to the listener.
+  pub fn notify(&self, change: &MembershipChange) {
+    (self.inner)(change);
+  }
+}
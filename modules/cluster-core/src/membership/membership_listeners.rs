@@ -0,0 +1,26 @@
+use alloc::vec::Vec;
+
+use super::MembershipListener;
+
+/// Listeners registered on a membership, keyed by subscription id.
+#[derive(Default)]
+pub(crate) struct MembershipListeners {
+  next_id: u64,
+  entries: Vec<(u64, MembershipListener)>,
+}
+
+impl MembershipListeners {
+  pub(crate) fn add(&mut self, listener: MembershipListener) -> u64 {
+    self.next_id += 1;
+    self.entries.push((self.next_id, listener));
+    self.next_id
+  }
+
+  pub(crate) fn remove(&mut self, id: u64) {
+    self.entries.retain(|(entry_id, _)| *entry_id != id);
+  }
+
+  pub(crate) fn snapshot(&self) -> Vec<MembershipListener> {
+    self.entries.iter().map(|(_, listener)| listener.clone()).collect()
+  }
+}
@@ -0,0 +1,23 @@
+use cellex_utils_core_rs::sync::ArcShared;
+use spin::RwLock;
+
+use super::membership_listeners::MembershipListeners;
+
+/// Subscription handle returned by
+/// [`ClusterMembership::subscribe`](super::ClusterMembership::subscribe). Unsubscribes on drop.
+pub struct MembershipSubscription {
+  listeners: ArcShared<RwLock<MembershipListeners>>,
+  id:        u64,
+}
+
+impl MembershipSubscription {
+  pub(crate) const fn new(listeners: ArcShared<RwLock<MembershipListeners>>, id: u64) -> Self {
+    Self { listeners, id }
+  }
+}
+
+impl Drop for MembershipSubscription {
+  fn drop(&mut self) {
+    self.listeners.write().remove(self.id);
+  }
+}
@@ -0,0 +1,157 @@
+use alloc::{
+  collections::BTreeMap,
+  string::{String, ToString},
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use cellex_remote_core_rs::codec::{RemoteCodecError, WireReader, WireWriter};
+
+use super::{Member, MemberStatus, MembershipChange};
+
+/// Versioned view of the cluster members, exchanged between nodes by gossip.
+///
+/// Merging two tables keeps the most advanced view of every member, so tables converge no matter
+/// in which order gossip arrives. The version grows with every change the local table applies.
+///
+/// Members are keyed by node and uid, so a node restarted on the same address joins as a new
+/// member next to the tombstone of its previous incarnation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MembershipTable {
+  members: BTreeMap<(String, u64), Member>,
+  version: u64,
+}
+
+impl MembershipTable {
+  /// Creates an empty table.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { members: BTreeMap::new(), version: 0 }
+  }
+
+  /// Returns the version of the table.
+  #[must_use]
+  pub const fn version(&self) -> u64 {
+    self.version
+  }
+
+  /// Returns the number of members, removed ones and earlier incarnations included.
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.members.len()
+  }
+
+  /// Returns `true` when the table knows no member.
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.members.is_empty()
+  }
+
+  /// Returns the latest incarnation of `node`, if known: the least advanced one, the one with the
+  /// highest uid on ties.
+  #[must_use]
+  pub fn get(&self, node: &NodeId) -> Option<&Member> {
+    self.incarnations(node).rev().min_by_key(|member| member.status())
+  }
+
+  /// Iterates over the incarnations of `node` in uid order.
+  #[must_use]
+  pub fn incarnations(&self, node: &NodeId) -> impl DoubleEndedIterator<Item = &Member> {
+    let node = node.to_string();
+    self.members.range((node.clone(), 0)..=(node, u64::MAX)).map(|(_, member)| member)
+  }
+
+  /// Iterates over all members, removed ones included, in node and uid order.
+  pub fn members(&self) -> impl Iterator<Item = &Member> {
+    self.members.values()
+  }
+
+  /// Returns the leader: the first member in node order that is up or leaving.
+  #[must_use]
+  pub fn leader(&self) -> Option<&Member> {
+    self.members.values().find(|member| matches!(member.status(), MemberStatus::Up | MemberStatus::Leaving))
+  }
+
+  /// Merges `member` into the table, returning the resulting change if the table moved.
+  pub fn update(&mut self, member: Member) -> Option<MembershipChange> {
+    let change = self.apply(member);
+    if change.is_some() {
+      self.version += 1;
+    }
+    change
+  }
+
+  /// Merges every member of `other` into the table, returning the changes in node order.
+  pub fn merge(&mut self, other: &Self) -> Vec<MembershipChange> {
+    let changes: Vec<MembershipChange> =
+      other.members.values().filter_map(|member| self.apply(member.clone())).collect();
+    if !changes.is_empty() {
+      self.version = self.version.max(other.version) + 1;
+    }
+    changes
+  }
+
+  /// Returns `true` when merging the table into `other` would not change `other`.
+  #[must_use]
+  pub fn is_covered_by(&self, other: &Self) -> bool {
+    self.members.iter().all(|(key, member)| other.members.get(key).is_some_and(|known| known.merge(member) == *known))
+  }
+
+  /// Encodes the table with the remote wire layout.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError`] when the table is too large to be encoded.
+  pub fn encode(&self) -> Result<Vec<u8>, RemoteCodecError> {
+    let mut writer = WireWriter::new();
+    writer.put_u64(self.version);
+    let count = u32::try_from(self.members.len()).map_err(|_| RemoteCodecError::FrameTooLarge(self.members.len()))?;
+    writer.put_u32(count);
+    for member in self.members.values() {
+      writer.put_node(member.node())?;
+      writer.put_u64(member.uid());
+      writer.put_u8(member.status().code());
+      writer.put_u64(member.up_number());
+    }
+    Ok(writer.into_bytes())
+  }
+
+  /// Decodes a table written by [`MembershipTable::encode`].
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError`] when `bytes` are not a well-formed table.
+  pub fn decode(bytes: &[u8]) -> Result<Self, RemoteCodecError> {
+    let mut reader = WireReader::new(bytes);
+    let version = reader.u64()?;
+    let count = reader.u32()?;
+    let mut members = BTreeMap::new();
+    for _ in 0..count {
+      let node = reader.node()?;
+      let uid = reader.u64()?;
+      let code = reader.u8()?;
+      let status = MemberStatus::from_code(code).ok_or(RemoteCodecError::UnknownTag(code))?;
+      let member = Member::new(node).with_uid(uid).with_status(status).with_up_number(reader.u64()?);
+      members.insert((member.node().to_string(), uid), member);
+    }
+    reader.finish()?;
+    Ok(Self { members, version })
+  }
+
+  fn apply(&mut self, member: Member) -> Option<MembershipChange> {
+    let key = (member.node().to_string(), member.uid());
+    match self.members.get(&key) {
+      | Some(known) => {
+        let merged = known.merge(&member);
+        if merged == *known {
+          return None;
+        }
+        let previous = known.status();
+        self.members.insert(key, merged.clone());
+        Some(MembershipChange::new(merged, Some(previous)))
+      },
+      | None => {
+        self.members.insert(key, member.clone());
+        Some(MembershipChange::new(member, None))
+      },
+    }
+  }
+}
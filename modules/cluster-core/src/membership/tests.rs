@@ -0,0 +1,241 @@
+extern crate std;
+
+use std::{
+  format,
+  string::{String, ToString},
+  sync::{Arc, Mutex},
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor::Props,
+  actor_runtime::GenericActorRuntime,
+  actor_system::{GenericActorSystem, GenericActorSystemConfig},
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  process::pid::NodeId,
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_remote_core_rs::{
+  endpoint::EndpointManager,
+  loopback::{LoopbackNetwork, LoopbackTransport},
+  outbound::RemoteOutbound,
+};
+use cellex_utils_core_rs::sync::ArcShared;
+
+use super::{
+  ClusterMembership, Member, MemberStatus, MembershipChange, MembershipConfig, MembershipListener, MembershipTable,
+};
+
+type TestResult<T = ()> = Result<T, String>;
+type TestSystem = GenericActorSystem<String, TokioActorRuntime>;
+type Changes = Arc<Mutex<Vec<String>>>;
+
+struct TestNode {
+  system:     TestSystem,
+  membership: ClusterMembership<LoopbackTransport>,
+}
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn seeded(seeds: &[u16]) -> MembershipConfig {
+  seeds.iter().fold(MembershipConfig::new(), |config, port| config.with_seed_node(node(*port)))
+}
+
+fn started(network: &LoopbackNetwork, port: u16, config: MembershipConfig) -> TestResult<TestNode> {
+  let system_config = GenericActorSystemConfig::default().with_node_id(node(port));
+  let system = GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), system_config);
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(network.transport(&node(port)), system_id, node(port)));
+  let outbound = system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      RemoteOutbound::new(endpoints, extension)
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())?;
+  let membership = ClusterMembership::new(outbound, config);
+  membership.start(system.process_registry()).map_err(|err| format!("start: {err}"))?;
+  Ok(TestNode { system, membership })
+}
+
+fn rounds(network: &LoopbackNetwork, nodes: &[&TestNode], count: usize) {
+  for _ in 0..count {
+    for test_node in nodes {
+      test_node.membership.tick();
+    }
+    network.flush();
+  }
+}
+
+fn statuses(membership: &ClusterMembership<LoopbackTransport>) -> Vec<(u16, MemberStatus)> {
+  membership.members().iter().map(|member| (member.node().port().unwrap_or(0), member.status())).collect()
+}
+
+fn describe(change: &MembershipChange) -> String {
+  format!("{} {:?}", change.member().node(), change.status())
+}
+
+fn recorder() -> (MembershipListener, Changes) {
+  let changes: Changes = Arc::new(Mutex::new(Vec::new()));
+  let recorded = changes.clone();
+  let listener = MembershipListener::new(move |change: &MembershipChange| {
+    recorded.lock().unwrap_or_else(|err| err.into_inner()).push(describe(change));
+  });
+  (listener, changes)
+}
+
+fn taken(changes: &Changes) -> Vec<String> {
+  core::mem::take(&mut *changes.lock().unwrap_or_else(|err| err.into_inner()))
+}
+
+#[test]
+fn merging_tables_keeps_the_most_advanced_view_of_each_member() -> TestResult {
+  let mut local = MembershipTable::new();
+  let _ = local.update(Member::new(node(2551)).with_status(MemberStatus::Up).with_up_number(1));
+  let _ = local.update(Member::new(node(2552)));
+  let mut remote = MembershipTable::new();
+  let _ = remote.update(Member::new(node(2552)).with_status(MemberStatus::Up).with_up_number(2));
+  let _ = remote.update(Member::new(node(2553)));
+  let _ = remote.update(Member::new(node(2551)));
+
+  let decoded = MembershipTable::decode(&remote.encode().map_err(|err| format!("encode: {err}"))?)
+    .map_err(|err| format!("decode: {err}"))?;
+  assert_eq!(decoded, remote);
+
+  let changes = local.merge(&decoded);
+  let described: Vec<String> = changes.iter().map(describe).collect();
+  assert_eq!(described, vec!["127.0.0.1:2552 Up".to_string(), "127.0.0.1:2553 Joining".to_string()]);
+  assert_eq!(changes[0].previous(), Some(MemberStatus::Joining));
+  assert_eq!(local.get(&node(2551)).map(Member::status), Some(MemberStatus::Up));
+  assert_eq!(local.get(&node(2552)).map(Member::up_number), Some(2));
+  assert_eq!(local.version(), 4);
+  assert!(remote.is_covered_by(&local));
+  assert!(!local.is_covered_by(&remote));
+  assert!(local.merge(&remote).is_empty());
+  Ok(())
+}
+
+#[test]
+fn first_seed_node_bootstraps_the_cluster_and_admits_joining_nodes() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let seed = started(&network, 2551, seeded(&[2551]))?;
+  let second = started(&network, 2552, seeded(&[2551]))?;
+  let third = started(&network, 2553, seeded(&[2551]))?;
+
+  rounds(&network, &[&seed], 1);
+  assert_eq!(statuses(&seed.membership), vec![(2551, MemberStatus::Up)]);
+  assert_eq!(statuses(&second.membership), vec![(2552, MemberStatus::Joining)]);
+
+  second.membership.join();
+  third.membership.join();
+  network.flush();
+  rounds(&network, &[&seed, &second, &third], 3);
+
+  let expected = vec![(2551, MemberStatus::Up), (2552, MemberStatus::Up), (2553, MemberStatus::Up)];
+  for test_node in [&seed, &second, &third] {
+    assert_eq!(statuses(&test_node.membership), expected);
+    assert_eq!(test_node.membership.leader(), Some(node(2551)));
+  }
+  let up_numbers: Vec<u64> = third.membership.members().iter().map(Member::up_number).collect();
+  assert_eq!(up_numbers[0], 1);
+  assert!(up_numbers[1] > 1 && up_numbers[2] > 1 && up_numbers[1] != up_numbers[2]);
+  Ok(())
+}
+
+#[test]
+fn leaving_members_exit_and_are_removed_while_subscribers_observe_each_step() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let mut seed = started(&network, 2551, seeded(&[2551]))?;
+  let leaver = started(&network, 2552, seeded(&[2551]))?;
+  leaver.membership.join();
+  rounds(&network, &[&seed, &leaver], 3);
+
+  let observed: Changes = Arc::new(Mutex::new(Vec::new()));
+  let recorded = observed.clone();
+  let observer = seed
+    .system
+    .root_context()
+    .spawn(Props::new(move |_, change: String| {
+      recorded.lock().unwrap_or_else(|err| err.into_inner()).push(change);
+      Ok(())
+    }))
+    .map_err(|err| format!("spawn: {err:?}"))?;
+  let _subscription = seed.membership.subscribe(MembershipListener::new(move |change: &MembershipChange| {
+    let _ = observer.tell(describe(change));
+  }));
+
+  leaver.membership.leave();
+  rounds(&network, &[&seed, &leaver], 3);
+  seed.system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+
+  assert_eq!(taken(&observed), vec![
+    "127.0.0.1:2552 Leaving".to_string(),
+    "127.0.0.1:2552 Exiting".to_string(),
+    "127.0.0.1:2552 Removed".to_string(),
+  ]);
+  assert_eq!(statuses(&seed.membership), vec![(2551, MemberStatus::Up)]);
+  assert_eq!(seed.membership.member(&node(2552)).map(|member| member.status()), Some(MemberStatus::Removed));
+  Ok(())
+}
+
+#[test]
+fn downed_members_are_removed_and_dropped_subscriptions_stop_receiving() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let seed = started(&network, 2551, seeded(&[2551]))?;
+  let other = started(&network, 2552, seeded(&[2551]))?;
+  other.membership.join();
+  rounds(&network, &[&seed, &other], 3);
+
+  let (listener, changes) = recorder();
+  let subscription = seed.membership.subscribe(listener);
+  seed.membership.down(&node(2552));
+  assert_eq!(taken(&changes), vec!["127.0.0.1:2552 Down".to_string()]);
+
+  drop(subscription);
+  rounds(&network, &[&seed], 1);
+  assert!(taken(&changes).is_empty());
+  assert_eq!(seed.membership.member(&node(2552)).map(|member| member.status()), Some(MemberStatus::Removed));
+  assert_eq!(statuses(&seed.membership), vec![(2551, MemberStatus::Up)]);
+  Ok(())
+}
+
+#[test]
+fn restarted_nodes_rejoin_as_a_new_incarnation() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let seed = started(&network, 2551, seeded(&[2551]))?;
+  let first = started(&network, 2552, seeded(&[2551]).with_uid(1))?;
+  first.membership.join();
+  rounds(&network, &[&seed, &first], 3);
+  first.membership.leave();
+  rounds(&network, &[&seed, &first], 3);
+  assert_eq!(statuses(&seed.membership), vec![(2551, MemberStatus::Up)]);
+
+  network.shutdown(&node(2552));
+  let second = started(&network, 2552, seeded(&[2551]).with_uid(2))?;
+  second.membership.join();
+  rounds(&network, &[&seed, &second], 3);
+  assert_eq!(statuses(&seed.membership), vec![(2551, MemberStatus::Up), (2552, MemberStatus::Up)]);
+  assert_eq!(seed.membership.member(&node(2552)).map(|member| member.uid()), Some(2));
+
+  let (listener, changes) = recorder();
+  let _subscription = seed.membership.subscribe(listener);
+  network.shutdown(&node(2552));
+  // The seed drops its connection to the crashed process once its gossip fails.
+  rounds(&network, &[&seed], 1);
+  let third = started(&network, 2552, seeded(&[2551]).with_uid(3))?;
+  third.membership.join();
+  network.flush();
+  rounds(&network, &[&seed, &third], 4);
+
+  assert_eq!(taken(&changes), vec![
+    "127.0.0.1:2552 Joining".to_string(),
+    "127.0.0.1:2552 Down".to_string(),
+    "127.0.0.1:2552 Removed".to_string(),
+    "127.0.0.1:2552 Up".to_string(),
+  ]);
+  assert_eq!(statuses(&third.membership), vec![(2551, MemberStatus::Up), (2552, MemberStatus::Up)]);
+  assert_eq!(third.membership.member(&node(2552)).map(|member| member.uid()), Some(3));
+  assert_eq!(seed.membership.table().incarnations(&node(2552)).count(), 3);
+  Ok(())
+}
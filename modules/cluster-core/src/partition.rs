@@ -0,0 +1,12 @@
+mod consistent_hash_ring;
+mod partition_strategy;
+mod rendezvous_hashing;
+mod stable_hash;
+
+#[cfg(test)]
+mod tests;
+
+pub use consistent_hash_ring::ConsistentHashRing;
+pub use partition_strategy::PartitionStrategy;
+pub use rendezvous_hashing::RendezvousHashing;
+pub(crate) use stable_hash::stable_hash;
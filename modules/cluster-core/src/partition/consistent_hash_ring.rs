@@ -0,0 +1,92 @@
+use alloc::{string::ToString, vec::Vec};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use spin::RwLock;
+
+use super::{stable_hash::stable_hash, PartitionStrategy};
+
+const DEFAULT_VIRTUAL_NODES: usize = 128;
+
+// Members the ring was built for, and its points as (hash, member index) in ring order.
+type Ring = (Vec<NodeId>, Vec<(u64, usize)>);
+
+/// Consistent-hash ring with virtual nodes.
+///
+/// Each member is hashed onto the ring at several points, its virtual nodes, and an identity is
+/// owned by the member of the first point at or after the hash of the identity. A member that
+/// comes or goes only takes over or gives away the arcs next to its own points; more virtual
+/// nodes spread those arcs, and so the load, more evenly across members.
+///
+/// The ring is rebuilt only when it is asked with other members than the previous time.
+pub struct ConsistentHashRing {
+  virtual_nodes: usize,
+  ring:          RwLock<Ring>,
+}
+
+impl ConsistentHashRing {
+  /// Creates a ring placing 128 virtual nodes per member.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { virtual_nodes: DEFAULT_VIRTUAL_NODES, ring: RwLock::new((Vec::new(), Vec::new())) }
+  }
+
+  /// Sets the number of virtual nodes per member, at least one.
+  #[must_use]
+  pub fn with_virtual_nodes(mut self, virtual_nodes: usize) -> Self {
+    self.virtual_nodes = virtual_nodes.max(1);
+    self.ring = RwLock::new((Vec::new(), Vec::new()));
+    self
+  }
+
+  /// Returns the number of virtual nodes per member.
+  #[must_use]
+  pub const fn virtual_nodes(&self) -> usize {
+    self.virtual_nodes
+  }
+
+  fn points(&self, members: &[NodeId]) -> Vec<(u64, usize)> {
+    let mut points = Vec::with_capacity(members.len() * self.virtual_nodes);
+    for (index, node) in members.iter().enumerate() {
+      let name = node.to_string();
+      for virtual_node in 0..self.virtual_nodes {
+        points.push((stable_hash(&[name.as_bytes(), &(virtual_node as u64).to_be_bytes()]), index));
+      }
+    }
+    // Colliding points are ordered by node so that the ring does not depend on member order.
+    points.sort_unstable_by(|left, right| {
+      left.0.cmp(&right.0).then_with(|| members[left.1].to_string().cmp(&members[right.1].to_string()))
+    });
+    points
+  }
+
+  fn lookup<'a>(points: &[(u64, usize)], hash: u64, members: &'a [NodeId]) -> Option<&'a NodeId> {
+    let position = points.partition_point(|(point, _)| *point < hash);
+    let (_, index) = points.get(position).or_else(|| points.first())?;
+    members.get(*index)
+  }
+}
+
+impl Default for ConsistentHashRing {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl PartitionStrategy for ConsistentHashRing {
+  fn owner<'a>(&self, identity: &str, members: &'a [NodeId]) -> Option<&'a NodeId> {
+    if members.is_empty() {
+      return None;
+    }
+    let hash = stable_hash(&[identity.as_bytes()]);
+    {
+      let ring = self.ring.read();
+      if ring.0 == members && !ring.1.is_empty() {
+        return Self::lookup(&ring.1, hash, members);
+      }
+    }
+    let points = self.points(members);
+    let owner = Self::lookup(&points, hash, members);
+    *self.ring.write() = (members.to_vec(), points);
+    owner
+  }
+}
@@ -0,0 +1,13 @@
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use cellex_utils_core_rs::sync::shared::SharedBound;
+
+/// Placement of identities on the members of a cluster.
+///
+/// Implementations must be deterministic: every node asking with the same identity and the same
+/// members gets the same owner, whatever the order of `members`. They should also move as few
+/// identities as possible when a member comes or goes, since every moved identity is handed off
+/// to its new owner.
+pub trait PartitionStrategy: SharedBound + 'static {
+  /// Returns the member of `members` owning `identity`, or `None` when `members` is empty.
+  fn owner<'a>(&self, identity: &str, members: &'a [NodeId]) -> Option<&'a NodeId>;
+}
@@ -0,0 +1,37 @@
+use alloc::string::ToString;
+use core::cmp::Reverse;
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use super::{stable_hash::stable_hash, PartitionStrategy};
+
+/// Rendezvous (highest random weight) hashing.
+///
+/// Every member gets a score from the hash of its node and the identity, and the highest score
+/// owns the identity. A member that goes only takes its own identities with it, and a member that
+/// comes only takes the identities it now scores highest on. Lookups cost one hash per member,
+/// without any state to keep.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RendezvousHashing;
+
+impl RendezvousHashing {
+  /// Creates the strategy.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self
+  }
+}
+
+impl PartitionStrategy for RendezvousHashing {
+  fn owner<'a>(&self, identity: &str, members: &'a [NodeId]) -> Option<&'a NodeId> {
+    // Ties are practically impossible, but still fall to the lowest node on every node.
+    members
+      .iter()
+      .map(|node| {
+        let name = node.to_string();
+        (stable_hash(&[name.as_bytes(), identity.as_bytes()]), Reverse(name), node)
+      })
+      .max_by(|left, right| (left.0, &left.1).cmp(&(right.0, &right.1)))
+      .map(|(_, _, node)| node)
+  }
+}
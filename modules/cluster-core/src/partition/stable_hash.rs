@@ -0,0 +1,16 @@
+/// Hashes `parts` with FNV-1a followed by a 64-bit finalizer.
+///
+/// The result depends only on the bytes, so it is identical on every node and platform. The
+/// finalizer spreads inputs that differ in a few trailing bytes, such as the virtual nodes of one
+/// member, over the whole ring.
+pub(crate) fn stable_hash(parts: &[&[u8]]) -> u64 {
+  let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+  for part in parts {
+    for byte in part.iter().chain(&[0xff]) {
+      hash = (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+    }
+  }
+  hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+  hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+  hash ^ (hash >> 31)
+}
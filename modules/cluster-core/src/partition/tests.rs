@@ -0,0 +1,80 @@
+extern crate std;
+
+use std::{format, string::String, vec::Vec};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use super::{ConsistentHashRing, PartitionStrategy, RendezvousHashing};
+
+const IDENTITIES: usize = 3000;
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn nodes(ports: &[u16]) -> Vec<NodeId> {
+  ports.iter().map(|port| node(*port)).collect()
+}
+
+fn identities() -> Vec<String> {
+  (0..IDENTITIES).map(|index| format!("counter/account-{index}")).collect()
+}
+
+fn placement(strategy: &impl PartitionStrategy, members: &[NodeId]) -> Vec<Option<NodeId>> {
+  identities().iter().map(|identity| strategy.owner(identity, members).cloned()).collect()
+}
+
+fn assert_deterministic_and_spread(first: &impl PartitionStrategy, second: &impl PartitionStrategy) {
+  let members = nodes(&[2551, 2552, 2553]);
+  let reversed = nodes(&[2553, 2552, 2551]);
+  let placed = placement(first, &members);
+  assert_eq!(placed, placement(second, &reversed));
+  assert_eq!(placed, placement(first, &members));
+  for member in &members {
+    let share = placed.iter().filter(|owner| owner.as_ref() == Some(member)).count();
+    assert!(share > IDENTITIES / 5, "{member} owns only {share} identities");
+  }
+  assert_eq!(first.owner("counter/account-1", &[]), None);
+}
+
+fn assert_minimal_hand_off(strategy: &impl PartitionStrategy) {
+  let before = placement(strategy, &nodes(&[2551, 2552, 2553]));
+  let grown = placement(strategy, &nodes(&[2551, 2552, 2553, 2554]));
+  let mut moved = 0;
+  for (old, new) in before.iter().zip(&grown) {
+    if old != new {
+      assert_eq!(new.as_ref(), Some(&node(2554)));
+      moved += 1;
+    }
+  }
+  assert!(moved > IDENTITIES / 8 && moved < IDENTITIES / 2, "{moved} identities moved to the new member");
+
+  let shrunk = placement(strategy, &nodes(&[2551, 2553]));
+  for (old, new) in before.iter().zip(&shrunk) {
+    if old != new {
+      assert_eq!(old.as_ref(), Some(&node(2552)));
+    }
+  }
+}
+
+#[test]
+fn consistent_hash_ring_places_identities_deterministically_and_evenly() {
+  assert_deterministic_and_spread(&ConsistentHashRing::new(), &ConsistentHashRing::default());
+  assert_eq!(ConsistentHashRing::new().with_virtual_nodes(0).virtual_nodes(), 1);
+}
+
+#[test]
+fn consistent_hash_ring_only_moves_identities_of_the_changed_member() {
+  assert_minimal_hand_off(&ConsistentHashRing::new());
+  assert_minimal_hand_off(&ConsistentHashRing::new().with_virtual_nodes(16));
+}
+
+#[test]
+fn rendezvous_hashing_places_identities_deterministically_and_evenly() {
+  assert_deterministic_and_spread(&RendezvousHashing::new(), &RendezvousHashing);
+}
+
+#[test]
+fn rendezvous_hashing_only_moves_identities_of_the_changed_member() {
+  assert_minimal_hand_off(&RendezvousHashing::new());
+}
@@ -0,0 +1,15 @@
+mod distributed_pub_sub;
+mod pub_sub_error;
+mod pub_sub_gossip;
+mod pub_sub_state;
+mod subscriber;
+mod subscriber_bucket;
+mod topic_delivery;
+
+#[cfg(test)]
+mod tests;
+
+pub use distributed_pub_sub::DistributedPubSub;
+pub use pub_sub_error::PubSubError;
+pub use subscriber::Subscriber;
+pub use topic_delivery::TopicDelivery;
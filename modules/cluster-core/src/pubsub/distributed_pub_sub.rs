@@ -0,0 +1,509 @@
+use alloc::{
+  collections::BTreeMap,
+  format,
+  string::{String, ToString},
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::{actor_ref::ActorRef, ActorPath},
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::MetadataStorageMode,
+    process::{
+      pid::{NodeId, Pid, PidTag},
+      process_registry::ProcessResolution,
+    },
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::{AnyMessage, MessageEnvelope},
+  },
+};
+use cellex_remote_core_rs::{
+  codec::{RemoteDelivery, RemotePayloadFrame},
+  delivery::{RemoteFrameInterceptor, RemoteInboundDispatcher, RemoteMessage, RemoteProcessRegistry},
+  outbound::RemoteOutbound,
+  transport::{RemoteTransport, TransportError},
+};
+use cellex_serialization_core_rs::message::SerializedMessage;
+use cellex_utils_core_rs::{
+  collections::queue::priority::DEFAULT_PRIORITY,
+  sync::{shared::SharedBound, ArcShared},
+};
+use spin::RwLock;
+
+use super::{
+  pub_sub_gossip::PubSubGossip, pub_sub_state::PubSubState, subscriber_bucket::SubscriberBucket, PubSubError,
+  Subscriber, TopicDelivery,
+};
+use crate::{
+  membership::ClusterMembership,
+  virtual_actor::{PendingDelivery, RouteFn},
+  CLUSTER_SERIALIZER_ID,
+};
+
+const PUB_SUB_TAG: &str = "pubsub";
+const TOPIC_TAG_PREFIX: &str = "topic:";
+const GOSSIP_TYPE_NAME: &str = "cellex.cluster.PubSubGossip";
+
+// Tells whether the pid still resolves to a local process.
+#[cfg(target_has_atomic = "ptr")]
+type AliveFn = dyn Fn(&Pid) -> bool + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type AliveFn = dyn Fn(&Pid) -> bool;
+
+/// Topic-based publish/subscribe spanning the members of a cluster.
+///
+/// Actors subscribe to a topic on their own node, optionally as a member of a group. Each node
+/// keeps its subscribers in a versioned bucket, and every [`DistributedPubSub::tick`] gossips the
+/// bucket versions to a few other members, which answer with the buckets the sender lacks, so
+/// every member learns which nodes subscribe to which topics.
+///
+/// Publishing with [`TopicDelivery::All`] sends the message once to every subscribing node, which
+/// hands it to its local subscribers; [`TopicDelivery::OnePerGroup`] picks one subscriber of each
+/// group in turn. Subscribers whose actor stopped are removed by their node, and the buckets of
+/// members that exited or were downed are dropped.
+pub struct DistributedPubSub<T>
+where
+  T: RemoteTransport, {
+  membership: ClusterMembership<T>,
+  state:      ArcShared<RwLock<PubSubState>>,
+  route:      ArcShared<RwLock<Option<ArcShared<RouteFn>>>>,
+  alive:      ArcShared<RwLock<Option<ArcShared<AliveFn>>>>,
+  cursor:     ArcShared<RwLock<usize>>,
+}
+
+impl<T> Clone for DistributedPubSub<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self {
+      membership: self.membership.clone(),
+      state:      self.state.clone(),
+      route:      self.route.clone(),
+      alive:      self.alive.clone(),
+      cursor:     self.cursor.clone(),
+    }
+  }
+}
+
+impl<T> DistributedPubSub<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates publish/subscribe over the members of `membership`.
+  #[must_use]
+  pub fn new(membership: ClusterMembership<T>) -> Self {
+    let local = membership.local().clone();
+    let mut state = PubSubState::default();
+    state.buckets.insert(local.to_string(), SubscriberBucket::new(local));
+    Self {
+      membership,
+      state: ArcShared::new(RwLock::new(state)),
+      route: ArcShared::new(RwLock::new(None)),
+      alive: ArcShared::new(RwLock::new(None)),
+      cursor: ArcShared::new(RwLock::new(0)),
+    }
+  }
+
+  /// Returns the membership the topics span.
+  #[must_use]
+  pub const fn membership(&self) -> &ClusterMembership<T> {
+    &self.membership
+  }
+
+  /// Returns the outbound side used to reach other members.
+  #[must_use]
+  pub const fn outbound(&self) -> &RemoteOutbound<T> {
+    self.membership.outbound()
+  }
+
+  /// Returns every subscriber of `topic` known to the local node.
+  #[must_use]
+  pub fn subscribers(&self, topic: &str) -> Vec<Subscriber> {
+    self
+      .state
+      .read()
+      .buckets
+      .values()
+      .filter(|bucket| self.is_live(&bucket.node))
+      .flat_map(|bucket| bucket.subscribers(topic).iter().cloned())
+      .collect()
+  }
+
+  /// Publishes `message` on `topic`. Subscribers must accept messages of type `U`.
+  ///
+  /// # Errors
+  /// Returns [`PubSubError`] when publish/subscribe is not started on the local node, or the
+  /// message cannot be sent to a subscribing node. The other subscribers still receive it.
+  pub fn publish<U>(&self, topic: &str, message: &U, delivery: TopicDelivery) -> Result<(), PubSubError>
+  where
+    U: RemoteMessage + Clone, {
+    let route = self.route.read().clone().ok_or(PubSubError::NotStarted)?;
+    let local = self.membership.local().clone();
+    let mut result = Ok(());
+    for (node, pid) in self.targets(topic, delivery) {
+      let sent = match pid {
+        | Some(pid) if node == local => {
+          let envelope =
+            PriorityEnvelope::new(AnyMessage::new(MessageEnvelope::user(message.clone())), DEFAULT_PRIORITY);
+          self.deliver_local(&route, topic, &pid, PendingDelivery::Local(envelope));
+          Ok(())
+        },
+        | Some(pid) => self.outbound().send_user(&pid, message, DEFAULT_PRIORITY, None),
+        | None => self.outbound().send_user(&self.topic_pid(&node, topic), message, DEFAULT_PRIORITY, None),
+      };
+      if let Err(err) = sent {
+        result = Err(PubSubError::from(err));
+      }
+    }
+    result
+  }
+
+  /// Removes the subscribers whose actor stopped, drops the buckets of members that exited or
+  /// were downed, and gossips the bucket versions to the next members.
+  pub fn tick(&self) {
+    if let Some(alive) = self.alive.read().clone() {
+      let local = self.membership.local().to_string();
+      let mut state = self.state.write();
+      if let Some(bucket) = state.buckets.get_mut(&local) {
+        let topics: Vec<String> = bucket.topics.keys().cloned().collect();
+        for topic in topics {
+          bucket.update(&topic, |subscribers| subscribers.retain(|subscriber| alive(subscriber.pid())));
+        }
+      }
+    }
+    self.state.write().buckets.retain(|_, bucket| self.is_live(&bucket.node));
+    let peers = self.peers();
+    if peers.is_empty() {
+      return;
+    }
+    let count = self.membership.config().gossip_fanout().min(peers.len());
+    let targets: Vec<NodeId> = {
+      let mut cursor = self.cursor.write();
+      let start = *cursor % peers.len();
+      *cursor = start + count;
+      peers.iter().cycle().skip(start).take(count).cloned().collect()
+    };
+    let status = self.status();
+    for node in &targets {
+      self.gossip(node, &status);
+    }
+  }
+
+  /// Handles subscriber gossip and messages published on local topics, returning every other
+  /// delivery.
+  #[must_use]
+  pub fn handle_frame(&self, delivery: RemoteDelivery) -> Option<RemoteDelivery> {
+    let RemoteDelivery { target, frame } = delivery;
+    let Some(tag) = target.tag().map(|tag| tag.0.clone()) else {
+      return Some(RemoteDelivery::new(target, frame));
+    };
+    if let Some(topic) = tag.strip_prefix(TOPIC_TAG_PREFIX) {
+      // Without a route the message is dropped, as for any unknown target.
+      if let Some(route) = self.route.read().clone() {
+        let local = self.membership.local().to_string();
+        let pids: Vec<Pid> = self.state.read().buckets.get(&local).map_or_else(Vec::new, |bucket| {
+          bucket.subscribers(topic).iter().map(|subscriber| subscriber.pid().clone()).collect()
+        });
+        for pid in pids {
+          self.deliver_local(&route, topic, &pid, PendingDelivery::Remote(frame.clone()));
+        }
+      }
+      return None;
+    }
+    if tag != PUB_SUB_TAG {
+      return Some(RemoteDelivery::new(target, frame));
+    }
+    let RemotePayloadFrame::User { serialized } = &frame.payload else {
+      return Some(RemoteDelivery::new(target, frame));
+    };
+    if serialized.serializer_id != CLUSTER_SERIALIZER_ID || serialized.type_name.as_deref() != Some(GOSSIP_TYPE_NAME) {
+      return Some(RemoteDelivery::new(target, frame));
+    }
+    // Malformed gossip is dropped; the next round repeats it.
+    if let (Ok(gossip), Some(sender)) =
+      (PubSubGossip::decode(&serialized.payload), frame.reply_to.as_ref().and_then(Pid::node))
+    {
+      self.handle_gossip(gossip, sender);
+    }
+    None
+  }
+
+  fn handle_gossip(&self, gossip: PubSubGossip, sender: &NodeId) {
+    match gossip {
+      | PubSubGossip::Status(versions) => {
+        let theirs: BTreeMap<String, u64> =
+          versions.iter().map(|(node, version)| (node.to_string(), *version)).collect();
+        let (delta, behind) = {
+          let state = self.state.read();
+          let delta: Vec<SubscriberBucket> = state
+            .buckets
+            .iter()
+            .filter(|(key, bucket)| theirs.get(*key).is_none_or(|version| bucket.version > *version))
+            .map(|(_, bucket)| bucket.clone())
+            .collect();
+          let behind = versions.iter().any(|(node, version)| {
+            self.is_live(node) && state.buckets.get(&node.to_string()).is_none_or(|bucket| bucket.version < *version)
+          });
+          (delta, behind)
+        };
+        if !delta.is_empty() {
+          self.gossip(sender, &PubSubGossip::Delta(delta));
+        }
+        if behind {
+          self.gossip(sender, &self.status());
+        }
+      },
+      | PubSubGossip::Delta(buckets) => {
+        let local = self.membership.local();
+        let mut state = self.state.write();
+        for bucket in buckets {
+          if bucket.node == *local || !self.is_live(&bucket.node) {
+            continue;
+          }
+          let key = bucket.node.to_string();
+          if state.buckets.get(&key).is_none_or(|known| known.version < bucket.version) {
+            state.buckets.insert(key, bucket);
+          }
+        }
+      },
+    }
+  }
+
+  /// Returns the nodes to send a message published on `topic` to, with the subscriber to hand it
+  /// to, or `None` for every local subscriber of the node.
+  fn targets(&self, topic: &str, delivery: TopicDelivery) -> Vec<(NodeId, Option<Pid>)> {
+    let local = self.membership.local();
+    let mut state = self.state.write();
+    let live = state.buckets.values().filter(|bucket| self.is_live(&bucket.node));
+    match delivery {
+      | TopicDelivery::All => live
+        .flat_map(|bucket| {
+          let subscribers = bucket.subscribers(topic);
+          if bucket.node == *local {
+            subscribers.iter().map(|subscriber| (bucket.node.clone(), Some(subscriber.pid().clone()))).collect()
+          } else if subscribers.is_empty() {
+            Vec::new()
+          } else {
+            alloc::vec![(bucket.node.clone(), None)]
+          }
+        })
+        .collect(),
+      | TopicDelivery::OnePerGroup => {
+        let mut groups: BTreeMap<String, Vec<Subscriber>> = BTreeMap::new();
+        for subscriber in live.flat_map(|bucket| bucket.subscribers(topic).iter()) {
+          if let Some(group) = subscriber.group() {
+            groups.entry(group.to_string()).or_default().push(subscriber.clone());
+          }
+        }
+        groups
+          .into_iter()
+          .map(|(group, members)| {
+            let turn = state.turns.entry(format!("{topic}/{group}")).or_default();
+            let chosen = members[*turn % members.len()].pid().clone();
+            *turn = turn.wrapping_add(1);
+            (chosen.node().cloned().unwrap_or_else(|| local.clone()), Some(chosen))
+          })
+          .collect()
+      },
+    }
+  }
+
+  /// Hands `delivery` to the local subscriber `pid`, removing it when its actor stopped.
+  fn deliver_local(&self, route: &ArcShared<RouteFn>, topic: &str, pid: &Pid, delivery: PendingDelivery) {
+    if route(pid, delivery).is_some() {
+      self.update_local(topic, |subscribers| subscribers.retain(|subscriber| subscriber.pid() != pid));
+    }
+  }
+
+  fn update_local(&self, topic: &str, change: impl FnOnce(&mut Vec<Subscriber>)) {
+    let local = self.membership.local().to_string();
+    if let Some(bucket) = self.state.write().buckets.get_mut(&local) {
+      bucket.update(topic, change);
+    }
+  }
+
+  /// Returns `true` for the local node and for members that neither exited nor were downed.
+  fn is_live(&self, node: &NodeId) -> bool {
+    node == self.membership.local() || self.membership.member(node).is_some_and(|member| member.status().is_active())
+  }
+
+  fn peers(&self) -> Vec<NodeId> {
+    let local = self.membership.local();
+    self
+      .membership
+      .members()
+      .into_iter()
+      .filter(|member| member.status().is_active() && member.node() != local)
+      .map(|member| member.node().clone())
+      .collect()
+  }
+
+  fn status(&self) -> PubSubGossip {
+    PubSubGossip::Status(
+      self.state.read().buckets.values().map(|bucket| (bucket.node.clone(), bucket.version)).collect(),
+    )
+  }
+
+  fn gossip(&self, node: &NodeId, gossip: &PubSubGossip) {
+    let Ok(payload) = gossip.encode() else {
+      return;
+    };
+    let serialized = SerializedMessage::new(CLUSTER_SERIALIZER_ID, payload).with_type_name(GOSSIP_TYPE_NAME);
+    // Lost gossip is repeated by the next round.
+    let _ = self.outbound().send_control(
+      &self.pub_sub_pid(node),
+      RemotePayloadFrame::User { serialized },
+      DEFAULT_PRIORITY,
+      Some(self.pub_sub_pid(self.membership.local())),
+    );
+  }
+
+  fn pub_sub_pid(&self, node: &NodeId) -> Pid {
+    Pid::new(self.outbound().system().clone(), ActorPath::new())
+      .with_node(node.clone())
+      .with_tag(PidTag::new(PUB_SUB_TAG))
+  }
+
+  fn topic_pid(&self, node: &NodeId, topic: &str) -> Pid {
+    Pid::new(self.outbound().system().clone(), ActorPath::new())
+      .with_node(node.clone())
+      .with_tag(PidTag::new(format!("{TOPIC_TAG_PREFIX}{topic}")))
+  }
+}
+
+impl<T> DistributedPubSub<T>
+where
+  T: RemoteTransport + 'static,
+{
+  /// Subscribes `actor` to `topic`, so that it receives every message published with
+  /// [`TopicDelivery::All`].
+  ///
+  /// # Errors
+  /// Returns [`PubSubError::MissingPid`] when the actor has no PID yet.
+  pub fn subscribe<M, AR>(&self, topic: &str, actor: &ActorRef<M, AR>) -> Result<(), PubSubError>
+  where
+    M: RemoteMessage,
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+    self.add_subscriber(topic, None, actor)
+  }
+
+  /// Subscribes `actor` to `topic` as a member of `group`, so that it also shares the messages
+  /// published with [`TopicDelivery::OnePerGroup`] with the other members of the group.
+  ///
+  /// # Errors
+  /// Returns [`PubSubError::MissingPid`] when the actor has no PID yet.
+  pub fn subscribe_to_group<M, AR>(
+    &self,
+    topic: &str,
+    group: &str,
+    actor: &ActorRef<M, AR>,
+  ) -> Result<(), PubSubError>
+  where
+    M: RemoteMessage,
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+    self.add_subscriber(topic, Some(group.into()), actor)
+  }
+
+  /// Removes every subscription of `actor` to `topic`.
+  ///
+  /// # Errors
+  /// Returns [`PubSubError::MissingPid`] when the actor has no PID yet.
+  pub fn unsubscribe<M, AR>(&self, topic: &str, actor: &ActorRef<M, AR>) -> Result<(), PubSubError>
+  where
+    M: RemoteMessage,
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+    let pid = self.local_pid(actor)?;
+    self.update_local(topic, |subscribers| subscribers.retain(|subscriber| *subscriber.pid() != pid));
+    Ok(())
+  }
+
+  fn add_subscriber<M, AR>(
+    &self,
+    topic: &str,
+    group: Option<String>,
+    actor: &ActorRef<M, AR>,
+  ) -> Result<(), PubSubError>
+  where
+    M: RemoteMessage,
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+    let subscriber = Subscriber::new(self.local_pid(actor)?, group);
+    // Messages published from other nodes are decoded by the subscriber's node.
+    self.outbound().register_message::<M>();
+    self.update_local(topic, |subscribers| {
+      if !subscribers.contains(&subscriber) {
+        subscribers.push(subscriber);
+      }
+    });
+    Ok(())
+  }
+
+  fn local_pid<M, AR>(&self, actor: &ActorRef<M, AR>) -> Result<Pid, PubSubError>
+  where
+    M: RemoteMessage,
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+    let pid = actor.pid().ok_or(PubSubError::MissingPid)?;
+    Ok(pid.with_node(self.membership.local().clone()))
+  }
+}
+
+impl<T> DistributedPubSub<T>
+where
+  T: RemoteTransport + 'static,
+  Self: SharedBound,
+{
+  /// Handles subscriber gossip and published messages arriving through `dispatcher`, and
+  /// delivers to local subscribers through it.
+  #[must_use]
+  pub fn attach<MF>(&self, dispatcher: RemoteInboundDispatcher<MF>) -> RemoteInboundDispatcher<MF>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    *self.route.write() = Some(PendingDelivery::route_through(dispatcher.clone()));
+    let registry = dispatcher.clone();
+    *self.alive.write() = Some(
+      ArcShared::new(move |pid: &Pid| matches!(registry.registry().resolve_pid(pid), ProcessResolution::Local(_)))
+        .into_dyn(|f| f as &AliveFn),
+    );
+    let pub_sub = self.clone();
+    dispatcher
+      .with_interceptor(RemoteFrameInterceptor::new(move |delivery: RemoteDelivery| pub_sub.handle_frame(delivery)))
+  }
+
+  /// Starts listening on the local node with the membership and publish/subscribe attached to
+  /// inbound delivery.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the transport cannot listen on the local node.
+  pub fn start<MF>(&self, registry: ArcShared<RemoteProcessRegistry<MF>>) -> Result<(), TransportError>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    let dispatcher = self.attach(self.membership.attach(self.outbound().inbound_dispatcher(registry)));
+    self.outbound().endpoints().start(dispatcher)
+  }
+}
@@ -0,0 +1,15 @@
+use cellex_remote_core_rs::outbound::RemoteSendError;
+
+/// Errors raised while subscribing to or publishing on a distributed topic.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum PubSubError {
+  /// Publish/subscribe is not attached to inbound delivery on the local node.
+  #[error("distributed pub/sub is not started on the local node")]
+  NotStarted,
+  /// The subscribing actor has no PID yet, e.g. because its spawning handler did not return.
+  #[error("subscriber has no pid")]
+  MissingPid,
+  /// The message could not be sent to a subscribing node.
+  #[error(transparent)]
+  Send(#[from] RemoteSendError),
+}
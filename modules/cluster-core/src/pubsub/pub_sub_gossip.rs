@@ -0,0 +1,95 @@
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+use cellex_actor_core_rs::api::process::pid::{NodeId, Pid};
+use cellex_remote_core_rs::codec::{RemoteCodecError, WireReader, WireWriter};
+
+use super::{subscriber_bucket::SubscriberBucket, Subscriber};
+
+const STATUS: u8 = 0;
+const DELTA: u8 = 1;
+
+/// Gossip replicating the subscriber buckets between members.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PubSubGossip {
+  /// Version of every bucket known to the sender.
+  Status(Vec<(NodeId, u64)>),
+  /// Buckets the receiver holds an older version of.
+  Delta(Vec<SubscriberBucket>),
+}
+
+impl PubSubGossip {
+  pub(crate) fn encode(&self) -> Result<Vec<u8>, RemoteCodecError> {
+    let mut writer = WireWriter::new();
+    match self {
+      | Self::Status(versions) => {
+        writer.put_u8(STATUS);
+        put_len(&mut writer, versions.len())?;
+        for (node, version) in versions {
+          writer.put_node(node)?;
+          writer.put_u64(*version);
+        }
+      },
+      | Self::Delta(buckets) => {
+        writer.put_u8(DELTA);
+        put_len(&mut writer, buckets.len())?;
+        for bucket in buckets {
+          writer.put_node(&bucket.node)?;
+          writer.put_u64(bucket.version);
+          put_len(&mut writer, bucket.topics.len())?;
+          for (topic, subscribers) in &bucket.topics {
+            writer.put_str(topic)?;
+            put_len(&mut writer, subscribers.len())?;
+            for subscriber in subscribers {
+              writer.put_str(&subscriber.pid().to_string())?;
+              writer.put_opt_str(subscriber.group())?;
+            }
+          }
+        }
+      },
+    }
+    Ok(writer.into_bytes())
+  }
+
+  pub(crate) fn decode(bytes: &[u8]) -> Result<Self, RemoteCodecError> {
+    let mut reader = WireReader::new(bytes);
+    let gossip = match reader.u8()? {
+      | STATUS => {
+        let count = reader.u32()?;
+        let mut versions = Vec::new();
+        for _ in 0..count {
+          let node = reader.node()?;
+          versions.push((node, reader.u64()?));
+        }
+        Self::Status(versions)
+      },
+      | DELTA => {
+        let count = reader.u32()?;
+        let mut buckets = Vec::new();
+        for _ in 0..count {
+          let node = reader.node()?;
+          let version = reader.u64()?;
+          let mut topics = BTreeMap::new();
+          for _ in 0..reader.u32()? {
+            let topic = reader.string()?;
+            let mut subscribers = Vec::new();
+            for _ in 0..reader.u32()? {
+              let pid = Pid::parse(&reader.string()?).map_err(|_| RemoteCodecError::InvalidPid)?;
+              subscribers.push(Subscriber::new(pid, reader.opt_string()?));
+            }
+            topics.insert(topic, subscribers);
+          }
+          buckets.push(SubscriberBucket { node, version, topics });
+        }
+        Self::Delta(buckets)
+      },
+      | other => return Err(RemoteCodecError::UnknownTag(other)),
+    };
+    reader.finish()?;
+    Ok(gossip)
+  }
+}
+
+fn put_len(writer: &mut WireWriter, len: usize) -> Result<(), RemoteCodecError> {
+  writer.put_u32(u32::try_from(len).map_err(|_| RemoteCodecError::FrameTooLarge(len))?);
+  Ok(())
+}
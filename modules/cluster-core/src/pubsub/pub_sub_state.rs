@@ -0,0 +1,11 @@
+use alloc::{collections::BTreeMap, string::String};
+
+use super::subscriber_bucket::SubscriberBucket;
+
+/// Replicated subscriber buckets keyed by node, and the turn of each topic group.
+#[derive(Default)]
+pub(crate) struct PubSubState {
+  pub(crate) buckets: BTreeMap<String, SubscriberBucket>,
+  /// Next subscriber to pick in each group, keyed by `topic/group`.
+  pub(crate) turns:   BTreeMap<String, usize>,
+}
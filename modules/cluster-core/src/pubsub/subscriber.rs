@@ -0,0 +1,30 @@
+use alloc::string::String;
+
+use cellex_actor_core_rs::api::process::pid::Pid;
+
+/// Actor subscribed to a topic, optionally as a member of a group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Subscriber {
+  pid:   Pid,
+  group: Option<String>,
+}
+
+impl Subscriber {
+  /// Creates a subscriber.
+  #[must_use]
+  pub const fn new(pid: Pid, group: Option<String>) -> Self {
+    Self { pid, group }
+  }
+
+  /// Returns the PID of the subscribing actor, including its node.
+  #[must_use]
+  pub const fn pid(&self) -> &Pid {
+    &self.pid
+  }
+
+  /// Returns the group the subscriber belongs to, if any.
+  #[must_use]
+  pub fn group(&self) -> Option<&str> {
+    self.group.as_deref()
+  }
+}
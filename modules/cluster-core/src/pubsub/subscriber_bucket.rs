@@ -0,0 +1,39 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use super::Subscriber;
+
+/// Subscribers living on one node, by topic. Only the owning node changes its bucket, raising
+/// the version each time, so replicas keep the bucket with the highest version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SubscriberBucket {
+  pub(crate) node:    NodeId,
+  pub(crate) version: u64,
+  pub(crate) topics:  BTreeMap<String, Vec<Subscriber>>,
+}
+
+impl SubscriberBucket {
+  pub(crate) const fn new(node: NodeId) -> Self {
+    Self { node, version: 0, topics: BTreeMap::new() }
+  }
+
+  pub(crate) fn subscribers(&self, topic: &str) -> &[Subscriber] {
+    self.topics.get(topic).map_or(&[], Vec::as_slice)
+  }
+
+  /// Applies `change` to the subscribers of `topic`, raising the version when they changed.
+  pub(crate) fn update(&mut self, topic: &str, change: impl FnOnce(&mut Vec<Subscriber>)) -> bool {
+    let subscribers = self.topics.entry(topic.into()).or_default();
+    let previous = subscribers.clone();
+    change(subscribers);
+    let changed = *subscribers != previous;
+    if subscribers.is_empty() {
+      self.topics.remove(topic);
+    }
+    if changed {
+      self.version += 1;
+    }
+    changed
+  }
+}
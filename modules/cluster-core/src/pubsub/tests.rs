@@ -0,0 +1,232 @@
+extern crate std;
+
+use std::{
+  format,
+  string::{String, ToString},
+  sync::{Arc, Mutex},
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor::{actor_ref::ActorRef, Props},
+  actor_runtime::GenericActorRuntime,
+  actor_system::{GenericActorSystem, GenericActorSystemConfig},
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  mailbox::messages::SystemMessage,
+  process::pid::NodeId,
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_remote_core_rs::{
+  delivery::RemoteMessage,
+  endpoint::EndpointManager,
+  loopback::{LoopbackNetwork, LoopbackTransport},
+  outbound::RemoteOutbound,
+};
+use cellex_serialization_core_rs::{
+  error::{DeserializationError, SerializationError},
+  impl_type_key,
+};
+use cellex_serialization_json_rs::{shared_json_serializer, SERDE_JSON_SERIALIZER_ID};
+use cellex_utils_core_rs::sync::ArcShared;
+use serde::{Deserialize, Serialize};
+
+use super::{DistributedPubSub, PubSubError, TopicDelivery};
+use crate::membership::{ClusterMembership, MembershipConfig};
+
+type TestResult<T = ()> = Result<T, String>;
+type TestSystem = GenericActorSystem<Add, TokioActorRuntime>;
+type Received = Arc<Mutex<Vec<(String, u32)>>>;
+type Listener = ActorRef<Add, TokioActorRuntime>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Add {
+  amount: u32,
+}
+
+impl_type_key!(Add, "test.Add");
+
+impl RemoteMessage for Add {
+  fn encode_payload(&self) -> Result<Vec<u8>, SerializationError> {
+    serde_json::to_vec(self).map_err(|err| SerializationError::custom(err.to_string()))
+  }
+
+  fn decode_payload(bytes: &[u8]) -> Result<Self, DeserializationError> {
+    serde_json::from_slice(bytes).map_err(|err| DeserializationError::custom(err.to_string()))
+  }
+}
+
+struct TestNode {
+  system:  TestSystem,
+  pub_sub: DistributedPubSub<LoopbackTransport>,
+}
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn started(network: &LoopbackNetwork, port: u16) -> TestResult<TestNode> {
+  let config = GenericActorSystemConfig::default().with_node_id(node(port));
+  let system = GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), config);
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(network.transport(&node(port)), system_id, node(port)));
+  let outbound = system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      let _ = extension.register_serializer(shared_json_serializer());
+      extension.bind_type::<Add>(SERDE_JSON_SERIALIZER_ID).map_err(|err| format!("bind: {err:?}"))?;
+      Ok::<_, String>(RemoteOutbound::new(endpoints, extension))
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())??;
+  let membership = ClusterMembership::new(outbound, MembershipConfig::new().with_seed_node(node(2551)));
+  let pub_sub = DistributedPubSub::new(membership);
+  pub_sub.start(system.process_registry()).map_err(|err| format!("start: {err}"))?;
+  Ok(TestNode { system, pub_sub })
+}
+
+fn listener(test_node: &mut TestNode, name: &str, received: &Received) -> TestResult<Listener> {
+  let name = name.to_string();
+  let received = received.clone();
+  test_node
+    .system
+    .root_context()
+    .spawn(Props::new(move |_, message: Add| {
+      received.lock().unwrap_or_else(|err| err.into_inner()).push((name.clone(), message.amount));
+      Ok(())
+    }))
+    .map_err(|err| format!("spawn: {err:?}"))
+}
+
+fn rounds(network: &LoopbackNetwork, nodes: &mut [&mut TestNode], count: usize) -> TestResult {
+  for _ in 0..count {
+    for test_node in nodes.iter() {
+      test_node.pub_sub.membership().tick();
+      test_node.pub_sub.tick();
+    }
+    for _ in 0..3 {
+      network.flush();
+      for test_node in nodes.iter_mut() {
+        test_node.system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+      }
+    }
+  }
+  Ok(())
+}
+
+fn form_cluster(network: &LoopbackNetwork, nodes: &mut [&mut TestNode]) -> TestResult {
+  for test_node in nodes.iter() {
+    test_node.pub_sub.membership().join();
+  }
+  rounds(network, nodes, 4)
+}
+
+fn taken(received: &Received) -> Vec<(String, u32)> {
+  let mut taken = core::mem::take(&mut *received.lock().unwrap_or_else(|err| err.into_inner()));
+  taken.sort_unstable();
+  taken
+}
+
+fn publish(test_node: &TestNode, topic: &str, amount: u32, delivery: TopicDelivery) -> TestResult {
+  test_node.pub_sub.publish(topic, &Add { amount }, delivery).map_err(|err| format!("publish: {err}"))
+}
+
+#[test]
+fn published_messages_reach_every_subscriber_on_every_node() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let mut first = started(&network, 2551)?;
+  let mut second = started(&network, 2552)?;
+  let mut third = started(&network, 2553)?;
+  form_cluster(&network, &mut [&mut first, &mut second, &mut third])?;
+  let a = listener(&mut first, "a", &received)?;
+  let b = listener(&mut second, "b", &received)?;
+  let c = listener(&mut second, "c", &received)?;
+  let other = listener(&mut third, "other", &received)?;
+  first.pub_sub.subscribe("news", &a).map_err(|err| format!("subscribe: {err}"))?;
+  second.pub_sub.subscribe("news", &b).map_err(|err| format!("subscribe: {err}"))?;
+  second.pub_sub.subscribe("news", &c).map_err(|err| format!("subscribe: {err}"))?;
+  third.pub_sub.subscribe("sports", &other).map_err(|err| format!("subscribe: {err}"))?;
+  rounds(&network, &mut [&mut first, &mut second, &mut third], 3)?;
+  assert_eq!(third.pub_sub.subscribers("news").len(), 3);
+
+  publish(&third, "news", 1, TopicDelivery::All)?;
+  publish(&first, "news", 2, TopicDelivery::All)?;
+  rounds(&network, &mut [&mut first, &mut second, &mut third], 1)?;
+  let expected: Vec<(String, u32)> =
+    ["a", "b", "c"].iter().flat_map(|name| [(name.to_string(), 1), (name.to_string(), 2)]).collect();
+  assert_eq!(taken(&received), expected);
+
+  second.pub_sub.unsubscribe("news", &b).map_err(|err| format!("unsubscribe: {err}"))?;
+  rounds(&network, &mut [&mut first, &mut second, &mut third], 3)?;
+  publish(&third, "news", 3, TopicDelivery::All)?;
+  rounds(&network, &mut [&mut first, &mut second, &mut third], 1)?;
+  assert_eq!(taken(&received), vec![("a".to_string(), 3), ("c".to_string(), 3)]);
+  Ok(())
+}
+
+#[test]
+fn one_subscriber_of_each_group_receives_each_message_in_turn() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let mut first = started(&network, 2551)?;
+  let mut second = started(&network, 2552)?;
+  form_cluster(&network, &mut [&mut first, &mut second])?;
+  let worker_a = listener(&mut first, "worker-a", &received)?;
+  let worker_b = listener(&mut second, "worker-b", &received)?;
+  let auditor = listener(&mut second, "auditor", &received)?;
+  let ungrouped = listener(&mut first, "ungrouped", &received)?;
+  first.pub_sub.subscribe_to_group("jobs", "workers", &worker_a).map_err(|err| format!("subscribe: {err}"))?;
+  second.pub_sub.subscribe_to_group("jobs", "workers", &worker_b).map_err(|err| format!("subscribe: {err}"))?;
+  second.pub_sub.subscribe_to_group("jobs", "audit", &auditor).map_err(|err| format!("subscribe: {err}"))?;
+  first.pub_sub.subscribe("jobs", &ungrouped).map_err(|err| format!("subscribe: {err}"))?;
+  rounds(&network, &mut [&mut first, &mut second], 3)?;
+
+  for amount in 1..=4 {
+    publish(&first, "jobs", amount, TopicDelivery::OnePerGroup)?;
+  }
+  rounds(&network, &mut [&mut first, &mut second], 1)?;
+
+  let taken = taken(&received);
+  let count = |name: &str| taken.iter().filter(|(receiver, _)| receiver == name).count();
+  assert_eq!((count("worker-a"), count("worker-b"), count("auditor"), count("ungrouped")), (2, 2, 4, 0));
+  let mut jobs: Vec<u32> =
+    taken.iter().filter(|(receiver, _)| receiver.starts_with("worker")).map(|(_, amount)| *amount).collect();
+  jobs.sort_unstable();
+  assert_eq!(jobs, vec![1, 2, 3, 4]);
+  Ok(())
+}
+
+#[test]
+fn subscriptions_are_removed_when_the_actor_or_its_node_dies() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let mut first = started(&network, 2551)?;
+  let mut second = started(&network, 2552)?;
+  let mut third = started(&network, 2553)?;
+  form_cluster(&network, &mut [&mut first, &mut second, &mut third])?;
+  let a = listener(&mut first, "a", &received)?;
+  let b = listener(&mut second, "b", &received)?;
+  let c = listener(&mut third, "c", &received)?;
+  first.pub_sub.subscribe("news", &a).map_err(|err| format!("subscribe: {err}"))?;
+  second.pub_sub.subscribe("news", &b).map_err(|err| format!("subscribe: {err}"))?;
+  third.pub_sub.subscribe("news", &c).map_err(|err| format!("subscribe: {err}"))?;
+  rounds(&network, &mut [&mut first, &mut second, &mut third], 3)?;
+  assert_eq!(first.pub_sub.subscribers("news").len(), 3);
+
+  b.send_system(SystemMessage::Stop).map_err(|err| format!("stop: {err:?}"))?;
+  rounds(&network, &mut [&mut first, &mut second, &mut third], 3)?;
+  let nodes: Vec<Option<u16>> =
+    first.pub_sub.subscribers("news").iter().map(|subscriber| subscriber.pid().node().and_then(NodeId::port)).collect();
+  assert_eq!(nodes, vec![Some(2551), Some(2553)]);
+
+  first.pub_sub.membership().down(&node(2553));
+  rounds(&network, &mut [&mut first, &mut second], 3)?;
+  assert_eq!(first.pub_sub.subscribers("news").len(), 1);
+  assert_eq!(second.pub_sub.subscribers("news").len(), 1);
+  publish(&second, "news", 1, TopicDelivery::All)?;
+  rounds(&network, &mut [&mut first, &mut second], 1)?;
+  assert_eq!(taken(&received), vec![("a".to_string(), 1)]);
+
+  let unstarted = DistributedPubSub::new(first.pub_sub.membership().clone());
+  assert_eq!(unstarted.publish("news", &Add { amount: 2 }, TopicDelivery::All), Err(PubSubError::NotStarted));
+  Ok(())
+}
@@ -0,0 +1,10 @@
+/// How a message published on a topic is spread over its subscribers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TopicDelivery {
+  /// Every subscriber of the topic receives the message.
+  #[default]
+  All,
+  /// One subscriber of each group receives the message, chosen in turn. Subscribers outside any
+  /// group do not receive it.
+  OnePerGroup,
+}
@@ -0,0 +1,13 @@
+mod cluster_group_router;
+mod cluster_pool_router;
+mod cluster_router_error;
+mod cluster_routing_logic;
+mod pool_routees;
+
+#[cfg(test)]
+mod tests;
+
+pub use cluster_group_router::ClusterGroupRouter;
+pub use cluster_pool_router::ClusterPoolRouter;
+pub use cluster_router_error::ClusterRouterError;
+pub use cluster_routing_logic::ClusterRoutingLogic;
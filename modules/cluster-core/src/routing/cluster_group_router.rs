@@ -0,0 +1,268 @@
+use alloc::{format, string::String, vec::Vec};
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::{actor_ref::ActorRef, ActorPath},
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::MetadataStorageMode,
+    process::pid::{NodeId, Pid, PidTag},
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::{AnyMessage, MessageEnvelope},
+  },
+};
+use cellex_remote_core_rs::{
+  codec::RemoteDelivery,
+  delivery::{RemoteFrameInterceptor, RemoteInboundDispatcher, RemoteMessage, RemoteProcessRegistry},
+  outbound::RemoteOutbound,
+  transport::{RemoteTransport, TransportError},
+};
+use cellex_utils_core_rs::{
+  collections::queue::priority::DEFAULT_PRIORITY,
+  sync::{shared::SharedBound, ArcShared},
+};
+use spin::RwLock;
+
+use super::{ClusterRouterError, ClusterRoutingLogic};
+use crate::{
+  membership::{ClusterMembership, MemberStatus},
+  virtual_actor::{PendingDelivery, RouteFn},
+};
+
+const GROUP_TAG_PREFIX: &str = "group:";
+
+/// Router spreading messages over the actors registered under a service name on every member.
+///
+/// Each member registers its routee for the service with [`ClusterGroupRouter::register`]. The
+/// routees are looked up by name at every [`ClusterGroupRouter::tell`] among the members that are
+/// up, so members joining or leaving the cluster are picked up without any bookkeeping. A message
+/// routed to a member is sent to a group PID naming the service there, and handed to the routee
+/// registered on that member; members without a routee drop it.
+pub struct ClusterGroupRouter<T>
+where
+  T: RemoteTransport, {
+  membership: ClusterMembership<T>,
+  service:    String,
+  logic:      ClusterRoutingLogic,
+  routee:     ArcShared<RwLock<Option<Pid>>>,
+  turn:       ArcShared<RwLock<usize>>,
+  route:      ArcShared<RwLock<Option<ArcShared<RouteFn>>>>,
+}
+
+impl<T> Clone for ClusterGroupRouter<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self {
+      membership: self.membership.clone(),
+      service:    self.service.clone(),
+      logic:      self.logic,
+      routee:     self.routee.clone(),
+      turn:       self.turn.clone(),
+      route:      self.route.clone(),
+    }
+  }
+}
+
+impl<T> ClusterGroupRouter<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates a round-robin router over the routees of `service` on the members of `membership`.
+  #[must_use]
+  pub fn new(membership: ClusterMembership<T>, service: &str) -> Self {
+    Self {
+      membership,
+      service: service.into(),
+      logic: ClusterRoutingLogic::default(),
+      routee: ArcShared::new(RwLock::new(None)),
+      turn: ArcShared::new(RwLock::new(0)),
+      route: ArcShared::new(RwLock::new(None)),
+    }
+  }
+
+  /// Picks routees with `logic` instead.
+  #[must_use]
+  pub const fn with_logic(mut self, logic: ClusterRoutingLogic) -> Self {
+    self.logic = logic;
+    self
+  }
+
+  /// Returns the membership the routees are looked up in.
+  #[must_use]
+  pub const fn membership(&self) -> &ClusterMembership<T> {
+    &self.membership
+  }
+
+  /// Returns the outbound side used to reach other members.
+  #[must_use]
+  pub const fn outbound(&self) -> &RemoteOutbound<T> {
+    self.membership.outbound()
+  }
+
+  /// Returns the service name the routees are registered under.
+  #[must_use]
+  pub fn service(&self) -> &str {
+    &self.service
+  }
+
+  /// Returns how routees are picked.
+  #[must_use]
+  pub const fn logic(&self) -> ClusterRoutingLogic {
+    self.logic
+  }
+
+  /// Registers `actor` as the routee of the service on the local node, replacing any previous
+  /// one.
+  ///
+  /// # Errors
+  /// Returns [`ClusterRouterError::MissingPid`] when the actor has no PID.
+  pub fn register<M, AR>(&self, actor: &ActorRef<M, AR>) -> Result<(), ClusterRouterError>
+  where
+    M: RemoteMessage,
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+    let pid = actor.pid().ok_or(ClusterRouterError::MissingPid)?;
+    // Messages routed from other nodes are decoded by the routee's node.
+    self.outbound().register_message::<M>();
+    *self.routee.write() = Some(pid.with_node(self.membership.local().clone()));
+    Ok(())
+  }
+
+  /// Unregisters the routee of the local node.
+  pub fn unregister(&self) {
+    *self.routee.write() = None;
+  }
+
+  /// Returns the routee registered on the local node, if any.
+  #[must_use]
+  pub fn local_routee(&self) -> Option<Pid> {
+    self.routee.read().clone()
+  }
+
+  /// Returns the routees on the members that are up: the registered routee for the local node,
+  /// and a group PID naming the service for every other member.
+  #[must_use]
+  pub fn routees(&self) -> Vec<Pid> {
+    let local = self.membership.local();
+    self
+      .membership
+      .members()
+      .into_iter()
+      .filter(|member| member.status() == MemberStatus::Up)
+      .filter_map(
+        |member| {
+          if member.node() == local {
+            self.local_routee()
+          } else {
+            Some(self.group_pid(member.node()))
+          }
+        },
+      )
+      .collect()
+  }
+
+  /// Sends `message` to the routees picked by the routing logic.
+  ///
+  /// # Errors
+  /// Returns [`ClusterRouterError::NoRoutees`] when no member that is up has a routee,
+  /// [`ClusterRouterError::NotStarted`] when a local routee is picked before the router is
+  /// attached, and [`ClusterRouterError::Send`] when the message cannot be sent to a member.
+  pub fn tell<U>(&self, message: &U) -> Result<(), ClusterRouterError>
+  where
+    U: RemoteMessage + Clone, {
+    let routees = self.routees();
+    if routees.is_empty() {
+      return Err(ClusterRouterError::NoRoutees);
+    }
+    let mut result = Ok(());
+    for pid in self.logic.select(&routees, &self.turn) {
+      let sent = match pid.node() {
+        | Some(node) if node != self.membership.local() => {
+          self.outbound().send_user(pid, message, DEFAULT_PRIORITY, None).map_err(ClusterRouterError::from)
+        },
+        | _ => {
+          let envelope =
+            PriorityEnvelope::new(AnyMessage::new(MessageEnvelope::user(message.clone())), DEFAULT_PRIORITY);
+          self.deliver_local(pid, PendingDelivery::Local(envelope))
+        },
+      };
+      if let Err(err) = sent {
+        result = Err(err);
+      }
+    }
+    result
+  }
+
+  /// Hands messages addressed to the group PID of the service to the local routee, returning
+  /// every other delivery.
+  #[must_use]
+  pub fn handle_frame(&self, delivery: RemoteDelivery) -> Option<RemoteDelivery> {
+    let RemoteDelivery { target, frame } = delivery;
+    let ours =
+      target.tag().and_then(|tag| tag.0.strip_prefix(GROUP_TAG_PREFIX)).is_some_and(|service| service == self.service);
+    if !ours {
+      return Some(RemoteDelivery::new(target, frame));
+    }
+    // Without a local routee the frame is dropped, as for any unknown target.
+    if let Some(pid) = self.local_routee() {
+      let _ = self.deliver_local(&pid, PendingDelivery::Remote(frame));
+    }
+    None
+  }
+
+  fn deliver_local(&self, pid: &Pid, delivery: PendingDelivery) -> Result<(), ClusterRouterError> {
+    let route = self.route.read().clone().ok_or(ClusterRouterError::NotStarted)?;
+    if route(pid, delivery).is_some() {
+      // The routee stopped; it no longer serves the service.
+      let mut routee = self.routee.write();
+      if routee.as_ref() == Some(pid) {
+        *routee = None;
+      }
+    }
+    Ok(())
+  }
+
+  fn group_pid(&self, node: &NodeId) -> Pid {
+    Pid::new(self.outbound().system().clone(), ActorPath::new())
+      .with_node(node.clone())
+      .with_tag(PidTag::new(format!("{GROUP_TAG_PREFIX}{}", self.service)))
+  }
+}
+
+impl<T> ClusterGroupRouter<T>
+where
+  T: RemoteTransport + 'static,
+  Self: SharedBound,
+{
+  /// Hands messages for the service arriving through `dispatcher` to the local routee, and
+  /// delivers local messages through it.
+  #[must_use]
+  pub fn attach<MF>(&self, dispatcher: RemoteInboundDispatcher<MF>) -> RemoteInboundDispatcher<MF>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    *self.route.write() = Some(PendingDelivery::route_through(dispatcher.clone()));
+    let router = self.clone();
+    dispatcher
+      .with_interceptor(RemoteFrameInterceptor::new(move |delivery: RemoteDelivery| router.handle_frame(delivery)))
+  }
+
+  /// Starts listening on the local node with the membership and the router attached to inbound
+  /// delivery.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the transport cannot listen on the local node.
+  pub fn start<MF>(&self, registry: ArcShared<RemoteProcessRegistry<MF>>) -> Result<(), TransportError>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    let dispatcher = self.attach(self.membership.attach(self.outbound().inbound_dispatcher(registry)));
+    self.outbound().endpoints().start(dispatcher)
+  }
+}
@@ -0,0 +1,253 @@
+use alloc::{
+  string::{String, ToString},
+  vec::Vec,
+};
+use core::{
+  future::Future,
+  pin::Pin,
+  task::{Context, Poll, Waker},
+};
+
+use cellex_actor_core_rs::{
+  api::process::pid::{NodeId, Pid},
+  shared::mailbox::MailboxFactory,
+};
+use cellex_remote_core_rs::{
+  activation::RemoteSpawner,
+  delivery::{RemoteInboundDispatcher, RemoteMessage, RemoteProcessRegistry},
+  outbound::RemoteOutbound,
+  transport::{RemoteTransport, TransportError},
+};
+use cellex_utils_core_rs::{
+  collections::queue::priority::DEFAULT_PRIORITY,
+  sync::{shared::SharedBound, ArcShared},
+};
+use spin::RwLock;
+
+use super::{pool_routees::PoolRoutees, ClusterRouterError, ClusterRoutingLogic};
+use crate::membership::{
+  ClusterMembership, MemberStatus, MembershipChange, MembershipListener, MembershipSubscription,
+};
+
+/// Router spreading messages over routees it deploys on the other members of the cluster.
+///
+/// The router keeps a number of routees of a registered kind on every other member that is up,
+/// spawning them through a [`RemoteSpawner`]. Members hosting routees announce their activator
+/// through [`ClusterPoolRouter::serve`]. Every [`ClusterPoolRouter::tick`] collects the routees
+/// spawned since the last one, forgets the routees of members that are no longer up, and deploys
+/// the routees missing on the members that are; [`ClusterPoolRouter::track`] also does so on
+/// every membership change. Failed deployments are retried on the next tick.
+pub struct ClusterPoolRouter<T>
+where
+  T: RemoteTransport, {
+  membership:       ClusterMembership<T>,
+  spawner:          RemoteSpawner<T>,
+  kind:             String,
+  routees_per_node: usize,
+  logic:            ClusterRoutingLogic,
+  state:            ArcShared<RwLock<PoolRoutees>>,
+  turn:             ArcShared<RwLock<usize>>,
+}
+
+impl<T> Clone for ClusterPoolRouter<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self {
+      membership:       self.membership.clone(),
+      spawner:          self.spawner.clone(),
+      kind:             self.kind.clone(),
+      routees_per_node: self.routees_per_node,
+      logic:            self.logic,
+      state:            self.state.clone(),
+      turn:             self.turn.clone(),
+    }
+  }
+}
+
+impl<T> ClusterPoolRouter<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates a round-robin router keeping one routee of `kind` on every other member of
+  /// `membership` that is up.
+  #[must_use]
+  pub fn new(membership: ClusterMembership<T>, kind: &str) -> Self {
+    let spawner = RemoteSpawner::new(membership.outbound().clone());
+    Self {
+      membership,
+      spawner,
+      kind: kind.into(),
+      routees_per_node: 1,
+      logic: ClusterRoutingLogic::default(),
+      state: ArcShared::new(RwLock::new(PoolRoutees::default())),
+      turn: ArcShared::new(RwLock::new(0)),
+    }
+  }
+
+  /// Keeps `routees_per_node` routees on every member instead.
+  #[must_use]
+  pub const fn with_routees_per_node(mut self, routees_per_node: usize) -> Self {
+    self.routees_per_node = routees_per_node;
+    self
+  }
+
+  /// Picks routees with `logic` instead.
+  #[must_use]
+  pub const fn with_logic(mut self, logic: ClusterRoutingLogic) -> Self {
+    self.logic = logic;
+    self
+  }
+
+  /// Returns the membership the routees are deployed over.
+  #[must_use]
+  pub const fn membership(&self) -> &ClusterMembership<T> {
+    &self.membership
+  }
+
+  /// Returns the outbound side used to reach other members.
+  #[must_use]
+  pub const fn outbound(&self) -> &RemoteOutbound<T> {
+    self.membership.outbound()
+  }
+
+  /// Returns the spawner deploying the routees, which also serves deployments on this member.
+  #[must_use]
+  pub const fn spawner(&self) -> &RemoteSpawner<T> {
+    &self.spawner
+  }
+
+  /// Returns the kind of the routees.
+  #[must_use]
+  pub fn kind(&self) -> &str {
+    &self.kind
+  }
+
+  /// Returns how many routees are kept on every member.
+  #[must_use]
+  pub const fn routees_per_node(&self) -> usize {
+    self.routees_per_node
+  }
+
+  /// Returns how routees are picked.
+  #[must_use]
+  pub const fn logic(&self) -> ClusterRoutingLogic {
+    self.logic
+  }
+
+  /// Hosts routees on the local node through the activator actor `activator`, built from
+  /// [`RemoteSpawner::activator_props`] on [`ClusterPoolRouter::spawner`]. The routees receive
+  /// messages of type `M`.
+  pub fn serve<M>(&self, activator: Pid)
+  where
+    M: RemoteMessage, {
+    // Messages routed from other nodes are decoded by the routee's node.
+    self.outbound().register_message::<M>();
+    self.spawner.serve(activator);
+  }
+
+  /// Returns the deployed routees, in node order.
+  #[must_use]
+  pub fn routees(&self) -> Vec<Pid> {
+    self.state.read().deployed.values().flatten().cloned().collect()
+  }
+
+  /// Collects the routees spawned since the last tick, forgets the routees of members that are
+  /// no longer up and deploys the routees missing on the members that are.
+  pub fn tick(&self) {
+    let local = self.membership.local();
+    let up: Vec<NodeId> = self
+      .membership
+      .members()
+      .into_iter()
+      .filter(|member| member.status() == MemberStatus::Up && member.node() != local)
+      .map(|member| member.node().clone())
+      .collect();
+    let mut state = self.state.write();
+    let PoolRoutees { deployed, pending } = &mut *state;
+    let mut cx = Context::from_waker(Waker::noop());
+    pending.retain_mut(|(node, future)| match Pin::new(future).poll(&mut cx) {
+      | Poll::Pending => true,
+      | Poll::Ready(result) => {
+        // Failed deployments are retried below.
+        if let Ok(Ok(pid)) = result {
+          deployed.entry(node.to_string()).or_default().push(pid);
+        }
+        false
+      },
+    });
+    deployed.retain(|node, _| up.iter().any(|member| member.to_string() == *node));
+    pending.retain(|(node, _)| up.contains(node));
+    for node in &up {
+      let running = deployed.get(&node.to_string()).map_or(0, Vec::len)
+        + pending.iter().filter(|(deploying, _)| deploying == node).count();
+      for _ in running..self.routees_per_node {
+        if let Ok(future) = self.spawner.spawn(node, &self.kind) {
+          pending.push((node.clone(), future));
+        }
+      }
+    }
+  }
+
+  /// Sends `message` to the routees picked by the routing logic.
+  ///
+  /// # Errors
+  /// Returns [`ClusterRouterError::NoRoutees`] when no routee is deployed yet, and
+  /// [`ClusterRouterError::Send`] when the message cannot be sent to a routee.
+  pub fn tell<U>(&self, message: &U) -> Result<(), ClusterRouterError>
+  where
+    U: RemoteMessage, {
+    let routees = self.routees();
+    if routees.is_empty() {
+      return Err(ClusterRouterError::NoRoutees);
+    }
+    let mut result = Ok(());
+    for pid in self.logic.select(&routees, &self.turn) {
+      if let Err(err) = self.outbound().send_user(pid, message, DEFAULT_PRIORITY, None) {
+        result = Err(ClusterRouterError::from(err));
+      }
+    }
+    result
+  }
+}
+
+impl<T> ClusterPoolRouter<T>
+where
+  T: RemoteTransport + 'static,
+  Self: SharedBound,
+{
+  /// Redeploys the routees whenever a member comes up or stops being up. The tracking stops when
+  /// the returned subscription is dropped.
+  #[must_use]
+  pub fn track(&self) -> MembershipSubscription {
+    let router = self.clone();
+    self.membership.subscribe(MembershipListener::new(move |change: &MembershipChange| {
+      if change.status() == MemberStatus::Up || change.previous() == Some(MemberStatus::Up) {
+        router.tick();
+      }
+    }))
+  }
+
+  /// Hooks the spawner deploying the routees into `dispatcher`.
+  #[must_use]
+  pub fn attach<MF>(&self, dispatcher: RemoteInboundDispatcher<MF>) -> RemoteInboundDispatcher<MF>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    self.spawner.attach(dispatcher)
+  }
+
+  /// Starts listening on the local node with the membership and the spawner attached to inbound
+  /// delivery.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the transport cannot listen on the local node.
+  pub fn start<MF>(&self, registry: ArcShared<RemoteProcessRegistry<MF>>) -> Result<(), TransportError>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    let dispatcher = self.attach(self.membership.attach(self.outbound().inbound_dispatcher(registry)));
+    self.outbound().endpoints().start(dispatcher)
+  }
+}
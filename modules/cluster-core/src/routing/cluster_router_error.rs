@@ -0,0 +1,18 @@
+use cellex_remote_core_rs::outbound::RemoteSendError;
+
+/// Errors raised while routing a message through a cluster router.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ClusterRouterError {
+  /// No routee is available on the members that are up.
+  #[error("no routee is available")]
+  NoRoutees,
+  /// The router is not attached to inbound delivery on the local node.
+  #[error("the router is not started on the local node")]
+  NotStarted,
+  /// The routee has no PID.
+  #[error("routee has no pid")]
+  MissingPid,
+  /// The message could not be sent to a routee.
+  #[error(transparent)]
+  Send(#[from] RemoteSendError),
+}
@@ -0,0 +1,28 @@
+use cellex_actor_core_rs::api::process::pid::Pid;
+use spin::RwLock;
+
+/// How a cluster router picks the routees receiving a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClusterRoutingLogic {
+  /// One routee receives each message, chosen in turn.
+  #[default]
+  RoundRobin,
+  /// Every routee receives each message.
+  Broadcast,
+}
+
+impl ClusterRoutingLogic {
+  /// Picks the routees receiving the next message, advancing `turn` when they are taken in turn.
+  pub(crate) fn select<'a>(self, routees: &'a [Pid], turn: &RwLock<usize>) -> &'a [Pid] {
+    match self {
+      | Self::RoundRobin if routees.is_empty() => routees,
+      | Self::RoundRobin => {
+        let mut turn = turn.write();
+        let index = *turn % routees.len();
+        *turn = index + 1;
+        &routees[index..=index]
+      },
+      | Self::Broadcast => routees,
+    }
+  }
+}
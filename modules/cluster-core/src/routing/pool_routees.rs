@@ -0,0 +1,16 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use cellex_actor_core_rs::api::{
+  actor::ask::AskFuture,
+  process::pid::{NodeId, Pid},
+};
+use cellex_remote_core_rs::activation::RemoteSpawnResult;
+
+/// Routees of a pool router, deployed or being deployed, by member.
+#[derive(Default)]
+pub(crate) struct PoolRoutees {
+  /// Routees deployed on each member, keyed by node.
+  pub(crate) deployed: BTreeMap<String, Vec<Pid>>,
+  /// Deployments waiting for the spawned PID.
+  pub(crate) pending:  Vec<(NodeId, AskFuture<RemoteSpawnResult>)>,
+}
@@ -0,0 +1,246 @@
+extern crate std;
+
+use std::{
+  format,
+  string::{String, ToString},
+  sync::{Arc, Mutex},
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor::Props,
+  actor_runtime::GenericActorRuntime,
+  actor_system::{GenericActorSystem, GenericActorSystemConfig},
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  process::pid::NodeId,
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_remote_core_rs::{
+  activation::{RemoteActivatorMessage, RemoteKinds},
+  delivery::RemoteMessage,
+  endpoint::EndpointManager,
+  loopback::{LoopbackNetwork, LoopbackTransport},
+  outbound::RemoteOutbound,
+};
+use cellex_serialization_core_rs::{
+  error::{DeserializationError, SerializationError},
+  impl_type_key,
+};
+use cellex_serialization_json_rs::{shared_json_serializer, SERDE_JSON_SERIALIZER_ID};
+use cellex_utils_core_rs::{collections::Element, sync::ArcShared};
+use serde::{Deserialize, Serialize};
+
+use super::{ClusterGroupRouter, ClusterPoolRouter, ClusterRouterError, ClusterRoutingLogic};
+use crate::membership::{ClusterMembership, MembershipConfig};
+
+type TestResult<T = ()> = Result<T, String>;
+type Received = Arc<Mutex<Vec<(u16, u32)>>>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Work {
+  id: u32,
+}
+
+impl_type_key!(Work, "test.Work");
+
+impl RemoteMessage for Work {
+  fn encode_payload(&self) -> Result<Vec<u8>, SerializationError> {
+    serde_json::to_vec(self).map_err(|err| SerializationError::custom(err.to_string()))
+  }
+
+  fn decode_payload(bytes: &[u8]) -> Result<Self, DeserializationError> {
+    serde_json::from_slice(bytes).map_err(|err| DeserializationError::custom(err.to_string()))
+  }
+}
+
+struct GroupNode {
+  system: GenericActorSystem<Work, TokioActorRuntime>,
+  router: ClusterGroupRouter<LoopbackTransport>,
+}
+
+struct PoolNode {
+  system: GenericActorSystem<RemoteActivatorMessage, TokioActorRuntime>,
+  router: ClusterPoolRouter<LoopbackTransport>,
+}
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn membership<M>(
+  network: &LoopbackNetwork,
+  system: &GenericActorSystem<M, TokioActorRuntime>,
+  port: u16,
+) -> TestResult<ClusterMembership<LoopbackTransport>>
+where
+  M: Element, {
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(network.transport(&node(port)), system_id, node(port)));
+  let outbound = system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      let _ = extension.register_serializer(shared_json_serializer());
+      extension.bind_type::<Work>(SERDE_JSON_SERIALIZER_ID).map_err(|err| format!("bind: {err:?}"))?;
+      Ok::<_, String>(RemoteOutbound::new(endpoints, extension))
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())??;
+  Ok(ClusterMembership::new(outbound, MembershipConfig::new().with_seed_node(node(2551))))
+}
+
+fn group_node(network: &LoopbackNetwork, port: u16, received: &Received) -> TestResult<GroupNode> {
+  let config = GenericActorSystemConfig::default().with_node_id(node(port));
+  let mut system = GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), config);
+  let router = ClusterGroupRouter::new(membership(network, &system, port)?, "workers");
+  router.start(system.process_registry()).map_err(|err| format!("start: {err}"))?;
+  let received = received.clone();
+  let worker = system
+    .root_context()
+    .spawn(Props::new(move |_, work: Work| {
+      received.lock().unwrap_or_else(|err| err.into_inner()).push((port, work.id));
+      Ok(())
+    }))
+    .map_err(|err| format!("spawn: {err:?}"))?;
+  router.register(&worker).map_err(|err| format!("register: {err}"))?;
+  Ok(GroupNode { system, router })
+}
+
+fn pool_node(network: &LoopbackNetwork, port: u16, received: &Received) -> TestResult<PoolNode> {
+  let config = GenericActorSystemConfig::default().with_node_id(node(port));
+  let mut system = GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), config);
+  let router = ClusterPoolRouter::new(membership(network, &system, port)?, "worker").with_routees_per_node(2);
+  router.start(system.process_registry()).map_err(|err| format!("start: {err}"))?;
+  let kinds = RemoteKinds::<TokioActorRuntime>::new();
+  let received = received.clone();
+  kinds.register("worker", move || {
+    let received = received.clone();
+    Props::new(move |_, work: Work| {
+      received.lock().unwrap_or_else(|err| err.into_inner()).push((port, work.id));
+      Ok(())
+    })
+  });
+  let activator =
+    system.root_context().spawn(router.spawner().activator_props(kinds)).map_err(|err| format!("spawn: {err:?}"))?;
+  router.serve::<Work>(activator.pid().ok_or_else(|| "activator pid expected".to_string())?);
+  Ok(PoolNode { system, router })
+}
+
+fn group_rounds(network: &LoopbackNetwork, nodes: &mut [GroupNode], count: usize) -> TestResult {
+  for _ in 0..count {
+    for test_node in nodes.iter() {
+      test_node.router.membership().tick();
+    }
+    for _ in 0..3 {
+      network.flush();
+      for test_node in nodes.iter_mut() {
+        test_node.system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+      }
+    }
+  }
+  Ok(())
+}
+
+fn pool_rounds(network: &LoopbackNetwork, nodes: &mut [PoolNode], count: usize) -> TestResult {
+  for _ in 0..count {
+    for test_node in nodes.iter() {
+      test_node.router.membership().tick();
+      test_node.router.tick();
+    }
+    for _ in 0..3 {
+      network.flush();
+      for test_node in nodes.iter_mut() {
+        test_node.system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+      }
+    }
+  }
+  Ok(())
+}
+
+fn taken(received: &Received) -> Vec<(u16, u32)> {
+  let mut taken = core::mem::take(&mut *received.lock().unwrap_or_else(|err| err.into_inner()));
+  taken.sort_unstable();
+  taken
+}
+
+#[test]
+fn group_router_spreads_messages_over_the_routees_of_every_member() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let mut nodes = vec![
+    group_node(&network, 2551, &received)?,
+    group_node(&network, 2552, &received)?,
+    group_node(&network, 2553, &received)?,
+  ];
+  for test_node in &nodes {
+    test_node.router.membership().join();
+  }
+  group_rounds(&network, &mut nodes, 4)?;
+  assert_eq!(nodes[0].router.routees().len(), 3);
+
+  for id in 0..3 {
+    nodes[0].router.tell(&Work { id }).map_err(|err| format!("tell: {err}"))?;
+  }
+  group_rounds(&network, &mut nodes, 1)?;
+  let ports: Vec<u16> = taken(&received).into_iter().map(|(port, _)| port).collect();
+  assert_eq!(ports, vec![2551, 2552, 2553]);
+
+  let broadcast = nodes[1].router.clone().with_logic(ClusterRoutingLogic::Broadcast);
+  broadcast.tell(&Work { id: 7 }).map_err(|err| format!("tell: {err}"))?;
+  group_rounds(&network, &mut nodes, 1)?;
+  assert_eq!(taken(&received), vec![(2551, 7), (2552, 7), (2553, 7)]);
+
+  // Members leaving the cluster stop receiving messages without any bookkeeping.
+  nodes[0].router.membership().down(&node(2553));
+  for id in 0..4 {
+    nodes[0].router.tell(&Work { id }).map_err(|err| format!("tell: {err}"))?;
+  }
+  group_rounds(&network, &mut nodes, 1)?;
+  let ports: Vec<u16> = taken(&received).into_iter().map(|(port, _)| port).collect();
+  assert_eq!(ports, vec![2551, 2551, 2552, 2552]);
+  Ok(())
+}
+
+#[test]
+fn group_router_without_routees_reports_it() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let mut nodes = vec![group_node(&network, 2551, &received)?];
+  assert_eq!(nodes[0].router.tell(&Work { id: 1 }), Err(ClusterRouterError::NoRoutees));
+
+  nodes[0].router.membership().join();
+  group_rounds(&network, &mut nodes, 2)?;
+  assert!(nodes[0].router.local_routee().is_some());
+  nodes[0].router.unregister();
+  assert_eq!(nodes[0].router.tell(&Work { id: 1 }), Err(ClusterRouterError::NoRoutees));
+  Ok(())
+}
+
+#[test]
+fn pool_router_deploys_routees_on_other_members_and_follows_membership() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let mut nodes = vec![
+    pool_node(&network, 2551, &received)?,
+    pool_node(&network, 2552, &received)?,
+    pool_node(&network, 2553, &received)?,
+  ];
+  assert_eq!(nodes[0].router.tell(&Work { id: 1 }), Err(ClusterRouterError::NoRoutees));
+  for test_node in &nodes {
+    test_node.router.membership().join();
+  }
+  pool_rounds(&network, &mut nodes, 6)?;
+
+  let routees = nodes[0].router.routees();
+  let hosts: Vec<Option<u16>> = routees.iter().map(|pid| pid.node().and_then(NodeId::port)).collect();
+  assert_eq!(hosts, vec![Some(2552), Some(2552), Some(2553), Some(2553)]);
+  for id in 0..4 {
+    nodes[0].router.tell(&Work { id }).map_err(|err| format!("tell: {err}"))?;
+  }
+  pool_rounds(&network, &mut nodes, 1)?;
+  assert_eq!(taken(&received), vec![(2552, 0), (2552, 1), (2553, 2), (2553, 3)]);
+
+  let _tracking = nodes[0].router.track();
+  nodes[0].router.membership().down(&node(2553));
+  let hosts: Vec<Option<u16>> = nodes[0].router.routees().iter().map(|pid| pid.node().and_then(NodeId::port)).collect();
+  assert_eq!(hosts, vec![Some(2552), Some(2552)]);
+  Ok(())
+}
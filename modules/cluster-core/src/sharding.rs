@@ -0,0 +1,18 @@
+mod cluster_sharding;
+mod hash_shard_extractor;
+mod message_extractor;
+mod shard_region;
+mod sharding_config;
+mod sharding_error;
+mod sharding_protocol;
+mod sharding_state;
+
+#[cfg(test)]
+mod tests;
+
+pub use cluster_sharding::ClusterSharding;
+pub use hash_shard_extractor::HashShardExtractor;
+pub use message_extractor::MessageExtractor;
+pub use shard_region::ShardRegion;
+pub use sharding_config::ShardingConfig;
+pub use sharding_error::ShardingError;
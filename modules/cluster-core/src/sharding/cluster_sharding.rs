@@ -0,0 +1,499 @@
+use alloc::{
+  collections::BTreeMap,
+  format,
+  string::{String, ToString},
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::ActorPath,
+    process::pid::{NodeId, Pid, PidTag},
+  },
+  shared::mailbox::MailboxFactory,
+};
+use cellex_remote_core_rs::{
+  codec::{RemoteDelivery, RemotePayloadFrame},
+  delivery::{RemoteFrameInterceptor, RemoteInboundDispatcher, RemoteMessage, RemoteProcessRegistry},
+  outbound::RemoteOutbound,
+  transport::{RemoteTransport, TransportError},
+};
+use cellex_serialization_core_rs::message::SerializedMessage;
+use cellex_utils_core_rs::{
+  collections::queue::priority::DEFAULT_PRIORITY,
+  sync::{shared::SharedBound, ArcShared},
+};
+use spin::RwLock;
+
+use super::{
+  sharding_protocol::ShardingProtocol, sharding_state::ShardingState, MessageExtractor, ShardRegion, ShardingConfig,
+  ShardingError,
+};
+use crate::{
+  membership::{ClusterMembership, MemberStatus},
+  virtual_actor::{ClusterIdentity, PendingDelivery, VirtualActors},
+  CLUSTER_SERIALIZER_ID,
+};
+
+pub(super) const SHARDING_TAG: &str = "sharding";
+const ENTITY_TAG_PREFIX: &str = "shard:";
+pub(super) const PROTOCOL_TYPE_NAME: &str = "cellex.cluster.ShardingProtocol";
+
+/// Sharded entities spread over the cluster in shards that move between members.
+///
+/// Messages sent through a [`ShardRegion`] are mapped to an entity and a shard by a
+/// [`MessageExtractor`]. Each shard lives on one member, its home, and its entities run there as
+/// activations of the [`VirtualActors`] given to [`ClusterSharding::new`]: they are spawned from
+/// the [`ClusterKinds`](crate::virtual_actor::ClusterKinds) entry named after the entity type
+/// and passivate when idle.
+///
+/// The coordinator, running on the oldest member, allocates every new shard to the member hosting
+/// the fewest shards and replicates the homes to all members. On each
+/// [`ClusterSharding::tick`] it rebalances: shards of leaving members and shards of the most
+/// loaded member are handed off, i.e. their entities are stopped, and allocated again. Messages
+/// for a shard whose home is unknown or moving are held back until the new home is known.
+pub struct ClusterSharding<T>
+where
+  T: RemoteTransport, {
+  actors: VirtualActors<T>,
+  config: ShardingConfig,
+  state:  ArcShared<RwLock<ShardingState>>,
+}
+
+impl<T> Clone for ClusterSharding<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self { actors: self.actors.clone(), config: self.config, state: self.state.clone() }
+  }
+}
+
+impl<T> ClusterSharding<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates sharding hosting entities through `actors`.
+  #[must_use]
+  pub fn new(actors: VirtualActors<T>, config: ShardingConfig) -> Self {
+    Self { actors, config, state: ArcShared::new(RwLock::new(ShardingState::default())) }
+  }
+
+  /// Returns the virtual actors hosting the local entities.
+  #[must_use]
+  pub const fn actors(&self) -> &VirtualActors<T> {
+    &self.actors
+  }
+
+  /// Returns the membership the shards are spread over.
+  #[must_use]
+  pub const fn membership(&self) -> &ClusterMembership<T> {
+    self.actors.membership()
+  }
+
+  /// Returns the outbound side used to reach other members.
+  #[must_use]
+  pub const fn outbound(&self) -> &RemoteOutbound<T> {
+    self.actors.outbound()
+  }
+
+  /// Returns the sharding settings.
+  #[must_use]
+  pub const fn config(&self) -> &ShardingConfig {
+    &self.config
+  }
+
+  /// Returns the member running the coordinator, or `None` while no member is up.
+  #[must_use]
+  pub fn coordinator(&self) -> Option<NodeId> {
+    self.membership().oldest()
+  }
+
+  /// Returns a handle sending messages to the entities of `type_name`.
+  ///
+  /// Every node must use the same type name and an equivalent extractor for the type.
+  #[must_use]
+  pub fn region<M, E>(&self, type_name: impl Into<String>, extractor: E) -> ShardRegion<T, M, E>
+  where
+    M: RemoteMessage,
+    E: MessageExtractor<M>, {
+    self.outbound().register_message::<M>();
+    ShardRegion::new(self.clone(), type_name.into(), extractor)
+  }
+
+  /// Returns the known home of every allocated shard of `type_name`, by shard id.
+  #[must_use]
+  pub fn shard_homes(&self, type_name: &str) -> BTreeMap<String, NodeId> {
+    self
+      .state
+      .read()
+      .homes
+      .iter()
+      .filter_map(|(key, home)| {
+        let (kind, shard) = key.split_once('/')?;
+        (kind == type_name).then(|| (shard.to_string(), home.clone()))
+      })
+      .collect()
+  }
+
+  /// Rebalances the shards when the local node runs the coordinator, and asks again for the
+  /// homes of the shards messages are held back for.
+  pub fn tick(&self) {
+    if self.is_coordinator() {
+      self.rebalance();
+      self.broadcast();
+    }
+    let held: Vec<String> = self.state.read().held.keys().cloned().collect();
+    for key in &held {
+      self.request_home(key);
+    }
+  }
+
+  /// Handles protocol messages and messages for entities, returning every other delivery.
+  #[must_use]
+  pub fn handle_frame(&self, delivery: RemoteDelivery) -> Option<RemoteDelivery> {
+    let RemoteDelivery { target, frame } = delivery;
+    let Some(tag) = target.tag().map(|tag| tag.0.clone()) else {
+      return Some(RemoteDelivery::new(target, frame));
+    };
+    if tag == SHARDING_TAG {
+      let RemotePayloadFrame::User { serialized } = &frame.payload else {
+        return Some(RemoteDelivery::new(target, frame));
+      };
+      if serialized.serializer_id != CLUSTER_SERIALIZER_ID
+        || serialized.type_name.as_deref() != Some(PROTOCOL_TYPE_NAME)
+      {
+        return Some(RemoteDelivery::new(target, frame));
+      }
+      // Malformed messages are dropped; regions ask again and the coordinator repeats the homes.
+      if let Ok(message) = ShardingProtocol::decode(&serialized.payload) {
+        self.handle_protocol(message, frame.reply_to.as_ref().and_then(Pid::node));
+      }
+      return None;
+    }
+    let entity = tag.strip_prefix(ENTITY_TAG_PREFIX).and_then(|key| {
+      let (kind, rest) = key.split_once('/')?;
+      let (shard, entity) = rest.split_once('/')?;
+      Some((shard.to_string(), ClusterIdentity::new(kind, entity)))
+    });
+    match entity {
+      | Some((shard, identity)) => {
+        // Undeliverable messages are dropped, as for any unknown target.
+        let _ = self.deliver(&shard, identity, PendingDelivery::Remote(frame));
+        None
+      },
+      | None => Some(RemoteDelivery::new(target, frame)),
+    }
+  }
+
+  pub(crate) fn deliver(
+    &self,
+    shard_id: &str,
+    identity: ClusterIdentity,
+    delivery: PendingDelivery,
+  ) -> Result<(), ShardingError> {
+    let key = format!("{}/{shard_id}", identity.kind());
+    {
+      let mut state = self.state.write();
+      if let Some(held) = state.held.get_mut(&key) {
+        held.push((identity, delivery));
+        return Ok(());
+      }
+      if let Some(home) = state.homes.get(&key).cloned() {
+        return self.send_home(&mut state, &home, &key, &identity, delivery);
+      }
+      state.held.insert(key.clone(), vec![(identity, delivery)]);
+    }
+    self.request_home(&key);
+    Ok(())
+  }
+
+  fn send_home(
+    &self,
+    state: &mut ShardingState,
+    home: &NodeId,
+    key: &str,
+    identity: &ClusterIdentity,
+    delivery: PendingDelivery,
+  ) -> Result<(), ShardingError> {
+    if home == self.membership().local() {
+      state.entities.entry(key.to_string()).or_default().insert(identity.identity().to_string());
+      return self.actors.route_to(identity, delivery).map_err(ShardingError::from);
+    }
+    let target = Pid::new(self.outbound().system().clone(), ActorPath::new())
+      .with_node(home.clone())
+      .with_tag(PidTag::new(format!("{ENTITY_TAG_PREFIX}{key}/{}", identity.identity())));
+    match delivery {
+      | PendingDelivery::Remote(frame) => self.outbound().forward(&target, frame),
+      | PendingDelivery::Local(envelope) => {
+        let (message, priority) = envelope.into_parts();
+        self.outbound().send_any(&target, &message, priority)
+      },
+    }
+    .map_err(ShardingError::from)
+  }
+
+  fn handle_protocol(&self, message: ShardingProtocol, sender: Option<&NodeId>) {
+    match message {
+      | ShardingProtocol::GetShardHome(key) => {
+        if !self.is_coordinator() {
+          return;
+        }
+        let allocated = {
+          let mut state = self.state.write();
+          !state.homes.contains_key(&key) && !state.rebalancing.contains_key(&key) && self.allocate(&mut state, key)
+        };
+        if allocated {
+          self.broadcast();
+        } else if let Some(sender) = sender {
+          self.send_homes(sender);
+        }
+      },
+      | ShardingProtocol::ShardHomes { version, homes } => {
+        let mut state = self.state.write();
+        if version > state.version {
+          state.version = version;
+          state.homes = homes;
+          self.flush(&mut state);
+        }
+      },
+      | ShardingProtocol::HandOff(key) => {
+        let entities = {
+          let mut state = self.state.write();
+          // Until the coordinator replicates the next home, messages for the shard are held back
+          // and its home asked again instead of activating its entities here once more. The version
+          // is left alone so that the homes replicated next still replace the local ones.
+          state.homes.remove(&key);
+          state.entities.remove(&key).unwrap_or_default()
+        };
+        let kind = key.split_once('/').map_or(key.as_str(), |(kind, _)| kind);
+        for entity in entities {
+          let _ = self.actors.passivate(&ClusterIdentity::new(kind, entity));
+        }
+        if let Some(sender) = sender {
+          self.send_protocol(sender, &ShardingProtocol::ShardStopped(key));
+        }
+      },
+      | ShardingProtocol::ShardStopped(key) => {
+        if !self.is_coordinator() {
+          return;
+        }
+        let allocated = {
+          let mut state = self.state.write();
+          state.rebalancing.remove(&key).is_some() && self.allocate(&mut state, key)
+        };
+        if allocated {
+          self.broadcast();
+        }
+      },
+    }
+  }
+
+  fn rebalance(&self) {
+    let members = self.membership().members();
+    let up: Vec<NodeId> =
+      members.iter().filter(|member| member.status() == MemberStatus::Up).map(|member| member.node().clone()).collect();
+    let status = |node: &NodeId| members.iter().find(|member| member.node() == node).map(|member| member.status());
+    let mut hand_offs = Vec::new();
+    {
+      let mut state = self.state.write();
+      let mut changed = false;
+      // Leaving and exiting members stop the entities of their shards before these move; the
+      // shards of downed or removed members move right away.
+      let homes: Vec<(String, NodeId)> = state.homes.iter().map(|(key, home)| (key.clone(), home.clone())).collect();
+      for (key, home) in homes {
+        match status(&home) {
+          | Some(MemberStatus::Up) => {},
+          | Some(MemberStatus::Leaving | MemberStatus::Exiting) => hand_offs.push((key, home)),
+          | _ => {
+            state.homes.remove(&key);
+            changed |= self.allocate(&mut state, key);
+          },
+        }
+      }
+      let stalled: Vec<String> = state
+        .rebalancing
+        .iter()
+        .filter(|(_, source)| {
+          !matches!(status(source), Some(MemberStatus::Up | MemberStatus::Leaving | MemberStatus::Exiting))
+        })
+        .map(|(key, _)| key.clone())
+        .collect();
+      for key in stalled {
+        state.rebalancing.remove(&key);
+        changed |= self.allocate(&mut state, key);
+      }
+      if !up.is_empty() {
+        let mut counts: BTreeMap<String, (usize, NodeId)> =
+          up.iter().map(|node| (node.to_string(), (0, node.clone()))).collect();
+        for home in state.homes.values() {
+          if let Some((count, _)) = counts.get_mut(&home.to_string()) {
+            *count += 1;
+          }
+        }
+        while state.rebalancing.len() + hand_offs.len() < self.config.max_simultaneous_rebalance() {
+          let (Some(most), Some(least)) = (
+            counts
+              .iter()
+              .max_by_key(|(name, (count, _))| (*count, core::cmp::Reverse(*name)))
+              .map(|(name, _)| name.clone()),
+            counts.iter().min_by_key(|(name, (count, _))| (*count, *name)).map(|(name, _)| name.clone()),
+          ) else {
+            break;
+          };
+          let (most_count, most_node) = counts[&most].clone();
+          if most_count.saturating_sub(counts[&least].0) <= self.config.rebalance_threshold() {
+            break;
+          }
+          let Some(key) = state
+            .homes
+            .iter()
+            .find(|(key, home)| **home == most_node && !hand_offs.iter().any(|(moving, _)| moving == *key))
+            .map(|(key, _)| key.clone())
+          else {
+            break;
+          };
+          hand_offs.push((key, most_node));
+          if let Some(entry) = counts.get_mut(&most) {
+            entry.0 -= 1;
+          }
+          if let Some(entry) = counts.get_mut(&least) {
+            entry.0 += 1;
+          }
+        }
+      }
+      for (key, home) in &hand_offs {
+        state.homes.remove(key);
+        state.rebalancing.insert(key.clone(), home.clone());
+        changed = true;
+      }
+      if changed {
+        state.version += 1;
+      }
+    }
+    for (key, home) in hand_offs {
+      self.send_protocol(&home, &ShardingProtocol::HandOff(key));
+    }
+  }
+
+  /// Allocates `key` to the member that is up and hosts the fewest shards.
+  fn allocate(&self, state: &mut ShardingState, key: String) -> bool {
+    let mut counts: BTreeMap<String, (usize, NodeId)> = self
+      .membership()
+      .members()
+      .iter()
+      .filter(|member| member.status() == MemberStatus::Up)
+      .map(|member| (member.node().to_string(), (0, member.node().clone())))
+      .collect();
+    for home in state.homes.values() {
+      if let Some((count, _)) = counts.get_mut(&home.to_string()) {
+        *count += 1;
+      }
+    }
+    let Some((_, (_, home))) = counts.into_iter().min_by_key(|(name, (count, _))| (*count, name.clone())) else {
+      return false;
+    };
+    state.homes.insert(key, home);
+    state.version += 1;
+    true
+  }
+
+  /// Hands the held messages of every shard with a known home to that home.
+  fn flush(&self, state: &mut ShardingState) {
+    let known: Vec<String> = state.held.keys().filter(|key| state.homes.contains_key(*key)).cloned().collect();
+    for key in known {
+      let (Some(held), Some(home)) = (state.held.remove(&key), state.homes.get(&key).cloned()) else {
+        continue;
+      };
+      for (identity, delivery) in held {
+        // Messages that cannot be delivered are dropped, as for any unknown target.
+        let _ = self.send_home(state, &home, &key, &identity, delivery);
+      }
+    }
+  }
+
+  /// Flushes the local held messages and sends the shard homes to every other member.
+  fn broadcast(&self) {
+    self.flush(&mut self.state.write());
+    let local = self.membership().local().clone();
+    for member in self.membership().members() {
+      if member.status().is_active() && *member.node() != local {
+        self.send_homes(member.node());
+      }
+    }
+  }
+
+  fn send_homes(&self, node: &NodeId) {
+    let message = {
+      let state = self.state.read();
+      ShardingProtocol::ShardHomes { version: state.version, homes: state.homes.clone() }
+    };
+    self.send_protocol(node, &message);
+  }
+
+  fn request_home(&self, key: &str) {
+    if let Some(coordinator) = self.coordinator() {
+      self.send_protocol(&coordinator, &ShardingProtocol::GetShardHome(key.to_string()));
+    }
+  }
+
+  fn send_protocol(&self, node: &NodeId, message: &ShardingProtocol) {
+    let local = self.membership().local();
+    if node == local {
+      self.handle_protocol(message.clone(), Some(local));
+      return;
+    }
+    let Ok(payload) = message.encode() else {
+      return;
+    };
+    let serialized = SerializedMessage::new(CLUSTER_SERIALIZER_ID, payload).with_type_name(PROTOCOL_TYPE_NAME);
+    // Lost messages are repeated: regions ask again on every tick and the coordinator answers.
+    let _ = self.outbound().send_control(
+      &self.sharding_pid(node),
+      RemotePayloadFrame::User { serialized },
+      DEFAULT_PRIORITY,
+      Some(self.sharding_pid(local)),
+    );
+  }
+
+  fn is_coordinator(&self) -> bool {
+    self.coordinator().as_ref() == Some(self.membership().local())
+  }
+
+  fn sharding_pid(&self, node: &NodeId) -> Pid {
+    Pid::new(self.outbound().system().clone(), ActorPath::new())
+      .with_node(node.clone())
+      .with_tag(PidTag::new(SHARDING_TAG))
+  }
+}
+
+impl<T> ClusterSharding<T>
+where
+  T: RemoteTransport + 'static,
+  Self: SharedBound,
+{
+  /// Handles the sharding protocol and messages for entities arriving through `dispatcher`.
+  #[must_use]
+  pub fn attach<MF>(&self, dispatcher: RemoteInboundDispatcher<MF>) -> RemoteInboundDispatcher<MF>
+  where
+    MF: MailboxFactory + 'static, {
+    let sharding = self.clone();
+    dispatcher
+      .with_interceptor(RemoteFrameInterceptor::new(move |delivery: RemoteDelivery| sharding.handle_frame(delivery)))
+  }
+
+  /// Starts listening on the local node with the membership, the virtual actors and the sharding
+  /// attached to inbound delivery.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the transport cannot listen on the local node.
+  pub fn start<MF>(&self, registry: ArcShared<RemoteProcessRegistry<MF>>) -> Result<(), TransportError>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    let dispatcher = self.outbound().inbound_dispatcher(registry);
+    let dispatcher = self.attach(self.actors.attach(self.membership().attach(dispatcher)));
+    self.outbound().endpoints().start(dispatcher)
+  }
+}
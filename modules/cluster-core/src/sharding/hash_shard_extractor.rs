@@ -0,0 +1,44 @@
+use alloc::string::{String, ToString};
+
+use cellex_utils_core_rs::sync::shared::SharedBound;
+
+use super::MessageExtractor;
+use crate::partition::stable_hash;
+
+/// Extractor spreading entities over a fixed number of shards by the hash of their id.
+///
+/// Shards are named `0` to `number_of_shards - 1`. The number of shards must stay the same for the
+/// lifetime of the cluster; about ten times the largest expected number of members is a good
+/// choice.
+#[derive(Debug, Clone)]
+pub struct HashShardExtractor<F> {
+  number_of_shards: u32,
+  entity_id:        F,
+}
+
+impl<F> HashShardExtractor<F> {
+  /// Creates an extractor reading entity ids with `entity_id` and hashing them into
+  /// `number_of_shards` shards, at least one.
+  pub fn new(number_of_shards: u32, entity_id: F) -> Self {
+    Self { number_of_shards: number_of_shards.max(1), entity_id }
+  }
+
+  /// Returns the number of shards.
+  #[must_use]
+  pub const fn number_of_shards(&self) -> u32 {
+    self.number_of_shards
+  }
+}
+
+impl<M, F> MessageExtractor<M> for HashShardExtractor<F>
+where
+  F: Fn(&M) -> String + SharedBound + 'static,
+{
+  fn entity_id(&self, message: &M) -> String {
+    (self.entity_id)(message)
+  }
+
+  fn shard_id(&self, message: &M) -> String {
+    (stable_hash(&[self.entity_id(message).as_bytes()]) % u64::from(self.number_of_shards)).to_string()
+  }
+}
@@ -0,0 +1,14 @@
+use alloc::string::String;
+
+use cellex_utils_core_rs::sync::shared::SharedBound;
+
+/// Maps the messages of a sharded entity type to the entity and the shard they are addressed to.
+///
+/// Every node must extract the same ids from the same message. Shard ids must not contain `/`.
+pub trait MessageExtractor<M>: SharedBound + 'static {
+  /// Returns the id of the entity `message` is addressed to.
+  fn entity_id(&self, message: &M) -> String;
+
+  /// Returns the id of the shard hosting the entity `message` is addressed to.
+  fn shard_id(&self, message: &M) -> String;
+}
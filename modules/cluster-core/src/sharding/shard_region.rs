@@ -0,0 +1,71 @@
+use alloc::{format, string::String};
+use core::marker::PhantomData;
+
+use cellex_actor_core_rs::shared::{
+  mailbox::messages::PriorityEnvelope,
+  messaging::{AnyMessage, MessageEnvelope},
+};
+use cellex_remote_core_rs::{delivery::RemoteMessage, transport::RemoteTransport};
+use cellex_utils_core_rs::{collections::queue::priority::DEFAULT_PRIORITY, sync::ArcShared};
+
+use super::{ClusterSharding, MessageExtractor, ShardingError};
+use crate::virtual_actor::{ClusterIdentity, PendingDelivery};
+
+/// Handle sending messages to the sharded entities of one type, obtained from
+/// [`ClusterSharding::region`].
+pub struct ShardRegion<T, M, E>
+where
+  T: RemoteTransport, {
+  sharding:  ClusterSharding<T>,
+  type_name: String,
+  extractor: ArcShared<E>,
+  _marker:   PhantomData<fn(M)>,
+}
+
+impl<T, M, E> Clone for ShardRegion<T, M, E>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self {
+      sharding:  self.sharding.clone(),
+      type_name: self.type_name.clone(),
+      extractor: self.extractor.clone(),
+      _marker:   PhantomData,
+    }
+  }
+}
+
+impl<T, M, E> ShardRegion<T, M, E>
+where
+  T: RemoteTransport,
+  M: RemoteMessage,
+  E: MessageExtractor<M>,
+{
+  pub(crate) fn new(sharding: ClusterSharding<T>, type_name: String, extractor: E) -> Self {
+    Self { sharding, type_name, extractor: ArcShared::new(extractor), _marker: PhantomData }
+  }
+
+  /// Returns the entity type name.
+  #[must_use]
+  pub fn type_name(&self) -> &str {
+    &self.type_name
+  }
+
+  /// Sends `message` to its entity, wherever its shard lives.
+  ///
+  /// Messages are held back while the coordinator allocates or moves the shard.
+  ///
+  /// # Errors
+  /// Returns [`ShardingError`] when the type name or the shard id contains `/`, or the message
+  /// cannot be delivered to the entity or sent to the home of its shard.
+  pub fn tell(&self, message: M) -> Result<(), ShardingError> {
+    let shard_id = self.extractor.shard_id(&message);
+    if self.type_name.contains('/') || shard_id.contains('/') {
+      return Err(ShardingError::InvalidId(format!("{}/{shard_id}", self.type_name)));
+    }
+    let identity = ClusterIdentity::new(self.type_name.clone(), self.extractor.entity_id(&message));
+    let envelope = PriorityEnvelope::new(AnyMessage::new(MessageEnvelope::user(message)), DEFAULT_PRIORITY);
+    self.sharding.deliver(&shard_id, identity, PendingDelivery::Local(envelope))
+  }
+}
@@ -0,0 +1,50 @@
+/// Settings of [`ClusterSharding`](super::ClusterSharding).
+///
+/// The coordinator moves shards from the member hosting the most shards to the one hosting the
+/// fewest while they differ by more than the rebalance threshold, at most a few shards at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShardingConfig {
+  rebalance_threshold:        usize,
+  max_simultaneous_rebalance: usize,
+}
+
+impl ShardingConfig {
+  /// Creates a configuration rebalancing as soon as two members differ by more than one shard,
+  /// moving up to 3 shards at a time.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { rebalance_threshold: 1, max_simultaneous_rebalance: 3 }
+  }
+
+  /// Sets by how many shards two members may differ before shards are moved.
+  #[must_use]
+  pub const fn with_rebalance_threshold(mut self, rebalance_threshold: usize) -> Self {
+    self.rebalance_threshold = rebalance_threshold;
+    self
+  }
+
+  /// Sets how many shards may be moving at the same time.
+  #[must_use]
+  pub const fn with_max_simultaneous_rebalance(mut self, max_simultaneous_rebalance: usize) -> Self {
+    self.max_simultaneous_rebalance = max_simultaneous_rebalance;
+    self
+  }
+
+  /// Returns by how many shards two members may differ before shards are moved.
+  #[must_use]
+  pub const fn rebalance_threshold(&self) -> usize {
+    self.rebalance_threshold
+  }
+
+  /// Returns how many shards may be moving at the same time.
+  #[must_use]
+  pub const fn max_simultaneous_rebalance(&self) -> usize {
+    self.max_simultaneous_rebalance
+  }
+}
+
+impl Default for ShardingConfig {
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -0,0 +1,19 @@
+use alloc::string::String;
+
+use cellex_remote_core_rs::outbound::RemoteSendError;
+
+use crate::virtual_actor::VirtualActorError;
+
+/// Errors raised while sending a message to a sharded entity.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ShardingError {
+  /// The entity type name or the shard id contains `/`.
+  #[error("invalid entity type or shard id: {0}")]
+  InvalidId(String),
+  /// The message could not be delivered to the entity on the local node.
+  #[error(transparent)]
+  Entity(#[from] VirtualActorError),
+  /// The message could not be sent to the node hosting the shard.
+  #[error(transparent)]
+  Send(#[from] RemoteSendError),
+}
@@ -0,0 +1,75 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use cellex_remote_core_rs::codec::{RemoteCodecError, WireReader, WireWriter};
+
+const GET_SHARD_HOME: u8 = 0;
+const SHARD_HOMES: u8 = 1;
+const HAND_OFF: u8 = 2;
+const SHARD_STOPPED: u8 = 3;
+
+/// Messages exchanged between the shard regions and the coordinator. Shards are keyed by
+/// `type/shard`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ShardingProtocol {
+  /// Asks the coordinator to allocate the shard, or to tell where it lives.
+  GetShardHome(String),
+  /// Every allocated shard with its home, at the given version.
+  ShardHomes { version: u64, homes: BTreeMap<String, NodeId> },
+  /// Asks the region hosting the shard to stop its entities.
+  HandOff(String),
+  /// Confirms that the entities of the shard were stopped.
+  ShardStopped(String),
+}
+
+impl ShardingProtocol {
+  pub(crate) fn encode(&self) -> Result<Vec<u8>, RemoteCodecError> {
+    let mut writer = WireWriter::new();
+    match self {
+      | Self::GetShardHome(shard) => {
+        writer.put_u8(GET_SHARD_HOME);
+        writer.put_str(shard)?;
+      },
+      | Self::ShardHomes { version, homes } => {
+        writer.put_u8(SHARD_HOMES);
+        writer.put_u64(*version);
+        writer.put_u32(u32::try_from(homes.len()).map_err(|_| RemoteCodecError::FrameTooLarge(homes.len()))?);
+        for (shard, home) in homes {
+          writer.put_str(shard)?;
+          writer.put_node(home)?;
+        }
+      },
+      | Self::HandOff(shard) => {
+        writer.put_u8(HAND_OFF);
+        writer.put_str(shard)?;
+      },
+      | Self::ShardStopped(shard) => {
+        writer.put_u8(SHARD_STOPPED);
+        writer.put_str(shard)?;
+      },
+    }
+    Ok(writer.into_bytes())
+  }
+
+  pub(crate) fn decode(bytes: &[u8]) -> Result<Self, RemoteCodecError> {
+    let mut reader = WireReader::new(bytes);
+    let message = match reader.u8()? {
+      | GET_SHARD_HOME => Self::GetShardHome(reader.string()?),
+      | SHARD_HOMES => {
+        let version = reader.u64()?;
+        let count = reader.u32()?;
+        let mut homes = BTreeMap::new();
+        for _ in 0..count {
+          let shard = reader.string()?;
+          homes.insert(shard, reader.node()?);
+        }
+        Self::ShardHomes { version, homes }
+      },
+      | HAND_OFF => Self::HandOff(reader.string()?),
+      | SHARD_STOPPED => Self::ShardStopped(reader.string()?),
+      | other => return Err(RemoteCodecError::UnknownTag(other)),
+    };
+    reader.finish()?;
+    Ok(message)
+  }
+}
@@ -0,0 +1,24 @@
+use alloc::{
+  collections::{BTreeMap, BTreeSet},
+  string::String,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use crate::virtual_actor::{ClusterIdentity, PendingDelivery};
+
+/// Shard homes as last allocated by the coordinator, keyed by `type/shard`, together with the
+/// local bookkeeping of the region.
+#[derive(Default)]
+pub(crate) struct ShardingState {
+  /// Version of `homes`, raised by the coordinator with every allocation change.
+  pub(crate) version:     u64,
+  pub(crate) homes:       BTreeMap<String, NodeId>,
+  /// Messages held back while the home of their shard is unknown.
+  pub(crate) held:        BTreeMap<String, Vec<(ClusterIdentity, PendingDelivery)>>,
+  /// Entities that received messages in each local shard.
+  pub(crate) entities:    BTreeMap<String, BTreeSet<String>>,
+  /// Shards the coordinator is moving, with the member handing them off.
+  pub(crate) rebalancing: BTreeMap<String, NodeId>,
+}
@@ -0,0 +1,348 @@
+extern crate std;
+
+use core::time::Duration;
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  format,
+  string::{String, ToString},
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+  },
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor::{actor_context::ActorContext, ActorPath},
+  actor_runtime::GenericActorRuntime,
+  actor_system::{GenericActorSystem, GenericActorSystemConfig},
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  mailbox::messages::PriorityChannel,
+  process::pid::{NodeId, Pid, PidTag},
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_remote_core_rs::{
+  codec::{RemoteDelivery, RemoteMessageFrame, RemotePayloadFrame},
+  delivery::RemoteMessage,
+  endpoint::EndpointManager,
+  loopback::{LoopbackNetwork, LoopbackTransport},
+  outbound::RemoteOutbound,
+};
+use cellex_serialization_core_rs::{
+  error::{DeserializationError, SerializationError},
+  impl_type_key,
+  message::SerializedMessage,
+};
+use cellex_serialization_json_rs::{shared_json_serializer, SERDE_JSON_SERIALIZER_ID};
+use cellex_utils_core_rs::{collections::queue::priority::DEFAULT_PRIORITY, sync::ArcShared};
+use serde::{Deserialize, Serialize};
+
+use super::{
+  cluster_sharding::{PROTOCOL_TYPE_NAME, SHARDING_TAG},
+  sharding_protocol::ShardingProtocol,
+  ClusterSharding, HashShardExtractor, MessageExtractor, ShardRegion, ShardingConfig, ShardingError,
+};
+use crate::{
+  membership::{ClusterMembership, MembershipConfig},
+  virtual_actor::{ClusterIdentity, ClusterKinds, VirtualActivatorMessage, VirtualActors},
+  CLUSTER_SERIALIZER_ID,
+};
+
+type TestResult<T = ()> = Result<T, String>;
+type TestSystem = GenericActorSystem<VirtualActivatorMessage, TokioActorRuntime>;
+type Received = Arc<Mutex<Vec<(u16, String, u32)>>>;
+type AccountExtractor = HashShardExtractor<fn(&Deposit) -> String>;
+type Accounts = ShardRegion<LoopbackTransport, Deposit, AccountExtractor>;
+
+const SHARDS: u32 = 4;
+const ACCOUNTS: u32 = 16;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Deposit {
+  account: u32,
+  amount:  u32,
+}
+
+impl_type_key!(Deposit, "test.Deposit");
+
+impl RemoteMessage for Deposit {
+  fn encode_payload(&self) -> Result<Vec<u8>, SerializationError> {
+    serde_json::to_vec(self).map_err(|err| SerializationError::custom(err.to_string()))
+  }
+
+  fn decode_payload(bytes: &[u8]) -> Result<Self, DeserializationError> {
+    serde_json::from_slice(bytes).map_err(|err| DeserializationError::custom(err.to_string()))
+  }
+}
+
+fn account_id(message: &Deposit) -> String {
+  format!("account-{}", message.account)
+}
+
+fn extractor() -> AccountExtractor {
+  HashShardExtractor::new(SHARDS, account_id as fn(&Deposit) -> String)
+}
+
+struct TestNode {
+  system:   TestSystem,
+  sharding: ClusterSharding<LoopbackTransport>,
+  accounts: Accounts,
+}
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn started(
+  network: &LoopbackNetwork,
+  port: u16,
+  received: &Received,
+  activations: &Arc<AtomicUsize>,
+) -> TestResult<TestNode> {
+  let config = GenericActorSystemConfig::default().with_node_id(node(port));
+  let mut system = GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), config);
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(network.transport(&node(port)), system_id, node(port)));
+  let outbound = system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      let _ = extension.register_serializer(shared_json_serializer());
+      extension.bind_type::<Deposit>(SERDE_JSON_SERIALIZER_ID).map_err(|err| format!("bind: {err:?}"))?;
+      Ok::<_, String>(RemoteOutbound::new(endpoints, extension))
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())??;
+  let membership = ClusterMembership::new(outbound, MembershipConfig::new().with_seed_node(node(2551)));
+  let sharding = ClusterSharding::new(VirtualActors::new(membership), ShardingConfig::new());
+  sharding.start(system.process_registry()).map_err(|err| format!("start: {err}"))?;
+  let activator = system
+    .root_context()
+    .spawn(sharding.actors().activator_props(accounts(port, received, activations)))
+    .map_err(|err| format!("spawn: {err:?}"))?;
+  sharding.actors().serve(activator.pid().ok_or_else(|| "activator pid expected".to_string())?);
+  let accounts = sharding.region("account", extractor());
+  Ok(TestNode { system, sharding, accounts })
+}
+
+fn accounts(port: u16, received: &Received, activations: &Arc<AtomicUsize>) -> ClusterKinds<TokioActorRuntime> {
+  let kinds = ClusterKinds::new();
+  let received = received.clone();
+  let activations = activations.clone();
+  kinds.register("account", Duration::from_secs(60), move |identity: &ClusterIdentity| {
+    activations.fetch_add(1, Ordering::SeqCst);
+    let identity = identity.identity().to_string();
+    let received = received.clone();
+    move |_: &mut ActorContext<'_, '_, Deposit, TokioActorRuntime>, message: Deposit| {
+      received.lock().unwrap_or_else(|err| err.into_inner()).push((port, identity.clone(), message.amount));
+      Ok(())
+    }
+  });
+  kinds
+}
+
+fn rounds(network: &LoopbackNetwork, nodes: &mut [&mut TestNode], count: usize) -> TestResult {
+  for _ in 0..count {
+    for test_node in nodes.iter() {
+      test_node.sharding.membership().tick();
+      test_node.sharding.tick();
+    }
+    for _ in 0..3 {
+      network.flush();
+      for test_node in nodes.iter_mut() {
+        test_node.system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+      }
+    }
+  }
+  Ok(())
+}
+
+fn form_cluster(network: &LoopbackNetwork, nodes: &mut [&mut TestNode]) -> TestResult {
+  for test_node in nodes.iter() {
+    test_node.sharding.membership().join();
+  }
+  rounds(network, nodes, 4)
+}
+
+fn deposit_to_every_account(test_node: &TestNode, amount: u32) -> TestResult {
+  for account in 0..ACCOUNTS {
+    test_node.accounts.tell(Deposit { account, amount }).map_err(|err| format!("tell: {err}"))?;
+  }
+  Ok(())
+}
+
+fn taken(received: &Received) -> Vec<(u16, String, u32)> {
+  let mut taken = core::mem::take(&mut *received.lock().unwrap_or_else(|err| err.into_inner()));
+  taken.sort_unstable();
+  taken
+}
+
+fn home_of(test_node: &TestNode, account: u32) -> Option<u16> {
+  let shard = extractor().shard_id(&Deposit { account, amount: 0 });
+  test_node.sharding.shard_homes("account").get(&shard).and_then(NodeId::port)
+}
+
+fn hosted_by(test_node: &TestNode, port: u16) -> usize {
+  test_node.sharding.shard_homes("account").values().filter(|home| home.port() == Some(port)).count()
+}
+
+/// Hands `message` to the sharding of `test_node` as if the member on `from` had sent it.
+fn deliver_protocol(test_node: &TestNode, from: u16, message: &ShardingProtocol) -> TestResult {
+  let payload = message.encode().map_err(|err| format!("encode: {err}"))?;
+  let serialized = SerializedMessage::new(CLUSTER_SERIALIZER_ID, payload).with_type_name(PROTOCOL_TYPE_NAME);
+  let system = test_node.system.process_registry().system().clone();
+  let sharding_pid =
+    |node: NodeId| Pid::new(system.clone(), ActorPath::new()).with_node(node).with_tag(PidTag::new(SHARDING_TAG));
+  let target = sharding_pid(test_node.sharding.membership().local().clone());
+  let payload = RemotePayloadFrame::User { serialized };
+  let frame =
+    RemoteMessageFrame::new(DEFAULT_PRIORITY, PriorityChannel::Control, payload, Some(sharding_pid(node(from))));
+  match test_node.sharding.handle_frame(RemoteDelivery::new(target, frame)) {
+    | None => Ok(()),
+    | Some(_) => Err("protocol message not consumed".to_string()),
+  }
+}
+
+fn assert_delivered_on_homes(test_node: &TestNode, received: &Received, amount: u32) {
+  let taken = taken(received);
+  assert_eq!(taken.len(), ACCOUNTS as usize);
+  for (port, identity, deposited) in taken {
+    let account = identity.trim_start_matches("account-").parse::<u32>().unwrap_or(u32::MAX);
+    assert_eq!(home_of(test_node, account), Some(port), "{identity} received on {port}");
+    assert_eq!(deposited, amount);
+  }
+}
+
+#[test]
+fn hash_shard_extractor_maps_entities_to_stable_shards() {
+  let extractor = extractor();
+  let shards: BTreeSet<String> =
+    (0..ACCOUNTS).map(|account| extractor.shard_id(&Deposit { account, amount: 0 })).collect();
+  assert_eq!(shards.len(), SHARDS as usize);
+  for account in 0..ACCOUNTS {
+    let first = Deposit { account, amount: 1 };
+    let second = Deposit { account, amount: 2 };
+    assert_eq!(extractor.shard_id(&first), extractor.shard_id(&second));
+    assert_eq!(extractor.entity_id(&first), format!("account-{account}"));
+  }
+  assert_eq!(HashShardExtractor::new(0, account_id).number_of_shards(), 1);
+}
+
+#[test]
+fn entities_are_activated_once_on_the_home_of_their_shard() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let activations = Arc::new(AtomicUsize::new(0));
+  let mut first = started(&network, 2551, &received, &activations)?;
+  let mut second = started(&network, 2552, &received, &activations)?;
+  form_cluster(&network, &mut [&mut first, &mut second])?;
+  assert_eq!(first.sharding.coordinator(), Some(node(2551)));
+
+  deposit_to_every_account(&first, 1)?;
+  deposit_to_every_account(&second, 1)?;
+  rounds(&network, &mut [&mut first, &mut second], 2)?;
+
+  assert_eq!(first.sharding.shard_homes("account"), second.sharding.shard_homes("account"));
+  assert_eq!((hosted_by(&first, 2551), hosted_by(&first, 2552)), (2, 2));
+  let taken = taken(&received);
+  assert_eq!(taken.len(), 2 * ACCOUNTS as usize);
+  for (port, identity, _) in &taken {
+    let account = identity.trim_start_matches("account-").parse::<u32>().unwrap_or(u32::MAX);
+    assert_eq!(home_of(&first, account), Some(*port), "{identity} received on {port}");
+  }
+  assert_eq!(activations.load(Ordering::SeqCst), ACCOUNTS as usize);
+  Ok(())
+}
+
+#[test]
+fn shards_are_rebalanced_to_a_joining_member() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let activations = Arc::new(AtomicUsize::new(0));
+  let mut first = started(&network, 2551, &received, &activations)?;
+  form_cluster(&network, &mut [&mut first])?;
+  deposit_to_every_account(&first, 1)?;
+  rounds(&network, &mut [&mut first], 1)?;
+  assert_eq!(hosted_by(&first, 2551), SHARDS as usize);
+  assert_delivered_on_homes(&first, &received, 1);
+
+  let mut second = started(&network, 2552, &received, &activations)?;
+  form_cluster(&network, &mut [&mut first, &mut second])?;
+  rounds(&network, &mut [&mut first, &mut second], 2)?;
+
+  assert_eq!((hosted_by(&first, 2551), hosted_by(&first, 2552)), (2, 2));
+  assert_eq!(first.sharding.shard_homes("account"), second.sharding.shard_homes("account"));
+  deposit_to_every_account(&first, 2)?;
+  rounds(&network, &mut [&mut first, &mut second], 1)?;
+  assert_delivered_on_homes(&first, &received, 2);
+  Ok(())
+}
+
+#[test]
+fn shards_of_a_leaving_member_are_handed_off() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let activations = Arc::new(AtomicUsize::new(0));
+  let mut first = started(&network, 2551, &received, &activations)?;
+  let mut second = started(&network, 2552, &received, &activations)?;
+  form_cluster(&network, &mut [&mut first, &mut second])?;
+  deposit_to_every_account(&second, 1)?;
+  rounds(&network, &mut [&mut first, &mut second], 2)?;
+  assert_eq!(hosted_by(&first, 2552), 2);
+  assert_delivered_on_homes(&first, &received, 1);
+
+  second.sharding.membership().leave();
+  rounds(&network, &mut [&mut first, &mut second], 2)?;
+  assert_eq!(hosted_by(&first, 2551), SHARDS as usize);
+  assert!(second.sharding.actors().lookup().is_empty());
+
+  deposit_to_every_account(&first, 2)?;
+  rounds(&network, &mut [&mut first], 1)?;
+  assert_delivered_on_homes(&first, &received, 2);
+  Ok(())
+}
+
+#[test]
+fn messages_sent_while_a_shard_is_handed_off_wait_for_its_new_home() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let activations = Arc::new(AtomicUsize::new(0));
+  let mut first = started(&network, 2551, &received, &activations)?;
+  let mut second = started(&network, 2552, &received, &activations)?;
+  form_cluster(&network, &mut [&mut first, &mut second])?;
+  deposit_to_every_account(&second, 1)?;
+  rounds(&network, &mut [&mut first, &mut second], 2)?;
+  assert_delivered_on_homes(&first, &received, 1);
+
+  let account = (0..ACCOUNTS)
+    .find(|account| home_of(&second, *account) == Some(2552))
+    .ok_or_else(|| "shard on 2552 expected".to_string())?;
+  let shard = extractor().shard_id(&Deposit { account, amount: 0 });
+  deliver_protocol(&second, 2551, &ShardingProtocol::HandOff(format!("account/{shard}")))?;
+  second.accounts.tell(Deposit { account, amount: 2 }).map_err(|err| format!("tell: {err}"))?;
+  second.system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+  assert!(taken(&received).is_empty());
+
+  let mut homes: BTreeMap<String, NodeId> = second
+    .sharding
+    .shard_homes("account")
+    .into_iter()
+    .map(|(shard, home)| (format!("account/{shard}"), home))
+    .collect();
+  homes.insert(format!("account/{shard}"), node(2551));
+  let moved = ShardingProtocol::ShardHomes { version: u64::MAX, homes };
+  deliver_protocol(&first, 2551, &moved)?;
+  deliver_protocol(&second, 2551, &moved)?;
+  network.flush();
+  first.system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+  assert_eq!(taken(&received), std::vec![(2551, format!("account-{account}"), 2)]);
+  Ok(())
+}
+
+#[test]
+fn ids_containing_the_key_separator_are_rejected() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let activations = Arc::new(AtomicUsize::new(0));
+  let single = started(&network, 2551, &received, &activations)?;
+  let region = single.sharding.region("bank/account", extractor());
+  assert!(matches!(region.tell(Deposit { account: 1, amount: 1 }), Err(ShardingError::InvalidId(_))));
+  Ok(())
+}
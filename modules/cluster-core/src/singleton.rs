@@ -0,0 +1,11 @@
+mod cluster_singleton;
+mod singleton_error;
+mod singleton_manager_message;
+mod singleton_state;
+
+#[cfg(test)]
+mod tests;
+
+pub use cluster_singleton::ClusterSingleton;
+pub use singleton_error::SingletonError;
+pub use singleton_manager_message::SingletonManagerMessage;
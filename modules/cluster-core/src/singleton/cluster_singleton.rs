@@ -0,0 +1,409 @@
+use alloc::{format, string::String, vec::Vec};
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::{ActorPath, Props},
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::MetadataStorageMode,
+    process::pid::{NodeId, Pid, PidTag},
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::{AnyMessage, MessageEnvelope},
+  },
+};
+use cellex_remote_core_rs::{
+  activation::{PendingChildren, SpawnedChild},
+  codec::{RemoteDelivery, RemotePayloadFrame},
+  delivery::{RemoteFrameInterceptor, RemoteInboundDispatcher, RemoteMessage, RemoteProcessRegistry},
+  outbound::RemoteOutbound,
+  transport::{RemoteTransport, TransportError},
+};
+use cellex_serialization_core_rs::message::SerializedMessage;
+use cellex_utils_core_rs::{
+  collections::queue::priority::DEFAULT_PRIORITY,
+  sync::{shared::SharedBound, ArcShared},
+};
+use spin::RwLock;
+
+use super::{singleton_state::SingletonState, SingletonError, SingletonManagerMessage};
+use crate::{
+  membership::{ClusterMembership, MemberStatus, MembershipChange, MembershipListener, MembershipSubscription},
+  virtual_actor::{PendingDelivery, RouteFn},
+  CLUSTER_SERIALIZER_ID,
+};
+
+const SINGLETON_TAG_PREFIX: &str = "singleton:";
+const STOPPED_TYPE_NAME: &str = "cellex.cluster.SingletonStopped";
+
+/// Actor running exactly once in the cluster, on its oldest member.
+///
+/// The host is the member that has been up the longest, i.e. the one with the lowest up number.
+/// Every node runs a manager actor, built from [`ClusterSingleton::manager_props`] and announced
+/// through [`ClusterSingleton::serve`], which spawns the instance once the local node becomes the
+/// host.
+///
+/// When the host leaves, it stops its instance as soon as it is no longer up and reports the stop
+/// to the next oldest member, which takes over once it received that report or once the previous
+/// host is down or removed. Messages sent through [`ClusterSingleton::tell`] or a proxy built from
+/// [`ClusterSingleton::proxy_props`] go to the host; they are held back while no member is up or
+/// the new host waits for the previous one, and passed on to the new host when they reach the
+/// previous one.
+pub struct ClusterSingleton<T>
+where
+  T: RemoteTransport, {
+  name:       String,
+  membership: ClusterMembership<T>,
+  state:      ArcShared<RwLock<SingletonState>>,
+  route:      ArcShared<RwLock<Option<ArcShared<RouteFn>>>>,
+}
+
+impl<T> Clone for ClusterSingleton<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self {
+      name:       self.name.clone(),
+      membership: self.membership.clone(),
+      state:      self.state.clone(),
+      route:      self.route.clone(),
+    }
+  }
+}
+
+impl<T> ClusterSingleton<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates the singleton `name` hosted by the oldest member of `membership`. Every node must
+  /// use the same name.
+  #[must_use]
+  pub fn new(name: impl Into<String>, membership: ClusterMembership<T>) -> Self {
+    Self {
+      name: name.into(),
+      membership,
+      state: ArcShared::new(RwLock::new(SingletonState::default())),
+      route: ArcShared::new(RwLock::new(None)),
+    }
+  }
+
+  /// Returns the name of the singleton.
+  #[must_use]
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the membership the singleton is hosted in.
+  #[must_use]
+  pub const fn membership(&self) -> &ClusterMembership<T> {
+    &self.membership
+  }
+
+  /// Returns the outbound side used to reach other members.
+  #[must_use]
+  pub const fn outbound(&self) -> &RemoteOutbound<T> {
+    self.membership.outbound()
+  }
+
+  /// Returns the oldest member that is up, or `None` while no member is up.
+  #[must_use]
+  pub fn host(&self) -> Option<NodeId> {
+    self.membership.oldest()
+  }
+
+  /// Returns the instance running on the local node, if any.
+  #[must_use]
+  pub fn instance(&self) -> Option<Pid> {
+    self.state.read().instance.clone()
+  }
+
+  /// Hosts the singleton on the local node through the manager actor `manager` whenever the local
+  /// node is the oldest member.
+  pub fn serve(&self, manager: Pid) {
+    self.state.write().manager = Some(manager);
+    self.hand_over();
+  }
+
+  /// Sends `message` to the singleton, wherever it runs.
+  ///
+  /// # Errors
+  /// Returns [`SingletonError`] when the singleton is not started on the local node, or the
+  /// message cannot be sent to the host.
+  pub fn tell<U>(&self, message: U) -> Result<(), SingletonError>
+  where
+    U: RemoteMessage, {
+    if self.route.read().is_none() {
+      return Err(SingletonError::NotStarted);
+    }
+    let local = self.membership.local();
+    match self.host() {
+      | Some(host) if host != *local && self.state.read().held.is_empty() => {
+        let target = self.singleton_pid(&host);
+        self.outbound().send_user(&target, &message, DEFAULT_PRIORITY, None).map_err(SingletonError::from)
+      },
+      | _ => {
+        self.outbound().register_message::<U>();
+        let envelope = PriorityEnvelope::new(AnyMessage::new(MessageEnvelope::user(message)), DEFAULT_PRIORITY);
+        self.deliver(PendingDelivery::Local(envelope));
+        Ok(())
+      },
+    }
+  }
+
+  /// Routes deliveries addressed to the singleton, returning every other delivery.
+  #[must_use]
+  pub fn handle_frame(&self, delivery: RemoteDelivery) -> Option<RemoteDelivery> {
+    let RemoteDelivery { target, frame } = delivery;
+    let addressed =
+      target.tag().and_then(|tag| tag.0.strip_prefix(SINGLETON_TAG_PREFIX)).is_some_and(|name| name == self.name);
+    if !addressed {
+      return Some(RemoteDelivery::new(target, frame));
+    }
+    if let RemotePayloadFrame::User { serialized } = &frame.payload {
+      if serialized.serializer_id == CLUSTER_SERIALIZER_ID && serialized.type_name.as_deref() == Some(STOPPED_TYPE_NAME)
+      {
+        if let Some(previous) = frame.reply_to.as_ref().and_then(Pid::node) {
+          self.released(previous.clone());
+        }
+        return None;
+      }
+    }
+    self.deliver(PendingDelivery::Remote(frame));
+    None
+  }
+
+  /// Moves the singleton to the current host: starts the local instance when the local node
+  /// became the host and the previous host stopped its own, stops it when another member took
+  /// over, and hands the held messages to the instance or to the new host.
+  pub fn hand_over(&self) {
+    let Some(route) = self.route.read().clone() else {
+      return;
+    };
+    let host = self.host();
+    let local = self.membership.local();
+    let mut state = self.state.write();
+    if state.host != host {
+      let previous = core::mem::replace(&mut state.host, host.clone());
+      if host.as_ref() == Some(local) {
+        // The previous host may still run its instance until it sees the local node take over.
+        let released = state.released.take();
+        state.awaiting = previous.filter(|previous| previous != local && released.as_ref() != Some(previous));
+      } else if previous.as_ref() == Some(local) {
+        state.releasing = true;
+      }
+    }
+    if host.as_ref() == Some(local) {
+      if let Some(previous) = state.awaiting.clone() {
+        if !self.is_gone(&previous) {
+          return;
+        }
+        state.awaiting = None;
+      }
+      match state.instance.clone() {
+        | Some(instance) => {
+          for delivery in core::mem::take(&mut state.held) {
+            if let Some(delivery) = route(&instance, delivery) {
+              // The instance stopped on its own; the manager starts a new one.
+              state.instance = None;
+              state.held.push(delivery);
+            }
+          }
+          if state.instance.is_some() {
+            return;
+          }
+        },
+        | None if state.starting => return,
+        | None => {},
+      }
+      let request = AnyMessage::new(MessageEnvelope::user(SingletonManagerMessage::Start));
+      state.starting = state.manager.as_ref().is_some_and(|manager| {
+        route(manager, PendingDelivery::Local(PriorityEnvelope::new(request, DEFAULT_PRIORITY))).is_none()
+      });
+      return;
+    }
+    if let Some(instance) = state.instance.take() {
+      let _ = route(&instance, PendingDelivery::stop());
+    }
+    let Some(host) = host else {
+      return;
+    };
+    // An instance still starting is stopped once it started, and only then reported.
+    if state.releasing && !state.starting {
+      state.releasing = false;
+      self.report_stopped(&host);
+    }
+    let target = self.singleton_pid(&host);
+    // Messages the host cannot be reached for are dropped, as for any other remote send.
+    for delivery in core::mem::take(&mut state.held) {
+      let _ = match delivery {
+        | PendingDelivery::Remote(frame) => self.outbound().forward(&target, frame),
+        | PendingDelivery::Local(envelope) => {
+          let (message, priority) = envelope.into_parts();
+          self.outbound().send_any(&target, &message, priority)
+        },
+      };
+    }
+  }
+
+  fn deliver(&self, delivery: PendingDelivery) {
+    self.state.write().held.push(delivery);
+    self.hand_over();
+  }
+
+  fn released(&self, previous: NodeId) {
+    {
+      let mut state = self.state.write();
+      if state.awaiting.as_ref() == Some(&previous) {
+        state.awaiting = None;
+      } else {
+        // The report may arrive before the local node sees itself as the host.
+        state.released = Some(previous);
+      }
+    }
+    self.hand_over();
+  }
+
+  fn is_gone(&self, node: &NodeId) -> bool {
+    self
+      .membership
+      .member(node)
+      .is_none_or(|member| matches!(member.status(), MemberStatus::Down | MemberStatus::Removed))
+  }
+
+  fn report_stopped(&self, host: &NodeId) {
+    let serialized = SerializedMessage::new(CLUSTER_SERIALIZER_ID, Vec::new()).with_type_name(STOPPED_TYPE_NAME);
+    // A lost report leaves the new host waiting until the local node is down or removed.
+    let _ = self.outbound().send_control(
+      &self.singleton_pid(host),
+      RemotePayloadFrame::User { serialized },
+      DEFAULT_PRIORITY,
+      Some(self.singleton_pid(self.membership.local())),
+    );
+  }
+
+  fn started(&self, instance: Option<Pid>) {
+    {
+      let mut state = self.state.write();
+      state.starting = false;
+      state.instance = instance;
+    }
+    self.hand_over();
+  }
+
+  fn singleton_pid(&self, node: &NodeId) -> Pid {
+    Pid::new(self.outbound().system().clone(), ActorPath::new())
+      .with_node(node.clone())
+      .with_tag(PidTag::new(format!("{SINGLETON_TAG_PREFIX}{}", self.name)))
+  }
+}
+
+impl<T> ClusterSingleton<T>
+where
+  T: RemoteTransport + 'static,
+{
+  /// Builds the manager actor spawning the singleton instance from `factory` while the local
+  /// node is the host.
+  ///
+  /// Spawn the returned `Props` once per node, either at the root of a system whose message type
+  /// is [`SingletonManagerMessage`] or as a child of any other actor, and pass its PID to
+  /// [`ClusterSingleton::serve`].
+  #[must_use]
+  pub fn manager_props<M, AR, F>(&self, factory: F) -> Props<SingletonManagerMessage, AR>
+  where
+    M: RemoteMessage,
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode,
+    F: Fn() -> Props<M, AR> + 'static, {
+    self.outbound().register_message::<M>();
+    let singleton = self.clone();
+    let mut spawned: PendingChildren<()> = PendingChildren::new();
+    Props::new(move |ctx, message: SingletonManagerMessage| {
+      match message {
+        | SingletonManagerMessage::Start => {
+          let instance = SpawnedChild::new(ctx.spawn_child(factory()));
+          if spawned.push(ctx, (), instance, SingletonManagerMessage::Started).is_err() {
+            singleton.started(None);
+          }
+        },
+        | SingletonManagerMessage::Started => {
+          for ((), instance) in spawned.take() {
+            singleton.started(instance);
+          }
+        },
+      }
+      Ok(())
+    })
+  }
+
+  /// Builds a proxy actor sending every message it receives to the singleton, wherever it runs.
+  ///
+  /// The proxy can be spawned on any node; messages are held back during a hand-over like those
+  /// sent through [`ClusterSingleton::tell`].
+  #[must_use]
+  pub fn proxy_props<M, AR>(&self) -> Props<M, AR>
+  where
+    M: RemoteMessage,
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+    let singleton = self.clone();
+    Props::new(move |_, message: M| {
+      // Messages the host cannot be reached for are dropped, as for any other remote send.
+      let _ = singleton.tell(message);
+      Ok(())
+    })
+  }
+}
+
+impl<T> ClusterSingleton<T>
+where
+  T: RemoteTransport + 'static,
+  Self: SharedBound,
+{
+  /// Hands the singleton over whenever a member comes up, stops being up, or is down or removed.
+  /// The hand-over stops when the returned subscription is dropped.
+  #[must_use]
+  pub fn hand_over_on_change(&self) -> MembershipSubscription {
+    let singleton = self.clone();
+    self.membership.subscribe(MembershipListener::new(move |change: &MembershipChange| {
+      let status = change.status();
+      if matches!(status, MemberStatus::Up | MemberStatus::Down | MemberStatus::Removed)
+        || change.previous() == Some(MemberStatus::Up)
+      {
+        singleton.hand_over();
+      }
+    }))
+  }
+
+  /// Routes messages for the singleton arriving through `dispatcher`, and delivers local messages
+  /// and start requests through it.
+  #[must_use]
+  pub fn attach<MF>(&self, dispatcher: RemoteInboundDispatcher<MF>) -> RemoteInboundDispatcher<MF>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    *self.route.write() = Some(PendingDelivery::route_through(dispatcher.clone()));
+    let singleton = self.clone();
+    dispatcher
+      .with_interceptor(RemoteFrameInterceptor::new(move |delivery: RemoteDelivery| singleton.handle_frame(delivery)))
+  }
+
+  /// Starts listening on the local node with the membership and the singleton attached to
+  /// inbound delivery.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the transport cannot listen on the local node.
+  pub fn start<MF>(&self, registry: ArcShared<RemoteProcessRegistry<MF>>) -> Result<(), TransportError>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    let dispatcher = self.attach(self.membership.attach(self.outbound().inbound_dispatcher(registry)));
+    self.outbound().endpoints().start(dispatcher)
+  }
+}
@@ -0,0 +1,12 @@
+use cellex_remote_core_rs::outbound::RemoteSendError;
+
+/// Errors raised while sending a message to a cluster singleton.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum SingletonError {
+  /// The singleton is not attached to inbound delivery on the local node.
+  #[error("cluster singleton is not started on the local node")]
+  NotStarted,
+  /// The message could not be sent to the hosting node.
+  #[error(transparent)]
+  Send(#[from] RemoteSendError),
+}
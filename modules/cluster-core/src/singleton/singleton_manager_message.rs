@@ -0,0 +1,8 @@
+/// Messages handled by the manager actor of [`ClusterSingleton`](super::ClusterSingleton).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SingletonManagerMessage {
+  /// Spawns the singleton instance on the local node.
+  Start,
+  /// Internal follow-up registering the instance spawned by the previous message.
+  Started,
+}
@@ -0,0 +1,24 @@
+use alloc::vec::Vec;
+
+use cellex_actor_core_rs::api::process::pid::{NodeId, Pid};
+
+use crate::virtual_actor::PendingDelivery;
+
+/// Local side of a cluster singleton: the manager, the running instance, and the messages held
+/// back while no instance can take them.
+///
+/// `host` is the host last handed over to. `awaiting` names the previous host whose stop the
+/// local node waits for before starting its own instance, `released` the last previous host that
+/// reported its stop, and `releasing` is set while the local node still owes the next host that
+/// report.
+#[derive(Default)]
+pub(crate) struct SingletonState {
+  pub(crate) manager:   Option<Pid>,
+  pub(crate) instance:  Option<Pid>,
+  pub(crate) starting:  bool,
+  pub(crate) held:      Vec<PendingDelivery>,
+  pub(crate) host:      Option<NodeId>,
+  pub(crate) awaiting:  Option<NodeId>,
+  pub(crate) released:  Option<NodeId>,
+  pub(crate) releasing: bool,
+}
@@ -0,0 +1,257 @@
+extern crate std;
+
+use std::{
+  format,
+  string::{String, ToString},
+  sync::{Arc, Mutex},
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor::{actor_ref::ActorRef, Props},
+  actor_runtime::GenericActorRuntime,
+  actor_system::{GenericActorSystem, GenericActorSystemConfig},
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  process::pid::NodeId,
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_remote_core_rs::{
+  delivery::RemoteMessage,
+  endpoint::EndpointManager,
+  loopback::{LoopbackNetwork, LoopbackTransport},
+  outbound::RemoteOutbound,
+};
+use cellex_serialization_core_rs::{
+  error::{DeserializationError, SerializationError},
+  impl_type_key,
+};
+use cellex_serialization_json_rs::{shared_json_serializer, SERDE_JSON_SERIALIZER_ID};
+use cellex_utils_core_rs::sync::ArcShared;
+use serde::{Deserialize, Serialize};
+
+use super::{ClusterSingleton, SingletonError, SingletonManagerMessage};
+use crate::membership::{ClusterMembership, MemberStatus, MembershipConfig, MembershipSubscription};
+
+type TestResult<T = ()> = Result<T, String>;
+type TestSystem = GenericActorSystem<Add, TokioActorRuntime>;
+type Received = Arc<Mutex<Vec<(u16, u32)>>>;
+type ManagerSlot = Arc<Mutex<Option<ActorRef<SingletonManagerMessage, TokioActorRuntime>>>>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Add {
+  amount: u32,
+}
+
+impl_type_key!(Add, "test.Add");
+
+impl RemoteMessage for Add {
+  fn encode_payload(&self) -> Result<Vec<u8>, SerializationError> {
+    serde_json::to_vec(self).map_err(|err| SerializationError::custom(err.to_string()))
+  }
+
+  fn decode_payload(bytes: &[u8]) -> Result<Self, DeserializationError> {
+    serde_json::from_slice(bytes).map_err(|err| DeserializationError::custom(err.to_string()))
+  }
+}
+
+struct TestNode {
+  system:     TestSystem,
+  singleton:  ClusterSingleton<LoopbackTransport>,
+  _hand_over: MembershipSubscription,
+}
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn started(network: &LoopbackNetwork, port: u16) -> TestResult<TestNode> {
+  let config = GenericActorSystemConfig::default().with_node_id(node(port));
+  let system = GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), config);
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(network.transport(&node(port)), system_id, node(port)));
+  let outbound = system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      let _ = extension.register_serializer(shared_json_serializer());
+      extension.bind_type::<Add>(SERDE_JSON_SERIALIZER_ID).map_err(|err| format!("bind: {err:?}"))?;
+      Ok::<_, String>(RemoteOutbound::new(endpoints, extension))
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())??;
+  let membership = ClusterMembership::new(outbound, MembershipConfig::new().with_seed_node(node(2551)));
+  let singleton = ClusterSingleton::new("scheduler", membership);
+  singleton.start(system.process_registry()).map_err(|err| format!("start: {err}"))?;
+  let hand_over = singleton.hand_over_on_change();
+  Ok(TestNode { system, singleton, _hand_over: hand_over })
+}
+
+/// Spawns the manager as the child of a guardian, since the root only spawns `Add` actors.
+fn serve(test_node: &mut TestNode, port: u16, received: &Received) -> TestResult {
+  let slot: ManagerSlot = Arc::new(Mutex::new(None));
+  let spawned = slot.clone();
+  let singleton = test_node.singleton.clone();
+  let received = received.clone();
+  let guardian = test_node
+    .system
+    .root_context()
+    .spawn(Props::new(move |ctx, _: Add| {
+      let received = received.clone();
+      let props = singleton.manager_props(move || {
+        let received = received.clone();
+        Props::new(move |_, message: Add| {
+          received.lock().unwrap_or_else(|err| err.into_inner()).push((port, message.amount));
+          Ok(())
+        })
+      });
+      *spawned.lock().unwrap_or_else(|err| err.into_inner()) = Some(ctx.spawn_child(props));
+      Ok(())
+    }))
+    .map_err(|err| format!("spawn: {err:?}"))?;
+  guardian.tell(Add { amount: 0 }).map_err(|err| format!("tell: {err:?}"))?;
+  test_node.system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+  let manager = slot.lock().unwrap_or_else(|err| err.into_inner()).as_ref().and_then(ActorRef::pid);
+  test_node.singleton.serve(manager.ok_or_else(|| "manager pid expected".to_string())?);
+  Ok(())
+}
+
+fn rounds(network: &LoopbackNetwork, nodes: &mut [&mut TestNode], count: usize) -> TestResult {
+  for _ in 0..count {
+    for test_node in nodes.iter() {
+      test_node.singleton.membership().tick();
+    }
+    for _ in 0..3 {
+      network.flush();
+      for test_node in nodes.iter_mut() {
+        test_node.system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+      }
+    }
+  }
+  Ok(())
+}
+
+fn form_cluster(network: &LoopbackNetwork, nodes: &mut [&mut TestNode]) -> TestResult {
+  for test_node in nodes.iter() {
+    test_node.singleton.membership().join();
+  }
+  rounds(network, nodes, 4)
+}
+
+fn taken(received: &Received) -> Vec<(u16, u32)> {
+  let mut taken = core::mem::take(&mut *received.lock().unwrap_or_else(|err| err.into_inner()));
+  taken.sort_unstable();
+  taken
+}
+
+#[test]
+fn oldest_member_hosts_the_singleton_and_receives_messages_from_every_node() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let mut first = started(&network, 2551)?;
+  let mut second = started(&network, 2552)?;
+  let mut third = started(&network, 2553)?;
+  serve(&mut first, 2551, &received)?;
+  serve(&mut second, 2552, &received)?;
+  serve(&mut third, 2553, &received)?;
+  form_cluster(&network, &mut [&mut first, &mut second, &mut third])?;
+
+  for test_node in [&first, &second, &third] {
+    assert_eq!(test_node.singleton.host(), Some(node(2551)));
+  }
+  assert!(first.singleton.instance().is_some());
+  assert!(second.singleton.instance().is_none() && third.singleton.instance().is_none());
+
+  first.singleton.tell(Add { amount: 1 }).map_err(|err| format!("tell: {err}"))?;
+  second.singleton.tell(Add { amount: 2 }).map_err(|err| format!("tell: {err}"))?;
+  let proxy =
+    third.system.root_context().spawn(third.singleton.proxy_props()).map_err(|err| format!("spawn: {err:?}"))?;
+  proxy.tell(Add { amount: 3 }).map_err(|err| format!("tell: {err:?}"))?;
+  rounds(&network, &mut [&mut first, &mut second, &mut third], 1)?;
+
+  assert_eq!(taken(&received), vec![(2551, 1), (2551, 2), (2551, 3)]);
+  Ok(())
+}
+
+#[test]
+fn singleton_moves_to_the_next_oldest_member_when_the_host_leaves() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let mut first = started(&network, 2551)?;
+  let mut second = started(&network, 2552)?;
+  let mut third = started(&network, 2553)?;
+  serve(&mut first, 2551, &received)?;
+  serve(&mut second, 2552, &received)?;
+  serve(&mut third, 2553, &received)?;
+  form_cluster(&network, &mut [&mut first, &mut second, &mut third])?;
+  third.singleton.tell(Add { amount: 1 }).map_err(|err| format!("tell: {err}"))?;
+  rounds(&network, &mut [&mut first, &mut second, &mut third], 1)?;
+  assert_eq!(taken(&received), vec![(2551, 1)]);
+
+  first.singleton.membership().leave();
+  assert!(first.singleton.instance().is_none());
+  // The other members still see the previous host, which passes the message on.
+  third.singleton.tell(Add { amount: 2 }).map_err(|err| format!("tell: {err}"))?;
+  rounds(&network, &mut [&mut first, &mut second, &mut third], 4)?;
+
+  for test_node in [&first, &second, &third] {
+    assert_eq!(test_node.singleton.host(), Some(node(2552)));
+  }
+  assert!(second.singleton.instance().is_some());
+  assert!(third.singleton.instance().is_none());
+  third.singleton.tell(Add { amount: 3 }).map_err(|err| format!("tell: {err}"))?;
+  rounds(&network, &mut [&mut second, &mut third], 1)?;
+  assert_eq!(taken(&received), vec![(2552, 2), (2552, 3)]);
+  Ok(())
+}
+
+#[test]
+fn messages_are_held_until_a_member_hosts_the_singleton() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let mut single = started(&network, 2551)?;
+  serve(&mut single, 2551, &received)?;
+  assert_eq!(single.singleton.host(), None);
+
+  single.singleton.tell(Add { amount: 1 }).map_err(|err| format!("tell: {err}"))?;
+  single.singleton.tell(Add { amount: 2 }).map_err(|err| format!("tell: {err}"))?;
+  single.system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+  assert!(received.lock().unwrap_or_else(|err| err.into_inner()).is_empty());
+
+  form_cluster(&network, &mut [&mut single])?;
+  assert_eq!(taken(&received), vec![(2551, 1), (2551, 2)]);
+
+  let unstarted = ClusterSingleton::new("scheduler", single.singleton.membership().clone());
+  assert_eq!(unstarted.tell(Add { amount: 3 }), Err(SingletonError::NotStarted));
+  Ok(())
+}
+
+#[test]
+fn new_host_waits_for_the_previous_one_to_stop_or_be_removed() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let mut first = started(&network, 2551)?;
+  let mut second = started(&network, 2552)?;
+  let mut third = started(&network, 2553)?;
+  serve(&mut first, 2551, &received)?;
+  serve(&mut second, 2552, &received)?;
+  serve(&mut third, 2553, &received)?;
+  form_cluster(&network, &mut [&mut first, &mut second, &mut third])?;
+
+  // The report of the stop is lost on the way to the next host.
+  network.partition(&node(2551), &node(2552));
+  first.singleton.membership().leave();
+  rounds(&network, &mut [&mut first, &mut second, &mut third], 1)?;
+  assert_eq!(second.singleton.host(), Some(node(2552)));
+  assert!(second.singleton.instance().is_none());
+  third.singleton.tell(Add { amount: 1 }).map_err(|err| format!("tell: {err}"))?;
+  network.flush();
+  second.system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+  assert!(taken(&received).is_empty());
+
+  rounds(&network, &mut [&mut first, &mut second, &mut third], 2)?;
+  assert_eq!(
+    second.singleton.membership().member(&node(2551)).map(|member| member.status()),
+    Some(MemberStatus::Removed)
+  );
+  assert!(second.singleton.instance().is_some());
+  assert_eq!(taken(&received), vec![(2552, 1)]);
+  Ok(())
+}
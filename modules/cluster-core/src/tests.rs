@@ -24,6 +24,7 @@ use cellex_actor_core_rs::{
       FailureEvent, FailureInfo,
     },
     metrics::{MetricsEvent, MetricsSink, MetricsSinkShared},
+    process::pid::NodeId,
     supervision::escalation::RootEscalationSink,
     test_support::TestMailboxFactory,
   },
@@ -111,13 +112,42 @@ fn cluster_failure_bridge_fan_out_dispatches_root_escalation() -> TestResult {
   let events = lock(&hub_events)?;
   assert_eq!(events.len(), 1);
 
-  let FailureEvent::RootEscalated(received_info) = &events[0];
+  let FailureEvent::RootEscalated(received_info) = &events[0] else {
+    return Err("root escalation expected".to_string());
+  };
 
   assert_eq!(received_info.actor, info.actor);
   assert_eq!(received_info.description(), info.description());
   Ok(())
 }
 
+#[test]
+fn cluster_failure_bridge_fan_out_forwards_node_reachability_events() -> TestResult {
+  let hub = FailureEventHub::new();
+  let hub_events = Arc::new(Mutex::new(Vec::new()));
+  let hub_events_clone = Arc::clone(&hub_events);
+  let _subscription = hub.subscribe(FailureEventListener::new(move |event: FailureEvent| {
+    hub_events_clone.lock().unwrap_or_else(|err| err.into_inner()).push(event);
+  }));
+
+  let mut remote_notifier = RemoteFailureNotifier::new(FailureEventHub::new());
+  let unreachable = Arc::new(Mutex::new(Vec::new()));
+  let unreachable_clone = Arc::clone(&unreachable);
+  remote_notifier.set_handler(FailureEventListener::new(move |event: FailureEvent| {
+    if let FailureEvent::NodeUnreachable(node) = event {
+      unreachable_clone.lock().unwrap_or_else(|err| err.into_inner()).push(node);
+    }
+  }));
+
+  let bridge = ClusterFailureBridge::new(hub, remote_notifier);
+  let node = NodeId::new("10.0.0.2", Some(2552));
+  bridge.fan_out(FailureEvent::NodeUnreachable(node.clone()));
+
+  assert_eq!(lock(&unreachable)?.as_slice(), &[node]);
+  assert!(matches!(lock(&hub_events)?.as_slice(), [FailureEvent::NodeUnreachable(_)]));
+  Ok(())
+}
+
 #[test]
 fn cluster_failure_bridge_fan_out_handles_hub_listener_call() -> TestResult {
   let hub = FailureEventHub::new();
@@ -200,7 +230,9 @@ fn cluster_failure_bridge_triggers_telemetry_metrics() {
   let local_sink = Arc::new(Mutex::new(local_root));
   let local_sink_clone: Arc<Mutex<RootEscalationSink<TestMailboxFactory>>> = Arc::clone(&local_sink);
   let _local_subscription = hub.subscribe(FailureEventListener::new(move |event: FailureEvent| {
-    let FailureEvent::RootEscalated(info) = event;
+    let FailureEvent::RootEscalated(info) = event else {
+      return;
+    };
     let handle_result = local_sink_clone.lock().unwrap_or_else(|err| err.into_inner()).handle(info, false);
     assert!(handle_result.is_ok(), "local root sink should handle failure: {:?}", handle_result.err());
   }));
@@ -220,7 +252,9 @@ fn cluster_failure_bridge_triggers_telemetry_metrics() {
 
   let mut remote_notifier = RemoteFailureNotifier::new(remote_hub);
   remote_notifier.set_handler(FailureEventListener::new(move |event: FailureEvent| {
-    let FailureEvent::RootEscalated(info) = event;
+    let FailureEvent::RootEscalated(info) = event else {
+      return;
+    };
     let handle_result = remote_sink_clone.lock().unwrap_or_else(|err| err.into_inner()).handle(info, false);
     assert!(handle_result.is_ok(), "remote root sink should handle failure: {:?}", handle_result.err());
   }));
@@ -266,7 +300,9 @@ fn cluster_failure_bridge_preserves_behavior_failure() -> TestResult {
   let captured_local: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
   let captured_local_clone = Arc::clone(&captured_local);
   let _subscription = hub.subscribe(FailureEventListener::new(move |event: FailureEvent| {
-    let FailureEvent::RootEscalated(info) = event;
+    let FailureEvent::RootEscalated(info) = event else {
+      return;
+    };
     if let Some(custom) = info.behavior_failure().as_any().downcast_ref::<ClusterBehaviorFailure>() {
       captured_local_clone.lock().unwrap_or_else(|err| err.into_inner()).replace(custom.0.to_owned());
     }
@@ -278,7 +314,9 @@ fn cluster_failure_bridge_preserves_behavior_failure() -> TestResult {
   let captured_remote: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
   let captured_remote_clone = Arc::clone(&captured_remote);
   remote_notifier.set_handler(FailureEventListener::new(move |event: FailureEvent| {
-    let FailureEvent::RootEscalated(info) = event;
+    let FailureEvent::RootEscalated(info) = event else {
+      return;
+    };
     if let Some(custom) = info.behavior_failure().as_any().downcast_ref::<ClusterBehaviorFailure>() {
       captured_remote_clone.lock().unwrap_or_else(|err| err.into_inner()).replace(custom.0.to_owned());
     }
@@ -360,7 +398,9 @@ fn cluster_serialization_router_roundtrip_with_fallback() -> TestResult {
   remote_notifier.set_handler(FailureEventListener::new({
     let remote_captured = Arc::clone(&remote_captured);
     move |event: FailureEvent| {
-      let FailureEvent::RootEscalated(info) = event;
+      let FailureEvent::RootEscalated(info) = event else {
+        return;
+      };
       if let Some(custom) = info.behavior_failure().as_any().downcast_ref::<RouterBehaviorFailure>() {
         if let Some(decoded) = decode_router_payload(&router_for_remote, custom.serialized()) {
           remote_captured.lock().unwrap_or_else(|err| err.into_inner()).replace(decoded.value);
@@ -374,7 +414,9 @@ fn cluster_serialization_router_roundtrip_with_fallback() -> TestResult {
   let _subscription = hub.subscribe(FailureEventListener::new({
     let local_captured = Arc::clone(&local_captured);
     move |event: FailureEvent| {
-      let FailureEvent::RootEscalated(info) = event;
+      let FailureEvent::RootEscalated(info) = event else {
+        return;
+      };
       if let Some(custom) = info.behavior_failure().as_any().downcast_ref::<RouterBehaviorFailure>() {
         if let Some(decoded) = decode_router_payload(&router_for_local, custom.serialized()) {
           local_captured.lock().unwrap_or_else(|err| err.into_inner()).replace(decoded.value);
@@ -0,0 +1,18 @@
+mod cluster_identity;
+mod cluster_kinds;
+mod identity_lookup;
+mod pending_delivery;
+mod virtual_activator_message;
+mod virtual_actor_error;
+mod virtual_actors;
+
+#[cfg(test)]
+mod tests;
+
+pub use cluster_identity::ClusterIdentity;
+pub use cluster_kinds::ClusterKinds;
+pub use identity_lookup::IdentityLookup;
+pub(crate) use pending_delivery::{PendingDelivery, RouteFn};
+pub use virtual_activator_message::VirtualActivatorMessage;
+pub use virtual_actor_error::VirtualActorError;
+pub use virtual_actors::VirtualActors;
@@ -0,0 +1,41 @@
+use alloc::string::String;
+use core::fmt;
+
+/// Address of a virtual actor: the kind it is activated from and its identity within the kind.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClusterIdentity {
+  kind:     String,
+  identity: String,
+}
+
+impl ClusterIdentity {
+  /// Creates the address of the virtual actor `identity` of `kind`.
+  ///
+  /// Kind names must not contain `/`, which separates the kind from the identity on the wire.
+  #[must_use]
+  pub fn new(kind: impl Into<String>, identity: impl Into<String>) -> Self {
+    Self { kind: kind.into(), identity: identity.into() }
+  }
+
+  /// Returns the kind name.
+  #[must_use]
+  pub fn kind(&self) -> &str {
+    &self.kind
+  }
+
+  /// Returns the identity within the kind.
+  #[must_use]
+  pub fn identity(&self) -> &str {
+    &self.identity
+  }
+
+  pub(crate) fn parse(key: &str) -> Option<Self> {
+    key.split_once('/').map(|(kind, identity)| Self::new(kind, identity))
+  }
+}
+
+impl fmt::Display for ClusterIdentity {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}/{}", self.kind, self.identity)
+  }
+}
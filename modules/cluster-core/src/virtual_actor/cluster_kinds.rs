@@ -0,0 +1,183 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::time::Duration;
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::{actor_context::ActorContext, actor_failure::ActorFailure, Props},
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    mailbox::messages::SystemMessage,
+    messaging::MetadataStorageMode,
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
+};
+use cellex_remote_core_rs::{
+  activation::SpawnedChild,
+  delivery::{RemoteMessage, RemoteMessageRegistry},
+};
+use cellex_utils_core_rs::sync::{shared::SharedBound, ArcShared};
+use spin::RwLock;
+
+use super::{ClusterIdentity, IdentityLookup, VirtualActivatorMessage};
+
+#[cfg(target_has_atomic = "ptr")]
+type ActivateFn<AR> = dyn for<'r, 'ctx> Fn(
+    &mut ActorContext<'r, 'ctx, VirtualActivatorMessage, AR>,
+    &ClusterIdentity,
+    &IdentityLookup,
+  ) -> SpawnedChild
+  + Send
+  + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type ActivateFn<AR> = dyn for<'r, 'ctx> Fn(
+  &mut ActorContext<'r, 'ctx, VirtualActivatorMessage, AR>,
+  &ClusterIdentity,
+  &IdentityLookup,
+) -> SpawnedChild;
+
+#[cfg(target_has_atomic = "ptr")]
+type RegisterFn = dyn Fn(&RemoteMessageRegistry) + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type RegisterFn = dyn Fn(&RemoteMessageRegistry);
+
+struct ClusterKind<AR>
+where
+  AR: ActorRuntime + 'static, {
+  activate: ArcShared<ActivateFn<AR>>,
+  register: ArcShared<RegisterFn>,
+}
+
+impl<AR> Clone for ClusterKind<AR>
+where
+  AR: ActorRuntime + 'static,
+{
+  fn clone(&self) -> Self {
+    Self { activate: self.activate.clone(), register: self.register.clone() }
+  }
+}
+
+/// Kinds of virtual actors the local node can activate, by kind name.
+///
+/// Each kind builds the message handler of a new activation from its identity. Activations are
+/// children of the activator actor of [`VirtualActors`](super::VirtualActors) and passivate,
+/// i.e. stop and leave the [`IdentityLookup`], once they received no message for the idle time of
+/// their kind.
+pub struct ClusterKinds<AR>
+where
+  AR: ActorRuntime + 'static, {
+  kinds: ArcShared<RwLock<BTreeMap<String, ClusterKind<AR>>>>,
+}
+
+impl<AR> Clone for ClusterKinds<AR>
+where
+  AR: ActorRuntime + 'static,
+{
+  fn clone(&self) -> Self {
+    Self { kinds: self.kinds.clone() }
+  }
+}
+
+impl<AR> Default for ClusterKinds<AR>
+where
+  AR: ActorRuntime + 'static,
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<AR> ClusterKinds<AR>
+where
+  AR: ActorRuntime + 'static,
+{
+  /// Creates an empty set of kinds.
+  #[must_use]
+  pub fn new() -> Self {
+    Self { kinds: ArcShared::new(RwLock::new(BTreeMap::new())) }
+  }
+
+  /// Returns `true` when a kind is registered under `kind`.
+  #[must_use]
+  pub fn contains(&self, kind: &str) -> bool {
+    self.kinds.read().contains_key(kind)
+  }
+
+  /// Returns the registered kind names in ascending order.
+  #[must_use]
+  pub fn kinds(&self) -> Vec<String> {
+    self.kinds.read().keys().cloned().collect()
+  }
+
+  /// Removes the kind registered under `kind`; running activations are left alone.
+  pub fn unregister(&self, kind: &str) {
+    self.kinds.write().remove(kind);
+  }
+}
+
+impl<AR> ClusterKinds<AR>
+where
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+  MailboxSignalOf<AR>: Clone,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode,
+{
+  /// Registers `kind`, replacing any previous kind of that name.
+  ///
+  /// `factory` builds the message handler of every new activation. An activation passivates once
+  /// it received no message for `passivate_after`, which relies on the receive timeout support of
+  /// the actor runtime.
+  pub fn register<V, F, H>(&self, kind: impl Into<String>, passivate_after: Duration, factory: F)
+  where
+    V: RemoteMessage,
+    F: Fn(&ClusterIdentity) -> H + SharedBound + 'static,
+    H: for<'r, 'ctx> FnMut(&mut ActorContext<'r, 'ctx, V, AR>, V) -> Result<(), ActorFailure> + 'static, {
+    let activate = ArcShared::new(
+      move |ctx: &mut ActorContext<'_, '_, VirtualActivatorMessage, AR>,
+            identity: &ClusterIdentity,
+            lookup: &IdentityLookup| {
+        let mut handler = factory(identity);
+        let identity = identity.clone();
+        let lookup = lookup.clone();
+        let props = Props::with_system_handler(
+          move |ctx: &mut ActorContext<'_, '_, V, AR>, message: V| {
+            ctx.set_receive_timeout(passivate_after);
+            handler(ctx, message)
+          },
+          Some(move |ctx: &mut ActorContext<'_, '_, V, AR>, message: SystemMessage| {
+            if matches!(message, SystemMessage::ReceiveTimeout) {
+              lookup.remove(&identity, ctx.self_pid());
+              let _ = ctx.send_system_to_self(SystemMessage::Stop);
+            }
+          }),
+        );
+        SpawnedChild::new(ctx.spawn_child(props))
+      },
+    )
+    .into_dyn(|f| f as &ActivateFn<AR>);
+    let register =
+      ArcShared::new(|messages: &RemoteMessageRegistry| messages.register::<V>()).into_dyn(|f| f as &RegisterFn);
+    self.kinds.write().insert(kind.into(), ClusterKind { activate, register });
+  }
+
+  /// Spawns the activation of `identity` as a child of the activator owning `ctx`, or returns
+  /// `None` when its kind is unknown.
+  ///
+  /// The message type of the kind is registered with `messages` first, so that frames from other
+  /// nodes can be decoded for the new activation.
+  pub(crate) fn activate(
+    &self,
+    ctx: &mut ActorContext<'_, '_, VirtualActivatorMessage, AR>,
+    identity: &ClusterIdentity,
+    lookup: &IdentityLookup,
+    messages: &RemoteMessageRegistry,
+  ) -> Option<SpawnedChild> {
+    let kind = self.kinds.read().get(identity.kind()).cloned()?;
+    (kind.register)(messages);
+    Some((kind.activate)(ctx, identity, lookup))
+  }
+}
@@ -0,0 +1,63 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use cellex_actor_core_rs::api::process::pid::Pid;
+use cellex_utils_core_rs::sync::ArcShared;
+use spin::RwLock;
+
+use super::ClusterIdentity;
+
+/// Virtual actors currently activated on the local node, by identity.
+#[derive(Clone)]
+pub struct IdentityLookup {
+  entries: ArcShared<RwLock<BTreeMap<ClusterIdentity, Pid>>>,
+}
+
+impl Default for IdentityLookup {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl IdentityLookup {
+  /// Creates an empty lookup.
+  #[must_use]
+  pub fn new() -> Self {
+    Self { entries: ArcShared::new(RwLock::new(BTreeMap::new())) }
+  }
+
+  /// Returns the PID of the activation of `identity`, if it is active.
+  #[must_use]
+  pub fn get(&self, identity: &ClusterIdentity) -> Option<Pid> {
+    self.entries.read().get(identity).cloned()
+  }
+
+  /// Returns the identities of all activations in ascending order.
+  #[must_use]
+  pub fn identities(&self) -> Vec<ClusterIdentity> {
+    self.entries.read().keys().cloned().collect()
+  }
+
+  /// Returns the number of activations.
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.entries.read().len()
+  }
+
+  /// Returns `true` when no virtual actor is active.
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.entries.read().is_empty()
+  }
+
+  pub(crate) fn insert(&self, identity: ClusterIdentity, pid: Pid) {
+    self.entries.write().insert(identity, pid);
+  }
+
+  /// Forgets the activation of `identity` if it is still the actor at `pid`.
+  pub(crate) fn remove(&self, identity: &ClusterIdentity, pid: &Pid) {
+    let mut entries = self.entries.write();
+    if entries.get(identity).is_some_and(|active| active.path() == pid.path()) {
+      entries.remove(identity);
+    }
+  }
+}
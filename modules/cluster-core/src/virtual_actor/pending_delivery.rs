@@ -0,0 +1,56 @@
+use cellex_actor_core_rs::{
+  api::{
+    mailbox::messages::SystemMessage,
+    process::{pid::Pid, process_registry::ProcessResolution},
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
+};
+use cellex_remote_core_rs::{
+  codec::{RemoteDelivery, RemoteMessageFrame},
+  delivery::RemoteInboundDispatcher,
+};
+use cellex_utils_core_rs::sync::{shared::SharedBound, ArcShared};
+
+// Hands the delivery back when the pid no longer resolves to a local process.
+#[cfg(target_has_atomic = "ptr")]
+pub(crate) type RouteFn = dyn Fn(&Pid, PendingDelivery) -> Option<PendingDelivery> + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+pub(crate) type RouteFn = dyn Fn(&Pid, PendingDelivery) -> Option<PendingDelivery>;
+
+/// Message on its way to a virtual actor, held back while the actor is being activated.
+pub(crate) enum PendingDelivery {
+  /// Frame received from another node, decoded once the activation is known.
+  Remote(RemoteMessageFrame),
+  /// Envelope sent from the local node.
+  Local(PriorityEnvelope<AnyMessage>),
+}
+
+impl PendingDelivery {
+  /// Returns the delivery stopping the target.
+  pub(crate) fn stop() -> Self {
+    Self::Local(PriorityEnvelope::from_system(SystemMessage::Stop).map(AnyMessage::new))
+  }
+
+  /// Builds the route delivering to local processes through `dispatcher`.
+  pub(crate) fn route_through<MF>(dispatcher: RemoteInboundDispatcher<MF>) -> ArcShared<RouteFn>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    ArcShared::new(move |pid: &Pid, delivery: Self| {
+      if !matches!(dispatcher.registry().resolve_pid(pid), ProcessResolution::Local(_)) {
+        return Some(delivery);
+      }
+      // Undeliverable messages are dead-lettered by the dispatcher.
+      let _ = match delivery {
+        | Self::Remote(frame) => dispatcher.dispatch(RemoteDelivery::new(pid.clone(), frame)),
+        | Self::Local(envelope) => dispatcher.deliver(pid, envelope),
+      };
+      None
+    })
+    .into_dyn(|f| f as &RouteFn)
+  }
+}
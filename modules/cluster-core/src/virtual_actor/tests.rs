@@ -0,0 +1,318 @@
+extern crate std;
+
+use core::time::Duration;
+use std::{
+  boxed::Box,
+  format,
+  string::{String, ToString},
+  sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc, Mutex,
+  },
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::actor_context::ActorContext,
+    actor_runtime::GenericActorRuntime,
+    actor_system::{GenericActorSystem, GenericActorSystemConfig},
+    extensions::{serializer_extension_id, SerializerRegistryExtension},
+    mailbox::messages::SystemMessage,
+    process::pid::NodeId,
+    receive_timeout::{ReceiveTimeoutScheduler, ReceiveTimeoutSchedulerFactory, ReceiveTimeoutSchedulerFactoryShared},
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::{AnyMessage, MapSystemShared},
+  },
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_remote_core_rs::{
+  delivery::RemoteMessage,
+  endpoint::EndpointManager,
+  loopback::{LoopbackNetwork, LoopbackTransport},
+  outbound::RemoteOutbound,
+};
+use cellex_serialization_core_rs::{
+  error::{DeserializationError, SerializationError},
+  impl_type_key,
+};
+use cellex_serialization_json_rs::{shared_json_serializer, SERDE_JSON_SERIALIZER_ID};
+use cellex_utils_core_rs::sync::ArcShared;
+use serde::{Deserialize, Serialize};
+
+use super::{ClusterIdentity, ClusterKinds, VirtualActivatorMessage, VirtualActorError, VirtualActors};
+use crate::membership::{ClusterMembership, MembershipConfig};
+
+type TestResult<T = ()> = Result<T, String>;
+type TestSystem = GenericActorSystem<VirtualActivatorMessage, TokioActorRuntime>;
+type TokioSender = <TokioMailboxFactory as MailboxFactory>::Producer<PriorityEnvelope<AnyMessage>>;
+type Received = Arc<Mutex<Vec<(u16, String, u32)>>>;
+type Armed = Arc<Mutex<Vec<(TokioSender, MapSystemShared<AnyMessage>, Arc<AtomicBool>)>>>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Add {
+  amount: u32,
+}
+
+impl_type_key!(Add, "test.Add");
+
+impl RemoteMessage for Add {
+  fn encode_payload(&self) -> Result<Vec<u8>, SerializationError> {
+    serde_json::to_vec(self).map_err(|err| SerializationError::custom(err.to_string()))
+  }
+
+  fn decode_payload(bytes: &[u8]) -> Result<Self, DeserializationError> {
+    serde_json::from_slice(bytes).map_err(|err| DeserializationError::custom(err.to_string()))
+  }
+}
+
+/// Receive timeouts that only fire when the test says so.
+#[derive(Clone, Default)]
+struct ManualTimeouts {
+  armed: Armed,
+}
+
+struct ManualTimeout {
+  armed: Arc<AtomicBool>,
+}
+
+impl ReceiveTimeoutScheduler for ManualTimeout {
+  fn set(&mut self, _duration: Duration) {
+    self.armed.store(true, Ordering::SeqCst);
+  }
+
+  fn cancel(&mut self) {
+    self.armed.store(false, Ordering::SeqCst);
+  }
+
+  fn notify_activity(&mut self) {}
+}
+
+impl ReceiveTimeoutSchedulerFactory<AnyMessage, TokioMailboxFactory> for ManualTimeouts {
+  fn create(&self, sender: TokioSender, map_system: MapSystemShared<AnyMessage>) -> Box<dyn ReceiveTimeoutScheduler> {
+    let armed = Arc::new(AtomicBool::new(false));
+    self.armed.lock().unwrap_or_else(|err| err.into_inner()).push((sender, map_system, armed.clone()));
+    Box::new(ManualTimeout { armed })
+  }
+}
+
+impl ManualTimeouts {
+  fn fire(&self) {
+    for (sender, map_system, armed) in self.armed.lock().unwrap_or_else(|err| err.into_inner()).iter() {
+      if armed.swap(false, Ordering::SeqCst) {
+        let _ = sender.try_send(PriorityEnvelope::from_system(SystemMessage::ReceiveTimeout).map(&**map_system));
+      }
+    }
+  }
+}
+
+struct TestNode {
+  system: TestSystem,
+  actors: VirtualActors<LoopbackTransport>,
+}
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn started(network: &LoopbackNetwork, port: u16, timeouts: &ManualTimeouts) -> TestResult<TestNode> {
+  let mut config = GenericActorSystemConfig::default().with_node_id(node(port));
+  config.set_receive_timeout_scheduler_factory_shared_opt(Some(ReceiveTimeoutSchedulerFactoryShared::new(
+    timeouts.clone(),
+  )));
+  let system = GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), config);
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(network.transport(&node(port)), system_id, node(port)));
+  let outbound = system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      let _ = extension.register_serializer(shared_json_serializer());
+      extension.bind_type::<Add>(SERDE_JSON_SERIALIZER_ID).map_err(|err| format!("bind: {err:?}"))?;
+      Ok::<_, String>(RemoteOutbound::new(endpoints, extension))
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())??;
+  let membership = ClusterMembership::new(outbound, MembershipConfig::new().with_seed_node(node(2551)));
+  let actors = VirtualActors::new(membership);
+  actors.start(system.process_registry()).map_err(|err| format!("start: {err}"))?;
+  Ok(TestNode { system, actors })
+}
+
+fn serve(test_node: &mut TestNode, kinds: ClusterKinds<TokioActorRuntime>) -> TestResult {
+  let activator = test_node
+    .system
+    .root_context()
+    .spawn(test_node.actors.activator_props(kinds))
+    .map_err(|err| format!("spawn: {err:?}"))?;
+  test_node.actors.serve(activator.pid().ok_or_else(|| "activator pid expected".to_string())?);
+  Ok(())
+}
+
+fn counters(port: u16, received: &Received, activations: &Arc<AtomicUsize>) -> ClusterKinds<TokioActorRuntime> {
+  let kinds = ClusterKinds::new();
+  let received = received.clone();
+  let activations = activations.clone();
+  kinds.register("counter", Duration::from_secs(60), move |identity: &ClusterIdentity| {
+    activations.fetch_add(1, Ordering::SeqCst);
+    let identity = identity.identity().to_string();
+    let received = received.clone();
+    move |_: &mut ActorContext<'_, '_, Add, TokioActorRuntime>, message: Add| {
+      received.lock().unwrap_or_else(|err| err.into_inner()).push((port, identity.clone(), message.amount));
+      Ok(())
+    }
+  });
+  kinds
+}
+
+fn settle(network: &LoopbackNetwork, nodes: &mut [&mut TestNode]) -> TestResult {
+  for _ in 0..4 {
+    network.flush();
+    for test_node in nodes.iter_mut() {
+      test_node.system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+    }
+  }
+  Ok(())
+}
+
+fn form_cluster(network: &LoopbackNetwork, nodes: &[&TestNode]) {
+  for test_node in nodes {
+    test_node.actors.membership().join();
+  }
+  for _ in 0..4 {
+    for test_node in nodes {
+      test_node.actors.membership().tick();
+    }
+    network.flush();
+  }
+}
+
+#[test]
+fn messages_activate_each_identity_once_on_its_owner() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let timeouts = ManualTimeouts::default();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let activations = Arc::new(AtomicUsize::new(0));
+  let mut first = started(&network, 2551, &timeouts)?;
+  let mut second = started(&network, 2552, &timeouts)?;
+  serve(&mut first, counters(2551, &received, &activations))?;
+  serve(&mut second, counters(2552, &received, &activations))?;
+  form_cluster(&network, &[&first, &second]);
+
+  let identities: Vec<ClusterIdentity> =
+    (0..8).map(|index| ClusterIdentity::new("counter", format!("account-{index}"))).collect();
+  for identity in &identities {
+    assert_eq!(first.actors.owner(identity), second.actors.owner(identity));
+    first.actors.tell(identity, Add { amount: 1 }).map_err(|err| format!("tell: {err}"))?;
+    second.actors.tell(identity, Add { amount: 2 }).map_err(|err| format!("tell: {err}"))?;
+  }
+  settle(&network, &mut [&mut first, &mut second])?;
+
+  assert_eq!(activations.load(Ordering::SeqCst), identities.len());
+  let received = received.lock().unwrap_or_else(|err| err.into_inner()).clone();
+  let mut owners = Vec::new();
+  for identity in &identities {
+    let owner = first.actors.owner(identity).and_then(|owner| owner.port()).ok_or("owner expected")?;
+    let mut amounts: Vec<u32> = received
+      .iter()
+      .filter(|(_, name, _)| name == identity.identity())
+      .map(|(port, _, amount)| {
+        assert_eq!(*port, owner);
+        *amount
+      })
+      .collect();
+    amounts.sort_unstable();
+    assert_eq!(amounts, vec![1, 2]);
+    owners.push(owner);
+  }
+  assert!(owners.contains(&2551) && owners.contains(&2552));
+  assert_eq!(first.actors.lookup().len() + second.actors.lookup().len(), identities.len());
+  Ok(())
+}
+
+#[test]
+fn idle_activations_passivate_and_reactivate_on_the_next_message() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let timeouts = ManualTimeouts::default();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let activations = Arc::new(AtomicUsize::new(0));
+  let mut single = started(&network, 2551, &timeouts)?;
+  serve(&mut single, counters(2551, &received, &activations))?;
+  form_cluster(&network, &[&single]);
+  let identity = ClusterIdentity::new("counter", "account-1");
+
+  single.actors.tell(&identity, Add { amount: 1 }).map_err(|err| format!("tell: {err}"))?;
+  single.actors.tell(&identity, Add { amount: 2 }).map_err(|err| format!("tell: {err}"))?;
+  settle(&network, &mut [&mut single])?;
+  assert_eq!(single.actors.lookup().identities(), vec![identity.clone()]);
+
+  timeouts.fire();
+  settle(&network, &mut [&mut single])?;
+  assert!(single.actors.lookup().is_empty());
+
+  single.actors.tell(&identity, Add { amount: 3 }).map_err(|err| format!("tell: {err}"))?;
+  settle(&network, &mut [&mut single])?;
+  assert_eq!(activations.load(Ordering::SeqCst), 2);
+  let amounts: Vec<u32> =
+    received.lock().unwrap_or_else(|err| err.into_inner()).iter().map(|(_, _, amount)| *amount).collect();
+  assert_eq!(amounts, vec![1, 2, 3]);
+  Ok(())
+}
+
+#[test]
+fn tell_reports_missing_members_and_activators() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let timeouts = ManualTimeouts::default();
+  let identity = ClusterIdentity::new("counter", "account-1");
+  let joining = started(&network, 2552, &timeouts)?;
+  assert_eq!(joining.actors.tell(&identity, Add { amount: 1 }), Err(VirtualActorError::NoMembers));
+
+  let single = started(&network, 2551, &timeouts)?;
+  form_cluster(&network, &[&single]);
+  assert_eq!(single.actors.tell(&identity, Add { amount: 1 }), Err(VirtualActorError::NoActivator));
+  assert!(single.actors.lookup().is_empty());
+  Ok(())
+}
+
+#[test]
+fn joining_members_take_over_only_the_identities_placed_on_them() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let timeouts = ManualTimeouts::default();
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let activations = Arc::new(AtomicUsize::new(0));
+  let mut first = started(&network, 2551, &timeouts)?;
+  serve(&mut first, counters(2551, &received, &activations))?;
+  form_cluster(&network, &[&first]);
+  let _hand_off = first.actors.hand_off_on_change();
+
+  let identities: Vec<ClusterIdentity> =
+    (0..8).map(|index| ClusterIdentity::new("counter", format!("account-{index}"))).collect();
+  for identity in &identities {
+    first.actors.tell(identity, Add { amount: 1 }).map_err(|err| format!("tell: {err}"))?;
+  }
+  settle(&network, &mut [&mut first])?;
+  assert_eq!(first.actors.lookup().len(), identities.len());
+
+  let mut second = started(&network, 2552, &timeouts)?;
+  serve(&mut second, counters(2552, &received, &activations))?;
+  form_cluster(&network, &[&first, &second]);
+  settle(&network, &mut [&mut first, &mut second])?;
+
+  let (kept, moved): (Vec<ClusterIdentity>, Vec<ClusterIdentity>) =
+    identities.iter().cloned().partition(|identity| first.actors.owner(identity) == Some(node(2551)));
+  assert!(!kept.is_empty() && !moved.is_empty());
+  assert_eq!(first.actors.lookup().identities(), kept);
+
+  for identity in &identities {
+    first.actors.tell(identity, Add { amount: 2 }).map_err(|err| format!("tell: {err}"))?;
+  }
+  settle(&network, &mut [&mut first, &mut second])?;
+  assert_eq!(activations.load(Ordering::SeqCst), identities.len() + moved.len());
+  assert_eq!(second.actors.lookup().identities(), moved);
+  let received = received.lock().unwrap_or_else(|err| err.into_inner()).clone();
+  for (port, name, amount) in received.iter().filter(|(_, _, amount)| *amount == 2) {
+    let expected = if moved.iter().any(|identity| identity.identity() == name) { 2552 } else { 2551 };
+    assert_eq!(*port, expected, "{name} received {amount} on the wrong member");
+  }
+  Ok(())
+}
@@ -0,0 +1,10 @@
+use super::ClusterIdentity;
+
+/// Messages handled by the activator actor of [`VirtualActors`](super::VirtualActors).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VirtualActivatorMessage {
+  /// Activates the virtual actor addressed by the identity.
+  Activate(ClusterIdentity),
+  /// Internal follow-up registering the activations spawned by the previous messages.
+  Activated,
+}
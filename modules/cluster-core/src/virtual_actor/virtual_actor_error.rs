@@ -0,0 +1,18 @@
+use cellex_remote_core_rs::outbound::RemoteSendError;
+
+/// Errors raised while sending a message to a virtual actor.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum VirtualActorError {
+  /// No member is up, so no node can host the virtual actor.
+  #[error("no member is up to host virtual actors")]
+  NoMembers,
+  /// The virtual actors are not attached to inbound delivery on the local node.
+  #[error("virtual actors are not started on the local node")]
+  NotStarted,
+  /// The local node hosts the virtual actor but no activator serves activations.
+  #[error("no activator serves virtual actors on the local node")]
+  NoActivator,
+  /// The message could not be sent to the hosting node.
+  #[error(transparent)]
+  Send(#[from] RemoteSendError),
+}
@@ -0,0 +1,356 @@
+use alloc::{collections::BTreeMap, format, string::ToString, vec, vec::Vec};
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::{ActorPath, Props},
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::MetadataStorageMode,
+    process::pid::{NodeId, Pid, PidTag},
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::{AnyMessage, MessageEnvelope},
+  },
+};
+use cellex_remote_core_rs::{
+  activation::PendingChildren,
+  codec::RemoteDelivery,
+  delivery::{RemoteFrameInterceptor, RemoteInboundDispatcher, RemoteMessage, RemoteProcessRegistry},
+  outbound::RemoteOutbound,
+  transport::{RemoteTransport, TransportError},
+};
+use cellex_utils_core_rs::{
+  collections::queue::priority::DEFAULT_PRIORITY,
+  sync::{shared::SharedBound, ArcShared},
+};
+use spin::RwLock;
+
+use super::{
+  pending_delivery::{PendingDelivery, RouteFn},
+  ClusterIdentity, ClusterKinds, IdentityLookup, VirtualActivatorMessage, VirtualActorError,
+};
+use crate::{
+  membership::{ClusterMembership, MemberStatus, MembershipChange, MembershipListener, MembershipSubscription},
+  partition::{ConsistentHashRing, PartitionStrategy},
+};
+
+const GRAIN_TAG_PREFIX: &str = "grain:";
+
+/// Virtual actors addressed by [`ClusterIdentity`] and activated on demand across the cluster.
+///
+/// [`VirtualActors::tell`] picks the member hosting an identity among the members that are up with
+/// a [`PartitionStrategy`], and sends the message to a grain PID naming the identity on that node.
+/// There the message goes to the running activation found in the [`IdentityLookup`], or is held
+/// back while the activator actor, built from [`VirtualActors::activator_props`] and announced
+/// through [`VirtualActors::serve`], spawns the activation from the matching [`ClusterKinds`]
+/// entry.
+///
+/// Activations passivate when idle; the next message activates the identity again. Messages
+/// already queued in the mailbox of a passivating activation are dead-lettered. When members come
+/// or go, [`VirtualActors::hand_off`] passivates the activations that moved to another member.
+pub struct VirtualActors<T>
+where
+  T: RemoteTransport, {
+  membership: ClusterMembership<T>,
+  partition:  ArcShared<dyn PartitionStrategy>,
+  lookup:     IdentityLookup,
+  activator:  ArcShared<RwLock<Option<Pid>>>,
+  pending:    ArcShared<RwLock<BTreeMap<ClusterIdentity, Vec<PendingDelivery>>>>,
+  route:      ArcShared<RwLock<Option<ArcShared<RouteFn>>>>,
+}
+
+impl<T> Clone for VirtualActors<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self {
+      membership: self.membership.clone(),
+      partition:  self.partition.clone(),
+      lookup:     self.lookup.clone(),
+      activator:  self.activator.clone(),
+      pending:    self.pending.clone(),
+      route:      self.route.clone(),
+    }
+  }
+}
+
+impl<T> VirtualActors<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates virtual actors placed among the members of `membership` by a
+  /// [`ConsistentHashRing`].
+  #[must_use]
+  pub fn new(membership: ClusterMembership<T>) -> Self {
+    Self {
+      membership,
+      partition: ArcShared::new(ConsistentHashRing::new()).into_dyn(|strategy| strategy as &dyn PartitionStrategy),
+      lookup: IdentityLookup::new(),
+      activator: ArcShared::new(RwLock::new(None)),
+      pending: ArcShared::new(RwLock::new(BTreeMap::new())),
+      route: ArcShared::new(RwLock::new(None)),
+    }
+  }
+
+  /// Places identities with `strategy` instead. Every node must use the same strategy.
+  #[must_use]
+  pub fn with_partition_strategy<P>(mut self, strategy: P) -> Self
+  where
+    P: PartitionStrategy, {
+    self.partition = ArcShared::new(strategy).into_dyn(|strategy| strategy as &dyn PartitionStrategy);
+    self
+  }
+
+  /// Returns the membership the virtual actors are placed in.
+  #[must_use]
+  pub const fn membership(&self) -> &ClusterMembership<T> {
+    &self.membership
+  }
+
+  /// Returns the outbound side used to reach other members.
+  #[must_use]
+  pub const fn outbound(&self) -> &RemoteOutbound<T> {
+    self.membership.outbound()
+  }
+
+  /// Returns the activations running on the local node.
+  #[must_use]
+  pub const fn lookup(&self) -> &IdentityLookup {
+    &self.lookup
+  }
+
+  /// Serves activations on the local node through the activator actor `activator`.
+  pub fn serve(&self, activator: Pid) {
+    *self.activator.write() = Some(activator);
+  }
+
+  /// Returns the local activator actor, if activations are served.
+  #[must_use]
+  pub fn activator(&self) -> Option<Pid> {
+    self.activator.read().clone()
+  }
+
+  /// Returns the member hosting `identity`, or `None` while no member is up.
+  ///
+  /// Every node computes the same owner from the same membership.
+  #[must_use]
+  pub fn owner(&self, identity: &ClusterIdentity) -> Option<NodeId> {
+    let members: Vec<NodeId> = self
+      .membership
+      .members()
+      .into_iter()
+      .filter(|member| member.status() == MemberStatus::Up)
+      .map(|member| member.node().clone())
+      .collect();
+    self.partition.owner(&identity.to_string(), &members).cloned()
+  }
+
+  /// Passivates the local activations whose identity is now owned by another member, so that
+  /// their next message activates them on the new owner. Returns how many were handed off.
+  ///
+  /// Only the identities whose owner changed are affected. Messages already queued in their
+  /// mailboxes are dead-lettered, as on passivation.
+  #[must_use]
+  pub fn hand_off(&self) -> usize {
+    let local = self.membership.local();
+    self
+      .lookup
+      .identities()
+      .iter()
+      .filter(|identity| self.owner(identity).is_some_and(|owner| owner != *local))
+      .filter(|identity| self.passivate(identity))
+      .count()
+  }
+
+  /// Hands off the moved activations whenever a member comes up or stops being up. The hand-off
+  /// stops when the returned subscription is dropped.
+  #[must_use]
+  pub fn hand_off_on_change(&self) -> MembershipSubscription
+  where
+    T: 'static,
+    Self: SharedBound, {
+    let actors = self.clone();
+    self.membership.subscribe(MembershipListener::new(move |change: &MembershipChange| {
+      if change.status() == MemberStatus::Up || change.previous() == Some(MemberStatus::Up) {
+        let _ = actors.hand_off();
+      }
+    }))
+  }
+
+  /// Sends `message` to the virtual actor `identity`, activating it on its owner if needed.
+  ///
+  /// # Errors
+  /// Returns [`VirtualActorError`] when no member can host the actor, the local node owns it but
+  /// does not serve activations, or the message cannot be sent to the owner.
+  pub fn tell<U>(&self, identity: &ClusterIdentity, message: U) -> Result<(), VirtualActorError>
+  where
+    U: RemoteMessage, {
+    let owner = self.owner(identity).ok_or(VirtualActorError::NoMembers)?;
+    if owner != *self.membership.local() {
+      let target = self.grain_pid(&owner, identity);
+      return self.outbound().send_user(&target, &message, DEFAULT_PRIORITY, None).map_err(VirtualActorError::from);
+    }
+    let envelope = PriorityEnvelope::new(AnyMessage::new(MessageEnvelope::user(message)), DEFAULT_PRIORITY);
+    self.route_to(identity, PendingDelivery::Local(envelope))
+  }
+
+  /// Routes deliveries addressed to grain PIDs, returning every other delivery.
+  pub fn handle_frame(&self, delivery: RemoteDelivery) -> Option<RemoteDelivery> {
+    let RemoteDelivery { target, frame } = delivery;
+    let identity = target.tag().and_then(|tag| tag.0.strip_prefix(GRAIN_TAG_PREFIX)).and_then(ClusterIdentity::parse);
+    match identity {
+      | Some(identity) => {
+        // Without a running activator the frame is dropped, as for any unknown target.
+        let _ = self.route_to(&identity, PendingDelivery::Remote(frame));
+        None
+      },
+      | None => Some(RemoteDelivery::new(target, frame)),
+    }
+  }
+
+  /// Stops the local activation of `identity`, returning `false` when none is running.
+  pub(crate) fn passivate(&self, identity: &ClusterIdentity) -> bool {
+    let (Some(route), Some(pid)) = (self.route.read().clone(), self.lookup.get(identity)) else {
+      return false;
+    };
+    self.lookup.remove(identity, &pid);
+    let _ = route(&pid, PendingDelivery::stop());
+    true
+  }
+
+  /// Delivers to the activation of `identity` on the local node, activating it if needed.
+  pub(crate) fn route_to(
+    &self,
+    identity: &ClusterIdentity,
+    delivery: PendingDelivery,
+  ) -> Result<(), VirtualActorError> {
+    let route = self.route.read().clone().ok_or(VirtualActorError::NotStarted)?;
+    {
+      let mut pending = self.pending.write();
+      if let Some(held) = pending.get_mut(identity) {
+        held.push(delivery);
+        return Ok(());
+      }
+      let delivery = match self.lookup.get(identity) {
+        | Some(pid) => match route(&pid, delivery) {
+          | None => return Ok(()),
+          | Some(delivery) => {
+            // The activation stopped without passivating, e.g. after a failure.
+            self.lookup.remove(identity, &pid);
+            delivery
+          },
+        },
+        | None => delivery,
+      };
+      pending.insert(identity.clone(), vec![delivery]);
+    }
+    let request = AnyMessage::new(MessageEnvelope::user(VirtualActivatorMessage::Activate(identity.clone())));
+    let requested = self.activator().is_some_and(|activator| {
+      route(&activator, PendingDelivery::Local(PriorityEnvelope::new(request, DEFAULT_PRIORITY))).is_none()
+    });
+    if requested {
+      Ok(())
+    } else {
+      self.pending.write().remove(identity);
+      Err(VirtualActorError::NoActivator)
+    }
+  }
+
+  fn activated(&self, identity: &ClusterIdentity, pid: Option<Pid>) {
+    // Held messages are handed over under the lock so that later messages cannot overtake them.
+    let mut pending = self.pending.write();
+    let held = pending.remove(identity).unwrap_or_default();
+    let Some(pid) = pid else {
+      return;
+    };
+    self.lookup.insert(identity.clone(), pid.clone());
+    if let Some(route) = self.route.read().clone() {
+      for delivery in held {
+        let _ = route(&pid, delivery);
+      }
+    }
+  }
+
+  fn grain_pid(&self, node: &NodeId, identity: &ClusterIdentity) -> Pid {
+    Pid::new(self.outbound().system().clone(), ActorPath::new())
+      .with_node(node.clone())
+      .with_tag(PidTag::new(format!("{GRAIN_TAG_PREFIX}{identity}")))
+  }
+}
+
+impl<T> VirtualActors<T>
+where
+  T: RemoteTransport + 'static,
+{
+  /// Builds the activator actor spawning activations from `kinds`.
+  ///
+  /// Spawn the returned `Props` once per node, either at the root of a system whose message type
+  /// is [`VirtualActivatorMessage`] or as a child of any other actor, and pass its PID to
+  /// [`VirtualActors::serve`].
+  #[must_use]
+  pub fn activator_props<AR>(&self, kinds: ClusterKinds<AR>) -> Props<VirtualActivatorMessage, AR>
+  where
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+    let actors = self.clone();
+    let mut spawned: PendingChildren<ClusterIdentity> = PendingChildren::new();
+    Props::new(move |ctx, message: VirtualActivatorMessage| {
+      match message {
+        | VirtualActivatorMessage::Activate(identity) => {
+          match kinds.activate(ctx, &identity, &actors.lookup, actors.outbound().messages()) {
+            | Some(child) => {
+              if let Err(failed) = spawned.push(ctx, identity, child, VirtualActivatorMessage::Activated) {
+                for identity in failed {
+                  actors.activated(&identity, None);
+                }
+              }
+            },
+            | None => actors.activated(&identity, None),
+          }
+        },
+        | VirtualActivatorMessage::Activated => {
+          for (identity, pid) in spawned.take() {
+            actors.activated(&identity, pid);
+          }
+        },
+      }
+      Ok(())
+    })
+  }
+}
+
+impl<T> VirtualActors<T>
+where
+  T: RemoteTransport + 'static,
+  Self: SharedBound,
+{
+  /// Routes messages for virtual actors arriving through `dispatcher` to their activations, and
+  /// delivers local messages and activation requests through it.
+  #[must_use]
+  pub fn attach<MF>(&self, dispatcher: RemoteInboundDispatcher<MF>) -> RemoteInboundDispatcher<MF>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    *self.route.write() = Some(PendingDelivery::route_through(dispatcher.clone()));
+    let actors = self.clone();
+    dispatcher
+      .with_interceptor(RemoteFrameInterceptor::new(move |delivery: RemoteDelivery| actors.handle_frame(delivery)))
+  }
+
+  /// Starts listening on the local node with the membership and the virtual actors attached to
+  /// inbound delivery.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the transport cannot listen on the local node.
+  pub fn start<MF>(&self, registry: ArcShared<RemoteProcessRegistry<MF>>) -> Result<(), TransportError>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    let dispatcher = self.attach(self.membership.attach(self.outbound().inbound_dispatcher(registry)));
+    self.outbound().endpoints().start(dispatcher)
+  }
+}
@@ -0,0 +1,18 @@
+mod pending_children;
+mod remote_activator_message;
+mod remote_kinds;
+mod remote_spawn_error;
+mod remote_spawn_request;
+mod remote_spawner;
+mod spawned_child;
+
+#[cfg(test)]
+mod tests;
+
+pub use pending_children::PendingChildren;
+pub use remote_activator_message::RemoteActivatorMessage;
+pub use remote_kinds::RemoteKinds;
+pub use remote_spawn_error::RemoteSpawnError;
+pub use remote_spawn_request::RemoteSpawnRequest;
+pub use remote_spawner::{RemoteSpawnResult, RemoteSpawner};
+pub use spawned_child::SpawnedChild;
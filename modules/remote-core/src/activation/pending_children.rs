@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::actor_context::ActorContext,
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::MetadataStorageMode,
+    process::pid::Pid,
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
+};
+use cellex_utils_core_rs::collections::Element;
+
+use super::SpawnedChild;
+
+/// Children spawned by an actor whose PIDs are read from a follow-up message.
+///
+/// An actor's children are registered once its handler returns, so their PIDs are unknown while
+/// spawning. Each child is kept along with a key, and the first one asks the actor for a
+/// follow-up message, in which [`PendingChildren::take`] returns every PID.
+pub struct PendingChildren<K> {
+  children: Vec<(K, SpawnedChild)>,
+}
+
+impl<K> PendingChildren<K> {
+  /// Creates an empty set of pending children.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { children: Vec::new() }
+  }
+
+  /// Keeps `child` under `key`, sending `follow_up` to the actor owning `ctx` unless an earlier
+  /// pending child already did.
+  ///
+  /// # Errors
+  /// Returns the key of every pending child, which are no longer kept, when the follow-up message
+  /// cannot be sent.
+  pub fn push<U, AR>(
+    &mut self,
+    ctx: &ActorContext<'_, '_, U, AR>,
+    key: K,
+    child: SpawnedChild,
+    follow_up: U,
+  ) -> Result<(), Vec<K>>
+  where
+    U: Element,
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+    self.children.push((key, child));
+    if self.children.len() == 1 && ctx.send_to_self(follow_up).is_err() {
+      return Err(self.children.drain(..).map(|(key, _)| key).collect());
+    }
+    Ok(())
+  }
+
+  /// Returns every pending child with its PID, to be called from the follow-up message.
+  pub fn take(&mut self) -> Vec<(K, Option<Pid>)> {
+    self.children.drain(..).map(|(key, child)| (key, child.pid())).collect()
+  }
+}
+
+impl<K> Default for PendingChildren<K> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
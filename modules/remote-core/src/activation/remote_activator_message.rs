@@ -0,0 +1,12 @@
+use super::RemoteSpawnRequest;
+
+/// Messages handled by the activator actor built by
+/// [`RemoteSpawner::activator_props`](super::RemoteSpawner::activator_props).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteActivatorMessage {
+  /// Spawn request received from another node.
+  Spawn(RemoteSpawnRequest),
+  /// Sent by the activator to itself to answer pending requests once their children are
+  /// registered.
+  Reply,
+}
@@ -0,0 +1,122 @@
+use alloc::{
+  collections::BTreeMap,
+  string::{String, ToString},
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::{actor_context::ActorContext, Props},
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::MetadataStorageMode,
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
+};
+use cellex_utils_core_rs::{
+  collections::Element,
+  sync::{shared::SharedBound, ArcShared},
+};
+use spin::RwLock;
+
+use super::{RemoteActivatorMessage, RemoteSpawnError, SpawnedChild};
+
+#[cfg(target_has_atomic = "ptr")]
+type SpawnFn<AR> =
+  dyn for<'r, 'ctx> Fn(&mut ActorContext<'r, 'ctx, RemoteActivatorMessage, AR>) -> SpawnedChild + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type SpawnFn<AR> = dyn for<'r, 'ctx> Fn(&mut ActorContext<'r, 'ctx, RemoteActivatorMessage, AR>) -> SpawnedChild;
+
+/// Named `Props` factories that other nodes may spawn on this node.
+///
+/// Actors spawned for a kind become children of the activator actor serving spawn requests, see
+/// [`RemoteSpawner::activator_props`](super::RemoteSpawner::activator_props).
+pub struct RemoteKinds<AR>
+where
+  AR: ActorRuntime + 'static, {
+  factories: ArcShared<RwLock<BTreeMap<String, ArcShared<SpawnFn<AR>>>>>,
+}
+
+impl<AR> Clone for RemoteKinds<AR>
+where
+  AR: ActorRuntime + 'static,
+{
+  fn clone(&self) -> Self {
+    Self { factories: self.factories.clone() }
+  }
+}
+
+impl<AR> Default for RemoteKinds<AR>
+where
+  AR: ActorRuntime + 'static,
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<AR> RemoteKinds<AR>
+where
+  AR: ActorRuntime + 'static,
+{
+  /// Creates an empty set of kinds.
+  #[must_use]
+  pub fn new() -> Self {
+    Self { factories: ArcShared::new(RwLock::new(BTreeMap::new())) }
+  }
+
+  /// Returns `true` when a factory is registered under `kind`.
+  #[must_use]
+  pub fn contains(&self, kind: &str) -> bool {
+    self.factories.read().contains_key(kind)
+  }
+
+  /// Returns the registered kind names in ascending order.
+  #[must_use]
+  pub fn kinds(&self) -> Vec<String> {
+    self.factories.read().keys().cloned().collect()
+  }
+
+  /// Removes the factory registered under `kind`; later requests for it are refused.
+  pub fn unregister(&self, kind: &str) {
+    self.factories.write().remove(kind);
+  }
+}
+
+impl<AR> RemoteKinds<AR>
+where
+  AR: ActorRuntime + 'static,
+  MailboxOf<AR>: MailboxFactory + Clone + 'static,
+  MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+  MailboxSignalOf<AR>: Clone,
+  MailboxConcurrencyOf<AR>: MetadataStorageMode,
+{
+  /// Registers `factory` under `kind`, replacing any previous factory of that name.
+  ///
+  /// The factory is invoked once per spawn request, so every remote spawn gets fresh `Props`.
+  pub fn register<V, F>(&self, kind: impl Into<String>, factory: F)
+  where
+    V: Element,
+    F: Fn() -> Props<V, AR> + SharedBound + 'static, {
+    let spawn = ArcShared::new(move |ctx: &mut ActorContext<'_, '_, RemoteActivatorMessage, AR>| {
+      SpawnedChild::new(ctx.spawn_child(factory()))
+    })
+    .into_dyn(|f| f as &SpawnFn<AR>);
+    self.factories.write().insert(kind.into(), spawn);
+  }
+
+  /// Spawns an actor of `kind` as a child of the activator owning `ctx`.
+  pub(crate) fn spawn(
+    &self,
+    ctx: &mut ActorContext<'_, '_, RemoteActivatorMessage, AR>,
+    kind: &str,
+  ) -> Result<SpawnedChild, RemoteSpawnError> {
+    // Release the lock before spawning so that factories may register further kinds.
+    let factory =
+      self.factories.read().get(kind).cloned().ok_or_else(|| RemoteSpawnError::UnknownKind(kind.to_string()))?;
+    Ok(factory(ctx))
+  }
+}
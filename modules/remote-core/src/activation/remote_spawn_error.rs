@@ -0,0 +1,15 @@
+use alloc::string::String;
+
+/// Reasons a node refuses a remote spawn request.
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum RemoteSpawnError {
+  /// No `Props` factory is registered under the requested kind on the target node.
+  #[error("kind `{0}` is not registered on the target node")]
+  UnknownKind(String),
+  /// The target node does not serve spawn requests.
+  #[error("the target node has no activator")]
+  NoActivator,
+  /// The activator of the target node could not accept or complete the request.
+  #[error("the activator rejected the spawn request")]
+  Rejected,
+}
@@ -0,0 +1,30 @@
+use alloc::string::String;
+
+use cellex_actor_core_rs::api::process::pid::Pid;
+
+/// Spawn request handed to the activator actor of the local node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteSpawnRequest {
+  kind:     String,
+  reply_to: Pid,
+}
+
+impl RemoteSpawnRequest {
+  /// Creates a request for `kind` whose outcome is sent to `reply_to`.
+  #[must_use]
+  pub fn new(kind: impl Into<String>, reply_to: Pid) -> Self {
+    Self { kind: kind.into(), reply_to }
+  }
+
+  /// Returns the requested kind.
+  #[must_use]
+  pub fn kind(&self) -> &str {
+    &self.kind
+  }
+
+  /// Returns the PID waiting for the outcome on the requesting node.
+  #[must_use]
+  pub const fn reply_to(&self) -> &Pid {
+    &self.reply_to
+  }
+}
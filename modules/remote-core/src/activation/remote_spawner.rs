@@ -0,0 +1,231 @@
+use cellex_actor_core_rs::{
+  api::{
+    actor::{ask::AskFuture, ActorPath, Props},
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::MetadataStorageMode,
+    process::pid::{NodeId, Pid, PidTag},
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::{AnyMessage, MessageEnvelope},
+  },
+};
+use cellex_utils_core_rs::{
+  collections::queue::priority::DEFAULT_PRIORITY,
+  sync::{shared::SharedBound, ArcShared},
+};
+use spin::RwLock;
+
+use super::{PendingChildren, RemoteActivatorMessage, RemoteKinds, RemoteSpawnError, RemoteSpawnRequest};
+use crate::{
+  codec::{RemoteDelivery, RemotePayloadFrame},
+  delivery::{RemoteFrameInterceptor, RemoteInboundDispatcher, RemoteProcessRegistry},
+  outbound::{RemoteOutbound, RemoteSendError},
+  transport::{RemoteTransport, TransportError},
+};
+
+const ACTIVATOR_TAG: &str = "activator";
+
+/// Outcome of a remote spawn: the PID of the new actor on the target node.
+pub type RemoteSpawnResult = Result<Pid, RemoteSpawnError>;
+
+/// Spawning of actors on other nodes by registered kind name.
+///
+/// [`RemoteSpawner::spawn`] sends the kind to the activator PID of the target node. There the
+/// request is handed to the local activator actor, built from
+/// [`RemoteSpawner::activator_props`] and announced through [`RemoteSpawner::serve`], which spawns
+/// a child from the matching [`RemoteKinds`] factory and answers with its PID.
+pub struct RemoteSpawner<T>
+where
+  T: RemoteTransport, {
+  outbound:  RemoteOutbound<T>,
+  activator: ArcShared<RwLock<Option<Pid>>>,
+}
+
+impl<T> Clone for RemoteSpawner<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self { outbound: self.outbound.clone(), activator: self.activator.clone() }
+  }
+}
+
+impl<T> RemoteSpawner<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates a spawner sending its requests through `outbound`.
+  #[must_use]
+  pub fn new(outbound: RemoteOutbound<T>) -> Self {
+    Self { outbound, activator: ArcShared::new(RwLock::new(None)) }
+  }
+
+  /// Returns the outbound side used to reach other nodes.
+  #[must_use]
+  pub const fn outbound(&self) -> &RemoteOutbound<T> {
+    &self.outbound
+  }
+
+  /// Serves spawn requests from other nodes through the local activator actor `activator`.
+  pub fn serve(&self, activator: Pid) {
+    *self.activator.write() = Some(activator);
+  }
+
+  /// Stops serving spawn requests; later requests are answered with
+  /// [`RemoteSpawnError::NoActivator`].
+  pub fn stop_serving(&self) {
+    *self.activator.write() = None;
+  }
+
+  /// Returns the local activator actor, if spawn requests are served.
+  #[must_use]
+  pub fn activator(&self) -> Option<Pid> {
+    self.activator.read().clone()
+  }
+
+  /// Asks `node` to spawn an actor of `kind` and returns a future completed with its PID.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError::NotRemote`] when `node` is the local node, and
+  /// [`RemoteSendError`] when the request cannot be sent.
+  pub fn spawn(&self, node: &NodeId, kind: &str) -> Result<AskFuture<RemoteSpawnResult>, RemoteSendError> {
+    let (future, reply_to) = self.outbound.reply_handles::<RemoteSpawnResult>();
+    let payload = RemotePayloadFrame::Spawn { kind: kind.into() };
+    // Dropping the future on failure releases the reply PID.
+    self.outbound.send_control(&self.activator_pid(node), payload, DEFAULT_PRIORITY, Some(reply_to)).map(|()| future)
+  }
+
+  /// Handles spawn protocol frames, returning the delivery when it belongs to regular traffic.
+  ///
+  /// `deliver` hands a request to the activator PID and reports whether it was accepted.
+  pub fn handle_frame<D>(&self, delivery: RemoteDelivery, deliver: D) -> Option<RemoteDelivery>
+  where
+    D: Fn(&Pid, RemoteSpawnRequest) -> bool, {
+    let RemoteDelivery { target, frame } = delivery;
+    match (&frame.payload, frame.reply_to.as_ref()) {
+      | (RemotePayloadFrame::Spawn { kind }, Some(reply_to)) => {
+        let outcome = match self.activator() {
+          | Some(activator) if deliver(&activator, RemoteSpawnRequest::new(kind.clone(), reply_to.clone())) => None,
+          | Some(_) => Some(RemoteSpawnError::Rejected),
+          | None => Some(RemoteSpawnError::NoActivator),
+        };
+        if let Some(error) = outcome {
+          let _ = self.reply(reply_to, Err(error));
+        }
+        None
+      },
+      | (RemotePayloadFrame::Spawned { result }, _) => {
+        if let Some(responder) = self.outbound.replies().take(&target) {
+          let _ = responder.send_with_priority(AnyMessage::new(MessageEnvelope::user(result.clone())), frame.priority);
+        }
+        None
+      },
+      | _ => Some(RemoteDelivery::new(target, frame)),
+    }
+  }
+
+  /// Sends the outcome of a spawn request to the requesting node.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError`] when the outcome cannot be sent.
+  pub fn reply(&self, reply_to: &Pid, result: RemoteSpawnResult) -> Result<(), RemoteSendError> {
+    self.outbound.send_control(reply_to, RemotePayloadFrame::Spawned { result }, DEFAULT_PRIORITY, None)
+  }
+
+  fn activator_pid(&self, node: &NodeId) -> Pid {
+    Pid::new(self.outbound.system().clone(), ActorPath::new())
+      .with_node(node.clone())
+      .with_tag(PidTag::new(ACTIVATOR_TAG))
+  }
+}
+
+impl<T> RemoteSpawner<T>
+where
+  T: RemoteTransport + 'static,
+{
+  /// Builds the activator actor spawning children from `kinds`.
+  ///
+  /// Spawn the returned `Props` once per node, either at the root of a system whose message type
+  /// is [`RemoteActivatorMessage`] or as a child of any other actor, and pass its PID to
+  /// [`RemoteSpawner::serve`].
+  #[must_use]
+  pub fn activator_props<AR>(&self, kinds: RemoteKinds<AR>) -> Props<RemoteActivatorMessage, AR>
+  where
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+    let spawner = self.clone();
+    let mut pending: PendingChildren<Pid> = PendingChildren::new();
+    Props::new(move |ctx, message: RemoteActivatorMessage| {
+      match message {
+        | RemoteActivatorMessage::Spawn(request) => match kinds.spawn(ctx, request.kind()) {
+          | Ok(child) => {
+            if let Err(rejected) = pending.push(ctx, request.reply_to().clone(), child, RemoteActivatorMessage::Reply) {
+              for reply_to in rejected {
+                let _ = spawner.reply(&reply_to, Err(RemoteSpawnError::Rejected));
+              }
+            }
+          },
+          | Err(error) => {
+            let _ = spawner.reply(request.reply_to(), Err(error));
+          },
+        },
+        | RemoteActivatorMessage::Reply => {
+          let node = spawner.outbound.node().clone();
+          for (reply_to, pid) in pending.take() {
+            let result = pid
+              .map(|pid| match pid.node() {
+                | Some(_) => pid,
+                | None => pid.with_node(node.clone()),
+              })
+              .ok_or(RemoteSpawnError::Rejected);
+            // An undeliverable outcome leaves the requester's ask pending, as for any lost reply.
+            let _ = spawner.reply(&reply_to, result);
+          }
+        },
+      }
+      Ok(())
+    })
+  }
+}
+
+impl<T> RemoteSpawner<T>
+where
+  T: RemoteTransport + 'static,
+  Self: SharedBound,
+{
+  /// Hooks the spawner into `dispatcher`.
+  ///
+  /// Spawn requests are handed to the activator through the dispatcher's process registry, and
+  /// spawn outcomes complete the pending futures of [`RemoteSpawner::spawn`].
+  #[must_use]
+  pub fn attach<MF>(&self, dispatcher: RemoteInboundDispatcher<MF>) -> RemoteInboundDispatcher<MF>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    let inbound = dispatcher.clone();
+    let spawner = self.clone();
+    dispatcher.with_interceptor(RemoteFrameInterceptor::new(move |delivery: RemoteDelivery| {
+      spawner.handle_frame(delivery, |activator, request| {
+        let message = RemoteActivatorMessage::Spawn(request);
+        let envelope = PriorityEnvelope::new(AnyMessage::new(MessageEnvelope::user(message)), DEFAULT_PRIORITY);
+        inbound.deliver(activator, envelope).is_ok()
+      })
+    }))
+  }
+
+  /// Starts listening on the local node with the spawner attached to inbound delivery.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the transport cannot listen on the local node.
+  pub fn start<MF>(&self, registry: ArcShared<RemoteProcessRegistry<MF>>) -> Result<(), TransportError>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    let dispatcher = self.attach(self.outbound.inbound_dispatcher(registry));
+    self.outbound.endpoints().start(dispatcher)
+  }
+}
@@ -0,0 +1,45 @@
+use alloc::boxed::Box;
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::actor_ref::ActorRef,
+    actor_runtime::{ActorRuntime, MailboxConcurrencyOf, MailboxOf, MailboxQueueOf, MailboxSignalOf},
+    messaging::MetadataStorageMode,
+    process::pid::Pid,
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
+};
+use cellex_utils_core_rs::collections::Element;
+
+/// Child spawned through `ActorContext::spawn_child`, whatever its message type.
+///
+/// Children are registered once the spawning handler returns, so the PID of a child is only
+/// known from the next message on; see [`PendingChildren`](super::PendingChildren).
+pub struct SpawnedChild {
+  pid: Box<dyn Fn() -> Option<Pid>>,
+}
+
+impl SpawnedChild {
+  /// Wraps the reference returned by `spawn_child`.
+  #[must_use]
+  pub fn new<V, AR>(child: ActorRef<V, AR>) -> Self
+  where
+    V: Element,
+    AR: ActorRuntime + 'static,
+    MailboxOf<AR>: MailboxFactory + Clone + 'static,
+    MailboxQueueOf<AR, PriorityEnvelope<AnyMessage>>: Clone,
+    MailboxSignalOf<AR>: Clone,
+    MailboxConcurrencyOf<AR>: MetadataStorageMode, {
+    Self { pid: Box::new(move || child.pid()) }
+  }
+
+  /// Returns the PID of the child, or `None` while it is not registered yet or when its
+  /// registration failed.
+  #[must_use]
+  pub fn pid(&self) -> Option<Pid> {
+    (self.pid)()
+  }
+}
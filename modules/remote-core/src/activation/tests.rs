@@ -0,0 +1,112 @@
+extern crate std;
+
+use std::{
+  format,
+  string::{String, ToString},
+};
+
+use cellex_actor_core_rs::api::{
+  actor::Props,
+  actor_runtime::GenericActorRuntime,
+  actor_system::{GenericActorSystem, GenericActorSystemConfig},
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  process::{pid::NodeId, process_registry::ProcessResolution},
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_utils_core_rs::sync::ArcShared;
+use futures::executor::block_on;
+
+use super::{RemoteActivatorMessage, RemoteKinds, RemoteSpawnError, RemoteSpawner};
+use crate::{
+  endpoint::EndpointManager,
+  loopback::{LoopbackNetwork, LoopbackTransport},
+  outbound::{RemoteOutbound, RemoteSendError},
+};
+
+type TestResult<T = ()> = Result<T, String>;
+type TestSystem = GenericActorSystem<RemoteActivatorMessage, TokioActorRuntime>;
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn started(network: &LoopbackNetwork, port: u16) -> TestResult<(TestSystem, RemoteSpawner<LoopbackTransport>)> {
+  let config = GenericActorSystemConfig::default().with_node_id(node(port));
+  let system = GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), config);
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(network.transport(&node(port)), system_id, node(port)));
+  let outbound = system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      RemoteOutbound::new(endpoints, extension)
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())?;
+  let spawner = RemoteSpawner::new(outbound);
+  spawner.start(system.process_registry()).map_err(|err| format!("start: {err}"))?;
+  Ok((system, spawner))
+}
+
+fn serve(
+  system: &mut TestSystem,
+  spawner: &RemoteSpawner<LoopbackTransport>,
+  kinds: RemoteKinds<TokioActorRuntime>,
+) -> TestResult {
+  let activator =
+    system.root_context().spawn(spawner.activator_props(kinds)).map_err(|err| format!("spawn: {err:?}"))?;
+  spawner.serve(activator.pid().ok_or_else(|| "activator pid expected".to_string())?);
+  Ok(())
+}
+
+#[test]
+fn spawn_creates_an_actor_of_the_registered_kind_on_the_target_node() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (_requester_system, requester) = started(&network, 2551)?;
+  let (mut host_system, host) = started(&network, 2552)?;
+  let kinds = RemoteKinds::<TokioActorRuntime>::new();
+  kinds.register("worker", || Props::new(|_, _: u32| Ok(())));
+  serve(&mut host_system, &host, kinds)?;
+
+  let future = requester.spawn(&node(2552), "worker").map_err(|err| format!("spawn: {err}"))?;
+  network.flush();
+  host_system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+  network.flush();
+
+  let pid = block_on(future).map_err(|err| format!("await: {err:?}"))?.map_err(|err| format!("remote: {err}"))?;
+  assert_eq!(pid.node(), Some(&node(2552)));
+  let activator = host.activator().ok_or_else(|| "activator expected".to_string())?;
+  assert_eq!(pid.path().parent().as_ref(), Some(activator.path()));
+  assert!(matches!(host_system.process_registry().resolve_pid(&pid), ProcessResolution::Local(_)));
+  assert!(requester.outbound().replies().is_empty());
+  Ok(())
+}
+
+#[test]
+fn spawn_reports_missing_activator_and_unknown_kinds() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (_requester_system, requester) = started(&network, 2551)?;
+  let (mut host_system, host) = started(&network, 2552)?;
+
+  let future = requester.spawn(&node(2552), "worker").map_err(|err| format!("spawn: {err}"))?;
+  network.flush();
+  assert_eq!(block_on(future).map_err(|err| format!("await: {err:?}"))?, Err(RemoteSpawnError::NoActivator));
+
+  serve(&mut host_system, &host, RemoteKinds::new())?;
+  let future = requester.spawn(&node(2552), "worker").map_err(|err| format!("spawn: {err}"))?;
+  network.flush();
+  host_system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+  network.flush();
+  assert_eq!(
+    block_on(future).map_err(|err| format!("await: {err:?}"))?,
+    Err(RemoteSpawnError::UnknownKind("worker".to_string()))
+  );
+  Ok(())
+}
+
+#[test]
+fn spawn_on_the_local_node_is_refused() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (_system, spawner) = started(&network, 2551)?;
+
+  assert_eq!(spawner.spawn(&node(2551), "worker").err(), Some(RemoteSendError::NotRemote));
+  assert!(spawner.outbound().replies().is_empty());
+  Ok(())
+}
@@ -1,19 +1,122 @@
+mod handshake_rejection;
+mod remote_delivery;
+mod remote_handshake;
 mod remote_message_frame;
 mod remote_payload_frame;
+mod remote_wire_frame;
+mod wire_reader;
+mod wire_writer;
+
+#[cfg(test)]
+mod tests;
+
+use alloc::{boxed::Box, string::ToString, vec::Vec};
 
 use cellex_actor_core_rs::{
   api::{
-    mailbox::{messages::PriorityChannel, ThreadSafe},
+    actor::{
+      actor_failure::{ActorFailure, DefaultBehaviorFailure},
+      ActorId, ActorPath,
+    },
+    failure::{metadata::FailureEscalationStage, FailureInfo, FailureMetadata},
+    mailbox::{
+      messages::{PriorityChannel, SystemMessage},
+      ThreadSafe,
+    },
     messaging::MessageMetadata,
-    process::pid::Pid,
+    process::{
+      dead_letter::DeadLetterReason,
+      pid::{Pid, SystemId},
+    },
   },
   shared::messaging::MessageEnvelope,
 };
-use cellex_serialization_core_rs::message::SerializedMessage;
+use cellex_serialization_core_rs::{
+  message::{MessageHeader, SerializedMessage},
+  SerializerId,
+};
+pub use handshake_rejection::HandshakeRejection;
+pub use remote_delivery::RemoteDelivery;
+pub use remote_handshake::RemoteHandshake;
 pub use remote_message_frame::RemoteMessageFrame;
 pub use remote_payload_frame::RemotePayloadFrame;
+pub use remote_wire_frame::RemoteWireFrame;
+pub use wire_reader::WireReader;
+pub use wire_writer::WireWriter;
+
+use crate::{
+  activation::{RemoteSpawnError, RemoteSpawnResult},
+  remote_envelope::RemoteEnvelope,
+};
+
+/// Size in bytes of the big-endian length prefix preceding every frame on a stream transport.
+pub const FRAME_LENGTH_PREFIX_SIZE: usize = 4;
+
+/// Upper bound accepted for a single encoded frame body.
+pub const MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+/// Bytes opening every frame body, identifying the cellex remoting protocol.
+pub const WIRE_MAGIC: [u8; 4] = *b"CLXR";
+
+/// Wire protocol version spoken by this release.
+///
+/// Version 2 adds [`RemoteWireFrame::Batch`], the only frame stamped with it. Every other frame
+/// keeps the version 1 layout and is stamped with [`MIN_PROTOCOL_VERSION`], so that older peers
+/// read them, except handshakes, whose header announces the version of their sender.
+pub const PROTOCOL_VERSION: u16 = 2;
+
+/// Oldest wire protocol version this release still reads.
+///
+/// The frame header and the handshake frames keep their layout across versions, so that peers
+/// can always tell each other which version they speak.
+pub const MIN_PROTOCOL_VERSION: u16 = 1;
+
+/// Oldest wire protocol version whose peers read [`RemoteWireFrame::Batch`].
+pub const BATCH_PROTOCOL_VERSION: u16 = 2;
+
+const KIND_HANDSHAKE: u8 = 0;
+const KIND_HANDSHAKE_ACK: u8 = 1;
+const KIND_HANDSHAKE_REJECTED: u8 = 2;
+const KIND_DELIVERY: u8 = 3;
+const KIND_BATCH: u8 = 4;
+
+const REJECT_UNSUPPORTED_VERSION: u8 = 0;
+const REJECT_SYSTEM_MISMATCH: u8 = 1;
+
+const CHANNEL_REGULAR: u8 = 0;
+const CHANNEL_CONTROL: u8 = 1;
+
+const PAYLOAD_SYSTEM: u8 = 0;
+const PAYLOAD_USER: u8 = 1;
+const PAYLOAD_TERMINATED: u8 = 2;
+const PAYLOAD_HEARTBEAT: u8 = 3;
+const PAYLOAD_HEARTBEAT_ACK: u8 = 4;
+const PAYLOAD_SPAWN: u8 = 5;
+const PAYLOAD_SPAWNED: u8 = 6;
+
+const SPAWN_OK: u8 = 0;
+const SPAWN_UNKNOWN_KIND: u8 = 1;
+const SPAWN_NO_ACTIVATOR: u8 = 2;
+const SPAWN_REJECTED: u8 = 3;
 
-use crate::remote_envelope::RemoteEnvelope;
+const REASON_UNREGISTERED_PID: u8 = 0;
+const REASON_TERMINATED: u8 = 1;
+const REASON_DELIVERY_REJECTED: u8 = 2;
+const REASON_NETWORK_UNREACHABLE: u8 = 3;
+
+const SYSTEM_WATCH: u8 = 0;
+const SYSTEM_UNWATCH: u8 = 1;
+const SYSTEM_STOP: u8 = 2;
+const SYSTEM_FAILURE: u8 = 3;
+const SYSTEM_RESTART: u8 = 4;
+const SYSTEM_SUSPEND: u8 = 5;
+const SYSTEM_RESUME: u8 = 6;
+const SYSTEM_ESCALATE: u8 = 7;
+const SYSTEM_RECEIVE_TIMEOUT: u8 = 8;
+const SYSTEM_TERMINATED: u8 = 9;
+
+const STAGE_INITIAL: u8 = 0;
+const STAGE_ESCALATED: u8 = 1;
 
 /// Errors that can occur when encoding or decoding remote envelopes.
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
@@ -21,6 +124,45 @@ pub enum RemoteCodecError {
   /// User metadata is currently unsupported for remote transport.
   #[error("user metadata is not supported in remote transport yet")]
   UnsupportedMetadata,
+  /// The termination reason has no wire representation.
+  #[error("termination reason cannot be encoded for remote transport")]
+  UnsupportedReason,
+  /// The system message has no wire representation.
+  #[error("system message cannot be encoded for remote transport")]
+  UnsupportedSystemMessage,
+  /// The frame ended before all fields were read.
+  #[error("frame is truncated")]
+  Truncated,
+  /// The frame contained bytes after the last field.
+  #[error("frame has {0} trailing bytes")]
+  TrailingBytes(usize),
+  /// A discriminant byte did not match any known variant.
+  #[error("unknown tag {0} in frame")]
+  UnknownTag(u8),
+  /// A string field was not valid UTF-8.
+  #[error("frame contains invalid UTF-8")]
+  InvalidUtf8,
+  /// A PID field could not be parsed.
+  #[error("frame contains an invalid pid")]
+  InvalidPid,
+  /// The frame exceeds [`MAX_FRAME_SIZE`].
+  #[error("frame of {0} bytes exceeds the maximum frame size")]
+  FrameTooLarge(usize),
+  /// The frame does not start with [`WIRE_MAGIC`].
+  #[error("frame does not start with the protocol magic bytes")]
+  InvalidMagic,
+  /// The frame was written with a protocol version this release cannot read.
+  #[error("protocol version {0} is not supported")]
+  UnsupportedVersion(u16),
+  /// The frame is valid but of another kind than expected.
+  #[error("unexpected frame kind {0}")]
+  UnexpectedFrame(u8),
+}
+
+/// Returns `true` when frames written with `version` can be read by this release.
+#[must_use]
+pub const fn is_supported_version(version: u16) -> bool {
+  version >= MIN_PROTOCOL_VERSION && version <= PROTOCOL_VERSION
 }
 
 /// Encodes a [`RemoteEnvelope`] carrying serialized user messages or system messages into a
@@ -52,8 +194,11 @@ pub fn frame_from_serialized_envelope(
 }
 
 /// Decodes a transport frame back into a [`RemoteEnvelope`] with serialized payloads.
+///
+/// Returns `None` for frames that carry remote-layer notifications (such as
+/// [`RemotePayloadFrame::Terminated`] or heartbeats) rather than an actor message.
 #[must_use]
-pub fn envelope_from_frame(frame: RemoteMessageFrame) -> RemoteEnvelope<MessageEnvelope<SerializedMessage>> {
+pub fn envelope_from_frame(frame: RemoteMessageFrame) -> Option<RemoteEnvelope<MessageEnvelope<SerializedMessage>>> {
   let RemoteMessageFrame { priority, channel, payload, reply_to } = frame;
   let message_envelope = match payload {
     | RemotePayloadFrame::System(system) => MessageEnvelope::System(system),
@@ -64,8 +209,15 @@ pub fn envelope_from_frame(frame: RemoteMessageFrame) -> RemoteEnvelope<MessageE
       };
       MessageEnvelope::user_with_metadata(serialized, metadata)
     },
+    | RemotePayloadFrame::Terminated { .. }
+    | RemotePayloadFrame::Heartbeat
+    | RemotePayloadFrame::HeartbeatAck
+    | RemotePayloadFrame::Spawn { .. }
+    | RemotePayloadFrame::Spawned { .. } => {
+      return None;
+    },
   };
-  RemoteEnvelope::new(message_envelope, priority, channel)
+  Some(RemoteEnvelope::new(message_envelope, priority, channel))
 }
 
 /// Helper to wrap a serialized user message into a [`MessageEnvelope`].
@@ -97,3 +249,482 @@ pub fn control_remote_envelope_with_reply(
   let envelope = MessageEnvelope::user_with_metadata(serialized, metadata);
   RemoteEnvelope::new(envelope, priority, PriorityChannel::Control)
 }
+
+/// Encodes a [`RemoteWireFrame`] into a frame body (without the length prefix).
+///
+/// # Errors
+/// Returns [`RemoteCodecError`] when the payload has no wire representation or exceeds
+/// [`MAX_FRAME_SIZE`].
+pub fn encode_wire_frame(frame: &RemoteWireFrame) -> Result<Vec<u8>, RemoteCodecError> {
+  let mut writer = WireWriter::new();
+  match frame {
+    | RemoteWireFrame::Handshake(handshake) => encode_handshake(&mut writer, KIND_HANDSHAKE, handshake)?,
+    | RemoteWireFrame::HandshakeAck(handshake) => encode_handshake(&mut writer, KIND_HANDSHAKE_ACK, handshake)?,
+    | RemoteWireFrame::HandshakeRejected { node, reason } => {
+      encode_header(&mut writer, MIN_PROTOCOL_VERSION, KIND_HANDSHAKE_REJECTED);
+      writer.put_node(node)?;
+      encode_rejection(&mut writer, *reason);
+    },
+    | RemoteWireFrame::Delivery(delivery) => encode_delivery_body(&mut writer, delivery)?,
+    | RemoteWireFrame::Batch(deliveries) => {
+      let frames = deliveries.iter().map(encode_delivery).collect::<Result<Vec<_>, _>>()?;
+      encode_batch_body(&mut writer, &frames)?;
+    },
+  }
+  finish_frame(writer)
+}
+
+/// Decodes a frame body produced by [`encode_wire_frame`].
+///
+/// Handshake frames are decoded whatever version they announce, so that the receiver can refuse
+/// the association; deliveries must use a version accepted by [`is_supported_version`].
+///
+/// # Errors
+/// Returns [`RemoteCodecError`] when the body is malformed or uses an unsupported version.
+pub fn decode_wire_frame(bytes: &[u8]) -> Result<RemoteWireFrame, RemoteCodecError> {
+  let mut reader = WireReader::new(bytes);
+  if reader.take_array::<4>()? != WIRE_MAGIC {
+    return Err(RemoteCodecError::InvalidMagic);
+  }
+  let version = reader.u16()?;
+  let frame = match reader.u8()? {
+    | KIND_HANDSHAKE => RemoteWireFrame::Handshake(decode_handshake(&mut reader, version)?),
+    | KIND_HANDSHAKE_ACK => RemoteWireFrame::HandshakeAck(decode_handshake(&mut reader, version)?),
+    | KIND_HANDSHAKE_REJECTED => {
+      let node = reader.node()?;
+      RemoteWireFrame::HandshakeRejected { node, reason: decode_rejection(&mut reader)? }
+    },
+    | KIND_DELIVERY => {
+      if !is_supported_version(version) {
+        return Err(RemoteCodecError::UnsupportedVersion(version));
+      }
+      let target = decode_pid(&mut reader)?;
+      RemoteWireFrame::Delivery(Box::new(RemoteDelivery::new(target, decode_frame(&mut reader)?)))
+    },
+    | KIND_BATCH => {
+      if version < BATCH_PROTOCOL_VERSION || !is_supported_version(version) {
+        return Err(RemoteCodecError::UnsupportedVersion(version));
+      }
+      RemoteWireFrame::Batch(decode_batch_body(&mut reader)?)
+    },
+    | other => return Err(RemoteCodecError::UnknownTag(other)),
+  };
+  reader.finish()?;
+  Ok(frame)
+}
+
+/// Encodes a [`RemoteDelivery`] into a frame body (without the length prefix).
+///
+/// # Errors
+/// Returns [`RemoteCodecError`] when the payload has no wire representation or exceeds
+/// [`MAX_FRAME_SIZE`].
+pub fn encode_delivery(delivery: &RemoteDelivery) -> Result<Vec<u8>, RemoteCodecError> {
+  let mut writer = WireWriter::new();
+  encode_delivery_body(&mut writer, delivery)?;
+  finish_frame(writer)
+}
+
+/// Decodes a delivery frame body produced by [`encode_delivery`].
+///
+/// # Errors
+/// Returns [`RemoteCodecError`] when the body is malformed, uses an unsupported version or is a
+/// handshake frame.
+pub fn decode_delivery(bytes: &[u8]) -> Result<RemoteDelivery, RemoteCodecError> {
+  match decode_wire_frame(bytes)? {
+    | RemoteWireFrame::Delivery(delivery) => Ok(*delivery),
+    | RemoteWireFrame::Handshake(_) => Err(RemoteCodecError::UnexpectedFrame(KIND_HANDSHAKE)),
+    | RemoteWireFrame::HandshakeAck(_) => Err(RemoteCodecError::UnexpectedFrame(KIND_HANDSHAKE_ACK)),
+    | RemoteWireFrame::HandshakeRejected { .. } => Err(RemoteCodecError::UnexpectedFrame(KIND_HANDSHAKE_REJECTED)),
+    | RemoteWireFrame::Batch(_) => Err(RemoteCodecError::UnexpectedFrame(KIND_BATCH)),
+  }
+}
+
+/// Encodes delivery frame bodies produced by [`encode_delivery`] into a single batch frame body.
+///
+/// # Errors
+/// Returns [`RemoteCodecError::FrameTooLarge`] when the batch exceeds [`MAX_FRAME_SIZE`].
+pub fn encode_batch(frames: &[Vec<u8>]) -> Result<Vec<u8>, RemoteCodecError> {
+  let mut writer = WireWriter::new();
+  encode_batch_body(&mut writer, frames)?;
+  finish_frame(writer)
+}
+
+/// Prepends the big-endian length prefix to a frame body.
+///
+/// # Errors
+/// Returns [`RemoteCodecError::FrameTooLarge`] when the body exceeds [`MAX_FRAME_SIZE`].
+pub fn length_prefixed(body: &[u8]) -> Result<Vec<u8>, RemoteCodecError> {
+  if body.len() > MAX_FRAME_SIZE {
+    return Err(RemoteCodecError::FrameTooLarge(body.len()));
+  }
+  let mut writer = WireWriter::new();
+  writer.put_bytes(body)?;
+  Ok(writer.into_bytes())
+}
+
+/// Interprets a length prefix read from a stream transport.
+///
+/// # Errors
+/// Returns [`RemoteCodecError::FrameTooLarge`] when the announced length exceeds
+/// [`MAX_FRAME_SIZE`].
+pub const fn frame_length(prefix: [u8; FRAME_LENGTH_PREFIX_SIZE]) -> Result<usize, RemoteCodecError> {
+  let len = u32::from_be_bytes(prefix) as usize;
+  if len > MAX_FRAME_SIZE {
+    return Err(RemoteCodecError::FrameTooLarge(len));
+  }
+  Ok(len)
+}
+
+fn encode_header(writer: &mut WireWriter, version: u16, kind: u8) {
+  for byte in WIRE_MAGIC {
+    writer.put_u8(byte);
+  }
+  writer.put_u16(version);
+  writer.put_u8(kind);
+}
+
+fn finish_frame(writer: WireWriter) -> Result<Vec<u8>, RemoteCodecError> {
+  let bytes = writer.into_bytes();
+  if bytes.len() > MAX_FRAME_SIZE {
+    return Err(RemoteCodecError::FrameTooLarge(bytes.len()));
+  }
+  Ok(bytes)
+}
+
+fn encode_delivery_body(writer: &mut WireWriter, delivery: &RemoteDelivery) -> Result<(), RemoteCodecError> {
+  encode_header(writer, MIN_PROTOCOL_VERSION, KIND_DELIVERY);
+  writer.put_str(&delivery.target.to_string())?;
+  encode_frame(writer, &delivery.frame)
+}
+
+fn encode_batch_body(writer: &mut WireWriter, frames: &[Vec<u8>]) -> Result<(), RemoteCodecError> {
+  encode_header(writer, BATCH_PROTOCOL_VERSION, KIND_BATCH);
+  writer.put_u32(u32::try_from(frames.len()).map_err(|_| RemoteCodecError::FrameTooLarge(frames.len()))?);
+  for frame in frames {
+    writer.put_bytes(frame)?;
+  }
+  Ok(())
+}
+
+fn decode_batch_body(reader: &mut WireReader<'_>) -> Result<Vec<RemoteDelivery>, RemoteCodecError> {
+  let count = reader.u32()?;
+  let mut deliveries = Vec::new();
+  for _ in 0..count {
+    match decode_wire_frame(&reader.bytes()?)? {
+      | RemoteWireFrame::Delivery(delivery) => deliveries.push(*delivery),
+      | _ => return Err(RemoteCodecError::UnexpectedFrame(KIND_BATCH)),
+    }
+  }
+  Ok(deliveries)
+}
+
+fn encode_handshake(writer: &mut WireWriter, kind: u8, handshake: &RemoteHandshake) -> Result<(), RemoteCodecError> {
+  encode_header(writer, handshake.version, kind);
+  writer.put_str(&handshake.system.to_string())?;
+  writer.put_node(&handshake.node)
+}
+
+fn decode_handshake(reader: &mut WireReader<'_>, version: u16) -> Result<RemoteHandshake, RemoteCodecError> {
+  let system = SystemId::new(reader.string()?);
+  let node = reader.node()?;
+  Ok(RemoteHandshake::new(system, node).with_version(version))
+}
+
+fn encode_rejection(writer: &mut WireWriter, reason: HandshakeRejection) {
+  match reason {
+    | HandshakeRejection::UnsupportedVersion(version) => {
+      writer.put_u8(REJECT_UNSUPPORTED_VERSION);
+      writer.put_u16(version);
+    },
+    | HandshakeRejection::SystemMismatch => writer.put_u8(REJECT_SYSTEM_MISMATCH),
+  }
+}
+
+fn decode_rejection(reader: &mut WireReader<'_>) -> Result<HandshakeRejection, RemoteCodecError> {
+  match reader.u8()? {
+    | REJECT_UNSUPPORTED_VERSION => Ok(HandshakeRejection::UnsupportedVersion(reader.u16()?)),
+    | REJECT_SYSTEM_MISMATCH => Ok(HandshakeRejection::SystemMismatch),
+    | other => Err(RemoteCodecError::UnknownTag(other)),
+  }
+}
+
+fn encode_frame(writer: &mut WireWriter, frame: &RemoteMessageFrame) -> Result<(), RemoteCodecError> {
+  writer.put_i8(frame.priority);
+  writer.put_u8(match frame.channel {
+    | PriorityChannel::Regular => CHANNEL_REGULAR,
+    | PriorityChannel::Control => CHANNEL_CONTROL,
+  });
+  let reply_to = frame.reply_to.as_ref().map(ToString::to_string);
+  writer.put_opt_str(reply_to.as_deref())?;
+  match &frame.payload {
+    | RemotePayloadFrame::System(message) => {
+      writer.put_u8(PAYLOAD_SYSTEM);
+      encode_system_message(writer, message)
+    },
+    | RemotePayloadFrame::User { serialized } => {
+      writer.put_u8(PAYLOAD_USER);
+      encode_serialized(writer, serialized)
+    },
+    | RemotePayloadFrame::Terminated { pid, reason } => {
+      writer.put_u8(PAYLOAD_TERMINATED);
+      writer.put_str(&pid.to_string())?;
+      encode_reason(writer, reason)
+    },
+    | RemotePayloadFrame::Heartbeat => {
+      writer.put_u8(PAYLOAD_HEARTBEAT);
+      Ok(())
+    },
+    | RemotePayloadFrame::HeartbeatAck => {
+      writer.put_u8(PAYLOAD_HEARTBEAT_ACK);
+      Ok(())
+    },
+    | RemotePayloadFrame::Spawn { kind } => {
+      writer.put_u8(PAYLOAD_SPAWN);
+      writer.put_str(kind)
+    },
+    | RemotePayloadFrame::Spawned { result } => {
+      writer.put_u8(PAYLOAD_SPAWNED);
+      encode_spawn_result(writer, result)
+    },
+  }
+}
+
+fn decode_frame(reader: &mut WireReader<'_>) -> Result<RemoteMessageFrame, RemoteCodecError> {
+  let priority = reader.i8()?;
+  let channel = match reader.u8()? {
+    | CHANNEL_REGULAR => PriorityChannel::Regular,
+    | CHANNEL_CONTROL => PriorityChannel::Control,
+    | other => return Err(RemoteCodecError::UnknownTag(other)),
+  };
+  let reply_to =
+    reader.opt_string()?.map(|pid| Pid::parse(&pid).map_err(|_| RemoteCodecError::InvalidPid)).transpose()?;
+  let payload = match reader.u8()? {
+    | PAYLOAD_SYSTEM => RemotePayloadFrame::System(decode_system_message(reader)?),
+    | PAYLOAD_USER => RemotePayloadFrame::User { serialized: decode_serialized(reader)? },
+    | PAYLOAD_TERMINATED => {
+      let pid = decode_pid(reader)?;
+      RemotePayloadFrame::Terminated { pid, reason: decode_reason(reader)? }
+    },
+    | PAYLOAD_HEARTBEAT => RemotePayloadFrame::Heartbeat,
+    | PAYLOAD_HEARTBEAT_ACK => RemotePayloadFrame::HeartbeatAck,
+    | PAYLOAD_SPAWN => RemotePayloadFrame::Spawn { kind: reader.string()? },
+    | PAYLOAD_SPAWNED => RemotePayloadFrame::Spawned { result: decode_spawn_result(reader)? },
+    | other => return Err(RemoteCodecError::UnknownTag(other)),
+  };
+  Ok(RemoteMessageFrame::new(priority, channel, payload, reply_to))
+}
+
+fn decode_pid(reader: &mut WireReader<'_>) -> Result<Pid, RemoteCodecError> {
+  Pid::parse(&reader.string()?).map_err(|_| RemoteCodecError::InvalidPid)
+}
+
+fn encode_spawn_result(writer: &mut WireWriter, result: &RemoteSpawnResult) -> Result<(), RemoteCodecError> {
+  match result {
+    | Ok(pid) => {
+      writer.put_u8(SPAWN_OK);
+      writer.put_str(&pid.to_string())
+    },
+    | Err(RemoteSpawnError::UnknownKind(kind)) => {
+      writer.put_u8(SPAWN_UNKNOWN_KIND);
+      writer.put_str(kind)
+    },
+    | Err(RemoteSpawnError::NoActivator) => {
+      writer.put_u8(SPAWN_NO_ACTIVATOR);
+      Ok(())
+    },
+    | Err(RemoteSpawnError::Rejected) => {
+      writer.put_u8(SPAWN_REJECTED);
+      Ok(())
+    },
+  }
+}
+
+fn decode_spawn_result(reader: &mut WireReader<'_>) -> Result<RemoteSpawnResult, RemoteCodecError> {
+  match reader.u8()? {
+    | SPAWN_OK => Ok(Ok(decode_pid(reader)?)),
+    | SPAWN_UNKNOWN_KIND => Ok(Err(RemoteSpawnError::UnknownKind(reader.string()?))),
+    | SPAWN_NO_ACTIVATOR => Ok(Err(RemoteSpawnError::NoActivator)),
+    | SPAWN_REJECTED => Ok(Err(RemoteSpawnError::Rejected)),
+    | other => Err(RemoteCodecError::UnknownTag(other)),
+  }
+}
+
+fn encode_reason(writer: &mut WireWriter, reason: &DeadLetterReason) -> Result<(), RemoteCodecError> {
+  writer.put_u8(match reason {
+    | DeadLetterReason::UnregisteredPid => REASON_UNREGISTERED_PID,
+    | DeadLetterReason::Terminated => REASON_TERMINATED,
+    | DeadLetterReason::DeliveryRejected => REASON_DELIVERY_REJECTED,
+    | DeadLetterReason::NetworkUnreachable => REASON_NETWORK_UNREACHABLE,
+    | DeadLetterReason::Custom(_) => return Err(RemoteCodecError::UnsupportedReason),
+  });
+  Ok(())
+}
+
+fn decode_reason(reader: &mut WireReader<'_>) -> Result<DeadLetterReason, RemoteCodecError> {
+  match reader.u8()? {
+    | REASON_UNREGISTERED_PID => Ok(DeadLetterReason::UnregisteredPid),
+    | REASON_TERMINATED => Ok(DeadLetterReason::Terminated),
+    | REASON_DELIVERY_REJECTED => Ok(DeadLetterReason::DeliveryRejected),
+    | REASON_NETWORK_UNREACHABLE => Ok(DeadLetterReason::NetworkUnreachable),
+    | other => Err(RemoteCodecError::UnknownTag(other)),
+  }
+}
+
+fn encode_system_message(writer: &mut WireWriter, message: &SystemMessage) -> Result<(), RemoteCodecError> {
+  match message {
+    | SystemMessage::Watch(id) => {
+      writer.put_u8(SYSTEM_WATCH);
+      writer.put_u64(id.0 as u64);
+    },
+    | SystemMessage::Unwatch(id) => {
+      writer.put_u8(SYSTEM_UNWATCH);
+      writer.put_u64(id.0 as u64);
+    },
+    | SystemMessage::Terminated(id) => {
+      writer.put_u8(SYSTEM_TERMINATED);
+      writer.put_u64(id.0 as u64);
+    },
+    | SystemMessage::Stop => writer.put_u8(SYSTEM_STOP),
+    | SystemMessage::Failure(info) => {
+      writer.put_u8(SYSTEM_FAILURE);
+      encode_failure_info(writer, info)?;
+    },
+    | SystemMessage::Restart => writer.put_u8(SYSTEM_RESTART),
+    | SystemMessage::Suspend => writer.put_u8(SYSTEM_SUSPEND),
+    | SystemMessage::Resume => writer.put_u8(SYSTEM_RESUME),
+    | SystemMessage::Escalate(info) => {
+      writer.put_u8(SYSTEM_ESCALATE);
+      encode_failure_info(writer, info)?;
+    },
+    | SystemMessage::ReceiveTimeout => writer.put_u8(SYSTEM_RECEIVE_TIMEOUT),
+    | _ => return Err(RemoteCodecError::UnsupportedSystemMessage),
+  }
+  Ok(())
+}
+
+fn decode_system_message(reader: &mut WireReader<'_>) -> Result<SystemMessage, RemoteCodecError> {
+  match reader.u8()? {
+    | SYSTEM_WATCH => Ok(SystemMessage::Watch(decode_actor_id(reader)?)),
+    | SYSTEM_UNWATCH => Ok(SystemMessage::Unwatch(decode_actor_id(reader)?)),
+    | SYSTEM_TERMINATED => Ok(SystemMessage::Terminated(decode_actor_id(reader)?)),
+    | SYSTEM_STOP => Ok(SystemMessage::Stop),
+    | SYSTEM_FAILURE => Ok(SystemMessage::Failure(decode_failure_info(reader)?)),
+    | SYSTEM_RESTART => Ok(SystemMessage::Restart),
+    | SYSTEM_SUSPEND => Ok(SystemMessage::Suspend),
+    | SYSTEM_RESUME => Ok(SystemMessage::Resume),
+    | SYSTEM_ESCALATE => Ok(SystemMessage::Escalate(decode_failure_info(reader)?)),
+    | SYSTEM_RECEIVE_TIMEOUT => Ok(SystemMessage::ReceiveTimeout),
+    | other => Err(RemoteCodecError::UnknownTag(other)),
+  }
+}
+
+/// Failures cross the wire by description: the behaviour failure is rebuilt as a
+/// [`DefaultBehaviorFailure`] carrying the original description and debug details.
+fn encode_failure_info(writer: &mut WireWriter, info: &FailureInfo) -> Result<(), RemoteCodecError> {
+  writer.put_u64(info.actor.0 as u64);
+  let segments = info.path.segments();
+  let depth = u16::try_from(segments.len()).map_err(|_| RemoteCodecError::FrameTooLarge(segments.len()))?;
+  writer.put_u16(depth);
+  for id in segments {
+    writer.put_u64(id.0 as u64);
+  }
+  writer.put_str(&info.failure.description())?;
+  let debug = info
+    .failure
+    .behavior()
+    .as_any()
+    .downcast_ref::<DefaultBehaviorFailure>()
+    .and_then(DefaultBehaviorFailure::debug_details);
+  writer.put_opt_str(debug)?;
+  encode_failure_metadata(writer, &info.failure_metadata)?;
+  match info.failure_escalation_stage {
+    | FailureEscalationStage::Initial => writer.put_u8(STAGE_INITIAL),
+    | FailureEscalationStage::Escalated { hops } => {
+      writer.put_u8(STAGE_ESCALATED);
+      writer.put_u8(hops);
+    },
+  }
+  Ok(())
+}
+
+fn decode_failure_info(reader: &mut WireReader<'_>) -> Result<FailureInfo, RemoteCodecError> {
+  let actor = decode_actor_id(reader)?;
+  let depth = reader.u16()?;
+  let mut path = ActorPath::new();
+  for _ in 0..depth {
+    path = path.push_child(decode_actor_id(reader)?);
+  }
+  let mut failure = DefaultBehaviorFailure::from_message(reader.string()?);
+  if let Some(debug) = reader.opt_string()? {
+    failure = failure.with_debug_details(debug);
+  }
+  let metadata = decode_failure_metadata(reader)?;
+  let stage = match reader.u8()? {
+    | STAGE_INITIAL => FailureEscalationStage::Initial,
+    | STAGE_ESCALATED => FailureEscalationStage::Escalated { hops: reader.u8()? },
+    | other => return Err(RemoteCodecError::UnknownTag(other)),
+  };
+  Ok(FailureInfo::new_with_metadata(actor, path, ActorFailure::new(failure), metadata).with_stage(stage))
+}
+
+fn encode_failure_metadata(writer: &mut WireWriter, metadata: &FailureMetadata) -> Result<(), RemoteCodecError> {
+  writer.put_opt_str(metadata.component.as_deref())?;
+  writer.put_opt_str(metadata.endpoint.as_deref())?;
+  writer.put_opt_str(metadata.transport.as_deref())?;
+  let tag_count =
+    u16::try_from(metadata.tags.len()).map_err(|_| RemoteCodecError::FrameTooLarge(metadata.tags.len()))?;
+  writer.put_u16(tag_count);
+  for (key, value) in &metadata.tags {
+    writer.put_str(key)?;
+    writer.put_str(value)?;
+  }
+  Ok(())
+}
+
+fn decode_failure_metadata(reader: &mut WireReader<'_>) -> Result<FailureMetadata, RemoteCodecError> {
+  let mut metadata = FailureMetadata::new();
+  metadata.component = reader.opt_string()?;
+  metadata.endpoint = reader.opt_string()?;
+  metadata.transport = reader.opt_string()?;
+  for _ in 0..reader.u16()? {
+    let key = reader.string()?;
+    let value = reader.string()?;
+    metadata.tags.insert(key, value);
+  }
+  Ok(metadata)
+}
+
+fn decode_actor_id(reader: &mut WireReader<'_>) -> Result<ActorId, RemoteCodecError> {
+  let raw = reader.u64()?;
+  usize::try_from(raw).map(ActorId).map_err(|_| RemoteCodecError::Truncated)
+}
+
+fn encode_serialized(writer: &mut WireWriter, serialized: &SerializedMessage) -> Result<(), RemoteCodecError> {
+  writer.put_u32(serialized.serializer_id.value());
+  writer.put_opt_str(serialized.type_name.as_deref())?;
+  let header_count =
+    u16::try_from(serialized.headers.len()).map_err(|_| RemoteCodecError::FrameTooLarge(serialized.headers.len()))?;
+  writer.put_u16(header_count);
+  for header in &serialized.headers {
+    writer.put_str(&header.key)?;
+    writer.put_str(&header.value)?;
+  }
+  writer.put_bytes(&serialized.payload)
+}
+
+fn decode_serialized(reader: &mut WireReader<'_>) -> Result<SerializedMessage, RemoteCodecError> {
+  let serializer_id = SerializerId::new(reader.u32()?);
+  let type_name = reader.opt_string()?;
+  let header_count = reader.u16()?;
+  let mut headers = Vec::with_capacity(usize::from(header_count));
+  for _ in 0..header_count {
+    let key = reader.string()?;
+    let value = reader.string()?;
+    headers.push(MessageHeader::new(key, value));
+  }
+  let payload = reader.bytes()?;
+  let mut serialized = SerializedMessage::new(serializer_id, payload);
+  serialized.type_name = type_name;
+  serialized.headers = headers;
+  Ok(serialized)
+}
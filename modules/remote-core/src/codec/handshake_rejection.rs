@@ -0,0 +1,10 @@
+/// Reason for refusing an association with a peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum HandshakeRejection {
+  /// The peer speaks a protocol version older than the oldest supported one.
+  #[error("protocol version {0} is not supported")]
+  UnsupportedVersion(u16),
+  /// The peer belongs to another actor system.
+  #[error("peer belongs to another actor system")]
+  SystemMismatch,
+}
@@ -0,0 +1,20 @@
+use cellex_actor_core_rs::api::process::pid::Pid;
+
+use super::remote_message_frame::RemoteMessageFrame;
+
+/// Unit of transfer between endpoints: a [`RemoteMessageFrame`] addressed to a target [`Pid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteDelivery {
+  /// PID of the receiving actor on the remote node.
+  pub target: Pid,
+  /// Frame carrying the payload.
+  pub frame:  RemoteMessageFrame,
+}
+
+impl RemoteDelivery {
+  /// Creates a new delivery.
+  #[must_use]
+  pub const fn new(target: Pid, frame: RemoteMessageFrame) -> Self {
+    Self { target, frame }
+  }
+}
@@ -0,0 +1,48 @@
+use cellex_actor_core_rs::api::process::pid::{NodeId, SystemId};
+
+use super::{HandshakeRejection, MIN_PROTOCOL_VERSION, PROTOCOL_VERSION};
+
+/// Identity announced by each side when an association between two nodes is opened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteHandshake {
+  /// Wire protocol version spoken by the announcing node.
+  pub version: u16,
+  /// Actor system the announcing node belongs to.
+  pub system:  SystemId,
+  /// Address the announcing node listens on.
+  pub node:    NodeId,
+}
+
+impl RemoteHandshake {
+  /// Creates a handshake announcing [`PROTOCOL_VERSION`].
+  #[must_use]
+  pub const fn new(system: SystemId, node: NodeId) -> Self {
+    Self { version: PROTOCOL_VERSION, system, node }
+  }
+
+  /// Overrides the announced protocol version.
+  #[must_use]
+  pub const fn with_version(mut self, version: u16) -> Self {
+    self.version = version;
+    self
+  }
+
+  /// Checks whether the local node announcing `self` can associate with `peer`, returning the
+  /// protocol version negotiated with it: the older of both announced versions.
+  ///
+  /// Peers announcing a newer version are accepted, since they still read the frames of the
+  /// local one; a newer peer that dropped support for it refuses the association on its side.
+  ///
+  /// # Errors
+  /// Returns [`HandshakeRejection`] when `peer` speaks a version older than
+  /// [`MIN_PROTOCOL_VERSION`] or belongs to another actor system.
+  pub fn accept(&self, peer: &Self) -> Result<u16, HandshakeRejection> {
+    if peer.version < MIN_PROTOCOL_VERSION {
+      return Err(HandshakeRejection::UnsupportedVersion(peer.version));
+    }
+    if peer.system != self.system {
+      return Err(HandshakeRejection::SystemMismatch);
+    }
+    Ok(self.version.min(peer.version))
+  }
+}
@@ -1,6 +1,13 @@
-use cellex_actor_core_rs::api::mailbox::messages::SystemMessage;
+use alloc::string::String;
+
+use cellex_actor_core_rs::api::{
+  mailbox::messages::SystemMessage,
+  process::{dead_letter::DeadLetterReason, pid::Pid},
+};
 use cellex_serialization_core_rs::message::SerializedMessage;
 
+use crate::activation::RemoteSpawnResult;
+
 /// Payload variants for remote transport.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RemotePayloadFrame {
@@ -11,4 +18,26 @@ pub enum RemotePayloadFrame {
     /// Serialized representation produced by the configured serializer.
     serialized: SerializedMessage,
   },
+  /// Notification that a watched actor is gone, addressed to one of its remote watchers.
+  Terminated {
+    /// PID of the actor that terminated.
+    pid:    Pid,
+    /// Why the actor is considered terminated.
+    reason: DeadLetterReason,
+  },
+  /// Liveness probe sent to the heartbeat PID of a remote node; the reply-to PID names the sender.
+  Heartbeat,
+  /// Answer to a [`RemotePayloadFrame::Heartbeat`], addressed to the probing node.
+  HeartbeatAck,
+  /// Request to spawn an actor of a registered kind, sent to the activator PID of a node; the
+  /// reply-to PID receives the outcome.
+  Spawn {
+    /// Name the `Props` factory is registered under on the target node.
+    kind: String,
+  },
+  /// Outcome of a [`RemotePayloadFrame::Spawn`], addressed to the reply-to PID of the request.
+  Spawned {
+    /// PID of the spawned actor, or why the target node refused the request.
+    result: RemoteSpawnResult,
+  },
 }
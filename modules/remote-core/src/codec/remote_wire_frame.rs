@@ -0,0 +1,26 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use super::{HandshakeRejection, RemoteDelivery, RemoteHandshake};
+
+/// Frame body exchanged between endpoints, preceded on the wire by the magic bytes, the protocol
+/// version and the frame kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteWireFrame {
+  /// First frame of every association, announcing the identity of the connecting node.
+  Handshake(RemoteHandshake),
+  /// Acceptance of a handshake, announcing the identity of the accepting node.
+  HandshakeAck(RemoteHandshake),
+  /// Refusal of a handshake by `node`.
+  HandshakeRejected {
+    /// Node refusing the association.
+    node:   NodeId,
+    /// Why the association was refused.
+    reason: HandshakeRejection,
+  },
+  /// Message addressed to an actor of the receiving node.
+  Delivery(Box<RemoteDelivery>),
+  /// Several messages written together, in order, by a batching endpoint.
+  Batch(Vec<RemoteDelivery>),
+}
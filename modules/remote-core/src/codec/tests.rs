@@ -0,0 +1,281 @@
+extern crate std;
+
+use std::{
+  format,
+  string::{String, ToString},
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor::{
+    actor_failure::{ActorFailure, DefaultBehaviorFailure},
+    ActorId, ActorPath,
+  },
+  failure::{metadata::FailureEscalationStage, FailureInfo, FailureMetadata},
+  mailbox::messages::{PriorityChannel, SystemMessage},
+  process::{
+    dead_letter::DeadLetterReason,
+    pid::{NodeId, Pid, SystemId},
+  },
+};
+use cellex_serialization_core_rs::{message::SerializedMessage, SerializerId};
+
+use super::{
+  decode_delivery, decode_wire_frame, encode_batch, encode_delivery, encode_wire_frame, frame_length, length_prefixed,
+  HandshakeRejection, RemoteCodecError, RemoteDelivery, RemoteHandshake, RemoteMessageFrame, RemotePayloadFrame,
+  RemoteWireFrame, FRAME_LENGTH_PREFIX_SIZE, MAX_FRAME_SIZE, MIN_PROTOCOL_VERSION, PROTOCOL_VERSION, WIRE_MAGIC,
+};
+use crate::activation::RemoteSpawnError;
+
+type TestResult<T = ()> = Result<T, String>;
+
+fn remote_pid(path: &[usize]) -> Pid {
+  let mut actor_path = ActorPath::new();
+  for id in path {
+    actor_path = actor_path.push_child(ActorId(*id));
+  }
+  Pid::new(SystemId::new("sys"), actor_path).with_node(NodeId::new("10.0.0.2", Some(2552)))
+}
+
+#[test]
+fn delivery_roundtrip_preserves_user_payload_and_reply_to() -> TestResult {
+  let mut serialized = SerializedMessage::new(SerializerId::new(7), vec![1, 2, 3]).with_type_name("example.Ping");
+  serialized.push_header("trace-id", "abc");
+  let frame = RemoteMessageFrame::new(
+    3,
+    PriorityChannel::Regular,
+    RemotePayloadFrame::User { serialized },
+    Some(remote_pid(&[9])),
+  );
+  let delivery = RemoteDelivery::new(remote_pid(&[1, 2]), frame);
+
+  let bytes = encode_delivery(&delivery).map_err(|err| format!("encode: {err}"))?;
+  let decoded = decode_delivery(&bytes).map_err(|err| format!("decode: {err}"))?;
+
+  assert_eq!(decoded, delivery);
+  Ok(())
+}
+
+#[test]
+fn delivery_roundtrip_preserves_system_messages() -> TestResult {
+  let messages = vec![
+    SystemMessage::Watch(ActorId(4)),
+    SystemMessage::Unwatch(ActorId(4)),
+    SystemMessage::Terminated(ActorId(4)),
+    SystemMessage::Stop,
+    SystemMessage::Restart,
+    SystemMessage::Suspend,
+    SystemMessage::Resume,
+    SystemMessage::ReceiveTimeout,
+  ];
+  for message in messages {
+    let frame =
+      RemoteMessageFrame::new(message.priority(), PriorityChannel::Control, RemotePayloadFrame::System(message), None);
+    let delivery = RemoteDelivery::new(remote_pid(&[1]), frame);
+    let bytes = encode_delivery(&delivery).map_err(|err| format!("encode: {err}"))?;
+    assert_eq!(decode_delivery(&bytes).map_err(|err| format!("decode: {err}"))?, delivery);
+  }
+  Ok(())
+}
+
+#[test]
+fn delivery_roundtrip_preserves_failures_with_metadata_and_stage() -> TestResult {
+  let metadata = FailureMetadata::new()
+    .with_component("orders")
+    .with_endpoint("10.0.0.2:2552")
+    .insert_tag("attempt", "3")
+    .insert_tag("shard", "7");
+  let failure = DefaultBehaviorFailure::from_message("boom").with_debug_details("stack: handle_order");
+  let info = FailureInfo::new_with_metadata(
+    ActorId(5),
+    ActorPath::new().push_child(ActorId(1)).push_child(ActorId(5)),
+    ActorFailure::new(failure),
+    metadata,
+  );
+  let escalated = info.escalate_to_parent().ok_or_else(|| "escalation expected".to_string())?;
+  assert_eq!(escalated.failure_escalation_stage, FailureEscalationStage::Escalated { hops: 1 });
+
+  for message in [SystemMessage::Failure(info), SystemMessage::Escalate(escalated)] {
+    let frame = RemoteMessageFrame::new(
+      message.priority(),
+      PriorityChannel::Control,
+      RemotePayloadFrame::System(message),
+      Some(remote_pid(&[1, 5])),
+    );
+    let delivery = RemoteDelivery::new(remote_pid(&[1]), frame);
+    let bytes = encode_delivery(&delivery).map_err(|err| format!("encode: {err}"))?;
+    let decoded = decode_delivery(&bytes).map_err(|err| format!("decode: {err}"))?;
+    assert_eq!(decoded, delivery);
+
+    let RemotePayloadFrame::System(SystemMessage::Failure(info) | SystemMessage::Escalate(info)) =
+      decoded.frame.payload
+    else {
+      return Err("failure payload expected".to_string());
+    };
+    let debug = info
+      .behavior_failure()
+      .as_any()
+      .downcast_ref::<DefaultBehaviorFailure>()
+      .and_then(DefaultBehaviorFailure::debug_details);
+    assert_eq!(debug, Some("stack: handle_order"));
+  }
+  Ok(())
+}
+
+#[test]
+fn delivery_roundtrip_preserves_termination_notices() -> TestResult {
+  for reason in [DeadLetterReason::Terminated, DeadLetterReason::UnregisteredPid] {
+    let payload = RemotePayloadFrame::Terminated { pid: remote_pid(&[2, 3]), reason };
+    let frame = RemoteMessageFrame::new(0, PriorityChannel::Control, payload, None);
+    let delivery = RemoteDelivery::new(remote_pid(&[1]), frame);
+    let bytes = encode_delivery(&delivery).map_err(|err| format!("encode: {err}"))?;
+    assert_eq!(decode_delivery(&bytes).map_err(|err| format!("decode: {err}"))?, delivery);
+  }
+
+  let payload = RemotePayloadFrame::Terminated { pid: remote_pid(&[2]), reason: DeadLetterReason::Custom("custom") };
+  let frame = RemoteMessageFrame::new(0, PriorityChannel::Control, payload, None);
+  assert_eq!(encode_delivery(&RemoteDelivery::new(remote_pid(&[1]), frame)), Err(RemoteCodecError::UnsupportedReason));
+  Ok(())
+}
+
+#[test]
+fn delivery_roundtrip_preserves_heartbeats() -> TestResult {
+  for payload in [RemotePayloadFrame::Heartbeat, RemotePayloadFrame::HeartbeatAck] {
+    let frame = RemoteMessageFrame::new(0, PriorityChannel::Control, payload, Some(remote_pid(&[9])));
+    let delivery = RemoteDelivery::new(remote_pid(&[1]), frame);
+    let bytes = encode_delivery(&delivery).map_err(|err| format!("encode: {err}"))?;
+    assert_eq!(decode_delivery(&bytes).map_err(|err| format!("decode: {err}"))?, delivery);
+  }
+  Ok(())
+}
+
+#[test]
+fn delivery_roundtrip_preserves_spawn_requests_and_outcomes() -> TestResult {
+  let payloads = vec![
+    RemotePayloadFrame::Spawn { kind: "worker".into() },
+    RemotePayloadFrame::Spawned { result: Ok(remote_pid(&[4, 7])) },
+    RemotePayloadFrame::Spawned { result: Err(RemoteSpawnError::UnknownKind("worker".into())) },
+    RemotePayloadFrame::Spawned { result: Err(RemoteSpawnError::NoActivator) },
+    RemotePayloadFrame::Spawned { result: Err(RemoteSpawnError::Rejected) },
+  ];
+  for payload in payloads {
+    let frame = RemoteMessageFrame::new(0, PriorityChannel::Control, payload, Some(remote_pid(&[9])));
+    let delivery = RemoteDelivery::new(remote_pid(&[1]), frame);
+    let bytes = encode_delivery(&delivery).map_err(|err| format!("encode: {err}"))?;
+    assert_eq!(decode_delivery(&bytes).map_err(|err| format!("decode: {err}"))?, delivery);
+  }
+  Ok(())
+}
+
+#[test]
+fn batch_roundtrip_preserves_deliveries_in_order() -> TestResult {
+  let deliveries: Vec<RemoteDelivery> = (0..3)
+    .map(|index| {
+      let serialized = SerializedMessage::new(SerializerId::new(7), vec![index]);
+      let frame = RemoteMessageFrame::new(0, PriorityChannel::Regular, RemotePayloadFrame::User { serialized }, None);
+      RemoteDelivery::new(remote_pid(&[usize::from(index)]), frame)
+    })
+    .collect();
+  let frames =
+    deliveries.iter().map(encode_delivery).collect::<Result<Vec<_>, _>>().map_err(|err| format!("encode: {err}"))?;
+
+  let bytes = encode_batch(&frames).map_err(|err| format!("encode batch: {err}"))?;
+  assert_eq!(bytes, encode_wire_frame(&RemoteWireFrame::Batch(deliveries.clone())).map_err(|err| format!("{err}"))?);
+  assert_eq!(decode_wire_frame(&bytes).map_err(|err| format!("decode: {err}"))?, RemoteWireFrame::Batch(deliveries));
+  assert_eq!(decode_delivery(&bytes), Err(RemoteCodecError::UnexpectedFrame(4)));
+
+  let mut older = bytes;
+  let version_offset = WIRE_MAGIC.len();
+  older[version_offset..version_offset + 2].copy_from_slice(&1_u16.to_be_bytes());
+  assert_eq!(decode_wire_frame(&older), Err(RemoteCodecError::UnsupportedVersion(1)));
+  Ok(())
+}
+
+#[test]
+fn decode_delivery_rejects_truncated_and_trailing_bytes() -> TestResult {
+  let frame =
+    RemoteMessageFrame::new(0, PriorityChannel::Control, RemotePayloadFrame::System(SystemMessage::Stop), None);
+  let bytes = encode_delivery(&RemoteDelivery::new(remote_pid(&[1]), frame)).map_err(|err| format!("encode: {err}"))?;
+
+  assert_eq!(decode_delivery(&bytes[..bytes.len() - 1]), Err(RemoteCodecError::Truncated));
+
+  let mut padded: Vec<u8> = bytes;
+  padded.push(0);
+  assert_eq!(decode_delivery(&padded), Err(RemoteCodecError::TrailingBytes(1)));
+  Ok(())
+}
+
+#[test]
+fn length_prefix_roundtrip() -> TestResult {
+  let body = vec![5_u8; 10];
+  let framed = length_prefixed(&body).map_err(|err| format!("prefix: {err}"))?;
+
+  let mut prefix = [0_u8; FRAME_LENGTH_PREFIX_SIZE];
+  prefix.copy_from_slice(&framed[..FRAME_LENGTH_PREFIX_SIZE]);
+  assert_eq!(frame_length(prefix), Ok(body.len()));
+  assert_eq!(&framed[FRAME_LENGTH_PREFIX_SIZE..], body.as_slice());
+
+  let oversized = u32::try_from(MAX_FRAME_SIZE + 1).map_err(|err| format!("size: {err}"))?.to_be_bytes();
+  assert_eq!(frame_length(oversized), Err(RemoteCodecError::FrameTooLarge(MAX_FRAME_SIZE + 1)));
+  Ok(())
+}
+
+#[test]
+fn handshake_frames_roundtrip() -> TestResult {
+  let handshake = RemoteHandshake::new(SystemId::new("sys"), NodeId::new("10.0.0.1", Some(2551)));
+  let frames = vec![
+    RemoteWireFrame::Handshake(handshake.clone()),
+    RemoteWireFrame::HandshakeAck(RemoteHandshake::new(SystemId::new("sys"), NodeId::new("embedded", None))),
+    RemoteWireFrame::HandshakeRejected {
+      node:   NodeId::new("10.0.0.2", Some(2552)),
+      reason: HandshakeRejection::UnsupportedVersion(7),
+    },
+    RemoteWireFrame::HandshakeRejected { node: handshake.node, reason: HandshakeRejection::SystemMismatch },
+  ];
+  for frame in frames {
+    let bytes = encode_wire_frame(&frame).map_err(|err| format!("encode: {err}"))?;
+    assert_eq!(&bytes[..WIRE_MAGIC.len()], WIRE_MAGIC.as_slice());
+    assert_eq!(decode_wire_frame(&bytes).map_err(|err| format!("decode: {err}"))?, frame);
+    assert!(matches!(decode_delivery(&bytes), Err(RemoteCodecError::UnexpectedFrame(_))));
+  }
+  Ok(())
+}
+
+#[test]
+fn handshake_from_newer_version_is_still_decoded() -> TestResult {
+  let handshake = RemoteHandshake::new(SystemId::new("sys"), NodeId::new("10.0.0.1", Some(2551))).with_version(9);
+  let bytes =
+    encode_wire_frame(&RemoteWireFrame::Handshake(handshake.clone())).map_err(|err| format!("encode: {err}"))?;
+  assert_eq!(decode_wire_frame(&bytes).map_err(|err| format!("decode: {err}"))?, RemoteWireFrame::Handshake(handshake));
+
+  let local = RemoteHandshake::new(SystemId::new("sys"), NodeId::new("10.0.0.2", Some(2552)));
+  let peer = RemoteHandshake::new(SystemId::new("sys"), NodeId::new("10.0.0.1", Some(2551)));
+  assert_eq!(local.accept(&peer), Ok(PROTOCOL_VERSION));
+  assert_eq!(local.accept(&peer.clone().with_version(9)), Ok(PROTOCOL_VERSION));
+  assert_eq!(local.accept(&peer.clone().with_version(1)), Ok(1));
+  assert_eq!(local.accept(&peer.clone().with_version(0)), Err(HandshakeRejection::UnsupportedVersion(0)));
+  assert_eq!(
+    local.accept(&RemoteHandshake::new(SystemId::new("other"), peer.node)),
+    Err(HandshakeRejection::SystemMismatch)
+  );
+  Ok(())
+}
+
+#[test]
+fn decode_rejects_foreign_magic_and_unsupported_delivery_versions() -> TestResult {
+  let frame =
+    RemoteMessageFrame::new(0, PriorityChannel::Control, RemotePayloadFrame::System(SystemMessage::Stop), None);
+  let bytes = encode_delivery(&RemoteDelivery::new(remote_pid(&[1]), frame)).map_err(|err| format!("encode: {err}"))?;
+  let version_offset = WIRE_MAGIC.len();
+  assert_eq!(bytes[version_offset..version_offset + 2], MIN_PROTOCOL_VERSION.to_be_bytes());
+
+  let mut foreign = bytes.clone();
+  foreign[0] = b'X';
+  assert_eq!(decode_wire_frame(&foreign), Err(RemoteCodecError::InvalidMagic));
+
+  let mut newer = bytes;
+  newer[version_offset..version_offset + 2].copy_from_slice(&(PROTOCOL_VERSION + 1).to_be_bytes());
+  assert_eq!(decode_delivery(&newer), Err(RemoteCodecError::UnsupportedVersion(PROTOCOL_VERSION + 1)));
+  Ok(())
+}
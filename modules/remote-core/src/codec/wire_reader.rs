@@ -0,0 +1,143 @@
+use alloc::{string::String, vec::Vec};
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use super::RemoteCodecError;
+
+/// Big-endian byte reader used by the binary frame decoding.
+///
+/// Reads what [`WireWriter`](super::WireWriter) wrote, failing with a [`RemoteCodecError`] on
+/// truncated or malformed input.
+#[derive(Debug)]
+pub struct WireReader<'a> {
+  bytes:  &'a [u8],
+  offset: usize,
+}
+
+impl<'a> WireReader<'a> {
+  /// Creates a reader positioned at the start of `bytes`.
+  #[must_use]
+  pub const fn new(bytes: &'a [u8]) -> Self {
+    Self { bytes, offset: 0 }
+  }
+
+  fn take(&mut self, len: usize) -> Result<&'a [u8], RemoteCodecError> {
+    let end = self.offset.checked_add(len).ok_or(RemoteCodecError::Truncated)?;
+    let slice = self.bytes.get(self.offset..end).ok_or(RemoteCodecError::Truncated)?;
+    self.offset = end;
+    Ok(slice)
+  }
+
+  /// Reads the next `N` bytes.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::Truncated`] when fewer than `N` bytes are left.
+  pub fn take_array<const N: usize>(&mut self) -> Result<[u8; N], RemoteCodecError> {
+    let mut array = [0_u8; N];
+    array.copy_from_slice(self.take(N)?);
+    Ok(array)
+  }
+
+  /// Reads a `u8`.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::Truncated`] when the input is exhausted.
+  pub fn u8(&mut self) -> Result<u8, RemoteCodecError> {
+    Ok(u8::from_be_bytes(self.take_array()?))
+  }
+
+  /// Reads an `i8`.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::Truncated`] when the input is exhausted.
+  pub fn i8(&mut self) -> Result<i8, RemoteCodecError> {
+    Ok(i8::from_be_bytes(self.take_array()?))
+  }
+
+  /// Reads a big-endian `u16`.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::Truncated`] when the input is exhausted.
+  pub fn u16(&mut self) -> Result<u16, RemoteCodecError> {
+    Ok(u16::from_be_bytes(self.take_array()?))
+  }
+
+  /// Reads a big-endian `u32`.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::Truncated`] when the input is exhausted.
+  pub fn u32(&mut self) -> Result<u32, RemoteCodecError> {
+    Ok(u32::from_be_bytes(self.take_array()?))
+  }
+
+  /// Reads a big-endian `u64`.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::Truncated`] when the input is exhausted.
+  pub fn u64(&mut self) -> Result<u64, RemoteCodecError> {
+    Ok(u64::from_be_bytes(self.take_array()?))
+  }
+
+  /// Reads a boolean byte.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::UnknownTag`] when the byte is neither 0 nor 1.
+  pub fn bool(&mut self) -> Result<bool, RemoteCodecError> {
+    match self.u8()? {
+      | 0 => Ok(false),
+      | 1 => Ok(true),
+      | other => Err(RemoteCodecError::UnknownTag(other)),
+    }
+  }
+
+  /// Reads a byte string prefixed with its `u32` length.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::Truncated`] when the input ends before the announced length.
+  pub fn bytes(&mut self) -> Result<Vec<u8>, RemoteCodecError> {
+    let len = self.u32()? as usize;
+    Ok(self.take(len)?.to_vec())
+  }
+
+  /// Reads a length-prefixed UTF-8 string.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::InvalidUtf8`] when the bytes are not valid UTF-8.
+  pub fn string(&mut self) -> Result<String, RemoteCodecError> {
+    String::from_utf8(self.bytes()?).map_err(|_| RemoteCodecError::InvalidUtf8)
+  }
+
+  /// Reads a presence flag followed by the string, if any.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError`] when the flag or the string is malformed.
+  pub fn opt_string(&mut self) -> Result<Option<String>, RemoteCodecError> {
+    if self.bool()? {
+      self.string().map(Some)
+    } else {
+      Ok(None)
+    }
+  }
+
+  /// Reads a node written by [`WireWriter::put_node`](super::WireWriter::put_node).
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError`] when the host or port is malformed.
+  pub fn node(&mut self) -> Result<NodeId, RemoteCodecError> {
+    let host = self.string()?;
+    let port = if self.bool()? { Some(self.u16()?) } else { None };
+    Ok(NodeId::new(host, port))
+  }
+
+  /// Ensures the whole input was read.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::TrailingBytes`] when unread bytes are left.
+  pub const fn finish(self) -> Result<(), RemoteCodecError> {
+    if self.offset == self.bytes.len() {
+      Ok(())
+    } else {
+      Err(RemoteCodecError::TrailingBytes(self.bytes.len() - self.offset))
+    }
+  }
+}
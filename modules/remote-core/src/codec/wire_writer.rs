@@ -0,0 +1,110 @@
+use alloc::vec::Vec;
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use super::RemoteCodecError;
+
+/// Big-endian byte writer used by the binary frame encoding.
+///
+/// Protocols layered on the remote transport use it to encode their payloads in the same layout
+/// as the frames carrying them.
+#[derive(Debug, Default)]
+pub struct WireWriter {
+  buffer: Vec<u8>,
+}
+
+impl WireWriter {
+  /// Creates an empty writer.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { buffer: Vec::new() }
+  }
+
+  /// Appends a `u8`.
+  pub fn put_u8(&mut self, value: u8) {
+    self.buffer.push(value);
+  }
+
+  /// Appends an `i8`.
+  pub fn put_i8(&mut self, value: i8) {
+    self.buffer.extend_from_slice(&value.to_be_bytes());
+  }
+
+  /// Appends a big-endian `u16`.
+  pub fn put_u16(&mut self, value: u16) {
+    self.buffer.extend_from_slice(&value.to_be_bytes());
+  }
+
+  /// Appends a big-endian `u32`.
+  pub fn put_u32(&mut self, value: u32) {
+    self.buffer.extend_from_slice(&value.to_be_bytes());
+  }
+
+  /// Appends a big-endian `u64`.
+  pub fn put_u64(&mut self, value: u64) {
+    self.buffer.extend_from_slice(&value.to_be_bytes());
+  }
+
+  /// Appends a boolean as a single byte.
+  pub fn put_bool(&mut self, value: bool) {
+    self.put_u8(u8::from(value));
+  }
+
+  /// Appends a byte string prefixed with its `u32` length.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::FrameTooLarge`] when `value` is longer than `u32::MAX` bytes.
+  pub fn put_bytes(&mut self, value: &[u8]) -> Result<(), RemoteCodecError> {
+    let len = u32::try_from(value.len()).map_err(|_| RemoteCodecError::FrameTooLarge(value.len()))?;
+    self.put_u32(len);
+    self.buffer.extend_from_slice(value);
+    Ok(())
+  }
+
+  /// Appends a length-prefixed UTF-8 string.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::FrameTooLarge`] when `value` is too long to be encoded.
+  pub fn put_str(&mut self, value: &str) -> Result<(), RemoteCodecError> {
+    self.put_bytes(value.as_bytes())
+  }
+
+  /// Appends a presence flag followed by the string, if any.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::FrameTooLarge`] when `value` is too long to be encoded.
+  pub fn put_opt_str(&mut self, value: Option<&str>) -> Result<(), RemoteCodecError> {
+    match value {
+      | Some(value) => {
+        self.put_bool(true);
+        self.put_str(value)
+      },
+      | None => {
+        self.put_bool(false);
+        Ok(())
+      },
+    }
+  }
+
+  /// Appends the host and optional port of `node`.
+  ///
+  /// # Errors
+  /// Returns [`RemoteCodecError::FrameTooLarge`] when the host is too long to be encoded.
+  pub fn put_node(&mut self, node: &NodeId) -> Result<(), RemoteCodecError> {
+    self.put_str(node.host())?;
+    match node.port() {
+      | Some(port) => {
+        self.put_bool(true);
+        self.put_u16(port);
+      },
+      | None => self.put_bool(false),
+    }
+    Ok(())
+  }
+
+  /// Returns the bytes written so far.
+  #[must_use]
+  pub fn into_bytes(self) -> Vec<u8> {
+    self.buffer
+  }
+}
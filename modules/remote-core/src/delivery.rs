@@ -0,0 +1,18 @@
+mod remote_delivery_error;
+mod remote_frame_interceptor;
+mod remote_inbound_dispatcher;
+mod remote_message;
+mod remote_message_registry;
+mod remote_reply_registry;
+mod remote_reply_route;
+
+#[cfg(test)]
+mod tests;
+
+pub use remote_delivery_error::RemoteDeliveryError;
+pub use remote_frame_interceptor::RemoteFrameInterceptor;
+pub use remote_inbound_dispatcher::{RemoteInboundDispatcher, RemoteProcessRegistry};
+pub use remote_message::RemoteMessage;
+pub use remote_message_registry::RemoteMessageRegistry;
+pub use remote_reply_registry::RemoteReplyRegistry;
+pub use remote_reply_route::RemoteReplyRoute;
@@ -0,0 +1,29 @@
+use alloc::string::String;
+
+use cellex_serialization_core_rs::{error::DeserializationError, SerializerId};
+
+/// Errors raised while delivering an inbound frame to a local actor.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum RemoteDeliveryError {
+  /// The user payload carries no type key.
+  #[error("inbound payload has no type name")]
+  MissingTypeName,
+  /// No decoder is registered for the payload type.
+  #[error("no remote message registered for type {0}")]
+  UnknownMessageType(String),
+  /// The serializer referenced by the payload is not registered locally.
+  #[error("serializer {0} is not registered")]
+  UnknownSerializer(SerializerId),
+  /// The payload bytes could not be decoded.
+  #[error("failed to decode inbound payload: {0}")]
+  Deserialization(DeserializationError),
+  /// The target PID does not refer to a live local actor; a dead letter has been published.
+  #[error("target pid could not be resolved")]
+  Unresolved,
+  /// The target mailbox rejected the message; a dead letter has been published.
+  #[error("target mailbox rejected the message")]
+  Rejected,
+  /// The frame carries a remote-layer notification that no interceptor consumed.
+  #[error("frame carries a notification without handler")]
+  Unhandled,
+}
@@ -0,0 +1,35 @@
+use cellex_utils_core_rs::sync::{shared::SharedBound, ArcShared};
+
+use crate::codec::RemoteDelivery;
+
+#[cfg(target_has_atomic = "ptr")]
+type InterceptFn = dyn Fn(RemoteDelivery) -> Option<RemoteDelivery> + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type InterceptFn = dyn Fn(RemoteDelivery) -> Option<RemoteDelivery>;
+
+/// Hook that sees inbound frames before they are delivered to local mailboxes.
+///
+/// Interceptors implement remote-layer protocols (death watch, heartbeats, ...) on top of the
+/// regular frame stream. An interceptor returns `None` when it consumed the frame and hands the
+/// frame back otherwise.
+#[derive(Clone)]
+pub struct RemoteFrameInterceptor {
+  inner: ArcShared<InterceptFn>,
+}
+
+impl RemoteFrameInterceptor {
+  /// Creates an interceptor from a closure.
+  #[must_use]
+  pub fn new<F>(f: F) -> Self
+  where
+    F: Fn(RemoteDelivery) -> Option<RemoteDelivery> + SharedBound + 'static, {
+    Self { inner: ArcShared::new(f).into_dyn(|func| func as &InterceptFn) }
+  }
+
+  /// Offers `delivery` to the interceptor, returning it when it was not consumed.
+  #[must_use]
+  pub fn intercept(&self, delivery: RemoteDelivery) -> Option<RemoteDelivery> {
+    (self.inner)(delivery)
+  }
+}
@@ -0,0 +1,231 @@
+use alloc::vec::Vec;
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::actor_ref::PriorityActorRef,
+    mailbox::ThreadSafe,
+    messaging::MessageMetadata,
+    process::{
+      dead_letter::{DeadLetter, DeadLetterReason},
+      pid::Pid,
+      process_registry::ProcessRegistry,
+    },
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
+};
+use cellex_serialization_core_rs::{message::SerializedMessage, InMemorySerializerRegistry};
+use cellex_utils_core_rs::sync::{shared::Shared, ArcShared};
+
+use super::{
+  RemoteDeliveryError, RemoteFrameInterceptor, RemoteMessage, RemoteMessageRegistry, RemoteReplyRegistry,
+  RemoteReplyRoute,
+};
+use crate::codec::{RemoteDelivery, RemoteMessageFrame, RemotePayloadFrame};
+
+/// Process registry type shared with the actor system whose actors receive remote frames.
+pub type RemoteProcessRegistry<MF> =
+  ProcessRegistry<PriorityActorRef<AnyMessage, MF>, ArcShared<PriorityEnvelope<AnyMessage>>>;
+
+/// Delivers decoded inbound frames into local mailboxes.
+///
+/// The target PID is resolved through the [`ProcessRegistry`]; unresolved targets and rejected
+/// envelopes are published to the registry's dead letter hub. Frames addressed to a pending reply
+/// PID complete the corresponding `ask` instead. When a [`RemoteReplyRoute`] is configured,
+/// inbound requests carrying a reply-to PID get a responder that answers over the network.
+/// [`RemoteFrameInterceptor`]s see every frame first and may consume it.
+pub struct RemoteInboundDispatcher<MF>
+where
+  MF: MailboxFactory, {
+  registry:     ArcShared<RemoteProcessRegistry<MF>>,
+  messages:     RemoteMessageRegistry,
+  serializers:  InMemorySerializerRegistry,
+  replies:      RemoteReplyRegistry,
+  reply_route:  Option<RemoteReplyRoute>,
+  interceptors: Vec<RemoteFrameInterceptor>,
+}
+
+impl<MF> Clone for RemoteInboundDispatcher<MF>
+where
+  MF: MailboxFactory,
+{
+  fn clone(&self) -> Self {
+    Self {
+      registry:     self.registry.clone(),
+      messages:     self.messages.clone(),
+      serializers:  self.serializers.clone(),
+      replies:      self.replies.clone(),
+      reply_route:  self.reply_route.clone(),
+      interceptors: self.interceptors.clone(),
+    }
+  }
+}
+
+impl<MF> RemoteInboundDispatcher<MF>
+where
+  MF: MailboxFactory,
+{
+  /// Creates a dispatcher delivering into `registry`, decoding payloads with `serializers`.
+  #[must_use]
+  pub fn new(registry: ArcShared<RemoteProcessRegistry<MF>>, serializers: InMemorySerializerRegistry) -> Self {
+    Self {
+      registry,
+      messages: RemoteMessageRegistry::new(),
+      serializers,
+      replies: RemoteReplyRegistry::new(),
+      reply_route: None,
+      interceptors: Vec::new(),
+    }
+  }
+
+  /// Replaces the registry of accepted user message types, typically to share it with the
+  /// outbound side.
+  #[must_use]
+  pub fn with_messages(mut self, messages: RemoteMessageRegistry) -> Self {
+    self.messages = messages;
+    self
+  }
+
+  /// Replaces the registry of pending replies, typically to share it with the outbound side.
+  #[must_use]
+  pub fn with_replies(mut self, replies: RemoteReplyRegistry) -> Self {
+    self.replies = replies;
+    self
+  }
+
+  /// Answers inbound requests carrying a reply-to PID through `route`.
+  #[must_use]
+  pub fn with_reply_route(mut self, route: RemoteReplyRoute) -> Self {
+    self.reply_route = Some(route);
+    self
+  }
+
+  /// Offers every inbound frame to `interceptor` before regular delivery.
+  #[must_use]
+  pub fn with_interceptor(mut self, interceptor: RemoteFrameInterceptor) -> Self {
+    self.interceptors.push(interceptor);
+    self
+  }
+
+  /// Returns the process registry used for resolution.
+  #[must_use]
+  pub const fn registry(&self) -> &ArcShared<RemoteProcessRegistry<MF>> {
+    &self.registry
+  }
+
+  /// Returns the registry of accepted user message types.
+  #[must_use]
+  pub const fn messages(&self) -> &RemoteMessageRegistry {
+    &self.messages
+  }
+
+  /// Returns the registry of pending replies.
+  #[must_use]
+  pub const fn replies(&self) -> &RemoteReplyRegistry {
+    &self.replies
+  }
+
+  /// Accepts inbound user messages of type `U`.
+  pub fn register_message<U>(&self)
+  where
+    U: RemoteMessage, {
+    self.messages.register::<U>();
+  }
+
+  /// Delivers a decoded frame to the mailbox of its target.
+  ///
+  /// # Errors
+  /// Returns [`RemoteDeliveryError`] when the payload cannot be decoded, the target is unknown,
+  /// or the mailbox rejects the envelope.
+  pub fn dispatch(&self, delivery: RemoteDelivery) -> Result<(), RemoteDeliveryError> {
+    let mut delivery = delivery;
+    for interceptor in &self.interceptors {
+      match interceptor.intercept(delivery) {
+        | Some(unconsumed) => delivery = unconsumed,
+        | None => return Ok(()),
+      }
+    }
+    let RemoteDelivery { target, frame } = delivery;
+    if let Some(responder) = self.replies.take(&target) {
+      let (message, priority) = self.envelope_from_frame(frame)?.into_parts();
+      return responder.send_with_priority(message, priority).map_err(|_| RemoteDeliveryError::Rejected);
+    }
+    let envelope = self.envelope_from_frame(frame)?;
+    self.deliver(&target, envelope)
+  }
+
+  /// Delivers an already materialised envelope to `target`.
+  ///
+  /// # Errors
+  /// Returns [`RemoteDeliveryError`] when the target is unknown or the mailbox rejects the
+  /// envelope.
+  pub fn deliver(&self, target: &Pid, envelope: PriorityEnvelope<AnyMessage>) -> Result<(), RemoteDeliveryError> {
+    let shared = ArcShared::new(envelope);
+    let handle = self.registry.resolve_or_dead_letter(target, shared.clone(), DeadLetterReason::UnregisteredPid);
+    let Some(handle) = handle else {
+      return Err(RemoteDeliveryError::Unresolved);
+    };
+    let Ok(envelope) = shared.try_unwrap() else {
+      return Err(RemoteDeliveryError::Unresolved);
+    };
+    match handle.try_send_envelope(envelope) {
+      | Ok(()) => Ok(()),
+      | Err(error) => {
+        let reason = DeadLetterReason::DeliveryRejected;
+        if let Some(envelope) = error.into_item() {
+          let letter = DeadLetter::new(target.clone(), ArcShared::new(envelope), reason);
+          self.registry.publish_dead_letter(&letter);
+        }
+        Err(RemoteDeliveryError::Rejected)
+      },
+    }
+  }
+
+  fn envelope_from_frame(
+    &self,
+    frame: RemoteMessageFrame,
+  ) -> Result<PriorityEnvelope<AnyMessage>, RemoteDeliveryError> {
+    let RemoteMessageFrame { priority, channel, payload, reply_to } = frame;
+    match payload {
+      | RemotePayloadFrame::System(message) => Ok(PriorityEnvelope::from_system(message).map(AnyMessage::new)),
+      | RemotePayloadFrame::User { serialized } => {
+        let metadata = match (reply_to, self.reply_route.as_ref()) {
+          | (Some(pid), Some(route)) => {
+            MessageMetadata::<ThreadSafe>::new().with_responder(route.responder(pid.clone())).with_responder_pid(pid)
+          },
+          | (Some(pid), None) => MessageMetadata::<ThreadSafe>::new().with_responder_pid(pid),
+          | (None, _) => MessageMetadata::<ThreadSafe>::new(),
+        };
+        let message = self.decode_user(&serialized, metadata)?;
+        Ok(PriorityEnvelope::with_channel(message, priority, channel))
+      },
+      | RemotePayloadFrame::Terminated { .. }
+      | RemotePayloadFrame::Heartbeat
+      | RemotePayloadFrame::HeartbeatAck
+      | RemotePayloadFrame::Spawn { .. }
+      | RemotePayloadFrame::Spawned { .. } => Err(RemoteDeliveryError::Unhandled),
+    }
+  }
+
+  fn decode_user(
+    &self,
+    serialized: &SerializedMessage,
+    metadata: MessageMetadata<ThreadSafe>,
+  ) -> Result<AnyMessage, RemoteDeliveryError> {
+    let type_name = serialized.type_name.as_deref().ok_or(RemoteDeliveryError::MissingTypeName)?;
+    if !self.messages.contains(type_name) {
+      return Err(RemoteDeliveryError::UnknownMessageType(type_name.into()));
+    }
+    let serializer = self
+      .serializers
+      .get(serialized.serializer_id)
+      .ok_or(RemoteDeliveryError::UnknownSerializer(serialized.serializer_id))?;
+    let bytes = serializer.deserialize(serialized).map_err(RemoteDeliveryError::Deserialization)?;
+    match self.messages.decode(type_name, &bytes, metadata) {
+      | Some(result) => result.map_err(RemoteDeliveryError::Deserialization),
+      | None => Err(RemoteDeliveryError::UnknownMessageType(type_name.into())),
+    }
+  }
+}
@@ -0,0 +1,25 @@
+use alloc::vec::Vec;
+
+use cellex_serialization_core_rs::{
+  error::{DeserializationError, SerializationError},
+  TypeKey,
+};
+use cellex_utils_core_rs::collections::Element;
+
+/// User message type that can cross node boundaries.
+///
+/// The payload bytes produced here are handed to the serializer resolved through the type key,
+/// so implementations typically delegate to the same format the bound serializer expects.
+pub trait RemoteMessage: Element + TypeKey + Sized {
+  /// Encodes the message into payload bytes.
+  ///
+  /// # Errors
+  /// Returns [`SerializationError`] when the message cannot be encoded.
+  fn encode_payload(&self) -> Result<Vec<u8>, SerializationError>;
+
+  /// Decodes the message from payload bytes.
+  ///
+  /// # Errors
+  /// Returns [`DeserializationError`] when the bytes do not describe a valid message.
+  fn decode_payload(bytes: &[u8]) -> Result<Self, DeserializationError>;
+}
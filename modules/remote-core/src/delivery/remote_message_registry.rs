@@ -0,0 +1,107 @@
+use alloc::{
+  collections::BTreeMap,
+  string::{String, ToString},
+  vec::Vec,
+};
+use core::any::TypeId;
+
+use cellex_actor_core_rs::{
+  api::{mailbox::ThreadSafe, messaging::MessageMetadata},
+  shared::messaging::{AnyMessage, MessageEnvelope},
+};
+use cellex_serialization_core_rs::error::{DeserializationError, SerializationError};
+use cellex_utils_core_rs::sync::ArcShared;
+use spin::RwLock;
+
+use super::RemoteMessage;
+
+#[cfg(target_has_atomic = "ptr")]
+type DecodeFn = dyn Fn(&[u8], MessageMetadata<ThreadSafe>) -> Result<AnyMessage, DeserializationError> + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type DecodeFn = dyn Fn(&[u8], MessageMetadata<ThreadSafe>) -> Result<AnyMessage, DeserializationError>;
+
+#[cfg(target_has_atomic = "ptr")]
+type EncodeFn = dyn Fn(&AnyMessage) -> Option<Result<Vec<u8>, SerializationError>> + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type EncodeFn = dyn Fn(&AnyMessage) -> Option<Result<Vec<u8>, SerializationError>>;
+
+type EncoderEntry = (String, ArcShared<EncodeFn>);
+
+/// Maps type keys carried by inbound frames to decoders producing typed user envelopes.
+///
+/// The registry also keeps the reverse direction: dynamic user envelopes whose payload type has
+/// been registered can be encoded back into payload bytes, which is how replies produced through
+/// [`MessageMetadata`] responders leave the node.
+#[derive(Clone)]
+pub struct RemoteMessageRegistry {
+  decoders: ArcShared<RwLock<BTreeMap<String, ArcShared<DecodeFn>>>>,
+  encoders: ArcShared<RwLock<BTreeMap<TypeId, EncoderEntry>>>,
+}
+
+impl RemoteMessageRegistry {
+  /// Creates an empty registry.
+  #[must_use]
+  pub fn new() -> Self {
+    Self {
+      decoders: ArcShared::new(RwLock::new(BTreeMap::new())),
+      encoders: ArcShared::new(RwLock::new(BTreeMap::new())),
+    }
+  }
+
+  /// Registers `U` so that payloads tagged with its type key can be delivered and user envelopes
+  /// carrying `U` can be encoded.
+  pub fn register<U>(&self)
+  where
+    U: RemoteMessage, {
+    let decoder = ArcShared::new(|bytes: &[u8], metadata: MessageMetadata<ThreadSafe>| {
+      let message = U::decode_payload(bytes)?;
+      Ok(AnyMessage::new(MessageEnvelope::user_with_metadata(message, metadata)))
+    })
+    .into_dyn(|f| f as &DecodeFn);
+    let encoder = ArcShared::new(|message: &AnyMessage| match message.downcast_ref::<MessageEnvelope<U>>()? {
+      | MessageEnvelope::User(user) => Some(user.message().encode_payload()),
+      | MessageEnvelope::System(_) => None,
+    })
+    .into_dyn(|f| f as &EncodeFn);
+    self.decoders.write().insert(U::type_key().to_string(), decoder);
+    self.encoders.write().insert(TypeId::of::<MessageEnvelope<U>>(), (U::type_key().to_string(), encoder));
+  }
+
+  /// Returns `true` when a decoder is registered for `type_key`.
+  #[must_use]
+  pub fn contains(&self, type_key: &str) -> bool {
+    self.decoders.read().contains_key(type_key)
+  }
+
+  /// Decodes payload bytes registered under `type_key` into a dynamic user envelope.
+  ///
+  /// Returns `None` when no decoder is registered for the key.
+  #[must_use]
+  pub fn decode(
+    &self,
+    type_key: &str,
+    bytes: &[u8],
+    metadata: MessageMetadata<ThreadSafe>,
+  ) -> Option<Result<AnyMessage, DeserializationError>> {
+    let decoder = self.decoders.read().get(type_key).cloned()?;
+    Some(decoder(bytes, metadata))
+  }
+
+  /// Encodes the user payload carried by a dynamic envelope.
+  ///
+  /// Returns the type key of the payload together with the encoded bytes, or `None` when the
+  /// message is not a user envelope of a registered type.
+  #[must_use]
+  pub fn encode(&self, message: &AnyMessage) -> Option<Result<(String, Vec<u8>), SerializationError>> {
+    let (type_key, encoder) = self.encoders.read().get(&message.type_id()).cloned()?;
+    encoder(message).map(|result| result.map(|bytes| (type_key, bytes)))
+  }
+}
+
+impl Default for RemoteMessageRegistry {
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -0,0 +1,70 @@
+use alloc::{
+  collections::BTreeMap,
+  format,
+  string::{String, ToString},
+};
+use core::sync::atomic::Ordering;
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::ActorPath,
+    mailbox::ThreadSafe,
+    process::pid::{NodeId, Pid, PidTag, SystemId},
+  },
+  internal::message::InternalMessageSender,
+};
+use cellex_utils_core_rs::sync::ArcShared;
+use portable_atomic::AtomicU64;
+use spin::RwLock;
+
+/// Pending `ask` responders waiting for a reply from another node.
+///
+/// Every outstanding request is assigned a temporary reply PID on the local node. Frames addressed
+/// to that PID bypass the process registry and complete the responder exactly once.
+#[derive(Clone)]
+pub struct RemoteReplyRegistry {
+  pending: ArcShared<RwLock<BTreeMap<String, InternalMessageSender<ThreadSafe>>>>,
+  next_id: ArcShared<AtomicU64>,
+}
+
+impl RemoteReplyRegistry {
+  /// Creates an empty registry.
+  #[must_use]
+  pub fn new() -> Self {
+    Self { pending: ArcShared::new(RwLock::new(BTreeMap::new())), next_id: ArcShared::new(AtomicU64::new(1)) }
+  }
+
+  /// Allocates a reply PID on `node` and associates it with `responder`.
+  #[must_use]
+  pub fn register(&self, system: &SystemId, node: &NodeId, responder: InternalMessageSender<ThreadSafe>) -> Pid {
+    let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+    let pid =
+      Pid::new(system.clone(), ActorPath::new()).with_node(node.clone()).with_tag(PidTag::new(format!("reply-{id}")));
+    self.pending.write().insert(pid.to_string(), responder);
+    pid
+  }
+
+  /// Removes and returns the responder registered for `pid`.
+  #[must_use]
+  pub fn take(&self, pid: &Pid) -> Option<InternalMessageSender<ThreadSafe>> {
+    self.pending.write().remove(&pid.to_string())
+  }
+
+  /// Returns the number of replies still outstanding.
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.pending.read().len()
+  }
+
+  /// Returns `true` when no reply is outstanding.
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.pending.read().is_empty()
+  }
+}
+
+impl Default for RemoteReplyRegistry {
+  fn default() -> Self {
+    Self::new()
+  }
+}
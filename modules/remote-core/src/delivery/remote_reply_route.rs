@@ -0,0 +1,49 @@
+use cellex_actor_core_rs::{
+  api::{mailbox::ThreadSafe, messaging::MessageSender, process::pid::Pid},
+  internal::message::InternalMessageSender,
+  shared::{mailbox::messages::PriorityEnvelope, messaging::AnyMessage},
+};
+use cellex_utils_core_rs::{
+  collections::queue::backend::QueueError,
+  sync::{shared::SharedBound, ArcShared},
+};
+
+#[cfg(target_has_atomic = "ptr")]
+type RouteFn = dyn Fn(&Pid, AnyMessage, i8) -> Result<(), QueueError<PriorityEnvelope<AnyMessage>>> + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type RouteFn = dyn Fn(&Pid, AnyMessage, i8) -> Result<(), QueueError<PriorityEnvelope<AnyMessage>>>;
+
+#[cfg(target_has_atomic = "ptr")]
+type SendFn = dyn Fn(AnyMessage, i8) -> Result<(), QueueError<PriorityEnvelope<AnyMessage>>> + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type SendFn = dyn Fn(AnyMessage, i8) -> Result<(), QueueError<PriorityEnvelope<AnyMessage>>>;
+
+/// Outbound path used to answer inbound requests carrying a reply-to PID.
+///
+/// The dispatcher turns the reply-to PID of every inbound user message into a responder so that
+/// `ActorContext::respond` sends the answer back over the network without knowing about it.
+#[derive(Clone)]
+pub struct RemoteReplyRoute {
+  route: ArcShared<RouteFn>,
+}
+
+impl RemoteReplyRoute {
+  /// Creates a route from a function sending a dynamic message with priority to a remote PID.
+  #[must_use]
+  pub fn new<F>(route: F) -> Self
+  where
+    F: Fn(&Pid, AnyMessage, i8) -> Result<(), QueueError<PriorityEnvelope<AnyMessage>>> + SharedBound + 'static, {
+    Self { route: ArcShared::new(route).into_dyn(|f| f as &RouteFn) }
+  }
+
+  /// Returns a responder forwarding every message to `reply_to`.
+  #[must_use]
+  pub fn responder(&self, reply_to: Pid) -> MessageSender<AnyMessage, ThreadSafe> {
+    let route = self.route.clone();
+    let send = ArcShared::new(move |message: AnyMessage, priority: i8| route(&reply_to, message, priority))
+      .into_dyn(|f| f as &SendFn);
+    MessageSender::from_internal(InternalMessageSender::new(send))
+  }
+}
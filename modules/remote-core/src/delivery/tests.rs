@@ -0,0 +1,154 @@
+extern crate std;
+
+use std::{
+  format,
+  string::{String, ToString},
+  sync::{Arc, Mutex},
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::Props,
+    actor_runtime::GenericActorRuntime,
+    actor_system::{GenericActorSystem, GenericActorSystemConfig},
+    mailbox::messages::{PriorityChannel, SystemMessage},
+    process::{
+      dead_letter::{DeadLetter, DeadLetterListener, DeadLetterReason},
+      pid::{NodeId, Pid},
+      process_registry::ProcessResolution,
+    },
+    test_support::TestMailboxFactory,
+  },
+  shared::{mailbox::messages::PriorityEnvelope, messaging::AnyMessage},
+};
+use cellex_serialization_core_rs::{
+  error::{DeserializationError, SerializationError},
+  impl_type_key, InMemorySerializerRegistry, TypeKey,
+};
+use cellex_serialization_json_rs::{shared_json_serializer, SerdeJsonSerializer};
+use cellex_utils_core_rs::sync::ArcShared;
+use serde::{Deserialize, Serialize};
+
+use super::{RemoteDeliveryError, RemoteInboundDispatcher, RemoteMessage};
+use crate::codec::{RemoteDelivery, RemoteMessageFrame, RemotePayloadFrame};
+
+type TestResult<T = ()> = Result<T, String>;
+type TestRuntime = GenericActorRuntime<TestMailboxFactory>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Greeting {
+  text: String,
+}
+
+impl_type_key!(Greeting, "test.Greeting");
+
+impl RemoteMessage for Greeting {
+  fn encode_payload(&self) -> Result<Vec<u8>, SerializationError> {
+    serde_json::to_vec(self).map_err(|err| SerializationError::custom(err.to_string()))
+  }
+
+  fn decode_payload(bytes: &[u8]) -> Result<Self, DeserializationError> {
+    serde_json::from_slice(bytes).map_err(|err| DeserializationError::custom(err.to_string()))
+  }
+}
+
+fn new_system() -> GenericActorSystem<Greeting, TestRuntime> {
+  let config = GenericActorSystemConfig::default().with_node_id(NodeId::new("127.0.0.1", Some(2552)));
+  GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TestMailboxFactory::unbounded()), config)
+}
+
+fn dispatcher_for(
+  system: &GenericActorSystem<Greeting, TestRuntime>,
+) -> TestResult<RemoteInboundDispatcher<TestMailboxFactory>> {
+  let serializers = InMemorySerializerRegistry::new();
+  serializers.register(shared_json_serializer()).map_err(|err| format!("register serializer: {err}"))?;
+  let dispatcher = RemoteInboundDispatcher::new(system.process_registry(), serializers);
+  dispatcher.register_message::<Greeting>();
+  Ok(dispatcher)
+}
+
+fn user_frame(message: &Greeting) -> TestResult<RemoteMessageFrame> {
+  let serialized = SerdeJsonSerializer::new()
+    .serialize_value(Some(<Greeting as TypeKey>::type_key()), message)
+    .map_err(|err| format!("serialize: {err}"))?;
+  Ok(RemoteMessageFrame::new(0, PriorityChannel::Regular, RemotePayloadFrame::User { serialized }, None))
+}
+
+#[test]
+fn dispatcher_delivers_user_payload_to_local_actor() -> TestResult {
+  let mut system = new_system();
+  let dispatcher = dispatcher_for(&system)?;
+  let received: Arc<Mutex<Vec<Greeting>>> = Arc::new(Mutex::new(Vec::new()));
+  let received_clone = received.clone();
+
+  let actor_ref = system
+    .root_context()
+    .spawn(Props::new(move |_, message: Greeting| {
+      received_clone.lock().unwrap_or_else(|err| err.into_inner()).push(message);
+      Ok(())
+    }))
+    .map_err(|err| format!("spawn: {err:?}"))?;
+  let target = actor_ref.pid().ok_or_else(|| "pid expected".to_string())?;
+
+  let greeting = Greeting { text: "hello".to_string() };
+  dispatcher.dispatch(RemoteDelivery::new(target, user_frame(&greeting)?)).map_err(|err| format!("dispatch: {err}"))?;
+  system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+
+  assert_eq!(received.lock().unwrap_or_else(|err| err.into_inner()).as_slice(), &[greeting]);
+  Ok(())
+}
+
+#[test]
+fn dispatcher_delivers_system_messages_without_type_information() -> TestResult {
+  let mut system = new_system();
+  let dispatcher = dispatcher_for(&system)?;
+  let actor_ref =
+    system.root_context().spawn(Props::new(|_, _: Greeting| Ok(()))).map_err(|err| format!("spawn: {err:?}"))?;
+  let target = actor_ref.pid().ok_or_else(|| "pid expected".to_string())?;
+
+  let frame =
+    RemoteMessageFrame::new(0, PriorityChannel::Control, RemotePayloadFrame::System(SystemMessage::Stop), None);
+  dispatcher.dispatch(RemoteDelivery::new(target.clone(), frame)).map_err(|err| format!("dispatch: {err}"))?;
+  system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+
+  let resolution = system.process_registry().resolve_pid(&target);
+  assert!(matches!(resolution, ProcessResolution::Unresolved));
+  Ok(())
+}
+
+#[test]
+fn dispatcher_dead_letters_unknown_targets() -> TestResult {
+  let system = new_system();
+  let dispatcher = dispatcher_for(&system)?;
+  let reasons: Arc<Mutex<Vec<DeadLetterReason>>> = Arc::new(Mutex::new(Vec::new()));
+  let reasons_clone = reasons.clone();
+  let listener = ArcShared::new(move |letter: &DeadLetter<ArcShared<PriorityEnvelope<AnyMessage>>>| {
+    reasons_clone.lock().unwrap_or_else(|err| err.into_inner()).push(letter.reason.clone());
+  })
+  .into_dyn(|f| f as &DeadLetterListener<ArcShared<PriorityEnvelope<AnyMessage>>>);
+  system.process_registry().subscribe_dead_letters(listener);
+
+  let target = Pid::parse("actor://cellex@127.0.0.1:2552/42").map_err(|err| format!("pid: {err:?}"))?;
+  let greeting = Greeting { text: "lost".to_string() };
+  let result = dispatcher.dispatch(RemoteDelivery::new(target, user_frame(&greeting)?));
+
+  assert_eq!(result, Err(RemoteDeliveryError::Unresolved));
+  assert_eq!(reasons.lock().unwrap_or_else(|err| err.into_inner()).as_slice(), &[DeadLetterReason::UnregisteredPid]);
+  Ok(())
+}
+
+#[test]
+fn dispatcher_rejects_unregistered_message_types() -> TestResult {
+  let system = new_system();
+  let serializers = InMemorySerializerRegistry::new();
+  serializers.register(shared_json_serializer()).map_err(|err| format!("register serializer: {err}"))?;
+  let dispatcher = RemoteInboundDispatcher::new(system.process_registry(), serializers);
+
+  let target = Pid::parse("actor://cellex@127.0.0.1:2552/1").map_err(|err| format!("pid: {err:?}"))?;
+  let greeting = Greeting { text: "hi".to_string() };
+  let result = dispatcher.dispatch(RemoteDelivery::new(target, user_frame(&greeting)?));
+
+  assert_eq!(result, Err(RemoteDeliveryError::UnknownMessageType("test.Greeting".to_string())));
+  Ok(())
+}
@@ -0,0 +1,14 @@
+mod batch_clock;
+mod endpoint_manager;
+mod outbound_batch;
+mod outbound_batch_config;
+mod outbound_queue;
+mod remote_endpoint;
+
+#[cfg(test)]
+mod tests;
+
+pub use batch_clock::BatchClock;
+pub use endpoint_manager::EndpointManager;
+pub use outbound_batch_config::OutboundBatchConfig;
+pub use remote_endpoint::RemoteEndpoint;
@@ -0,0 +1,11 @@
+use core::time::Duration;
+
+/// Monotonic clock timing the linger of outbound batches, returning the time elapsed since an
+/// arbitrary fixed origin.
+#[cfg(target_has_atomic = "ptr")]
+pub type BatchClock = dyn Fn() -> Duration + Send + Sync + 'static;
+
+/// Monotonic clock timing the linger of outbound batches, returning the time elapsed since an
+/// arbitrary fixed origin.
+#[cfg(not(target_has_atomic = "ptr"))]
+pub type BatchClock = dyn Fn() -> Duration + 'static;
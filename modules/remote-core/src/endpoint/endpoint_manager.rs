@@ -0,0 +1,665 @@
+use alloc::{
+  collections::{BTreeMap, BTreeSet},
+  string::{String, ToString},
+  vec::Vec,
+};
+use core::time::Duration;
+
+use cellex_actor_core_rs::{
+  api::{
+    mailbox::{messages::PriorityChannel, queue_mailbox::MailboxQueueConfig, MailboxOverflowPolicy},
+    metrics::{BatchFlushReason, MetricsEvent, MetricsSinkShared},
+    process::{
+      dead_letter::{DeadLetter, DeadLetterListener, DeadLetterReason},
+      pid::{NodeId, SystemId},
+    },
+  },
+  shared::{
+    mailbox::{messages::PriorityEnvelope, MailboxFactory},
+    messaging::AnyMessage,
+  },
+};
+use cellex_utils_core_rs::sync::{shared::SharedBound, ArcShared};
+use spin::{Mutex, RwLock};
+
+use super::{
+  outbound_batch::OutboundBatch, outbound_queue::OutboundQueue, BatchClock, OutboundBatchConfig, RemoteEndpoint,
+};
+use crate::{
+  codec::{
+    decode_wire_frame, encode_batch, encode_delivery, encode_wire_frame, envelope_from_frame, HandshakeRejection,
+    RemoteDelivery, RemoteHandshake, RemoteWireFrame, BATCH_PROTOCOL_VERSION,
+  },
+  delivery::RemoteInboundDispatcher,
+  transport::{InboundFrameHandler, RemoteTransport, TransportConnection, TransportError, UndeliveredFrameHandler},
+};
+
+type Endpoints<C> = ArcShared<RwLock<BTreeMap<String, RemoteEndpoint<C>>>>;
+type Queues = ArcShared<RwLock<BTreeMap<String, ArcShared<Mutex<OutboundQueue>>>>>;
+type DeadLetters = ArcShared<RwLock<Option<ArcShared<DeadLetterListener<ArcShared<PriorityEnvelope<AnyMessage>>>>>>>;
+
+/// Maintains the associations with every remote [`NodeId`] on top of a [`RemoteTransport`].
+///
+/// Each node is reached through two connections: frames on [`PriorityChannel::Control`], such as
+/// system messages, heartbeats and handshakes, travel on a control lane of their own so that they
+/// never queue behind user messages on the regular lane.
+///
+/// Every new connection starts with a [`RemoteHandshake`] announcing the local system, node and
+/// protocol version. The peer answers with its own identity, or refuses the association when it
+/// speaks an unsupported version or belongs to another actor system; refused nodes are not
+/// connected to again until they send a compatible handshake themselves. Both sides then speak
+/// the older of their two versions: acknowledgements and later handshakes announce it, and
+/// deliveries keep the version 1 layout unless batched for a peer that reads batches.
+///
+/// Outbound deliveries are encoded and written to the endpoint of the target PID's node, opening
+/// the connection on first use. Inbound frames are decoded and handed to a
+/// [`RemoteInboundDispatcher`] once [`EndpointManager::start`] has been called. Quarantined nodes
+/// are never connected to again.
+///
+/// With [`EndpointManager::with_batching`], regular deliveries to peers that read batches are
+/// queued per node and written together once a flush trigger of the [`OutboundBatchConfig`] is
+/// reached; control frames are always written right away. Each write of a batch is reported to
+/// the sink given to [`EndpointManager::with_metrics_sink`] as
+/// [`MetricsEvent::OutboundBatchFlushed`].
+///
+/// [`EndpointManager::with_outbound_queue`] bounds the regular lane of every node so that a
+/// stalled peer cannot exhaust memory: frames wait in the manager while the transport backlog is
+/// full, and the overflow policy decides what happens once that queue is full as well. Dropped and
+/// refused messages are published to the dead letter hub of the started system with
+/// [`DeadLetterReason::DeliveryRejected`], and messages that cannot be written because their node
+/// is unreachable, quarantined or closed with [`DeadLetterReason::NetworkUnreachable`]. Frames
+/// already accepted by the transport are dead-lettered as it reports them undelivered through
+/// [`RemoteTransport::report_undelivered`].
+pub struct EndpointManager<T>
+where
+  T: RemoteTransport, {
+  transport:    ArcShared<T>,
+  local:        RemoteHandshake,
+  endpoints:    Endpoints<T::Connection>,
+  control:      Endpoints<T::Connection>,
+  quarantined:  ArcShared<RwLock<BTreeSet<String>>>,
+  associations: ArcShared<RwLock<BTreeMap<String, RemoteHandshake>>>,
+  rejected:     ArcShared<RwLock<BTreeMap<String, HandshakeRejection>>>,
+  batching:     Option<OutboundBatchConfig>,
+  clock:        ArcShared<BatchClock>,
+  metrics:      Option<MetricsSinkShared>,
+  queue_config: MailboxQueueConfig,
+  queues:       Queues,
+  dead_letters: DeadLetters,
+}
+
+impl<T> Clone for EndpointManager<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self {
+      transport:    self.transport.clone(),
+      local:        self.local.clone(),
+      endpoints:    self.endpoints.clone(),
+      control:      self.control.clone(),
+      quarantined:  self.quarantined.clone(),
+      associations: self.associations.clone(),
+      rejected:     self.rejected.clone(),
+      batching:     self.batching,
+      clock:        self.clock.clone(),
+      metrics:      self.metrics.clone(),
+      queue_config: self.queue_config,
+      queues:       self.queues.clone(),
+      dead_letters: self.dead_letters.clone(),
+    }
+  }
+}
+
+impl<T> EndpointManager<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates a manager on top of `transport` for `system` running on `node`.
+  #[must_use]
+  pub fn new(transport: T, system: SystemId, node: NodeId) -> Self {
+    Self {
+      transport:    ArcShared::new(transport),
+      local:        RemoteHandshake::new(system, node),
+      endpoints:    ArcShared::new(RwLock::new(BTreeMap::new())),
+      control:      ArcShared::new(RwLock::new(BTreeMap::new())),
+      quarantined:  ArcShared::new(RwLock::new(BTreeSet::new())),
+      associations: ArcShared::new(RwLock::new(BTreeMap::new())),
+      rejected:     ArcShared::new(RwLock::new(BTreeMap::new())),
+      batching:     None,
+      clock:        ArcShared::new(|| Duration::ZERO).into_dyn(|f| f as &BatchClock),
+      metrics:      None,
+      queue_config: MailboxQueueConfig::default(),
+      queues:       ArcShared::new(RwLock::new(BTreeMap::new())),
+      dead_letters: ArcShared::new(RwLock::new(None)),
+    }
+  }
+
+  /// Batches regular deliveries according to `config`, with `clock` timing the linger.
+  ///
+  /// Batches are only built for peers whose negotiated protocol version is at least
+  /// [`BATCH_PROTOCOL_VERSION`]; until then deliveries are written one by one.
+  #[must_use]
+  pub fn with_batching<C>(mut self, config: OutboundBatchConfig, clock: C) -> Self
+  where
+    C: Fn() -> Duration + SharedBound + 'static, {
+    self.batching = Some(config);
+    self.clock = ArcShared::new(clock).into_dyn(|f| f as &BatchClock);
+    self
+  }
+
+  /// Bounds the regular lane of every node according to `config`.
+  ///
+  /// Up to `capacity` frames are handed to the transport while its backlog has room, and up to
+  /// `capacity` more wait in the manager. Once both are full, [`MailboxOverflowPolicy::DropOldest`]
+  /// evicts the oldest waiting frame, [`MailboxOverflowPolicy::DropNewest`] drops the new one, and
+  /// [`MailboxOverflowPolicy::Block`] refuses new deliveries with [`TransportError::Backpressure`].
+  /// The manager never waits for the peer under `Block`, since sending must not block the caller:
+  /// the refused message is dead-lettered with [`DeadLetterReason::DeliveryRejected`] like the
+  /// overflow of the other policies, and the error tells the sender to slow down until
+  /// [`EndpointManager::drain`] made room. A batch completed while blocked is still queued.
+  /// [`MailboxOverflowPolicy::Grow`] queues without limit.
+  #[must_use]
+  pub const fn with_outbound_queue(mut self, config: MailboxQueueConfig) -> Self {
+    self.queue_config = config;
+    self
+  }
+
+  /// Reports the writes of outbound batches to `sink`.
+  #[must_use]
+  pub fn with_metrics_sink(mut self, sink: MetricsSinkShared) -> Self {
+    self.metrics = Some(sink);
+    self
+  }
+
+  fn node_key(node: &NodeId) -> String {
+    node.to_string()
+  }
+
+  /// Returns the underlying transport.
+  #[must_use]
+  pub fn transport(&self) -> &T {
+    &self.transport
+  }
+
+  /// Returns the identity announced to peers.
+  #[must_use]
+  pub const fn local(&self) -> &RemoteHandshake {
+    &self.local
+  }
+
+  /// Starts listening on the local node and delivers inbound frames through `dispatcher`.
+  ///
+  /// Handshakes are answered by the manager itself. Deliveries are only dispatched from connections
+  /// whose handshake was accepted, as long as their node is not quarantined. Frames that cannot be
+  /// decoded or delivered are dropped; unresolved targets are reported to the dead letter hub by
+  /// the dispatcher, which also receives the outbound messages dropped from then on.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the transport cannot listen on the local node.
+  pub fn start<MF>(&self, dispatcher: RemoteInboundDispatcher<MF>) -> Result<(), TransportError>
+  where
+    T: 'static,
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    let publisher = dispatcher.clone();
+    let listener = ArcShared::new(move |letter: &DeadLetter<ArcShared<PriorityEnvelope<AnyMessage>>>| {
+      publisher.registry().publish_dead_letter(letter);
+    });
+    *self.dead_letters.write() = Some(listener.into_dyn(|f| f as &DeadLetterListener<_>));
+    let dead_letters = self.dead_letters.clone();
+    self.transport.report_undelivered(UndeliveredFrameHandler::new(move |frame: &[u8], reason: DeadLetterReason| {
+      Self::dead_letter_frame(&dead_letters, frame, reason);
+    }));
+    let manager = self.clone();
+    let handler =
+      InboundFrameHandler::with_peer(move |peer: &mut Option<NodeId>, bytes: &[u8]| match decode_wire_frame(bytes) {
+        | Ok(RemoteWireFrame::Delivery(delivery)) if manager.admits(peer.as_ref()) => {
+          let _ = dispatcher.dispatch(*delivery);
+        },
+        | Ok(RemoteWireFrame::Batch(deliveries)) if manager.admits(peer.as_ref()) => {
+          for delivery in deliveries {
+            let _ = dispatcher.dispatch(delivery);
+          }
+        },
+        | Ok(RemoteWireFrame::Delivery(_) | RemoteWireFrame::Batch(_)) | Err(_) => {},
+        | Ok(RemoteWireFrame::Handshake(handshake)) => {
+          let node = handshake.node.clone();
+          manager.handle_handshake(RemoteWireFrame::Handshake(handshake));
+          *peer = manager.association(&node).map(|_| node);
+        },
+        | Ok(frame) => manager.handle_handshake(frame),
+      });
+    self.transport.listen(&self.local.node, handler)
+  }
+
+  /// Returns the regular lane endpoint for `node`, connecting and sending the handshake when no
+  /// open association exists.
+  ///
+  /// # Errors
+  /// Returns [`TransportError::Quarantined`] for quarantined nodes,
+  /// [`TransportError::Rejected`] for nodes that refused the handshake, and [`TransportError`]
+  /// when the transport cannot connect to `node`.
+  pub fn endpoint(&self, node: &NodeId) -> Result<RemoteEndpoint<T::Connection>, TransportError> {
+    self.lane_endpoint(&self.endpoints, node)
+  }
+
+  /// Returns the control lane endpoint for `node`, connecting and sending the handshake when no
+  /// open association exists.
+  ///
+  /// # Errors
+  /// Returns the same errors as [`EndpointManager::endpoint`].
+  pub fn control_endpoint(&self, node: &NodeId) -> Result<RemoteEndpoint<T::Connection>, TransportError> {
+    self.lane_endpoint(&self.control, node)
+  }
+
+  fn lane_endpoint(
+    &self,
+    lane: &Endpoints<T::Connection>,
+    node: &NodeId,
+  ) -> Result<RemoteEndpoint<T::Connection>, TransportError> {
+    let key = Self::node_key(node);
+    if self.quarantined.read().contains(&key) {
+      return Err(TransportError::Quarantined(node.clone()));
+    }
+    if let Some(reason) = self.rejected.read().get(&key) {
+      return Err(TransportError::Rejected(node.clone(), *reason));
+    }
+    if let Some(endpoint) = lane.read().get(&key) {
+      if !endpoint.is_closed() {
+        return Ok(endpoint.clone());
+      }
+    }
+
+    // Connecting happens outside the lock so that a slow node never holds up the lanes of the others.
+    let endpoint = RemoteEndpoint::new(node.clone(), self.transport.connect(node)?);
+    let handshake = encode_wire_frame(&RemoteWireFrame::Handshake(self.announcement(node)))?;
+    if let Err(error) = endpoint.send(handshake) {
+      endpoint.close();
+      return Err(error);
+    }
+    let mut endpoints = lane.write();
+    if let Some(existing) = endpoints.get(&key) {
+      // Another sender connected in the meantime; its endpoint carries the earlier frames.
+      if !existing.is_closed() {
+        endpoint.close();
+        return Ok(existing.clone());
+      }
+    }
+    endpoints.insert(key, endpoint.clone());
+    Ok(endpoint)
+  }
+
+  /// Returns the handshake opening a connection to `node`, which announces the version negotiated
+  /// with it once associated, so that older peers can read every frame written to them.
+  fn announcement(&self, node: &NodeId) -> RemoteHandshake {
+    match self.associations.read().get(&Self::node_key(node)) {
+      | Some(peer) => self.local.clone().with_version(peer.version),
+      | None => self.local.clone(),
+    }
+  }
+
+  /// Encodes `delivery` and writes it to the lane of its target node matching the frame channel.
+  ///
+  /// When batching, regular deliveries are queued instead and the batch of the target node is
+  /// written once one of its flush triggers is reached.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the target has no node, the frame cannot be encoded, the
+  /// connection rejects it or the batch it completed, or [`TransportError::Backpressure`] when
+  /// the outbound queue of the node is full and blocking.
+  pub fn send(&self, delivery: &RemoteDelivery) -> Result<(), TransportError> {
+    let node = delivery.target.node().ok_or(TransportError::MissingNode)?;
+    let frame = encode_delivery(delivery)?;
+    match (delivery.frame.channel, self.batching) {
+      | (PriorityChannel::Control, _) => {
+        let sent = self.control_endpoint(node).and_then(|endpoint| endpoint.send(frame));
+        if sent.is_err() {
+          Self::dead_letter(&self.dead_letters, delivery.clone(), DeadLetterReason::NetworkUnreachable);
+        }
+        sent
+      },
+      | (PriorityChannel::Regular, _) if self.blocks(node) => {
+        Self::dead_letter(&self.dead_letters, delivery.clone(), DeadLetterReason::DeliveryRejected);
+        Err(TransportError::Backpressure(node.clone()))
+      },
+      | (PriorityChannel::Regular, Some(config)) if self.reads_batches(node) => self.enqueue(node, frame, &config),
+      | (PriorityChannel::Regular, _) => self.write_regular(node, frame),
+    }
+  }
+
+  /// Hands the queued frames of every node to the transport as far as its backlog allows.
+  ///
+  /// Call it periodically when the outbound queue is bounded, so that the queues of idle nodes are
+  /// not held back until the next delivery.
+  ///
+  /// # Errors
+  /// Returns the first [`TransportError`] raised while writing the frames.
+  pub fn drain(&self) -> Result<(), TransportError> {
+    let mut result = Ok(());
+    for queue in self.queue_list() {
+      let drained = self.drain_queue(&mut queue.lock());
+      if result.is_ok() {
+        result = drained;
+      }
+    }
+    result
+  }
+
+  /// Returns the number of regular lane frames waiting in the manager for `node`.
+  #[must_use]
+  pub fn queued(&self, node: &NodeId) -> usize {
+    self.existing_queue(node).map_or(0, |queue| queue.lock().len())
+  }
+
+  /// Writes the batches whose linger time has elapsed.
+  ///
+  /// Call it periodically, typically every linger interval, so that batches of idle nodes are not
+  /// held back until the next delivery.
+  ///
+  /// # Errors
+  /// Returns the first [`TransportError`] raised while writing the batches.
+  pub fn flush_expired(&self) -> Result<(), TransportError> {
+    let Some(config) = self.batching else {
+      return Ok(());
+    };
+    let now = (self.clock)();
+    self.flush_when(|batch| batch.flush_reason(&config, now))
+  }
+
+  /// Writes every pending batch.
+  ///
+  /// # Errors
+  /// Returns the first [`TransportError`] raised while writing the batches.
+  pub fn flush(&self) -> Result<(), TransportError> {
+    self.flush_when(|_| Some(BatchFlushReason::Explicit))
+  }
+
+  /// Returns the number of deliveries queued for `node` and not written yet.
+  #[must_use]
+  pub fn pending(&self, node: &NodeId) -> usize {
+    self.existing_queue(node).and_then(|queue| queue.lock().batch().map(OutboundBatch::len)).unwrap_or(0)
+  }
+
+  /// Returns the regular lane of `node`, creating it on first use.
+  ///
+  /// Each lane is locked on its own, so that writing to a slow node never holds up the others.
+  fn queue(&self, node: &NodeId) -> ArcShared<Mutex<OutboundQueue>> {
+    if let Some(queue) = self.existing_queue(node) {
+      return queue;
+    }
+    self
+      .queues
+      .write()
+      .entry(Self::node_key(node))
+      .or_insert_with(|| ArcShared::new(Mutex::new(OutboundQueue::new(node.clone()))))
+      .clone()
+  }
+
+  fn existing_queue(&self, node: &NodeId) -> Option<ArcShared<Mutex<OutboundQueue>>> {
+    self.queues.read().get(&Self::node_key(node)).cloned()
+  }
+
+  fn queue_list(&self) -> Vec<ArcShared<Mutex<OutboundQueue>>> {
+    self.queues.read().values().cloned().collect()
+  }
+
+  fn reads_batches(&self, node: &NodeId) -> bool {
+    self.associations.read().get(&Self::node_key(node)).is_some_and(|peer| peer.version >= BATCH_PROTOCOL_VERSION)
+  }
+
+  fn enqueue(&self, node: &NodeId, frame: Vec<u8>, config: &OutboundBatchConfig) -> Result<(), TransportError> {
+    let now = (self.clock)();
+    let queue = self.queue(node);
+    // The lane is locked while writing so that batches of the same node never overtake each other.
+    let mut queue = queue.lock();
+    let batch = queue.batch_mut(now);
+    batch.push(frame);
+    match batch.flush_reason(config, now) {
+      | Some(reason) => self.write_batch(&mut queue, reason),
+      | None => Ok(()),
+    }
+  }
+
+  fn flush_when<F>(&self, reason: F) -> Result<(), TransportError>
+  where
+    F: Fn(&OutboundBatch) -> Option<BatchFlushReason>, {
+    let mut result = Ok(());
+    for queue in self.queue_list() {
+      let mut queue = queue.lock();
+      let Some(reason) = queue.batch().and_then(&reason) else {
+        continue;
+      };
+      let written = self.write_batch(&mut queue, reason);
+      if result.is_ok() {
+        result = written;
+      }
+    }
+    result
+  }
+
+  /// Writes the batch of the locked lane `queue`, if any.
+  fn write_batch(&self, queue: &mut OutboundQueue, reason: BatchFlushReason) -> Result<(), TransportError> {
+    let Some(batch) = queue.take_batch() else {
+      return Ok(());
+    };
+    let (frames, bytes) = (batch.len(), batch.bytes());
+    let mut encoded = batch.into_frames();
+    // A lone delivery is written as is, saving the batch header.
+    let body = if encoded.len() == 1 { encoded.remove(0) } else { encode_batch(&encoded)? };
+    self.write_queued(queue, body)?;
+    if let Some(metrics) = &self.metrics {
+      metrics.with_ref(|sink| sink.record(MetricsEvent::OutboundBatchFlushed { frames, bytes, reason }));
+    }
+    Ok(())
+  }
+
+  fn blocks(&self, node: &NodeId) -> bool {
+    if self.queue_config.overflow_policy != MailboxOverflowPolicy::Block {
+      return false;
+    }
+    let Some(queue) = self.existing_queue(node) else {
+      return false;
+    };
+    let mut queue = queue.lock();
+    let _ = self.drain_queue(&mut queue);
+    queue.len() >= self.queue_config.capacity.to_usize()
+  }
+
+  fn write_regular(&self, node: &NodeId, frame: Vec<u8>) -> Result<(), TransportError> {
+    let queue = self.queue(node);
+    // The lane is locked while writing so that new frames never overtake queued ones.
+    let mut queue = queue.lock();
+    self.write_queued(&mut queue, frame)
+  }
+
+  /// Writes `frame` to the locked lane `queue`, behind the frames already waiting in it.
+  fn write_queued(&self, queue: &mut OutboundQueue, frame: Vec<u8>) -> Result<(), TransportError> {
+    let node = queue.node().clone();
+    if let Err(error) = self.drain_queue(queue) {
+      Self::dead_letter_frame(&self.dead_letters, &frame, DeadLetterReason::NetworkUnreachable);
+      return Err(error);
+    }
+    let capacity = self.queue_config.capacity.to_usize();
+    if queue.is_empty() {
+      match self.endpoint(&node) {
+        | Ok(endpoint) if endpoint.backlog() < capacity => return endpoint.send(frame),
+        | Ok(_) => {},
+        | Err(error) => {
+          Self::dead_letter_frame(&self.dead_letters, &frame, DeadLetterReason::NetworkUnreachable);
+          return Err(error);
+        },
+      }
+    }
+    if queue.len() < capacity {
+      queue.push(frame);
+      return Ok(());
+    }
+    match self.queue_config.overflow_policy {
+      | MailboxOverflowPolicy::DropOldest => {
+        if let Some(oldest) = queue.pop() {
+          Self::dead_letter_frame(&self.dead_letters, &oldest, DeadLetterReason::DeliveryRejected);
+        }
+        queue.push(frame);
+      },
+      | MailboxOverflowPolicy::DropNewest => {
+        Self::dead_letter_frame(&self.dead_letters, &frame, DeadLetterReason::DeliveryRejected)
+      },
+      | MailboxOverflowPolicy::Block | MailboxOverflowPolicy::Grow => queue.push(frame),
+    }
+    Ok(())
+  }
+
+  /// Writes queued frames while the transport backlog has room. Frames of a node that cannot be
+  /// reached any more are dead-lettered.
+  fn drain_queue(&self, queue: &mut OutboundQueue) -> Result<(), TransportError> {
+    if queue.is_empty() {
+      return Ok(());
+    }
+    let endpoint = match self.endpoint(queue.node()) {
+      | Ok(endpoint) => endpoint,
+      | Err(error) => {
+        for frame in queue.drain() {
+          Self::dead_letter_frame(&self.dead_letters, &frame, DeadLetterReason::NetworkUnreachable);
+        }
+        return Err(error);
+      },
+    };
+    let capacity = self.queue_config.capacity.to_usize();
+    while endpoint.backlog() < capacity {
+      let Some(frame) = queue.pop() else {
+        break;
+      };
+      endpoint.send(frame)?;
+    }
+    Ok(())
+  }
+
+  fn dead_letter_frame(dead_letters: &DeadLetters, frame: &[u8], reason: DeadLetterReason) {
+    match decode_wire_frame(frame) {
+      | Ok(RemoteWireFrame::Delivery(delivery)) => Self::dead_letter(dead_letters, *delivery, reason),
+      | Ok(RemoteWireFrame::Batch(deliveries)) => {
+        for delivery in deliveries {
+          Self::dead_letter(dead_letters, delivery, reason.clone());
+        }
+      },
+      | Ok(_) | Err(_) => {},
+    }
+  }
+
+  fn dead_letter(dead_letters: &DeadLetters, delivery: RemoteDelivery, reason: DeadLetterReason) {
+    let Some(listener) = dead_letters.read().clone() else {
+      return;
+    };
+    let RemoteDelivery { target, frame } = delivery;
+    let Some(envelope) = envelope_from_frame(frame) else {
+      return;
+    };
+    let (message, priority, channel) = envelope.into_parts_with_channel();
+    let envelope = PriorityEnvelope::with_channel(AnyMessage::new(message), priority, channel);
+    listener(&DeadLetter::new(target, ArcShared::new(envelope), reason));
+  }
+
+  /// Writes the pending batch of `node`, then closes and forgets both lanes to it.
+  ///
+  /// Frames still queued for `node` are dead-lettered as unreachable.
+  pub fn disconnect(&self, node: &NodeId) {
+    let key = Self::node_key(node);
+    let queue = self.queues.write().remove(&key);
+    if let Some(queue) = queue {
+      let mut queue = queue.lock();
+      let _ = self.write_batch(&mut queue, BatchFlushReason::Explicit);
+      for frame in queue.drain() {
+        Self::dead_letter_frame(&self.dead_letters, &frame, DeadLetterReason::NetworkUnreachable);
+      }
+    }
+    self.associations.write().remove(&key);
+    for lane in [&self.endpoints, &self.control] {
+      if let Some(endpoint) = lane.write().remove(&key) {
+        endpoint.close();
+      }
+    }
+  }
+
+  /// Closes the endpoint for `node` and refuses to reconnect to it.
+  pub fn quarantine(&self, node: &NodeId) {
+    self.quarantined.write().insert(Self::node_key(node));
+    self.disconnect(node);
+  }
+
+  /// Returns `true` when `node` has been quarantined.
+  #[must_use]
+  pub fn is_quarantined(&self, node: &NodeId) -> bool {
+    self.quarantined.read().contains(&Self::node_key(node))
+  }
+
+  /// Returns the identity `node` announced in its handshake, once the association is accepted.
+  ///
+  /// The returned version is the one negotiated with `node`, which frames written to it follow.
+  #[must_use]
+  pub fn association(&self, node: &NodeId) -> Option<RemoteHandshake> {
+    self.associations.read().get(&Self::node_key(node)).cloned()
+  }
+
+  /// Returns why `node` refused the association, if it did.
+  #[must_use]
+  pub fn rejection(&self, node: &NodeId) -> Option<HandshakeRejection> {
+    self.rejected.read().get(&Self::node_key(node)).copied()
+  }
+
+  /// Returns the nodes with an open connection on either lane.
+  #[must_use]
+  pub fn connected_nodes(&self) -> Vec<NodeId> {
+    let mut nodes = BTreeMap::new();
+    for lane in [&self.endpoints, &self.control] {
+      for (key, endpoint) in lane.read().iter().filter(|(_, endpoint)| !endpoint.is_closed()) {
+        nodes.entry(key.clone()).or_insert_with(|| endpoint.node().clone());
+      }
+    }
+    nodes.into_values().collect()
+  }
+
+  /// Applies a handshake frame received from a peer.
+  ///
+  /// Accepted handshakes are acknowledged over the control lane to the peer. Refusals travel on
+  /// a connection that is dropped right away, so that the refused peer is never associated.
+  pub fn handle_handshake(&self, frame: RemoteWireFrame) {
+    match frame {
+      | RemoteWireFrame::Handshake(peer) => match self.local.accept(&peer) {
+        | Ok(version) => {
+          let key = Self::node_key(&peer.node);
+          self.rejected.write().remove(&key);
+          let node = peer.node.clone();
+          self.associations.write().insert(key, peer.with_version(version));
+          let _ = self.reply(&node, &RemoteWireFrame::HandshakeAck(self.local.clone().with_version(version)));
+        },
+        | Err(reason) => {
+          let refusal = RemoteWireFrame::HandshakeRejected { node: self.local.node.clone(), reason };
+          if let (Ok(connection), Ok(bytes)) = (self.transport.connect(&peer.node), encode_wire_frame(&refusal)) {
+            let _ = connection.send(bytes);
+          }
+        },
+      },
+      | RemoteWireFrame::HandshakeAck(peer) => {
+        if let Ok(version) = self.local.accept(&peer) {
+          self.associations.write().insert(Self::node_key(&peer.node), peer.with_version(version));
+        }
+      },
+      | RemoteWireFrame::HandshakeRejected { node, reason } => {
+        self.rejected.write().insert(Self::node_key(&node), reason);
+        self.disconnect(&node);
+      },
+      | RemoteWireFrame::Delivery(_) | RemoteWireFrame::Batch(_) => {},
+    }
+  }
+
+  /// Returns `true` when deliveries read from a connection whose handshake announced `peer` are
+  /// dispatched.
+  fn admits(&self, peer: Option<&NodeId>) -> bool {
+    peer.is_some_and(|node| !self.is_quarantined(node))
+  }
+
+  fn reply(&self, node: &NodeId, frame: &RemoteWireFrame) -> Result<(), TransportError> {
+    self.control_endpoint(node)?.send(encode_wire_frame(frame)?)
+  }
+}
@@ -0,0 +1,51 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use cellex_actor_core_rs::api::metrics::BatchFlushReason;
+
+use super::OutboundBatchConfig;
+
+/// Encoded deliveries waiting to be written together to the regular lane of one node.
+pub(crate) struct OutboundBatch {
+  frames:    Vec<Vec<u8>>,
+  bytes:     usize,
+  opened_at: Duration,
+}
+
+impl OutboundBatch {
+  /// Opens an empty batch at `now`.
+  pub(crate) const fn new(now: Duration) -> Self {
+    Self { frames: Vec::new(), bytes: 0, opened_at: now }
+  }
+
+  pub(crate) const fn bytes(&self) -> usize {
+    self.bytes
+  }
+
+  pub(crate) const fn len(&self) -> usize {
+    self.frames.len()
+  }
+
+  /// Appends an encoded delivery.
+  pub(crate) fn push(&mut self, frame: Vec<u8>) {
+    self.bytes += frame.len();
+    self.frames.push(frame);
+  }
+
+  /// Returns why the batch must be written at `now`, if it must.
+  pub(crate) fn flush_reason(&self, config: &OutboundBatchConfig, now: Duration) -> Option<BatchFlushReason> {
+    if self.frames.len() >= config.max_frames() {
+      Some(BatchFlushReason::MaxFrames)
+    } else if self.bytes >= config.max_bytes() {
+      Some(BatchFlushReason::MaxBytes)
+    } else if now.saturating_sub(self.opened_at) >= config.linger() {
+      Some(BatchFlushReason::Linger)
+    } else {
+      None
+    }
+  }
+
+  pub(crate) fn into_frames(self) -> Vec<Vec<u8>> {
+    self.frames
+  }
+}
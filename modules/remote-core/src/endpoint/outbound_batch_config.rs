@@ -0,0 +1,65 @@
+use core::time::Duration;
+
+/// Flush triggers of the outbound batches of an [`EndpointManager`](super::EndpointManager).
+///
+/// A batch is written as soon as it holds `max_frames` messages or `max_bytes` encoded bytes, or
+/// once its oldest message has waited for `linger`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutboundBatchConfig {
+  max_frames: usize,
+  max_bytes:  usize,
+  linger:     Duration,
+}
+
+impl OutboundBatchConfig {
+  /// Creates a configuration flushing at 64 messages, 64 KiB or after 5ms.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { max_frames: 64, max_bytes: 64 * 1024, linger: Duration::from_millis(5) }
+  }
+
+  /// Sets how many messages a batch holds before it is written.
+  #[must_use]
+  pub const fn with_max_frames(mut self, max_frames: usize) -> Self {
+    self.max_frames = max_frames;
+    self
+  }
+
+  /// Sets how many encoded bytes a batch holds before it is written.
+  #[must_use]
+  pub const fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+    self.max_bytes = max_bytes;
+    self
+  }
+
+  /// Sets how long the oldest message of a batch may wait before the batch is written.
+  #[must_use]
+  pub const fn with_linger(mut self, linger: Duration) -> Self {
+    self.linger = linger;
+    self
+  }
+
+  /// Returns the maximum number of messages per batch.
+  #[must_use]
+  pub const fn max_frames(&self) -> usize {
+    self.max_frames
+  }
+
+  /// Returns the maximum encoded size of a batch.
+  #[must_use]
+  pub const fn max_bytes(&self) -> usize {
+    self.max_bytes
+  }
+
+  /// Returns the linger time.
+  #[must_use]
+  pub const fn linger(&self) -> Duration {
+    self.linger
+  }
+}
+
+impl Default for OutboundBatchConfig {
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -0,0 +1,57 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use core::time::Duration;
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use super::outbound_batch::OutboundBatch;
+
+/// Regular lane of one node: the batch being built, and the encoded frames waiting for the
+/// transport backlog to drain.
+pub(crate) struct OutboundQueue {
+  node:   NodeId,
+  batch:  Option<OutboundBatch>,
+  frames: VecDeque<Vec<u8>>,
+}
+
+impl OutboundQueue {
+  pub(crate) const fn new(node: NodeId) -> Self {
+    Self { node, batch: None, frames: VecDeque::new() }
+  }
+
+  pub(crate) const fn node(&self) -> &NodeId {
+    &self.node
+  }
+
+  pub(crate) fn len(&self) -> usize {
+    self.frames.len()
+  }
+
+  pub(crate) fn is_empty(&self) -> bool {
+    self.frames.is_empty()
+  }
+
+  pub(crate) fn push(&mut self, frame: Vec<u8>) {
+    self.frames.push_back(frame);
+  }
+
+  pub(crate) fn pop(&mut self) -> Option<Vec<u8>> {
+    self.frames.pop_front()
+  }
+
+  pub(crate) fn drain(&mut self) -> impl Iterator<Item = Vec<u8>> + '_ {
+    self.frames.drain(..)
+  }
+
+  pub(crate) const fn batch(&self) -> Option<&OutboundBatch> {
+    self.batch.as_ref()
+  }
+
+  /// Returns the batch being built, opening one at `now` when there is none.
+  pub(crate) fn batch_mut(&mut self, now: Duration) -> &mut OutboundBatch {
+    self.batch.get_or_insert_with(|| OutboundBatch::new(now))
+  }
+
+  pub(crate) const fn take_batch(&mut self) -> Option<OutboundBatch> {
+    self.batch.take()
+  }
+}
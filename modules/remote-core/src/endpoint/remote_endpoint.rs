@@ -0,0 +1,66 @@
+use alloc::vec::Vec;
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use cellex_utils_core_rs::sync::ArcShared;
+
+use crate::transport::{TransportConnection, TransportError};
+
+/// Association with a single remote node backed by one transport connection.
+pub struct RemoteEndpoint<C> {
+  node:       NodeId,
+  connection: ArcShared<C>,
+}
+
+impl<C> Clone for RemoteEndpoint<C> {
+  fn clone(&self) -> Self {
+    Self { node: self.node.clone(), connection: self.connection.clone() }
+  }
+}
+
+impl<C> RemoteEndpoint<C>
+where
+  C: TransportConnection,
+{
+  /// Creates an endpoint for `node` using `connection`.
+  #[must_use]
+  pub fn new(node: NodeId, connection: C) -> Self {
+    Self { node, connection: ArcShared::new(connection) }
+  }
+
+  /// Returns the remote node identifier.
+  #[must_use]
+  pub const fn node(&self) -> &NodeId {
+    &self.node
+  }
+
+  /// Returns the underlying connection.
+  #[must_use]
+  pub const fn connection(&self) -> &ArcShared<C> {
+    &self.connection
+  }
+
+  /// Sends an encoded frame body to the peer.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the connection rejects the frame.
+  pub fn send(&self, frame: Vec<u8>) -> Result<(), TransportError> {
+    self.connection.send(frame)
+  }
+
+  /// Returns how many frames the connection has not written to the peer yet.
+  #[must_use]
+  pub fn backlog(&self) -> usize {
+    self.connection.backlog()
+  }
+
+  /// Returns `true` once the connection is closed.
+  #[must_use]
+  pub fn is_closed(&self) -> bool {
+    self.connection.is_closed()
+  }
+
+  /// Closes the connection.
+  pub fn close(&self) {
+    self.connection.close();
+  }
+}
@@ -0,0 +1,545 @@
+extern crate std;
+
+use core::time::Duration;
+use std::{
+  format,
+  string::{String, ToString},
+  sync::{
+    atomic::{AtomicU64, Ordering},
+    mpsc, Arc, Mutex,
+  },
+  thread, vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::{ActorId, ActorPath},
+    actor_runtime::GenericActorRuntime,
+    actor_system::{GenericActorSystem, GenericActorSystemConfig},
+    mailbox::{
+      messages::{PriorityChannel, SystemMessage},
+      queue_mailbox::MailboxQueueConfig,
+      MailboxOverflowPolicy,
+    },
+    metrics::{BatchFlushReason, MetricsEvent, MetricsSink, MetricsSinkShared},
+    process::{
+      dead_letter::{DeadLetter, DeadLetterListener, DeadLetterReason},
+      pid::{NodeId, Pid, SystemId},
+    },
+  },
+  shared::{mailbox::messages::PriorityEnvelope, messaging::AnyMessage},
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_serialization_core_rs::InMemorySerializerRegistry;
+use cellex_utils_core_rs::{collections::queue::QueueSize, sync::ArcShared};
+
+use super::{EndpointManager, OutboundBatchConfig};
+use crate::{
+  codec::{
+    decode_wire_frame, encode_wire_frame, HandshakeRejection, RemoteDelivery, RemoteHandshake, RemoteMessageFrame,
+    RemotePayloadFrame, RemoteWireFrame, PROTOCOL_VERSION, WIRE_MAGIC,
+  },
+  delivery::RemoteInboundDispatcher,
+  loopback::{LoopbackConnection, LoopbackNetwork, LoopbackTransport},
+  transport::{InboundFrameHandler, RemoteTransport, TransportConnection, TransportError},
+};
+
+type TestResult<T = ()> = Result<T, String>;
+type TestSystem = GenericActorSystem<u32, TokioActorRuntime>;
+type DeadLetters = Arc<Mutex<Vec<(Pid, DeadLetterReason)>>>;
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn started(
+  network: &LoopbackNetwork,
+  system: &'static str,
+  port: u16,
+) -> TestResult<(EndpointManager<LoopbackTransport>, TestSystem)> {
+  started_with(network, system, port, |manager| manager)
+}
+
+fn started_with<F>(
+  network: &LoopbackNetwork,
+  system: &'static str,
+  port: u16,
+  configure: F,
+) -> TestResult<(EndpointManager<LoopbackTransport>, TestSystem)>
+where
+  F: FnOnce(EndpointManager<LoopbackTransport>) -> EndpointManager<LoopbackTransport>, {
+  let config = GenericActorSystemConfig::default().with_node_id(node(port));
+  let actor_system = GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), config);
+  let manager = configure(EndpointManager::new(network.transport(&node(port)), SystemId::new(system), node(port)));
+  let dispatcher = RemoteInboundDispatcher::new(actor_system.process_registry(), InMemorySerializerRegistry::new());
+  manager.start(dispatcher).map_err(|err| format!("listen: {err}"))?;
+  Ok((manager, actor_system))
+}
+
+type Received = Arc<Mutex<Vec<Vec<u8>>>>;
+
+#[derive(Clone, Default)]
+struct RecordingMetricsSink {
+  events: Arc<Mutex<Vec<MetricsEvent>>>,
+}
+
+impl MetricsSink for RecordingMetricsSink {
+  fn record(&self, event: MetricsEvent) {
+    self.events.lock().unwrap_or_else(|err| err.into_inner()).push(event);
+  }
+}
+
+/// Loopback transport whose connections to `gated` wait until `release` lets them through.
+#[derive(Clone)]
+struct GatedTransport {
+  inner:      LoopbackTransport,
+  gated:      NodeId,
+  connecting: Arc<Mutex<mpsc::Sender<()>>>,
+  release:    Arc<Mutex<mpsc::Receiver<()>>>,
+}
+
+impl RemoteTransport for GatedTransport {
+  type Connection = LoopbackConnection;
+
+  fn listen(&self, local: &NodeId, handler: InboundFrameHandler) -> Result<(), TransportError> {
+    self.inner.listen(local, handler)
+  }
+
+  fn connect(&self, remote: &NodeId) -> Result<Self::Connection, TransportError> {
+    if remote == &self.gated {
+      let _ = self.connecting.lock().unwrap_or_else(|err| err.into_inner()).send(());
+      let _ = self.release.lock().unwrap_or_else(|err| err.into_inner()).recv();
+    }
+    self.inner.connect(remote)
+  }
+}
+
+fn listening(network: &LoopbackNetwork, port: u16) -> TestResult<Received> {
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let received_clone = received.clone();
+  network
+    .transport(&node(port))
+    .listen(
+      &node(port),
+      InboundFrameHandler::new(move |frame: &[u8]| {
+        received_clone.lock().unwrap_or_else(|err| err.into_inner()).push(frame.to_vec());
+      }),
+    )
+    .map_err(|err| format!("listen: {err}"))?;
+  Ok(received)
+}
+
+fn stop(channel: PriorityChannel, port: u16) -> RemoteDelivery {
+  let target = Pid::new(SystemId::new("cellex"), ActorPath::new()).with_node(node(port));
+  RemoteDelivery::new(
+    target,
+    RemoteMessageFrame::new(0, channel, RemotePayloadFrame::System(SystemMessage::Stop), None),
+  )
+}
+
+fn stop_at(id: usize, port: u16) -> RemoteDelivery {
+  let target = Pid::new(SystemId::new("cellex"), ActorPath::new().push_child(ActorId(id))).with_node(node(port));
+  let payload = RemotePayloadFrame::System(SystemMessage::Stop);
+  RemoteDelivery::new(target, RemoteMessageFrame::new(0, PriorityChannel::Regular, payload, None))
+}
+
+fn dead_letters(system: &TestSystem) -> DeadLetters {
+  let letters: DeadLetters = Arc::new(Mutex::new(Vec::new()));
+  let letters_clone = letters.clone();
+  let listener = ArcShared::new(move |letter: &DeadLetter<ArcShared<PriorityEnvelope<AnyMessage>>>| {
+    letters_clone.lock().unwrap_or_else(|err| err.into_inner()).push((letter.pid.clone(), letter.reason.clone()));
+  })
+  .into_dyn(|f| f as &DeadLetterListener<ArcShared<PriorityEnvelope<AnyMessage>>>);
+  system.process_registry().subscribe_dead_letters(listener);
+  letters
+}
+
+fn delivered_frames(received: &Received) -> TestResult<Vec<RemoteWireFrame>> {
+  core::mem::take(&mut *received.lock().unwrap_or_else(|err| err.into_inner()))
+    .iter()
+    .map(|bytes| decode_wire_frame(bytes).map_err(|err| format!("decode: {err}")))
+    .filter(|frame| !matches!(frame, Ok(RemoteWireFrame::Handshake(_))))
+    .collect()
+}
+
+#[test]
+fn handshake_associates_nodes_of_the_same_system() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (first, _first_system) = started(&network, "cellex", 2551)?;
+  let (second, _second_system) = started(&network, "cellex", 2552)?;
+
+  first.endpoint(&node(2552)).map_err(|err| format!("endpoint: {err}"))?;
+  assert!(first.association(&node(2552)).is_none());
+  network.flush();
+
+  assert_eq!(first.association(&node(2552)), Some(RemoteHandshake::new(SystemId::new("cellex"), node(2552))));
+  assert_eq!(second.association(&node(2551)), Some(RemoteHandshake::new(SystemId::new("cellex"), node(2551))));
+  assert_eq!(second.connected_nodes(), std::vec![node(2551)]);
+
+  first.disconnect(&node(2552));
+  assert!(first.association(&node(2552)).is_none());
+  Ok(())
+}
+
+#[test]
+fn handshake_with_another_system_is_rejected() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (first, _first_system) = started(&network, "cellex", 2551)?;
+  let (second, _second_system) = started(&network, "other", 2552)?;
+
+  first.endpoint(&node(2552)).map_err(|err| format!("endpoint: {err}"))?;
+  network.flush();
+
+  assert_eq!(first.rejection(&node(2552)), Some(HandshakeRejection::SystemMismatch));
+  assert!(first.connected_nodes().is_empty());
+  assert_eq!(
+    first.endpoint(&node(2552)).err(),
+    Some(TransportError::Rejected(node(2552), HandshakeRejection::SystemMismatch))
+  );
+  assert!(second.association(&node(2551)).is_none());
+  assert!(second.connected_nodes().is_empty());
+  Ok(())
+}
+
+#[test]
+fn handshake_with_unsupported_version_is_rejected() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (manager, _system) = started(&network, "cellex", 2552)?;
+
+  let peer = network.transport(&node(2551));
+  let received: Arc<Mutex<Vec<Vec<u8>>>> = Arc::new(Mutex::new(Vec::new()));
+  let received_clone = received.clone();
+  peer
+    .listen(
+      &node(2551),
+      InboundFrameHandler::new(move |frame: &[u8]| {
+        received_clone.lock().unwrap_or_else(|err| err.into_inner()).push(frame.to_vec());
+      }),
+    )
+    .map_err(|err| format!("listen: {err}"))?;
+  let handshake = RemoteHandshake::new(SystemId::new("cellex"), node(2551)).with_version(0);
+  let bytes = encode_wire_frame(&RemoteWireFrame::Handshake(handshake)).map_err(|err| format!("encode: {err}"))?;
+  peer
+    .connect(&node(2552))
+    .map_err(|err| format!("connect: {err}"))?
+    .send(bytes)
+    .map_err(|err| format!("send: {err}"))?;
+  network.flush();
+
+  let frames = core::mem::take(&mut *received.lock().unwrap_or_else(|err| err.into_inner()));
+  let refusal = frames.first().ok_or_else(|| "refusal expected".to_string())?;
+  assert_eq!(decode_wire_frame(refusal).map_err(|err| format!("decode: {err}"))?, RemoteWireFrame::HandshakeRejected {
+    node:   node(2552),
+    reason: HandshakeRejection::UnsupportedVersion(0),
+  });
+  assert_eq!(frames.len(), 1);
+  assert!(manager.association(&node(2551)).is_none());
+  Ok(())
+}
+
+#[test]
+fn handshake_with_newer_version_negotiates_the_local_one() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (manager, _system) = started(&network, "cellex", 2552)?;
+  let received = listening(&network, 2551)?;
+
+  let handshake = RemoteHandshake::new(SystemId::new("cellex"), node(2551)).with_version(PROTOCOL_VERSION + 1);
+  let bytes = encode_wire_frame(&RemoteWireFrame::Handshake(handshake)).map_err(|err| format!("encode: {err}"))?;
+  let peer = network.transport(&node(2551));
+  peer
+    .connect(&node(2552))
+    .map_err(|err| format!("connect: {err}"))?
+    .send(bytes)
+    .map_err(|err| format!("send: {err}"))?;
+  network.flush();
+
+  let negotiated = manager.association(&node(2551)).ok_or_else(|| "association expected".to_string())?;
+  assert_eq!(negotiated.version, PROTOCOL_VERSION);
+  assert!(delivered_frames(&received)?.iter().any(|frame| matches!(frame, RemoteWireFrame::HandshakeAck(_))));
+  Ok(())
+}
+
+#[test]
+fn deliveries_are_dropped_until_their_connection_is_associated() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (_manager, system) = started(&network, "cellex", 2552)?;
+  let _received = listening(&network, 2551)?;
+  let letters = dead_letters(&system);
+  let connection = network.transport(&node(2551)).connect(&node(2552)).map_err(|err| format!("connect: {err}"))?;
+  let delivery = encode_wire_frame(&RemoteWireFrame::Delivery(std::boxed::Box::new(stop_at(7, 2552))))
+    .map_err(|err| format!("encode: {err}"))?;
+
+  connection.send(delivery.clone()).map_err(|err| format!("send: {err}"))?;
+  network.flush();
+  assert!(letters.lock().unwrap_or_else(|err| err.into_inner()).is_empty());
+
+  let handshake = RemoteHandshake::new(SystemId::new("cellex"), node(2551));
+  let bytes = encode_wire_frame(&RemoteWireFrame::Handshake(handshake)).map_err(|err| format!("encode: {err}"))?;
+  connection.send(bytes).map_err(|err| format!("send: {err}"))?;
+  connection.send(delivery).map_err(|err| format!("send: {err}"))?;
+  network.flush();
+  // The unknown target shows that the delivery reached the dispatcher.
+  let letters = letters.lock().unwrap_or_else(|err| err.into_inner()).clone();
+  assert_eq!(letters.iter().map(|(pid, _)| pid.clone()).collect::<Vec<_>>(), std::vec![stop_at(7, 2552).target]);
+  Ok(())
+}
+
+#[test]
+fn control_frames_travel_on_their_own_connection() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (first, _first_system) = started(&network, "cellex", 2551)?;
+  let (_second, _second_system) = started(&network, "cellex", 2552)?;
+
+  let regular = first.endpoint(&node(2552)).map_err(|err| format!("endpoint: {err}"))?;
+  let control = first.control_endpoint(&node(2552)).map_err(|err| format!("control endpoint: {err}"))?;
+  network.flush();
+  assert_eq!(first.connected_nodes(), std::vec![node(2552)]);
+
+  regular.close();
+  assert!(!control.is_closed());
+  let target = Pid::new(SystemId::new("cellex"), ActorPath::new()).with_node(node(2552));
+  let stop =
+    RemoteMessageFrame::new(0, PriorityChannel::Control, RemotePayloadFrame::System(SystemMessage::Stop), None);
+  let delivered = network.delivered();
+  first.send(&RemoteDelivery::new(target, stop)).map_err(|err| format!("send: {err}"))?;
+  assert!(regular.is_closed());
+  network.flush();
+  assert_eq!(network.delivered(), delivered + 1);
+  Ok(())
+}
+
+#[test]
+fn regular_deliveries_are_batched_until_a_flush_trigger() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received = listening(&network, 2552)?;
+  let now = Arc::new(AtomicU64::new(0));
+  let clock = now.clone();
+  let metrics = RecordingMetricsSink::default();
+  let config = OutboundBatchConfig::new().with_max_frames(3).with_linger(Duration::from_millis(10));
+  let manager = EndpointManager::new(network.transport(&node(2551)), SystemId::new("cellex"), node(2551))
+    .with_batching(config, move || Duration::from_millis(clock.load(Ordering::SeqCst)))
+    .with_metrics_sink(MetricsSinkShared::new(metrics.clone()));
+  manager.handle_handshake(RemoteWireFrame::HandshakeAck(RemoteHandshake::new(SystemId::new("cellex"), node(2552))));
+
+  for _ in 0..2 {
+    manager.send(&stop(PriorityChannel::Regular, 2552)).map_err(|err| format!("send: {err}"))?;
+  }
+  manager.send(&stop(PriorityChannel::Control, 2552)).map_err(|err| format!("send control: {err}"))?;
+  network.flush();
+  assert_eq!(manager.pending(&node(2552)), 2);
+  assert_eq!(delivered_frames(&received)?, std::vec![RemoteWireFrame::Delivery(std::boxed::Box::new(stop(
+    PriorityChannel::Control,
+    2552
+  )))]);
+
+  manager.send(&stop(PriorityChannel::Regular, 2552)).map_err(|err| format!("send: {err}"))?;
+  network.flush();
+  assert_eq!(manager.pending(&node(2552)), 0);
+  assert_eq!(delivered_frames(&received)?, std::vec![RemoteWireFrame::Batch(std::vec![
+    stop(PriorityChannel::Regular, 2552);
+    3
+  ])]);
+
+  manager.send(&stop(PriorityChannel::Regular, 2552)).map_err(|err| format!("send: {err}"))?;
+  manager.flush_expired().map_err(|err| format!("flush: {err}"))?;
+  assert_eq!(manager.pending(&node(2552)), 1);
+  now.store(10, Ordering::SeqCst);
+  manager.flush_expired().map_err(|err| format!("flush: {err}"))?;
+  network.flush();
+  assert_eq!(manager.pending(&node(2552)), 0);
+  assert_eq!(delivered_frames(&received)?.len(), 1);
+
+  let events = metrics.events.lock().unwrap_or_else(|err| err.into_inner()).clone();
+  let flushes: Vec<(usize, BatchFlushReason)> = events
+    .iter()
+    .filter_map(|event| match event {
+      | MetricsEvent::OutboundBatchFlushed { frames, reason, .. } => Some((*frames, *reason)),
+      | _ => None,
+    })
+    .collect();
+  assert_eq!(flushes, std::vec![(3, BatchFlushReason::MaxFrames), (1, BatchFlushReason::Linger)]);
+  Ok(())
+}
+
+#[test]
+fn batches_flush_on_size_and_before_disconnecting() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received = listening(&network, 2552)?;
+  let frame_size =
+    crate::codec::encode_delivery(&stop(PriorityChannel::Regular, 2552)).map_err(|err| format!("encode: {err}"))?.len();
+  let metrics = RecordingMetricsSink::default();
+  let config = OutboundBatchConfig::new().with_max_bytes(frame_size * 2).with_linger(Duration::from_secs(1));
+  let manager = EndpointManager::new(network.transport(&node(2551)), SystemId::new("cellex"), node(2551))
+    .with_batching(config, || Duration::ZERO)
+    .with_metrics_sink(MetricsSinkShared::new(metrics.clone()));
+  manager.handle_handshake(RemoteWireFrame::HandshakeAck(RemoteHandshake::new(SystemId::new("cellex"), node(2552))));
+
+  for _ in 0..3 {
+    manager.send(&stop(PriorityChannel::Regular, 2552)).map_err(|err| format!("send: {err}"))?;
+  }
+  assert_eq!(manager.pending(&node(2552)), 1);
+  manager.disconnect(&node(2552));
+  network.flush();
+
+  assert_eq!(delivered_frames(&received)?.len(), 2);
+  assert_eq!(*metrics.events.lock().unwrap_or_else(|err| err.into_inner()), std::vec![
+    MetricsEvent::OutboundBatchFlushed { frames: 2, bytes: frame_size * 2, reason: BatchFlushReason::MaxBytes },
+    MetricsEvent::OutboundBatchFlushed { frames: 1, bytes: frame_size, reason: BatchFlushReason::Explicit },
+  ]);
+  Ok(())
+}
+
+#[test]
+fn peers_without_batch_support_receive_deliveries_one_by_one() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received = listening(&network, 2552)?;
+  let manager = EndpointManager::new(network.transport(&node(2551)), SystemId::new("cellex"), node(2551))
+    .with_batching(OutboundBatchConfig::new(), || Duration::ZERO);
+  let peer = RemoteHandshake::new(SystemId::new("cellex"), node(2552)).with_version(1);
+  manager.handle_handshake(RemoteWireFrame::HandshakeAck(peer));
+
+  for _ in 0..2 {
+    manager.send(&stop(PriorityChannel::Regular, 2552)).map_err(|err| format!("send: {err}"))?;
+  }
+  network.flush();
+
+  assert_eq!(manager.pending(&node(2552)), 0);
+  let version_offset = WIRE_MAGIC.len();
+  for bytes in received.lock().unwrap_or_else(|err| err.into_inner()).iter() {
+    assert_eq!(bytes[version_offset..version_offset + 2], 1_u16.to_be_bytes());
+  }
+  let frames = delivered_frames(&received)?;
+  assert_eq!(frames.len(), 2);
+  assert!(frames.iter().all(|frame| matches!(frame, RemoteWireFrame::Delivery(_))));
+  Ok(())
+}
+
+#[test]
+fn full_outbound_queues_dead_letter_the_overflow() -> TestResult {
+  for (policy, dropped) in [(MailboxOverflowPolicy::DropOldest, [2, 3]), (MailboxOverflowPolicy::DropNewest, [4, 5])] {
+    let network = LoopbackNetwork::new();
+    let received = listening(&network, 2552)?;
+    let queue = MailboxQueueConfig::new(QueueSize::limited(2), policy);
+    let (manager, system) = started_with(&network, "cellex", 2551, |manager| manager.with_outbound_queue(queue))?;
+    let letters = dead_letters(&system);
+
+    // The handshake and the first delivery fill the transport backlog while the peer stalls.
+    for id in 1..=5 {
+      manager.send(&stop_at(id, 2552)).map_err(|err| format!("send {id}: {err}"))?;
+    }
+    assert_eq!(manager.queued(&node(2552)), 2);
+    let expected: Vec<(Pid, DeadLetterReason)> =
+      dropped.iter().map(|id| (stop_at(*id, 2552).target, DeadLetterReason::DeliveryRejected)).collect();
+    assert_eq!(*letters.lock().unwrap_or_else(|err| err.into_inner()), expected);
+
+    network.flush();
+    manager.drain().map_err(|err| format!("drain: {err}"))?;
+    network.flush();
+    assert_eq!(manager.queued(&node(2552)), 0);
+    assert_eq!(delivered_frames(&received)?.len(), 3);
+  }
+  Ok(())
+}
+
+#[test]
+fn blocking_outbound_queues_refuse_deliveries_until_the_peer_catches_up() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let received = listening(&network, 2552)?;
+  let queue = MailboxQueueConfig::new(QueueSize::limited(1), MailboxOverflowPolicy::Block);
+  let (manager, system) = started_with(&network, "cellex", 2551, |manager| manager.with_outbound_queue(queue))?;
+  let letters = dead_letters(&system);
+
+  manager.send(&stop_at(1, 2552)).map_err(|err| format!("send: {err}"))?;
+  assert_eq!(manager.queued(&node(2552)), 1);
+  // Blocking refuses right away instead of waiting, and dead-letters the refused message.
+  assert_eq!(manager.send(&stop_at(2, 2552)), Err(TransportError::Backpressure(node(2552))));
+  assert_eq!(manager.queued(&node(2552)), 1);
+  assert_eq!(*letters.lock().unwrap_or_else(|err| err.into_inner()), vec![(
+    stop_at(2, 2552).target,
+    DeadLetterReason::DeliveryRejected
+  )]);
+
+  network.flush();
+  manager.drain().map_err(|err| format!("drain: {err}"))?;
+  network.flush();
+  assert_eq!(delivered_frames(&received)?.len(), 1);
+  manager.send(&stop_at(3, 2552)).map_err(|err| format!("send after drain: {err}"))?;
+  network.flush();
+  assert_eq!(delivered_frames(&received)?.len(), 1);
+  assert_eq!(letters.lock().unwrap_or_else(|err| err.into_inner()).len(), 1);
+  Ok(())
+}
+
+#[test]
+fn a_node_slow_to_connect_does_not_hold_up_the_others() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let _slow = listening(&network, 2552)?;
+  let received = listening(&network, 2553)?;
+  let (connecting_tx, connecting_rx) = mpsc::channel();
+  let (release_tx, release_rx) = mpsc::channel();
+  let transport = GatedTransport {
+    inner:      network.transport(&node(2551)),
+    gated:      node(2552),
+    connecting: Arc::new(Mutex::new(connecting_tx)),
+    release:    Arc::new(Mutex::new(release_rx)),
+  };
+  let manager = EndpointManager::new(transport, SystemId::new("cellex"), node(2551));
+
+  let slow = manager.clone();
+  let slow_sender = thread::spawn(move || slow.send(&stop_at(1, 2552)));
+  connecting_rx.recv_timeout(Duration::from_secs(5)).map_err(|err| format!("connecting: {err}"))?;
+  let fast = manager;
+  let (sent_tx, sent_rx) = mpsc::channel();
+  let fast_sender = thread::spawn(move || {
+    let _ = sent_tx.send(fast.send(&stop_at(2, 2553)));
+  });
+  let sent = sent_rx.recv_timeout(Duration::from_secs(5)).map_err(|_| "send held up by the slow node".to_string());
+
+  release_tx.send(()).map_err(|err| format!("release: {err}"))?;
+  slow_sender.join().map_err(|_| "slow sender panicked".to_string())?.map_err(|err| format!("slow send: {err}"))?;
+  fast_sender.join().map_err(|_| "fast sender panicked".to_string())?;
+  sent?.map_err(|err| format!("fast send: {err}"))?;
+  network.flush();
+  assert_eq!(delivered_frames(&received)?.len(), 1);
+  Ok(())
+}
+
+#[test]
+fn messages_to_unreachable_nodes_are_dead_lettered() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let _received = listening(&network, 2552)?;
+  let queue = MailboxQueueConfig::new(QueueSize::limited(1), MailboxOverflowPolicy::DropNewest);
+  let (manager, system) = started_with(&network, "cellex", 2551, |manager| manager.with_outbound_queue(queue))?;
+  let letters = dead_letters(&system);
+
+  manager.send(&stop_at(1, 2552)).map_err(|err| format!("send: {err}"))?;
+  assert_eq!(manager.queued(&node(2552)), 1);
+  manager.quarantine(&node(2552));
+  assert_eq!(manager.send(&stop_at(2, 2552)), Err(TransportError::Quarantined(node(2552))));
+
+  let expected: Vec<(Pid, DeadLetterReason)> =
+    [1, 2].iter().map(|id| (stop_at(*id, 2552).target, DeadLetterReason::NetworkUnreachable)).collect();
+  assert_eq!(*letters.lock().unwrap_or_else(|err| err.into_inner()), expected);
+  assert_eq!(manager.queued(&node(2552)), 0);
+  Ok(())
+}
+
+#[test]
+fn control_frames_failing_on_an_open_connection_are_dead_lettered() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let _received = listening(&network, 2552)?;
+  let (manager, system) = started(&network, "cellex", 2551)?;
+  let letters = dead_letters(&system);
+  manager.send(&stop(PriorityChannel::Control, 2552)).map_err(|err| format!("send: {err}"))?;
+
+  // The control lane stays open until a write to the stopped node fails.
+  network.shutdown(&node(2552));
+  assert_eq!(manager.send(&stop(PriorityChannel::Control, 2552)), Err(TransportError::Closed));
+
+  assert_eq!(*letters.lock().unwrap_or_else(|err| err.into_inner()), vec![(
+    stop(PriorityChannel::Control, 2552).target,
+    DeadLetterReason::NetworkUnreachable
+  )]);
+  Ok(())
+}
@@ -0,0 +1,15 @@
+mod deadline_failure_detector;
+mod failure_detector_config;
+mod heartbeat_clock;
+mod monitored_node;
+mod node_reachability;
+mod remote_heartbeat;
+
+#[cfg(test)]
+mod tests;
+
+pub use deadline_failure_detector::DeadlineFailureDetector;
+pub use failure_detector_config::FailureDetectorConfig;
+pub use heartbeat_clock::HeartbeatClock;
+pub use node_reachability::NodeReachability;
+pub use remote_heartbeat::RemoteHeartbeat;
@@ -0,0 +1,44 @@
+use core::time::Duration;
+
+use super::FailureDetectorConfig;
+
+/// Deadline-based failure detector for a single node.
+///
+/// The node is considered available as long as the last heartbeat is no older than the heartbeat
+/// interval plus the acceptable pause. Timestamps are supplied by the caller so the detector stays
+/// independent of any clock.
+#[derive(Debug, Clone)]
+pub struct DeadlineFailureDetector {
+  deadline:       Duration,
+  last_heartbeat: Option<Duration>,
+}
+
+impl DeadlineFailureDetector {
+  /// Creates a detector using the thresholds of `config`.
+  #[must_use]
+  pub const fn new(config: &FailureDetectorConfig) -> Self {
+    Self {
+      deadline:       config.heartbeat_interval().saturating_add(config.acceptable_heartbeat_pause()),
+      last_heartbeat: None,
+    }
+  }
+
+  /// Records a heartbeat observed at `now`.
+  pub fn heartbeat(&mut self, now: Duration) {
+    self.last_heartbeat = Some(self.last_heartbeat.map_or(now, |last| last.max(now)));
+  }
+
+  /// Returns `true` when a heartbeat was observed recently enough at `now`.
+  ///
+  /// A detector that never saw a heartbeat is considered available.
+  #[must_use]
+  pub fn is_available(&self, now: Duration) -> bool {
+    self.last_heartbeat.is_none_or(|last| now.saturating_sub(last) <= self.deadline)
+  }
+
+  /// Returns the time of the last observed heartbeat.
+  #[must_use]
+  pub const fn last_heartbeat(&self) -> Option<Duration> {
+    self.last_heartbeat
+  }
+}
@@ -0,0 +1,68 @@
+use core::time::Duration;
+
+/// Thresholds of the heartbeat failure detector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FailureDetectorConfig {
+  heartbeat_interval:         Duration,
+  acceptable_heartbeat_pause: Duration,
+  quarantine_after:           Duration,
+}
+
+impl FailureDetectorConfig {
+  /// Creates a configuration with a 1s heartbeat interval, a 3s acceptable pause and quarantine
+  /// after 60s of unreachability.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self {
+      heartbeat_interval:         Duration::from_secs(1),
+      acceptable_heartbeat_pause: Duration::from_secs(3),
+      quarantine_after:           Duration::from_secs(60),
+    }
+  }
+
+  /// Sets how often heartbeats are sent to every monitored node.
+  #[must_use]
+  pub const fn with_heartbeat_interval(mut self, interval: Duration) -> Self {
+    self.heartbeat_interval = interval;
+    self
+  }
+
+  /// Sets how long heartbeats may be missing, on top of the interval, before a node is reported
+  /// unreachable.
+  #[must_use]
+  pub const fn with_acceptable_heartbeat_pause(mut self, pause: Duration) -> Self {
+    self.acceptable_heartbeat_pause = pause;
+    self
+  }
+
+  /// Sets how long a node may stay unreachable before it is quarantined.
+  #[must_use]
+  pub const fn with_quarantine_after(mut self, timeout: Duration) -> Self {
+    self.quarantine_after = timeout;
+    self
+  }
+
+  /// Returns the heartbeat interval.
+  #[must_use]
+  pub const fn heartbeat_interval(&self) -> Duration {
+    self.heartbeat_interval
+  }
+
+  /// Returns the acceptable heartbeat pause.
+  #[must_use]
+  pub const fn acceptable_heartbeat_pause(&self) -> Duration {
+    self.acceptable_heartbeat_pause
+  }
+
+  /// Returns the quarantine timeout.
+  #[must_use]
+  pub const fn quarantine_after(&self) -> Duration {
+    self.quarantine_after
+  }
+}
+
+impl Default for FailureDetectorConfig {
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -0,0 +1,11 @@
+use core::time::Duration;
+
+/// Monotonic clock driving the failure detector, returning the time elapsed since an arbitrary
+/// fixed origin.
+#[cfg(target_has_atomic = "ptr")]
+pub type HeartbeatClock = dyn Fn() -> Duration + Send + Sync + 'static;
+
+/// Monotonic clock driving the failure detector, returning the time elapsed since an arbitrary
+/// fixed origin.
+#[cfg(not(target_has_atomic = "ptr"))]
+pub type HeartbeatClock = dyn Fn() -> Duration + 'static;
@@ -0,0 +1,62 @@
+use core::time::Duration;
+
+use cellex_actor_core_rs::api::{failure::FailureEvent, process::pid::NodeId};
+
+use super::{DeadlineFailureDetector, FailureDetectorConfig, NodeReachability};
+
+/// Detector state of one node watched by [`RemoteHeartbeat`](super::RemoteHeartbeat).
+pub(crate) struct MonitoredNode {
+  node:              NodeId,
+  detector:          DeadlineFailureDetector,
+  reachability:      NodeReachability,
+  unreachable_since: Option<Duration>,
+}
+
+impl MonitoredNode {
+  /// Starts monitoring `node`, granting it a full deadline from `now`.
+  pub(crate) fn new(node: NodeId, config: &FailureDetectorConfig, now: Duration) -> Self {
+    let mut detector = DeadlineFailureDetector::new(config);
+    detector.heartbeat(now);
+    Self { node, detector, reachability: NodeReachability::Reachable, unreachable_since: None }
+  }
+
+  pub(crate) const fn node(&self) -> &NodeId {
+    &self.node
+  }
+
+  pub(crate) const fn reachability(&self) -> NodeReachability {
+    self.reachability
+  }
+
+  /// Records a heartbeat and reports the node reachable again when it was unreachable.
+  pub(crate) fn heartbeat(&mut self, now: Duration) -> Option<FailureEvent> {
+    if self.reachability == NodeReachability::Quarantined {
+      return None;
+    }
+    self.detector.heartbeat(now);
+    if self.reachability != NodeReachability::Unreachable {
+      return None;
+    }
+    self.reachability = NodeReachability::Reachable;
+    self.unreachable_since = None;
+    Some(FailureEvent::NodeReachable(self.node.clone()))
+  }
+
+  /// Applies the detector decision at `now`, returning the resulting transition.
+  pub(crate) fn evaluate(&mut self, now: Duration, quarantine_after: Duration) -> Option<FailureEvent> {
+    match self.reachability {
+      | NodeReachability::Reachable if !self.detector.is_available(now) => {
+        self.reachability = NodeReachability::Unreachable;
+        self.unreachable_since = Some(now);
+        Some(FailureEvent::NodeUnreachable(self.node.clone()))
+      },
+      | NodeReachability::Unreachable
+        if self.unreachable_since.is_some_and(|since| now.saturating_sub(since) >= quarantine_after) =>
+      {
+        self.reachability = NodeReachability::Quarantined;
+        Some(FailureEvent::NodeQuarantined(self.node.clone()))
+      },
+      | _ => None,
+    }
+  }
+}
@@ -0,0 +1,10 @@
+/// Liveness state the failure detector assigns to a monitored node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeReachability {
+  /// Heartbeats arrive within the acceptable pause.
+  Reachable,
+  /// Heartbeats stopped; the node may still come back.
+  Unreachable,
+  /// The node stayed unreachable past the quarantine timeout and is not reconnected.
+  Quarantined,
+}
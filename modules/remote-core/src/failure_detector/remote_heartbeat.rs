@@ -0,0 +1,224 @@
+use alloc::{
+  collections::BTreeMap,
+  string::{String, ToString},
+  vec::Vec,
+};
+use core::time::Duration;
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::ActorPath,
+    failure::{failure_event_stream::FailureEventListener, FailureEvent},
+    process::pid::{NodeId, Pid, PidTag},
+  },
+  shared::mailbox::MailboxFactory,
+};
+use cellex_utils_core_rs::{
+  collections::queue::priority::DEFAULT_PRIORITY,
+  sync::{shared::SharedBound, ArcShared},
+};
+use spin::RwLock;
+
+use super::{monitored_node::MonitoredNode, FailureDetectorConfig, HeartbeatClock, NodeReachability};
+use crate::{
+  codec::{RemoteDelivery, RemotePayloadFrame},
+  delivery::{RemoteFrameInterceptor, RemoteInboundDispatcher},
+  outbound::{RemoteOutbound, RemoteSendError},
+  transport::RemoteTransport,
+  watch::RemoteDeathWatch,
+};
+
+const HEARTBEAT_TAG: &str = "heartbeat";
+
+/// Heartbeat exchange and failure detection for the endpoints of one node.
+///
+/// Every [`RemoteHeartbeat::tick`] sends a heartbeat to each monitored node, whose heartbeat
+/// interceptor answers with an acknowledgement. Acknowledgements feed a
+/// [`DeadlineFailureDetector`](super::DeadlineFailureDetector) per node; reachability changes are
+/// published as [`FailureEvent::NodeUnreachable`], [`FailureEvent::NodeReachable`] and
+/// [`FailureEvent::NodeQuarantined`]. Quarantined nodes are never reconnected, and watchers
+/// registered through an attached [`RemoteDeathWatch`] are notified once a node is unreachable.
+pub struct RemoteHeartbeat<T>
+where
+  T: RemoteTransport, {
+  outbound:  RemoteOutbound<T>,
+  config:    FailureDetectorConfig,
+  clock:     ArcShared<HeartbeatClock>,
+  nodes:     ArcShared<RwLock<BTreeMap<String, MonitoredNode>>>,
+  listeners: Vec<FailureEventListener>,
+  watch:     Option<RemoteDeathWatch<T>>,
+}
+
+impl<T> Clone for RemoteHeartbeat<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self {
+      outbound:  self.outbound.clone(),
+      config:    self.config,
+      clock:     self.clock.clone(),
+      nodes:     self.nodes.clone(),
+      listeners: self.listeners.clone(),
+      watch:     self.watch.clone(),
+    }
+  }
+}
+
+impl<T> RemoteHeartbeat<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates a heartbeat sending through `outbound`, with `clock` as the time source.
+  #[must_use]
+  pub fn new<C>(outbound: RemoteOutbound<T>, config: FailureDetectorConfig, clock: C) -> Self
+  where
+    C: Fn() -> Duration + SharedBound + 'static, {
+    Self {
+      outbound,
+      config,
+      clock: ArcShared::new(clock).into_dyn(|f| f as &HeartbeatClock),
+      nodes: ArcShared::new(RwLock::new(BTreeMap::new())),
+      listeners: Vec::new(),
+      watch: None,
+    }
+  }
+
+  /// Publishes reachability changes to `listener`, typically the listener of a
+  /// `FailureEventStream`.
+  #[must_use]
+  pub fn with_failure_listener(mut self, listener: FailureEventListener) -> Self {
+    self.listeners.push(listener);
+    self
+  }
+
+  /// Notifies the watchers of `watch` when a monitored node becomes unreachable.
+  #[must_use]
+  pub fn with_death_watch(mut self, watch: RemoteDeathWatch<T>) -> Self {
+    self.watch = Some(watch);
+    self
+  }
+
+  /// Returns the outbound side used to send heartbeats.
+  #[must_use]
+  pub const fn outbound(&self) -> &RemoteOutbound<T> {
+    &self.outbound
+  }
+
+  /// Returns the detector thresholds.
+  #[must_use]
+  pub const fn config(&self) -> &FailureDetectorConfig {
+    &self.config
+  }
+
+  /// Starts exchanging heartbeats with `node`. Monitoring an already monitored node has no
+  /// effect.
+  pub fn monitor(&self, node: &NodeId) {
+    let now = (self.clock)();
+    self.nodes.write().entry(node.to_string()).or_insert_with(|| MonitoredNode::new(node.clone(), &self.config, now));
+  }
+
+  /// Stops exchanging heartbeats with `node`.
+  pub fn unmonitor(&self, node: &NodeId) {
+    self.nodes.write().remove(&node.to_string());
+  }
+
+  /// Returns the reachability of `node`, or `None` when it is not monitored.
+  #[must_use]
+  pub fn reachability(&self, node: &NodeId) -> Option<NodeReachability> {
+    self.nodes.read().get(&node.to_string()).map(MonitoredNode::reachability)
+  }
+
+  /// Sends a heartbeat to every monitored node that is not quarantined, then publishes the
+  /// reachability changes detected at the current time.
+  pub fn tick(&self) {
+    let targets: Vec<NodeId> = self
+      .nodes
+      .read()
+      .values()
+      .filter(|monitored| monitored.reachability() != NodeReachability::Quarantined)
+      .map(|monitored| monitored.node().clone())
+      .collect();
+    for node in &targets {
+      // A lost heartbeat is exactly what the detector is there to notice.
+      let _ = self.send(node, RemotePayloadFrame::Heartbeat);
+    }
+
+    let now = (self.clock)();
+    let quarantine_after = self.config.quarantine_after();
+    let events: Vec<FailureEvent> =
+      self.nodes.write().values_mut().filter_map(|monitored| monitored.evaluate(now, quarantine_after)).collect();
+    for event in &events {
+      self.publish(event);
+    }
+  }
+
+  /// Answers heartbeats and records acknowledgements, returning every other delivery.
+  pub fn handle_frame(&self, delivery: RemoteDelivery) -> Option<RemoteDelivery> {
+    let RemoteDelivery { target, frame } = delivery;
+    let sender = frame.reply_to.as_ref().and_then(Pid::node);
+    match (&frame.payload, sender) {
+      | (RemotePayloadFrame::Heartbeat, Some(node)) => {
+        let _ = self.send(node, RemotePayloadFrame::HeartbeatAck);
+        self.record(node);
+        None
+      },
+      | (RemotePayloadFrame::HeartbeatAck, Some(node)) => {
+        self.record(node);
+        None
+      },
+      | _ => Some(RemoteDelivery::new(target, frame)),
+    }
+  }
+
+  fn record(&self, node: &NodeId) {
+    let now = (self.clock)();
+    let event = self.nodes.write().get_mut(&node.to_string()).and_then(|monitored| monitored.heartbeat(now));
+    if let Some(event) = event {
+      self.publish(&event);
+    }
+  }
+
+  fn publish(&self, event: &FailureEvent) {
+    match event {
+      | FailureEvent::NodeUnreachable(node) => {
+        if let Some(watch) = self.watch.as_ref() {
+          watch.node_unreachable(node);
+        }
+      },
+      | FailureEvent::NodeQuarantined(node) => self.outbound.endpoints().quarantine(node),
+      | _ => {},
+    }
+    for listener in &self.listeners {
+      listener(event.clone());
+    }
+  }
+
+  fn send(&self, node: &NodeId, payload: RemotePayloadFrame) -> Result<(), RemoteSendError> {
+    let target = self.heartbeat_pid(node);
+    let reply_to = self.heartbeat_pid(self.outbound.node());
+    self.outbound.send_control(&target, payload, DEFAULT_PRIORITY, Some(reply_to))
+  }
+
+  fn heartbeat_pid(&self, node: &NodeId) -> Pid {
+    Pid::new(self.outbound.system().clone(), ActorPath::new())
+      .with_node(node.clone())
+      .with_tag(PidTag::new(HEARTBEAT_TAG))
+  }
+}
+
+impl<T> RemoteHeartbeat<T>
+where
+  T: RemoteTransport + 'static,
+  Self: SharedBound,
+{
+  /// Answers heartbeats arriving through `dispatcher` and records acknowledgements.
+  #[must_use]
+  pub fn attach<MF>(&self, dispatcher: RemoteInboundDispatcher<MF>) -> RemoteInboundDispatcher<MF>
+  where
+    MF: MailboxFactory, {
+    let heartbeat = self.clone();
+    dispatcher
+      .with_interceptor(RemoteFrameInterceptor::new(move |delivery: RemoteDelivery| heartbeat.handle_frame(delivery)))
+  }
+}
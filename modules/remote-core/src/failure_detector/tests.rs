@@ -0,0 +1,226 @@
+extern crate std;
+
+use core::time::Duration;
+use std::{
+  format,
+  string::{String, ToString},
+  sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+  },
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor::ask::create_ask_handles,
+  failure::{failure_event_stream::FailureEventListener, FailureEvent},
+  mailbox::{messages::PriorityChannel, ThreadSafe},
+  process::{
+    dead_letter::DeadLetterReason,
+    pid::{NodeId, Pid},
+  },
+};
+use futures::executor::block_on;
+
+use super::{DeadlineFailureDetector, FailureDetectorConfig, NodeReachability, RemoteHeartbeat};
+use crate::{
+  codec::{RemoteDelivery, RemoteMessageFrame, RemotePayloadFrame},
+  test_support::{new_system, node, outbound_for, RecordingTransport, TestResult},
+  transport::TransportError,
+  watch::{RemoteDeathWatch, RemoteTerminated},
+};
+
+type Events = Arc<Mutex<Vec<FailureEvent>>>;
+
+/// Manually advanced clock shared with the heartbeat under test.
+#[derive(Clone, Default)]
+struct ManualClock {
+  millis: Arc<AtomicU64>,
+}
+
+impl ManualClock {
+  fn advance(&self, millis: u64) {
+    self.millis.fetch_add(millis, Ordering::SeqCst);
+  }
+
+  fn now(&self) -> Duration {
+    Duration::from_millis(self.millis.load(Ordering::SeqCst))
+  }
+}
+
+fn config() -> FailureDetectorConfig {
+  FailureDetectorConfig::new()
+    .with_heartbeat_interval(Duration::from_millis(100))
+    .with_acceptable_heartbeat_pause(Duration::from_millis(200))
+    .with_quarantine_after(Duration::from_millis(1000))
+}
+
+fn heartbeat_for(
+  transport: &RecordingTransport,
+  clock: &ManualClock,
+) -> TestResult<(RemoteHeartbeat<RecordingTransport>, Events)> {
+  let events: Events = Arc::new(Mutex::new(Vec::new()));
+  let events_clone = events.clone();
+  let listener = FailureEventListener::new(move |event: FailureEvent| {
+    events_clone.lock().unwrap_or_else(|err| err.into_inner()).push(event);
+  });
+  let clock = clock.clone();
+  let heartbeat =
+    RemoteHeartbeat::new(outbound_for(&new_system::<u32>(node(2551)), transport, node(2551))?, config(), move || {
+      clock.now()
+    })
+    .with_failure_listener(listener);
+  Ok((heartbeat, events))
+}
+
+fn ack_from(remote: &NodeId) -> TestResult<RemoteDelivery> {
+  let from = Pid::parse(&format!("actor://cellex@{remote}#heartbeat")).map_err(|err| format!("pid: {err:?}"))?;
+  let to = Pid::parse("actor://cellex@127.0.0.1:2551#heartbeat").map_err(|err| format!("pid: {err:?}"))?;
+  let frame = RemoteMessageFrame::new(0, PriorityChannel::Control, RemotePayloadFrame::HeartbeatAck, Some(from));
+  Ok(RemoteDelivery::new(to, frame))
+}
+
+fn describe(events: &Mutex<Vec<FailureEvent>>) -> Vec<String> {
+  core::mem::take(&mut *events.lock().unwrap_or_else(|err| err.into_inner()))
+    .into_iter()
+    .map(|event| match event {
+      | FailureEvent::NodeUnreachable(node) => format!("unreachable {node}"),
+      | FailureEvent::NodeReachable(node) => format!("reachable {node}"),
+      | FailureEvent::NodeQuarantined(node) => format!("quarantined {node}"),
+      | FailureEvent::NodeDowned(node) => format!("downed {node}"),
+      | FailureEvent::RootEscalated(info) => format!("escalated {}", info.description()),
+      | other => format!("{other:?}"),
+    })
+    .collect()
+}
+
+#[test]
+fn deadline_detector_tolerates_acceptable_pause() {
+  let mut detector = DeadlineFailureDetector::new(&config());
+  assert!(detector.is_available(Duration::from_secs(10)));
+
+  detector.heartbeat(Duration::from_millis(1000));
+  assert!(detector.is_available(Duration::from_millis(1300)));
+  assert!(!detector.is_available(Duration::from_millis(1301)));
+
+  detector.heartbeat(Duration::from_millis(900));
+  assert_eq!(detector.last_heartbeat(), Some(Duration::from_millis(1000)));
+}
+
+#[test]
+fn tick_sends_heartbeats_to_monitored_nodes() -> TestResult {
+  let transport = RecordingTransport::default();
+  let clock = ManualClock::default();
+  let (heartbeat, _) = heartbeat_for(&transport, &clock)?;
+  heartbeat.monitor(&node(2552));
+  heartbeat.monitor(&node(2553));
+
+  heartbeat.tick();
+
+  let frames = transport.take()?;
+  let nodes: Vec<NodeId> = frames.iter().map(|(node_id, _)| node_id.clone()).collect();
+  assert_eq!(nodes, vec![node(2552), node(2553)]);
+  for (node_id, delivery) in frames {
+    assert_eq!(delivery.target.node(), Some(&node_id));
+    assert_eq!(delivery.frame.channel, PriorityChannel::Control);
+    assert_eq!(delivery.frame.payload, RemotePayloadFrame::Heartbeat);
+    assert_eq!(delivery.frame.reply_to.as_ref().and_then(Pid::node), Some(&node(2551)));
+  }
+  Ok(())
+}
+
+#[test]
+fn heartbeat_requests_are_acknowledged() -> TestResult {
+  let transport = RecordingTransport::default();
+  let clock = ManualClock::default();
+  let (heartbeat, _) = heartbeat_for(&transport, &clock)?;
+
+  let mut request = ack_from(&node(2552))?;
+  request.frame.payload = RemotePayloadFrame::Heartbeat;
+  assert!(heartbeat.handle_frame(request).is_none());
+
+  let (node_id, ack) = transport.take()?.pop().ok_or_else(|| "ack expected".to_string())?;
+  assert_eq!(node_id, node(2552));
+  assert_eq!(ack.frame.payload, RemotePayloadFrame::HeartbeatAck);
+  assert_eq!(ack.frame.reply_to.as_ref().and_then(Pid::node), Some(&node(2551)));
+  Ok(())
+}
+
+#[test]
+fn missing_acks_report_node_unreachable_until_it_answers_again() -> TestResult {
+  let transport = RecordingTransport::default();
+  let clock = ManualClock::default();
+  let (heartbeat, events) = heartbeat_for(&transport, &clock)?;
+  heartbeat.monitor(&node(2552));
+
+  clock.advance(300);
+  heartbeat.tick();
+  assert_eq!(heartbeat.reachability(&node(2552)), Some(NodeReachability::Reachable));
+  assert!(describe(&events).is_empty());
+
+  clock.advance(1);
+  heartbeat.tick();
+  assert_eq!(heartbeat.reachability(&node(2552)), Some(NodeReachability::Unreachable));
+  assert_eq!(describe(&events), vec!["unreachable 127.0.0.1:2552".to_string()]);
+
+  clock.advance(100);
+  heartbeat.tick();
+  assert!(describe(&events).is_empty());
+
+  assert!(heartbeat.handle_frame(ack_from(&node(2552))?).is_none());
+  assert_eq!(heartbeat.reachability(&node(2552)), Some(NodeReachability::Reachable));
+  assert_eq!(describe(&events), vec!["reachable 127.0.0.1:2552".to_string()]);
+  Ok(())
+}
+
+#[test]
+fn unreachable_node_is_quarantined_after_timeout() -> TestResult {
+  let transport = RecordingTransport::default();
+  let clock = ManualClock::default();
+  let (heartbeat, events) = heartbeat_for(&transport, &clock)?;
+  let endpoints = heartbeat.outbound().endpoints().clone();
+  heartbeat.monitor(&node(2552));
+
+  clock.advance(301);
+  heartbeat.tick();
+  clock.advance(1000);
+  heartbeat.tick();
+  assert_eq!(heartbeat.reachability(&node(2552)), Some(NodeReachability::Quarantined));
+  assert_eq!(describe(&events), vec![
+    "unreachable 127.0.0.1:2552".to_string(),
+    "quarantined 127.0.0.1:2552".to_string()
+  ]);
+  assert!(endpoints.is_quarantined(&node(2552)));
+  assert!(matches!(endpoints.endpoint(&node(2552)), Err(TransportError::Quarantined(_))));
+
+  drop(transport.take()?);
+  heartbeat.tick();
+  assert!(heartbeat.handle_frame(ack_from(&node(2552))?).is_none());
+  assert!(transport.take()?.is_empty());
+  assert_eq!(heartbeat.reachability(&node(2552)), Some(NodeReachability::Quarantined));
+  assert!(describe(&events).is_empty());
+  Ok(())
+}
+
+#[test]
+fn unreachable_node_terminates_remote_watches() -> TestResult {
+  let transport = RecordingTransport::default();
+  let clock = ManualClock::default();
+  let (heartbeat, _) = heartbeat_for(&transport, &clock)?;
+  let watch = RemoteDeathWatch::new(outbound_for(&new_system::<u32>(node(2551)), &transport, node(2551))?);
+  let heartbeat = heartbeat.with_death_watch(watch.clone());
+  heartbeat.monitor(&node(2552));
+
+  let watchee = Pid::parse("actor://cellex@127.0.0.1:2552/3").map_err(|err| format!("pid: {err:?}"))?;
+  let watcher = Pid::parse("actor://cellex@127.0.0.1:2551/7").map_err(|err| format!("pid: {err:?}"))?;
+  let (future, notify) = create_ask_handles::<RemoteTerminated, ThreadSafe>();
+  watch.watch(&watchee, &watcher, notify).map_err(|err| format!("watch: {err}"))?;
+
+  clock.advance(301);
+  heartbeat.tick();
+
+  let terminated = block_on(future).map_err(|err| format!("await: {err:?}"))?;
+  assert_eq!(terminated, RemoteTerminated::new(watchee, DeadLetterReason::NetworkUnreachable));
+  Ok(())
+}
@@ -1,7 +1,13 @@
 //! Core implementation of remote messaging functionality.
 //!
-//! This crate is a placeholder for future remote messaging logic.
-//! The current goal is to provide integration points for `FailureEventStream`.
+//! Provides the versioned wire encoding of [`codec::RemoteMessageFrame`], the transport
+//! abstraction, the per-node [`endpoint::EndpointManager`] with its association handshake and
+//! outbound batching, inbound delivery into local mailboxes, [`outbound::RemoteActorRef`] handles
+//! for actors on other nodes, cross-node death watch through [`watch::RemoteDeathWatch`] and
+//! heartbeat failure detection through [`failure_detector::RemoteHeartbeat`], spawning by kind
+//! name on other nodes through [`activation::RemoteSpawner`], together with integration points
+//! for `FailureEventStream`. Concrete socket transports live in `cellex-remote-std-rs`; the
+//! `test-support` feature adds an in-memory loopback transport for multi-node tests.
 
 #![deny(missing_docs)]
 #![cfg_attr(test, allow(clippy::unwrap_used, clippy::expect_used, clippy::disallowed_types))]
@@ -52,6 +58,7 @@
 #![deny(clippy::if_same_then_else)]
 #![deny(clippy::cmp_null)]
 #![no_std]
+#![allow(clippy::result_large_err)]
 #![allow(unknown_lints)]
 #![deny(cfg_std_forbid)]
 
@@ -61,9 +68,26 @@ extern crate alloc;
 #[cfg(feature = "alloc")]
 use alloc::borrow::ToOwned;
 
+/// Spawning of actors on other nodes by registered kind name.
+pub mod activation;
 /// Encoding helpers bridging remote envelopes and the serialization layer.
 pub mod codec;
+/// Inbound delivery of remote frames into local mailboxes.
+pub mod delivery;
+/// Per-node associations managed on top of a transport.
+pub mod endpoint;
+/// Heartbeat exchange and failure detection for remote endpoints.
+pub mod failure_detector;
+/// In-memory transport connecting several nodes of one process, with fault injection.
+#[cfg(any(test, feature = "test-support"))]
+pub mod loopback;
+/// Sending side resolving remote PIDs into location-transparent actor references.
+pub mod outbound;
 mod remote_envelope;
+/// Transport abstraction moving frame bodies between nodes.
+pub mod transport;
+/// Death watch across node boundaries.
+pub mod watch;
 
 use cellex_actor_core_rs::api::failure::{
   failure_event_stream::{FailureEventListener, FailureEventStream},
@@ -71,6 +95,8 @@ use cellex_actor_core_rs::api::failure::{
 };
 pub use remote_envelope::RemoteEnvelope;
 
+#[cfg(test)]
+mod test_support;
 #[cfg(test)]
 mod tests;
 
@@ -143,8 +169,17 @@ where
   ///
   /// * `info` - The failure information
   pub fn dispatch(&self, info: FailureInfo) {
+    self.dispatch_event(FailureEvent::RootEscalated(info));
+  }
+
+  /// Dispatches a failure event if a custom handler is configured.
+  ///
+  /// # Arguments
+  ///
+  /// * `event` - The failure event, such as a node reachability change
+  pub fn dispatch_event(&self, event: FailureEvent) {
     if let Some(handler) = self.handler.as_ref() {
-      handler(FailureEvent::RootEscalated(info));
+      handler(event);
     }
   }
 
@@ -154,11 +189,17 @@ where
   ///
   /// * `info` - The failure information
   pub fn emit(&self, info: FailureInfo) {
-    let event = FailureEvent::RootEscalated(info);
+    self.emit_event(FailureEvent::RootEscalated(info));
+  }
+
+  /// Sends a failure event to both the event hub and custom handler.
+  ///
+  /// # Arguments
+  ///
+  /// * `event` - The failure event, such as a node reachability change
+  pub fn emit_event(&self, event: FailureEvent) {
     self.hub.listener()(event.clone());
-    if let Some(handler) = self.handler.as_ref() {
-      handler(event);
-    }
+    self.dispatch_event(event);
   }
 }
 
@@ -0,0 +1,14 @@
+mod in_flight_frame;
+mod loopback_connection;
+mod loopback_faults;
+mod loopback_network;
+mod loopback_state;
+mod loopback_transport;
+
+#[cfg(test)]
+mod tests;
+
+pub use loopback_connection::LoopbackConnection;
+pub use loopback_faults::LoopbackFaults;
+pub use loopback_network::LoopbackNetwork;
+pub use loopback_transport::LoopbackTransport;
@@ -0,0 +1,8 @@
+use alloc::{string::String, vec::Vec};
+
+/// Frame travelling between two loopback nodes.
+pub(crate) struct InFlightFrame {
+  pub(crate) from:  String,
+  pub(crate) to:    String,
+  pub(crate) bytes: Vec<u8>,
+}
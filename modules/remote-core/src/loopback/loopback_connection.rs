@@ -0,0 +1,56 @@
+use alloc::vec::Vec;
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use portable_atomic::{AtomicBool, Ordering};
+
+use super::LoopbackNetwork;
+use crate::transport::{TransportConnection, TransportError};
+
+/// Outbound association created by [`LoopbackTransport`](super::LoopbackTransport).
+///
+/// The connection closes once its peer stops listening, so the endpoint manager reconnects and
+/// observes the node as unreachable. Its backlog counts the frames in flight from the local node
+/// to the peer on any connection, since the network does not tell connections apart.
+pub struct LoopbackConnection {
+  network: LoopbackNetwork,
+  local:   NodeId,
+  remote:  NodeId,
+  closed:  AtomicBool,
+}
+
+impl LoopbackConnection {
+  pub(crate) const fn new(network: LoopbackNetwork, local: NodeId, remote: NodeId) -> Self {
+    Self { network, local, remote, closed: AtomicBool::new(false) }
+  }
+
+  /// Returns the node frames are sent to.
+  #[must_use]
+  pub const fn remote(&self) -> &NodeId {
+    &self.remote
+  }
+}
+
+impl TransportConnection for LoopbackConnection {
+  fn send(&self, frame: Vec<u8>) -> Result<(), TransportError> {
+    if self.is_closed() {
+      return Err(TransportError::Closed);
+    }
+    let result = self.network.send(&self.local, &self.remote, frame);
+    if result.is_err() {
+      self.close();
+    }
+    result
+  }
+
+  fn backlog(&self) -> usize {
+    self.network.backlog(&self.local, &self.remote)
+  }
+
+  fn close(&self) {
+    self.closed.store(true, Ordering::SeqCst);
+  }
+
+  fn is_closed(&self) -> bool {
+    self.closed.load(Ordering::SeqCst)
+  }
+}
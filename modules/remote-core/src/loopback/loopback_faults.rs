@@ -0,0 +1,93 @@
+use core::time::Duration;
+
+/// Faults injected by a [`LoopbackNetwork`](super::LoopbackNetwork) into every frame it carries.
+///
+/// Probabilities are clamped to `0.0..=1.0`. The default configuration delivers every frame in
+/// order without delay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoopbackFaults {
+  latency:       Duration,
+  jitter:        Duration,
+  drop_rate:     f64,
+  reorder_rate:  f64,
+  reorder_delay: Duration,
+}
+
+impl LoopbackFaults {
+  /// Creates a configuration without any fault.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self {
+      latency:       Duration::ZERO,
+      jitter:        Duration::ZERO,
+      drop_rate:     0.0,
+      reorder_rate:  0.0,
+      reorder_delay: Duration::ZERO,
+    }
+  }
+
+  /// Delays every frame by `latency`.
+  #[must_use]
+  pub const fn with_latency(mut self, latency: Duration) -> Self {
+    self.latency = latency;
+    self
+  }
+
+  /// Adds a random delay of up to `jitter` on top of the latency.
+  #[must_use]
+  pub const fn with_jitter(mut self, jitter: Duration) -> Self {
+    self.jitter = jitter;
+    self
+  }
+
+  /// Silently discards frames with probability `rate`.
+  #[must_use]
+  pub const fn with_drop_rate(mut self, rate: f64) -> Self {
+    self.drop_rate = rate;
+    self
+  }
+
+  /// Holds frames back by `delay` with probability `rate`, letting later frames overtake them.
+  #[must_use]
+  pub const fn with_reordering(mut self, rate: f64, delay: Duration) -> Self {
+    self.reorder_rate = rate;
+    self.reorder_delay = delay;
+    self
+  }
+
+  /// Returns the fixed delay of every frame.
+  #[must_use]
+  pub const fn latency(&self) -> Duration {
+    self.latency
+  }
+
+  /// Returns the upper bound of the random extra delay.
+  #[must_use]
+  pub const fn jitter(&self) -> Duration {
+    self.jitter
+  }
+
+  /// Returns the probability that a frame is dropped.
+  #[must_use]
+  pub const fn drop_rate(&self) -> f64 {
+    self.drop_rate
+  }
+
+  /// Returns the probability that a frame is held back.
+  #[must_use]
+  pub const fn reorder_rate(&self) -> f64 {
+    self.reorder_rate
+  }
+
+  /// Returns how long held back frames are delayed.
+  #[must_use]
+  pub const fn reorder_delay(&self) -> Duration {
+    self.reorder_delay
+  }
+}
+
+impl Default for LoopbackFaults {
+  fn default() -> Self {
+    Self::new()
+  }
+}
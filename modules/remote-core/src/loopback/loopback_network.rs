@@ -0,0 +1,184 @@
+use alloc::{format, string::ToString, vec::Vec};
+use core::time::Duration;
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use cellex_utils_core_rs::sync::ArcShared;
+use spin::RwLock;
+
+use super::{loopback_state::LoopbackState, LoopbackConnection, LoopbackFaults, LoopbackTransport};
+use crate::transport::{InboundFrameHandler, TransportError};
+
+/// In-process network connecting several nodes through in-memory queues.
+///
+/// Each node obtains its [`LoopbackTransport`] from [`LoopbackNetwork::transport`] and plugs it
+/// into an [`EndpointManager`](crate::endpoint::EndpointManager) like any other transport. Sent
+/// frames stay in flight until the test moves the virtual clock with [`LoopbackNetwork::advance`]
+/// or drains the network with [`LoopbackNetwork::flush`]; handlers run on the calling thread, so
+/// a given seed and fault configuration always produce the same delivery order. Receivers see
+/// every frame from one node as coming from a single inbound connection.
+///
+/// Partitioned links silently lose frames in both directions, like a network that stopped
+/// routing packets, while new connections across them are refused.
+#[derive(Clone)]
+pub struct LoopbackNetwork {
+  state: ArcShared<RwLock<LoopbackState>>,
+}
+
+impl LoopbackNetwork {
+  /// Creates a network without faults.
+  #[must_use]
+  pub fn new() -> Self {
+    Self { state: ArcShared::new(RwLock::new(LoopbackState::new())) }
+  }
+
+  /// Injects `faults` into every frame sent afterwards.
+  #[must_use]
+  pub fn with_faults(self, faults: LoopbackFaults) -> Self {
+    self.set_faults(faults);
+    self
+  }
+
+  /// Seeds the generator deciding drops, jitter and reordering.
+  #[must_use]
+  pub fn with_seed(self, seed: u64) -> Self {
+    self.state.write().reseed(seed);
+    self
+  }
+
+  /// Replaces the faults injected into frames sent from now on.
+  pub fn set_faults(&self, faults: LoopbackFaults) {
+    self.state.write().set_faults(faults);
+  }
+
+  /// Returns the faults currently injected.
+  #[must_use]
+  pub fn faults(&self) -> LoopbackFaults {
+    self.state.read().faults()
+  }
+
+  /// Returns the transport used by `node` to reach the other nodes of this network.
+  #[must_use]
+  pub fn transport(&self, node: &NodeId) -> LoopbackTransport {
+    LoopbackTransport::new(self.clone(), node.clone())
+  }
+
+  /// Returns the virtual time, which only moves while frames are delivered.
+  #[must_use]
+  pub fn now(&self) -> Duration {
+    self.state.read().now()
+  }
+
+  /// Cuts the link between `a` and `b` in both directions. Frames already in flight on the link
+  /// are lost.
+  pub fn partition(&self, a: &NodeId, b: &NodeId) {
+    self.state.write().partition(a.to_string(), b.to_string());
+  }
+
+  /// Restores the link between `a` and `b`.
+  pub fn heal(&self, a: &NodeId, b: &NodeId) {
+    self.state.write().heal(a.to_string(), b.to_string());
+  }
+
+  /// Restores every link.
+  pub fn heal_all(&self) {
+    self.state.write().heal_all();
+  }
+
+  /// Returns `true` when the link between `a` and `b` is cut.
+  #[must_use]
+  pub fn is_partitioned(&self, a: &NodeId, b: &NodeId) -> bool {
+    self.state.read().is_partitioned(&a.to_string(), &b.to_string())
+  }
+
+  /// Stops delivering frames to `node`, as if its process had crashed.
+  pub fn shutdown(&self, node: &NodeId) {
+    self.state.write().shutdown(&node.to_string());
+  }
+
+  /// Returns `true` while `node` accepts frames.
+  #[must_use]
+  pub fn is_listening(&self, node: &NodeId) -> bool {
+    self.state.read().is_listening(&node.to_string())
+  }
+
+  /// Moves the virtual clock forward by `elapsed` and delivers every frame due in the meantime,
+  /// including frames sent by the handlers themselves.
+  pub fn advance(&self, elapsed: Duration) {
+    let until = self.now() + elapsed;
+    self.deliver_until(until);
+    self.state.write().advance_to(until);
+  }
+
+  /// Delivers frames until none is left in flight, moving the virtual clock to the due time of
+  /// the last one.
+  pub fn flush(&self) {
+    self.deliver_until(Duration::MAX);
+  }
+
+  /// Returns the number of frames waiting for delivery.
+  #[must_use]
+  pub fn in_flight(&self) -> usize {
+    self.state.read().in_flight()
+  }
+
+  /// Returns the number of frames handed to a receiver so far.
+  #[must_use]
+  pub fn delivered(&self) -> u64 {
+    self.state.read().delivered()
+  }
+
+  /// Returns the number of frames lost to drops, partitions or stopped nodes so far.
+  #[must_use]
+  pub fn dropped(&self) -> u64 {
+    self.state.read().dropped()
+  }
+
+  pub(crate) fn listen(&self, node: &NodeId, handler: InboundFrameHandler) -> Result<(), TransportError> {
+    if self.state.write().listen(node.to_string(), handler) {
+      Ok(())
+    } else {
+      Err(TransportError::Io(format!("{node} is already listening")))
+    }
+  }
+
+  pub(crate) fn connect(&self, local: &NodeId, remote: &NodeId) -> Result<LoopbackConnection, TransportError> {
+    let state = self.state.read();
+    if !state.is_listening(&remote.to_string()) || state.is_partitioned(&local.to_string(), &remote.to_string()) {
+      return Err(TransportError::Unreachable(remote.clone()));
+    }
+    Ok(LoopbackConnection::new(self.clone(), local.clone(), remote.clone()))
+  }
+
+  pub(crate) fn send(&self, from: &NodeId, to: &NodeId, frame: Vec<u8>) -> Result<(), TransportError> {
+    let to = to.to_string();
+    let mut state = self.state.write();
+    if !state.is_listening(&to) {
+      return Err(TransportError::Closed);
+    }
+    state.enqueue(from.to_string(), to, frame);
+    Ok(())
+  }
+
+  pub(crate) fn backlog(&self, from: &NodeId, to: &NodeId) -> usize {
+    self.state.read().backlog(&from.to_string(), &to.to_string())
+  }
+
+  fn deliver_until(&self, until: Duration) {
+    loop {
+      // The lock is released before the handler runs so that it can send replies.
+      let next = self.state.write().next_due(until);
+      let Some((handler, link, frame)) = next else {
+        return;
+      };
+      let mut peer = self.state.read().peer(&link);
+      handler.handle(&mut peer, &frame);
+      self.state.write().set_peer(link, peer);
+    }
+  }
+}
+
+impl Default for LoopbackNetwork {
+  fn default() -> Self {
+    Self::new()
+  }
+}
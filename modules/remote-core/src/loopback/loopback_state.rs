@@ -0,0 +1,201 @@
+use alloc::{
+  collections::{BTreeMap, BTreeSet},
+  string::String,
+  vec::Vec,
+};
+use core::time::Duration;
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use super::{in_flight_frame::InFlightFrame, LoopbackFaults};
+use crate::transport::InboundFrameHandler;
+
+const DEFAULT_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Sending and receiving node of a loopback link, which acts as a single connection.
+pub(crate) type Link = (String, String);
+
+/// Mutable state shared by every node attached to a loopback network.
+pub(crate) struct LoopbackState {
+  now:        Duration,
+  faults:     LoopbackFaults,
+  rng:        u64,
+  next_seq:   u64,
+  handlers:   BTreeMap<String, InboundFrameHandler>,
+  peers:      BTreeMap<Link, NodeId>,
+  partitions: BTreeSet<(String, String)>,
+  in_flight:  BTreeMap<(Duration, u64), InFlightFrame>,
+  delivered:  u64,
+  dropped:    u64,
+}
+
+impl LoopbackState {
+  pub(crate) const fn new() -> Self {
+    Self {
+      now:        Duration::ZERO,
+      faults:     LoopbackFaults::new(),
+      rng:        DEFAULT_SEED,
+      next_seq:   0,
+      handlers:   BTreeMap::new(),
+      peers:      BTreeMap::new(),
+      partitions: BTreeSet::new(),
+      in_flight:  BTreeMap::new(),
+      delivered:  0,
+      dropped:    0,
+    }
+  }
+
+  pub(crate) const fn now(&self) -> Duration {
+    self.now
+  }
+
+  pub(crate) const fn faults(&self) -> LoopbackFaults {
+    self.faults
+  }
+
+  pub(crate) const fn set_faults(&mut self, faults: LoopbackFaults) {
+    self.faults = faults;
+  }
+
+  pub(crate) const fn reseed(&mut self, seed: u64) {
+    // xorshift never leaves the all-zero state.
+    self.rng = if seed == 0 { DEFAULT_SEED } else { seed };
+  }
+
+  pub(crate) fn listen(&mut self, node: String, handler: InboundFrameHandler) -> bool {
+    if self.handlers.contains_key(&node) {
+      return false;
+    }
+    self.peers.retain(|(_, to), _| to != &node);
+    self.handlers.insert(node, handler);
+    true
+  }
+
+  pub(crate) fn shutdown(&mut self, node: &str) {
+    self.handlers.remove(node);
+  }
+
+  /// Returns the peer recorded by the receiver of `link`.
+  pub(crate) fn peer(&self, link: &Link) -> Option<NodeId> {
+    self.peers.get(link).cloned()
+  }
+
+  pub(crate) fn set_peer(&mut self, link: Link, peer: Option<NodeId>) {
+    match peer {
+      | Some(peer) => self.peers.insert(link, peer),
+      | None => self.peers.remove(&link),
+    };
+  }
+
+  pub(crate) fn is_listening(&self, node: &str) -> bool {
+    self.handlers.contains_key(node)
+  }
+
+  pub(crate) fn partition(&mut self, a: String, b: String) {
+    self.partitions.insert(Self::link(a, b));
+  }
+
+  pub(crate) fn heal(&mut self, a: String, b: String) {
+    self.partitions.remove(&Self::link(a, b));
+  }
+
+  pub(crate) fn heal_all(&mut self) {
+    self.partitions.clear();
+  }
+
+  pub(crate) fn is_partitioned(&self, a: &str, b: &str) -> bool {
+    self.partitions.iter().any(|(x, y)| (x == a && y == b) || (x == b && y == a))
+  }
+
+  pub(crate) fn in_flight(&self) -> usize {
+    self.in_flight.len()
+  }
+
+  pub(crate) fn backlog(&self, from: &str, to: &str) -> usize {
+    self.in_flight.values().filter(|frame| frame.from == from && frame.to == to).count()
+  }
+
+  pub(crate) const fn delivered(&self) -> u64 {
+    self.delivered
+  }
+
+  pub(crate) const fn dropped(&self) -> u64 {
+    self.dropped
+  }
+
+  /// Schedules `bytes` for delivery according to the configured faults.
+  pub(crate) fn enqueue(&mut self, from: String, to: String, bytes: Vec<u8>) {
+    if self.is_partitioned(&from, &to) || self.chance(self.faults.drop_rate()) {
+      self.dropped += 1;
+      return;
+    }
+    let mut due = self.now + self.faults.latency() + self.random_delay(self.faults.jitter());
+    if self.chance(self.faults.reorder_rate()) {
+      due += self.faults.reorder_delay();
+    }
+    let seq = self.next_seq;
+    self.next_seq += 1;
+    self.in_flight.insert((due, seq), InFlightFrame { from, to, bytes });
+  }
+
+  /// Removes the earliest frame due at or before `until` and returns it together with the
+  /// handler of its receiver and the link it travelled on. Frames crossing a partition or addressed
+  /// to a node that stopped listening are dropped on the way.
+  pub(crate) fn next_due(&mut self, until: Duration) -> Option<(InboundFrameHandler, Link, Vec<u8>)> {
+    loop {
+      let (&(due, seq), _) = self.in_flight.first_key_value()?;
+      if due > until {
+        return None;
+      }
+      let frame = self.in_flight.remove(&(due, seq))?;
+      self.now = self.now.max(due);
+      let handler = self.handlers.get(&frame.to).filter(|_| !self.is_partitioned(&frame.from, &frame.to)).cloned();
+      match handler {
+        | Some(handler) => {
+          self.delivered += 1;
+          return Some((handler, (frame.from, frame.to), frame.bytes));
+        },
+        | None => self.dropped += 1,
+      }
+    }
+  }
+
+  pub(crate) fn advance_to(&mut self, now: Duration) {
+    self.now = self.now.max(now);
+  }
+
+  fn link(a: String, b: String) -> (String, String) {
+    let mut nodes = [a, b];
+    nodes.sort();
+    let [a, b] = nodes;
+    (a, b)
+  }
+
+  const fn next_random(&mut self) -> u64 {
+    let mut x = self.rng;
+    x ^= x >> 12;
+    x ^= x << 25;
+    x ^= x >> 27;
+    self.rng = x;
+    x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+  }
+
+  fn chance(&mut self, probability: f64) -> bool {
+    if probability <= 0.0 {
+      return false;
+    }
+    if probability >= 1.0 {
+      return true;
+    }
+    let sample = (self.next_random() >> 11) as f64 / (1_u64 << 53) as f64;
+    sample < probability
+  }
+
+  fn random_delay(&mut self, bound: Duration) -> Duration {
+    let nanos = u64::try_from(bound.as_nanos()).unwrap_or(u64::MAX);
+    if nanos == 0 {
+      return Duration::ZERO;
+    }
+    Duration::from_nanos(self.next_random() % nanos.saturating_add(1))
+  }
+}
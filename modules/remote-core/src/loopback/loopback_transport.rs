@@ -0,0 +1,41 @@
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use super::{LoopbackConnection, LoopbackNetwork};
+use crate::transport::{InboundFrameHandler, RemoteTransport, TransportError};
+
+/// [`RemoteTransport`] of one node attached to a [`LoopbackNetwork`].
+#[derive(Clone)]
+pub struct LoopbackTransport {
+  network: LoopbackNetwork,
+  local:   NodeId,
+}
+
+impl LoopbackTransport {
+  pub(crate) const fn new(network: LoopbackNetwork, local: NodeId) -> Self {
+    Self { network, local }
+  }
+
+  /// Returns the node this transport sends from.
+  #[must_use]
+  pub const fn local(&self) -> &NodeId {
+    &self.local
+  }
+
+  /// Returns the network carrying the frames.
+  #[must_use]
+  pub const fn network(&self) -> &LoopbackNetwork {
+    &self.network
+  }
+}
+
+impl RemoteTransport for LoopbackTransport {
+  type Connection = LoopbackConnection;
+
+  fn listen(&self, local: &NodeId, handler: InboundFrameHandler) -> Result<(), TransportError> {
+    self.network.listen(local, handler)
+  }
+
+  fn connect(&self, remote: &NodeId) -> Result<Self::Connection, TransportError> {
+    self.network.connect(&self.local, remote)
+  }
+}
@@ -0,0 +1,308 @@
+extern crate std;
+
+use core::time::Duration;
+use std::{
+  format,
+  string::{String, ToString},
+  sync::{Arc, Mutex},
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor::{ask::create_ask_handles, ActorId, ActorPath},
+  actor_runtime::GenericActorRuntime,
+  actor_system::{GenericActorSystem, GenericActorSystemConfig},
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  failure::{failure_event_stream::FailureEventListener, FailureEvent},
+  mailbox::ThreadSafe,
+  process::{
+    dead_letter::DeadLetterReason,
+    pid::{NodeId, Pid, SystemId},
+  },
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_utils_core_rs::sync::ArcShared;
+use futures::executor::block_on;
+
+use super::{LoopbackFaults, LoopbackNetwork, LoopbackTransport};
+use crate::{
+  endpoint::EndpointManager,
+  failure_detector::{FailureDetectorConfig, NodeReachability, RemoteHeartbeat},
+  outbound::RemoteOutbound,
+  transport::{InboundFrameHandler, RemoteTransport, TransportConnection, TransportError},
+  watch::{RemoteDeathWatch, RemoteTerminated},
+};
+
+type TestResult<T = ()> = Result<T, String>;
+type Received = Arc<Mutex<Vec<Vec<u8>>>>;
+
+fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+fn listening(network: &LoopbackNetwork, port: u16) -> TestResult<(LoopbackTransport, Received)> {
+  let transport = network.transport(&node(port));
+  let received: Received = Arc::new(Mutex::new(Vec::new()));
+  let received_clone = received.clone();
+  transport
+    .listen(
+      &node(port),
+      InboundFrameHandler::new(move |frame: &[u8]| {
+        received_clone.lock().unwrap_or_else(|err| err.into_inner()).push(frame.to_vec());
+      }),
+    )
+    .map_err(|err| format!("listen: {err}"))?;
+  Ok((transport, received))
+}
+
+fn send_all(transport: &LoopbackTransport, to: &NodeId, count: u8) -> TestResult {
+  let connection = transport.connect(to).map_err(|err| format!("connect: {err}"))?;
+  for index in 0..count {
+    connection.send(vec![index]).map_err(|err| format!("send: {err}"))?;
+  }
+  Ok(())
+}
+
+fn take(received: &Received) -> Vec<Vec<u8>> {
+  core::mem::take(&mut *received.lock().unwrap_or_else(|err| err.into_inner()))
+}
+
+#[test]
+fn frames_are_delivered_in_order_once_flushed() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (sender, _) = listening(&network, 2551)?;
+  let (_, received) = listening(&network, 2552)?;
+
+  send_all(&sender, &node(2552), 3)?;
+  assert_eq!(network.in_flight(), 3);
+  assert!(take(&received).is_empty());
+
+  network.flush();
+  assert_eq!(take(&received), vec![vec![0], vec![1], vec![2]]);
+  assert_eq!(network.delivered(), 3);
+  assert_eq!(network.in_flight(), 0);
+  Ok(())
+}
+
+#[test]
+fn connecting_requires_a_listening_node() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (sender, _) = listening(&network, 2551)?;
+
+  assert!(matches!(sender.connect(&node(2552)), Err(TransportError::Unreachable(_))));
+  let handler = InboundFrameHandler::new(|_: &[u8]| {});
+  assert!(matches!(sender.listen(&node(2551), handler), Err(TransportError::Io(_))));
+  Ok(())
+}
+
+#[test]
+fn latency_holds_frames_until_the_clock_advances() -> TestResult {
+  let network = LoopbackNetwork::new().with_faults(LoopbackFaults::new().with_latency(Duration::from_millis(50)));
+  let (sender, _) = listening(&network, 2551)?;
+  let (_, received) = listening(&network, 2552)?;
+
+  send_all(&sender, &node(2552), 1)?;
+  network.advance(Duration::from_millis(49));
+  assert!(take(&received).is_empty());
+  network.advance(Duration::from_millis(1));
+  assert_eq!(take(&received), vec![vec![0]]);
+  assert_eq!(network.now(), Duration::from_millis(50));
+  Ok(())
+}
+
+#[test]
+fn drop_rate_discards_frames() -> TestResult {
+  let network = LoopbackNetwork::new().with_faults(LoopbackFaults::new().with_drop_rate(1.0));
+  let (sender, _) = listening(&network, 2551)?;
+  let (_, received) = listening(&network, 2552)?;
+
+  send_all(&sender, &node(2552), 4)?;
+  network.flush();
+  assert!(take(&received).is_empty());
+  assert_eq!(network.dropped(), 4);
+
+  network.set_faults(LoopbackFaults::new().with_drop_rate(0.5));
+  send_all(&sender, &node(2552), 100)?;
+  network.flush();
+  let delivered = take(&received).len();
+  assert!(delivered > 0 && delivered < 100, "delivered {delivered}");
+  assert_eq!(network.dropped() + network.delivered(), 104);
+  Ok(())
+}
+
+#[test]
+fn reordering_lets_later_frames_overtake_with_a_reproducible_order() -> TestResult {
+  let run = |seed: u64| -> TestResult<Vec<Vec<u8>>> {
+    let faults = LoopbackFaults::new().with_reordering(0.3, Duration::from_millis(10));
+    let network = LoopbackNetwork::new().with_seed(seed).with_faults(faults);
+    let (sender, _) = listening(&network, 2551)?;
+    let (_, received) = listening(&network, 2552)?;
+    send_all(&sender, &node(2552), 20)?;
+    network.flush();
+    Ok(take(&received))
+  };
+
+  let first = run(7)?;
+  assert_eq!(first, run(7)?);
+  let in_order: Vec<Vec<u8>> = (0..20).map(|index| vec![index]).collect();
+  assert_ne!(first, in_order);
+  let mut sorted = first;
+  sorted.sort();
+  assert_eq!(sorted, in_order);
+  Ok(())
+}
+
+#[test]
+fn partition_loses_frames_until_healed() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (first, first_received) = listening(&network, 2551)?;
+  let (second, second_received) = listening(&network, 2552)?;
+  let connection = first.connect(&node(2552)).map_err(|err| format!("connect: {err}"))?;
+
+  connection.send(vec![1]).map_err(|err| format!("send: {err}"))?;
+  network.partition(&node(2552), &node(2551));
+  assert!(network.is_partitioned(&node(2551), &node(2552)));
+  connection.send(vec![2]).map_err(|err| format!("send: {err}"))?;
+  send_all(&first, &node(2553), 0).err().ok_or_else(|| "unknown node expected".to_string())?;
+  assert!(matches!(second.connect(&node(2551)), Err(TransportError::Unreachable(_))));
+  network.flush();
+  assert_eq!(network.delivered(), 0);
+  assert_eq!(network.dropped(), 2);
+
+  network.heal(&node(2551), &node(2552));
+  connection.send(vec![3]).map_err(|err| format!("send: {err}"))?;
+  send_all(&second, &node(2551), 1)?;
+  network.flush();
+  assert_eq!(take(&second_received), vec![vec![3]]);
+  assert_eq!(take(&first_received), vec![vec![0]]);
+  Ok(())
+}
+
+#[test]
+fn shutdown_node_closes_connections_to_it() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let (sender, _) = listening(&network, 2551)?;
+  let (_, received) = listening(&network, 2552)?;
+  let endpoints = EndpointManager::new(sender, SystemId::new("cellex"), node(2551));
+  let connection = endpoints.endpoint(&node(2552)).map_err(|err| format!("endpoint: {err}"))?;
+
+  connection.send(vec![1]).map_err(|err| format!("send: {err}"))?;
+  network.shutdown(&node(2552));
+  assert!(!network.is_listening(&node(2552)));
+  assert_eq!(connection.send(vec![2]), Err(TransportError::Closed));
+  assert!(connection.is_closed());
+  assert!(matches!(endpoints.endpoint(&node(2552)), Err(TransportError::Unreachable(_))));
+
+  network.flush();
+  assert!(take(&received).is_empty());
+  Ok(())
+}
+
+fn new_system(node: NodeId) -> GenericActorSystem<u32, TokioActorRuntime> {
+  let config = GenericActorSystemConfig::default().with_node_id(node);
+  GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), config)
+}
+
+fn outbound_for(
+  system: &GenericActorSystem<u32, TokioActorRuntime>,
+  network: &LoopbackNetwork,
+  node: &NodeId,
+) -> TestResult<RemoteOutbound<LoopbackTransport>> {
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(network.transport(node), system_id, node.clone()));
+  system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      RemoteOutbound::new(endpoints, extension)
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())
+}
+
+fn heartbeat_for(
+  system: &GenericActorSystem<u32, TokioActorRuntime>,
+  network: &LoopbackNetwork,
+  node: &NodeId,
+  listener: FailureEventListener,
+) -> TestResult<RemoteHeartbeat<LoopbackTransport>> {
+  let config = FailureDetectorConfig::new()
+    .with_heartbeat_interval(Duration::from_millis(100))
+    .with_acceptable_heartbeat_pause(Duration::from_millis(200));
+  let outbound = outbound_for(system, network, node)?;
+  let clock = network.clone();
+  let heartbeat = RemoteHeartbeat::new(outbound.clone(), config, move || clock.now()).with_failure_listener(listener);
+  let dispatcher = heartbeat.attach(outbound.inbound_dispatcher(system.process_registry()));
+  outbound.endpoints().start(dispatcher).map_err(|err| format!("listen: {err}"))?;
+  Ok(heartbeat)
+}
+
+#[test]
+fn heartbeats_over_a_partitioned_link_report_the_peer_unreachable_until_healed() -> TestResult {
+  let network = LoopbackNetwork::new().with_faults(LoopbackFaults::new().with_latency(Duration::from_millis(10)));
+  let first_system = new_system(node(2551));
+  let second_system = new_system(node(2552));
+  let events: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+  let events_clone = events.clone();
+  let listener = FailureEventListener::new(move |event: FailureEvent| {
+    let description = match event {
+      | FailureEvent::NodeUnreachable(node) => format!("unreachable {node}"),
+      | FailureEvent::NodeReachable(node) => format!("reachable {node}"),
+      | other => format!("{other:?}"),
+    };
+    events_clone.lock().unwrap_or_else(|err| err.into_inner()).push(description);
+  });
+  let first = heartbeat_for(&first_system, &network, &node(2551), listener)?;
+  let second = heartbeat_for(&second_system, &network, &node(2552), FailureEventListener::new(|_| {}))?;
+  first.monitor(&node(2552));
+  second.monitor(&node(2551));
+  let run = |rounds: usize| {
+    for _ in 0..rounds {
+      first.tick();
+      second.tick();
+      network.advance(Duration::from_millis(100));
+    }
+  };
+
+  run(5);
+  assert_eq!(first.reachability(&node(2552)), Some(NodeReachability::Reachable));
+  assert!(events.lock().unwrap_or_else(|err| err.into_inner()).is_empty());
+
+  network.partition(&node(2551), &node(2552));
+  run(5);
+  assert_eq!(first.reachability(&node(2552)), Some(NodeReachability::Unreachable));
+  assert_eq!(second.reachability(&node(2551)), Some(NodeReachability::Unreachable));
+
+  network.heal_all();
+  run(2);
+  assert_eq!(first.reachability(&node(2552)), Some(NodeReachability::Reachable));
+  assert_eq!(core::mem::take(&mut *events.lock().unwrap_or_else(|err| err.into_inner())), vec![
+    "unreachable 127.0.0.1:2552".to_string(),
+    "reachable 127.0.0.1:2552".to_string()
+  ]);
+  Ok(())
+}
+
+#[test]
+fn death_watch_request_for_unknown_actor_is_answered_across_the_network() -> TestResult {
+  let network = LoopbackNetwork::new();
+  let client_system = new_system(node(2551));
+  let server_system = new_system(node(2552));
+  let client = RemoteDeathWatch::new(outbound_for(&client_system, &network, &node(2551))?);
+  client.start(client_system.process_registry()).map_err(|err| format!("listen: {err}"))?;
+  let server = RemoteDeathWatch::new(outbound_for(&server_system, &network, &node(2552))?);
+  server.start(server_system.process_registry()).map_err(|err| format!("listen: {err}"))?;
+
+  let system_id = client_system.process_registry().system().clone();
+  let watchee = Pid::new(system_id.clone(), ActorPath::new().push_child(ActorId(3))).with_node(node(2552));
+  let watcher = Pid::new(system_id, ActorPath::new().push_child(ActorId(7))).with_node(node(2551));
+  let (future, notify) = create_ask_handles::<RemoteTerminated, ThreadSafe>();
+  client.watch(&watchee, &watcher, notify).map_err(|err| format!("watch: {err}"))?;
+
+  network.flush();
+  let terminated = block_on(future).map_err(|err| format!("await: {err:?}"))?;
+  assert_eq!(terminated, RemoteTerminated::new(watchee.clone(), DeadLetterReason::UnregisteredPid));
+  assert!(!client.is_watching(&watchee));
+  assert_eq!(server.remote_watcher_count(&watchee), 0);
+  assert!(client.outbound().endpoints().association(&node(2552)).is_some());
+  assert!(server.outbound().endpoints().association(&node(2551)).is_some());
+  Ok(())
+}
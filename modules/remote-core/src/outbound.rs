@@ -0,0 +1,10 @@
+mod remote_actor_ref;
+mod remote_outbound;
+mod remote_send_error;
+
+#[cfg(test)]
+mod tests;
+
+pub use remote_actor_ref::RemoteActorRef;
+pub use remote_outbound::RemoteOutbound;
+pub use remote_send_error::RemoteSendError;
@@ -0,0 +1,139 @@
+use core::marker::PhantomData;
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::ask::AskFuture,
+    mailbox::{messages::SystemMessage, ThreadSafe},
+    messaging::MessageSender,
+    process::pid::Pid,
+  },
+  internal::message::InternalMessageSender,
+  shared::{mailbox::messages::PriorityEnvelope, messaging::AnyMessage},
+};
+use cellex_utils_core_rs::{
+  collections::queue::{backend::QueueError, priority::DEFAULT_PRIORITY},
+  sync::{shared::SharedBound, ArcShared},
+};
+
+use super::{RemoteOutbound, RemoteSendError};
+use crate::{delivery::RemoteMessage, transport::RemoteTransport};
+
+#[cfg(target_has_atomic = "ptr")]
+type SendFn = dyn Fn(AnyMessage, i8) -> Result<(), QueueError<PriorityEnvelope<AnyMessage>>> + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type SendFn = dyn Fn(AnyMessage, i8) -> Result<(), QueueError<PriorityEnvelope<AnyMessage>>>;
+
+/// Handle to an actor living on another node.
+///
+/// Mirrors the sending half of [`ActorRef`](cellex_actor_core_rs::api::actor::actor_ref::ActorRef):
+/// messages are serialized and written to the endpoint of the target node instead of a local
+/// mailbox, so the caller does not need to know where the actor runs.
+pub struct RemoteActorRef<U, T>
+where
+  U: RemoteMessage,
+  T: RemoteTransport, {
+  pid:      Pid,
+  outbound: RemoteOutbound<T>,
+  _marker:  PhantomData<fn(U)>,
+}
+
+impl<U, T> Clone for RemoteActorRef<U, T>
+where
+  U: RemoteMessage,
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self { pid: self.pid.clone(), outbound: self.outbound.clone(), _marker: PhantomData }
+  }
+}
+
+impl<U, T> RemoteActorRef<U, T>
+where
+  U: RemoteMessage,
+  T: RemoteTransport,
+{
+  pub(crate) const fn new(pid: Pid, outbound: RemoteOutbound<T>) -> Self {
+    Self { pid, outbound, _marker: PhantomData }
+  }
+
+  /// Returns the PID of the remote actor.
+  #[must_use]
+  pub const fn pid(&self) -> &Pid {
+    &self.pid
+  }
+
+  /// Sends a message with the default priority.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError`] when the message cannot be serialized or the transport rejects
+  /// the frame.
+  pub fn tell(&self, message: U) -> Result<(), RemoteSendError> {
+    self.tell_with_priority(message, DEFAULT_PRIORITY)
+  }
+
+  /// Sends a message with the specified priority.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError`] when the message cannot be serialized or the transport rejects
+  /// the frame.
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn tell_with_priority(&self, message: U, priority: i8) -> Result<(), RemoteSendError> {
+    self.outbound.send_user(&self.pid, &message, priority, None)
+  }
+
+  /// Sends a system message on the control channel.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError`] when the system message cannot be encoded or the transport
+  /// rejects the frame.
+  pub fn send_system(&self, message: SystemMessage) -> Result<(), RemoteSendError> {
+    self.outbound.send_system(&self.pid, message)
+  }
+
+  /// Sends `message` and returns a future completed by the remote actor's response.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError`] when the request cannot be sent.
+  pub fn ask<Resp>(&self, message: U) -> Result<AskFuture<Resp>, RemoteSendError>
+  where
+    Resp: RemoteMessage, {
+    self.ask_with(|_| message)
+  }
+
+  /// Builds the request from the reply PID allocated for it and returns a future completed by
+  /// the response addressed to that PID.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError`] when the request cannot be sent.
+  pub fn ask_with<Resp, F>(&self, factory: F) -> Result<AskFuture<Resp>, RemoteSendError>
+  where
+    Resp: RemoteMessage,
+    F: FnOnce(&Pid) -> U, {
+    self.outbound.register_message::<Resp>();
+    let (future, reply_to) = self.outbound.reply_handles::<Resp>();
+    let message = factory(&reply_to);
+    // Dropping the future on failure releases the reply PID.
+    self.outbound.send_user(&self.pid, &message, DEFAULT_PRIORITY, Some(reply_to)).map(|()| future)
+  }
+}
+
+impl<U, T> RemoteActorRef<U, T>
+where
+  U: RemoteMessage,
+  T: RemoteTransport + 'static,
+  RemoteOutbound<T>: SharedBound,
+{
+  /// Converts this reference into a message dispatcher usable wherever a local
+  /// [`MessageSender`] is expected.
+  #[must_use]
+  pub fn to_dispatcher(&self) -> MessageSender<U, ThreadSafe> {
+    let pid = self.pid.clone();
+    let outbound = self.outbound.clone();
+    let dispatch = ArcShared::new(move |message: AnyMessage, priority: i8| {
+      outbound.send_any(&pid, &message, priority).map_err(|_| QueueError::Disconnected)
+    })
+    .into_dyn(|f| f as &SendFn);
+    MessageSender::from_internal(InternalMessageSender::new(dispatch))
+  }
+}
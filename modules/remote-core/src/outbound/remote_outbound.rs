@@ -0,0 +1,281 @@
+use cellex_actor_core_rs::{
+  api::{
+    actor::ask::{create_ask_handles, AskFuture},
+    extensions::SerializerRegistryExtension,
+    mailbox::{
+      messages::{PriorityChannel, SystemMessage},
+      ThreadSafe,
+    },
+    process::pid::{NodeId, Pid, SystemId},
+  },
+  shared::{mailbox::MailboxFactory, messaging::AnyMessage},
+};
+use cellex_serialization_core_rs::{message::SerializedMessage, routing::SerializationRouter};
+use cellex_utils_core_rs::{
+  collections::{queue::backend::QueueError, Element},
+  sync::{shared::SharedBound, ArcShared},
+};
+
+use super::{RemoteActorRef, RemoteSendError};
+use crate::{
+  codec::{RemoteDelivery, RemoteMessageFrame, RemotePayloadFrame},
+  delivery::{
+    RemoteInboundDispatcher, RemoteMessage, RemoteMessageRegistry, RemoteProcessRegistry, RemoteReplyRegistry,
+    RemoteReplyRoute,
+  },
+  endpoint::EndpointManager,
+  transport::{RemoteTransport, TransportError},
+};
+
+/// Sending side of the remote layer for one local node.
+///
+/// User payloads are encoded through [`RemoteMessage`] and wrapped by the serializer that the
+/// [`SerializerRegistryExtension`] binds to their type key, then written to the endpoint of the
+/// target node. [`RemoteOutbound::inbound_dispatcher`] builds the matching receiving side so that
+/// replies to [`RemoteActorRef::ask`] and responses produced by remote requests find their way
+/// back.
+pub struct RemoteOutbound<T>
+where
+  T: RemoteTransport, {
+  endpoints: ArcShared<EndpointManager<T>>,
+  router:    SerializationRouter,
+  messages:  RemoteMessageRegistry,
+  replies:   RemoteReplyRegistry,
+}
+
+impl<T> Clone for RemoteOutbound<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self {
+      endpoints: self.endpoints.clone(),
+      router:    self.router.clone(),
+      messages:  self.messages.clone(),
+      replies:   self.replies.clone(),
+    }
+  }
+}
+
+impl<T> RemoteOutbound<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates the outbound side of the system and node announced by `endpoints`.
+  #[must_use]
+  pub fn new(endpoints: ArcShared<EndpointManager<T>>, serializers: &SerializerRegistryExtension) -> Self {
+    Self {
+      endpoints,
+      router: serializers.router(),
+      messages: RemoteMessageRegistry::new(),
+      replies: RemoteReplyRegistry::new(),
+    }
+  }
+
+  /// Returns the endpoint manager used to reach other nodes.
+  #[must_use]
+  pub const fn endpoints(&self) -> &ArcShared<EndpointManager<T>> {
+    &self.endpoints
+  }
+
+  /// Returns the registry of message types that can cross the node boundary.
+  #[must_use]
+  pub const fn messages(&self) -> &RemoteMessageRegistry {
+    &self.messages
+  }
+
+  /// Returns the registry of outstanding `ask` replies.
+  #[must_use]
+  pub const fn replies(&self) -> &RemoteReplyRegistry {
+    &self.replies
+  }
+
+  /// Returns the identifier of the local actor system.
+  #[must_use]
+  pub fn system(&self) -> &SystemId {
+    &self.endpoints.local().system
+  }
+
+  /// Returns the local node identifier.
+  #[must_use]
+  pub fn node(&self) -> &NodeId {
+    &self.endpoints.local().node
+  }
+
+  /// Allows `U` to be sent and received by this node.
+  pub fn register_message<U>(&self)
+  where
+    U: RemoteMessage, {
+    self.messages.register::<U>();
+  }
+
+  /// Returns `true` when `pid` lives on a node other than the local one.
+  #[must_use]
+  pub fn is_remote(&self, pid: &Pid) -> bool {
+    pid.node().is_some_and(|node| node != self.node())
+  }
+
+  /// Returns a handle sending `U` to the actor identified by `pid`.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError::NotRemote`] when `pid` does not refer to another node.
+  pub fn actor_ref<U>(&self, pid: Pid) -> Result<RemoteActorRef<U, T>, RemoteSendError>
+  where
+    U: RemoteMessage, {
+    if !self.is_remote(&pid) {
+      return Err(RemoteSendError::NotRemote);
+    }
+    self.register_message::<U>();
+    Ok(RemoteActorRef::new(pid, self.clone()))
+  }
+
+  /// Sends a typed user message to `target`.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError`] when the message cannot be serialized or the transport rejects
+  /// the frame.
+  pub fn send_user<U>(
+    &self,
+    target: &Pid,
+    message: &U,
+    priority: i8,
+    reply_to: Option<Pid>,
+  ) -> Result<(), RemoteSendError>
+  where
+    U: RemoteMessage, {
+    let payload = message.encode_payload().map_err(RemoteSendError::Serialization)?;
+    self.send_payload(target, U::type_key(), &payload, priority, reply_to)
+  }
+
+  /// Sends a dynamic user envelope to `target`.
+  ///
+  /// The payload type must have been registered through [`RemoteOutbound::register_message`].
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError`] when the message type is unknown, cannot be serialized, or the
+  /// transport rejects the frame.
+  pub fn send_any(&self, target: &Pid, message: &AnyMessage, priority: i8) -> Result<(), RemoteSendError> {
+    let (type_key, payload) = self
+      .messages
+      .encode(message)
+      .ok_or(RemoteSendError::UnregisteredMessage)?
+      .map_err(RemoteSendError::Serialization)?;
+    self.send_payload(target, &type_key, &payload, priority, None)
+  }
+
+  /// Sends a system message to `target` on the control channel.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError`] when the system message cannot be encoded or the transport
+  /// rejects the frame.
+  pub fn send_system(&self, target: &Pid, message: SystemMessage) -> Result<(), RemoteSendError> {
+    let priority = message.priority();
+    self.send_control(target, RemotePayloadFrame::System(message), priority, None)
+  }
+
+  fn send_payload(
+    &self,
+    target: &Pid,
+    type_key: &str,
+    payload: &[u8],
+    priority: i8,
+    reply_to: Option<Pid>,
+  ) -> Result<(), RemoteSendError> {
+    let serialized = self.serialize(type_key, payload)?;
+    let frame =
+      RemoteMessageFrame::new(priority, PriorityChannel::Regular, RemotePayloadFrame::User { serialized }, reply_to);
+    self.send_frame(target, frame)
+  }
+
+  fn serialize(&self, type_key: &str, payload: &[u8]) -> Result<SerializedMessage, RemoteSendError> {
+    let serializer =
+      self.router.resolve_or_fallback(type_key).ok_or_else(|| RemoteSendError::MissingSerializer(type_key.into()))?;
+    serializer.serialize_with_type_name(payload, type_key).map_err(RemoteSendError::Serialization)
+  }
+
+  /// Sends `payload` to `target` on the control lane, for protocols layered on the remote
+  /// transport.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError::NotRemote`] when `target` lives on the local node, and
+  /// [`RemoteSendError::Transport`] when the frame cannot be handed to the endpoint.
+  pub fn send_control(
+    &self,
+    target: &Pid,
+    payload: RemotePayloadFrame,
+    priority: i8,
+    reply_to: Option<Pid>,
+  ) -> Result<(), RemoteSendError> {
+    let frame = RemoteMessageFrame::new(priority, PriorityChannel::Control, payload, reply_to);
+    self.send_frame(target, frame)
+  }
+
+  /// Passes a frame received from another node on to `target` unchanged, for protocols that
+  /// relay messages to the node currently responsible for them.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError::NotRemote`] when `target` lives on the local node, and
+  /// [`RemoteSendError::Transport`] when the frame cannot be handed to the endpoint.
+  pub fn forward(&self, target: &Pid, frame: RemoteMessageFrame) -> Result<(), RemoteSendError> {
+    self.send_frame(target, frame)
+  }
+
+  fn send_frame(&self, target: &Pid, frame: RemoteMessageFrame) -> Result<(), RemoteSendError> {
+    if !self.is_remote(target) {
+      return Err(RemoteSendError::NotRemote);
+    }
+    let delivery = RemoteDelivery::new(target.clone(), frame);
+    self.endpoints.send(&delivery).map_err(RemoteSendError::from)
+  }
+
+  /// Creates an `ask` whose responder waits under a fresh reply PID, released again when the
+  /// future is dropped before the reply arrives.
+  pub(crate) fn reply_handles<Resp>(&self) -> (AskFuture<Resp>, Pid)
+  where
+    Resp: Element, {
+    let (future, responder) = create_ask_handles::<Resp, ThreadSafe>();
+    let reply_to = self.replies.register(self.system(), self.node(), responder.into_internal());
+    let replies = self.replies.clone();
+    let pending = reply_to.clone();
+    let future = future.with_cancel_hook(move || drop(replies.take(&pending)));
+    (future, reply_to)
+  }
+}
+
+impl<T> RemoteOutbound<T>
+where
+  T: RemoteTransport + 'static,
+  Self: SharedBound,
+{
+  /// Returns the route used by inbound requests to answer their remote senders.
+  #[must_use]
+  pub fn reply_route(&self) -> RemoteReplyRoute {
+    let outbound = self.clone();
+    RemoteReplyRoute::new(move |target: &Pid, message: AnyMessage, priority: i8| {
+      outbound.send_any(target, &message, priority).map_err(|_| QueueError::Disconnected)
+    })
+  }
+
+  /// Builds the receiving side delivering into `registry` and sharing this node's message and
+  /// reply registries.
+  #[must_use]
+  pub fn inbound_dispatcher<MF>(&self, registry: ArcShared<RemoteProcessRegistry<MF>>) -> RemoteInboundDispatcher<MF>
+  where
+    MF: MailboxFactory, {
+    RemoteInboundDispatcher::new(registry, self.router.serializers())
+      .with_messages(self.messages.clone())
+      .with_replies(self.replies.clone())
+      .with_reply_route(self.reply_route())
+  }
+
+  /// Starts listening on the local node and delivers inbound frames into `registry`.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the transport cannot listen on the local node.
+  pub fn start<MF>(&self, registry: ArcShared<RemoteProcessRegistry<MF>>) -> Result<(), TransportError>
+  where
+    MF: MailboxFactory + 'static,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    self.endpoints.start(self.inbound_dispatcher(registry))
+  }
+}
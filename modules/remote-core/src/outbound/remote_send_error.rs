@@ -0,0 +1,25 @@
+use alloc::string::String;
+
+use cellex_serialization_core_rs::error::SerializationError;
+
+use crate::transport::TransportError;
+
+/// Errors raised while sending a message to an actor on another node.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum RemoteSendError {
+  /// The PID refers to the local node (or to no node at all) and cannot be reached remotely.
+  #[error("pid does not refer to a remote node")]
+  NotRemote,
+  /// The message is not a user message of a type registered for remote delivery.
+  #[error("message type is not registered for remote delivery")]
+  UnregisteredMessage,
+  /// No serializer is bound to the type key of the message and no fallback is configured.
+  #[error("no serializer bound for type {0}")]
+  MissingSerializer(String),
+  /// The message could not be encoded.
+  #[error("failed to encode outbound payload: {0}")]
+  Serialization(SerializationError),
+  /// The transport rejected the frame.
+  #[error(transparent)]
+  Transport(#[from] TransportError),
+}
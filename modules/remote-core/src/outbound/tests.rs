@@ -0,0 +1,251 @@
+extern crate std;
+
+use std::{
+  format,
+  string::{String, ToString},
+  vec,
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor::{
+    actor_failure::ActorFailure,
+    ask::{ask_with_timeout, AskError},
+    Props,
+  },
+  actor_system::GenericActorSystem,
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  mailbox::messages::PriorityChannel,
+  process::pid::{NodeId, Pid, SystemId},
+};
+use cellex_serialization_core_rs::{
+  error::{DeserializationError, SerializationError},
+  impl_type_key, TypeKey,
+};
+use cellex_serialization_json_rs::{shared_json_serializer, SerdeJsonSerializer, SERDE_JSON_SERIALIZER_ID};
+use cellex_utils_core_rs::{collections::queue::priority::DEFAULT_PRIORITY, sync::ArcShared};
+use futures::{executor::block_on, future};
+use serde::{Deserialize, Serialize};
+
+use super::{RemoteOutbound, RemoteSendError};
+use crate::{
+  codec::{RemoteDelivery, RemoteMessageFrame, RemotePayloadFrame},
+  delivery::RemoteMessage,
+  endpoint::EndpointManager,
+  test_support::{self as test_support, new_system, node, RecordingTransport, TestResult, TestRuntime},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Greeting {
+  text: String,
+}
+
+impl_type_key!(Greeting, "test.Greeting");
+
+impl RemoteMessage for Greeting {
+  fn encode_payload(&self) -> Result<Vec<u8>, SerializationError> {
+    serde_json::to_vec(self).map_err(|err| SerializationError::custom(err.to_string()))
+  }
+
+  fn decode_payload(bytes: &[u8]) -> Result<Self, DeserializationError> {
+    serde_json::from_slice(bytes).map_err(|err| DeserializationError::custom(err.to_string()))
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Reply {
+  length: usize,
+}
+
+impl_type_key!(Reply, "test.Reply");
+
+impl RemoteMessage for Reply {
+  fn encode_payload(&self) -> Result<Vec<u8>, SerializationError> {
+    serde_json::to_vec(self).map_err(|err| SerializationError::custom(err.to_string()))
+  }
+
+  fn decode_payload(bytes: &[u8]) -> Result<Self, DeserializationError> {
+    serde_json::from_slice(bytes).map_err(|err| DeserializationError::custom(err.to_string()))
+  }
+}
+
+fn outbound_for(
+  system: &GenericActorSystem<Greeting, TestRuntime>,
+  transport: &RecordingTransport,
+  node: NodeId,
+) -> TestResult<RemoteOutbound<RecordingTransport>> {
+  system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      let _ = extension.register_serializer(shared_json_serializer());
+      extension.bind_type::<Greeting>(SERDE_JSON_SERIALIZER_ID).map_err(|err| format!("bind: {err:?}"))?;
+      extension.bind_type::<Reply>(SERDE_JSON_SERIALIZER_ID).map_err(|err| format!("bind: {err:?}"))
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())??;
+  test_support::outbound_for(system, transport, node)
+}
+
+#[test]
+fn remote_actor_ref_tell_writes_serialized_frame_to_target_node() -> TestResult {
+  let transport = RecordingTransport::default();
+  let system = new_system(node(2551));
+  let outbound = outbound_for(&system, &transport, node(2551))?;
+
+  let target = Pid::parse("actor://cellex@127.0.0.1:2552/3").map_err(|err| format!("pid: {err:?}"))?;
+  let remote = outbound.actor_ref::<Greeting>(target.clone()).map_err(|err| format!("actor ref: {err}"))?;
+  remote.tell(Greeting { text: "hello".to_string() }).map_err(|err| format!("tell: {err}"))?;
+  remote.tell_with_priority(Greeting { text: "urgent".to_string() }, 5).map_err(|err| format!("tell: {err}"))?;
+
+  let frames = transport.take()?;
+  assert_eq!(frames.len(), 2);
+  let priorities: Vec<i8> = frames.iter().map(|(_, delivery)| delivery.frame.priority).collect();
+  assert_eq!(priorities, vec![DEFAULT_PRIORITY, 5]);
+  for (node_id, delivery) in frames {
+    assert_eq!(node_id, node(2552));
+    assert_eq!(delivery.target, target);
+    assert_eq!(delivery.frame.reply_to, None);
+    let RemotePayloadFrame::User { serialized } = delivery.frame.payload else {
+      return Err("user payload expected".to_string());
+    };
+    assert_eq!(serialized.serializer_id, SERDE_JSON_SERIALIZER_ID);
+    assert_eq!(serialized.type_name.as_deref(), Some("test.Greeting"));
+  }
+  Ok(())
+}
+
+#[test]
+fn remote_actor_ref_ask_is_completed_by_reply_frame() -> TestResult {
+  let transport = RecordingTransport::default();
+  let mut system = new_system(node(2551));
+  let outbound = outbound_for(&system, &transport, node(2551))?;
+  let dispatcher = outbound.inbound_dispatcher(system.process_registry());
+
+  let target = Pid::parse("actor://cellex@127.0.0.1:2552/3").map_err(|err| format!("pid: {err:?}"))?;
+  let remote = outbound.actor_ref::<Greeting>(target).map_err(|err| format!("actor ref: {err}"))?;
+  let future = remote.ask::<Reply>(Greeting { text: "ping".to_string() }).map_err(|err| format!("ask: {err}"))?;
+  assert_eq!(outbound.replies().len(), 1);
+
+  let (_, request) = transport.take()?.pop().ok_or_else(|| "request frame expected".to_string())?;
+  let reply_to = request.frame.reply_to.ok_or_else(|| "reply-to expected".to_string())?;
+  assert_eq!(reply_to.node(), Some(&node(2551)));
+
+  let serialized = SerdeJsonSerializer::new()
+    .serialize_value(Some(<Reply as TypeKey>::type_key()), &Reply { length: 4 })
+    .map_err(|err| format!("serialize: {err}"))?;
+  let frame = RemoteMessageFrame::new(0, PriorityChannel::Regular, RemotePayloadFrame::User { serialized }, None);
+  dispatcher.dispatch(RemoteDelivery::new(reply_to, frame)).map_err(|err| format!("dispatch: {err}"))?;
+  system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+
+  let reply = block_on(future).map_err(|err| format!("await: {err:?}"))?;
+  assert_eq!(reply, Reply { length: 4 });
+  assert!(outbound.replies().is_empty());
+  Ok(())
+}
+
+#[test]
+fn remote_actor_ref_ask_releases_its_reply_pid_when_given_up() -> TestResult {
+  let transport = RecordingTransport::default();
+  let mut system = new_system(node(2551));
+  let outbound = outbound_for(&system, &transport, node(2551))?;
+  let dispatcher = outbound.inbound_dispatcher(system.process_registry());
+
+  let target = Pid::parse("actor://cellex@127.0.0.1:2552/3").map_err(|err| format!("pid: {err:?}"))?;
+  let remote = outbound.actor_ref::<Greeting>(target).map_err(|err| format!("actor ref: {err}"))?;
+  let dropped = remote.ask::<Reply>(Greeting { text: "dropped".to_string() }).map_err(|err| format!("ask: {err}"))?;
+  let timed_out = remote.ask::<Reply>(Greeting { text: "late".to_string() }).map_err(|err| format!("ask: {err}"))?;
+  assert_eq!(outbound.replies().len(), 2);
+
+  drop(dropped);
+  assert_eq!(outbound.replies().len(), 1);
+  let result = block_on(ask_with_timeout(timed_out, future::ready(())));
+  assert!(matches!(result, Err(AskError::Timeout)));
+  assert!(outbound.replies().is_empty());
+
+  // A reply arriving after the caller gave up finds no responder.
+  let (_, request) = transport.take()?.pop().ok_or_else(|| "request frame expected".to_string())?;
+  let reply_to = request.frame.reply_to.ok_or_else(|| "reply-to expected".to_string())?;
+  let serialized = SerdeJsonSerializer::new()
+    .serialize_value(Some(<Reply as TypeKey>::type_key()), &Reply { length: 4 })
+    .map_err(|err| format!("serialize: {err}"))?;
+  let frame = RemoteMessageFrame::new(0, PriorityChannel::Regular, RemotePayloadFrame::User { serialized }, None);
+  let _ = dispatcher.dispatch(RemoteDelivery::new(reply_to, frame));
+  system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+  assert!(outbound.replies().is_empty());
+  Ok(())
+}
+
+#[test]
+fn inbound_requests_answer_over_the_reply_route() -> TestResult {
+  let transport = RecordingTransport::default();
+  let mut system = new_system(node(2552));
+  let outbound = outbound_for(&system, &transport, node(2552))?;
+  outbound.register_message::<Greeting>();
+  outbound.register_message::<Reply>();
+  let dispatcher = outbound.inbound_dispatcher(system.process_registry());
+
+  let actor_ref = system
+    .root_context()
+    .spawn(Props::new(|ctx, message: Greeting| {
+      let responder = ctx
+        .message_metadata()
+        .and_then(|metadata| metadata.dispatcher_for::<Reply>())
+        .ok_or_else(|| ActorFailure::from_message("responder expected"))?;
+      responder
+        .dispatch_user(Reply { length: message.text.len() })
+        .map_err(|err| ActorFailure::from_message(format!("respond: {err:?}")))
+    }))
+    .map_err(|err| format!("spawn: {err:?}"))?;
+  let target = actor_ref.pid().ok_or_else(|| "pid expected".to_string())?;
+
+  let reply_to = Pid::parse("actor://cellex@127.0.0.1:2551#reply-1").map_err(|err| format!("pid: {err:?}"))?;
+  let serialized = SerdeJsonSerializer::new()
+    .serialize_value(Some(<Greeting as TypeKey>::type_key()), &Greeting { text: "hello".to_string() })
+    .map_err(|err| format!("serialize: {err}"))?;
+  let frame = RemoteMessageFrame::new(
+    0,
+    PriorityChannel::Regular,
+    RemotePayloadFrame::User { serialized },
+    Some(reply_to.clone()),
+  );
+  dispatcher.dispatch(RemoteDelivery::new(target, frame)).map_err(|err| format!("dispatch: {err}"))?;
+  system.run_until_idle().map_err(|err| format!("run: {err:?}"))?;
+
+  let (node_id, delivery) = transport.take()?.pop().ok_or_else(|| "reply frame expected".to_string())?;
+  assert_eq!(node_id, node(2551));
+  assert_eq!(delivery.target, reply_to);
+  let RemotePayloadFrame::User { serialized } = delivery.frame.payload else {
+    return Err("user payload expected".to_string());
+  };
+  let reply: Reply =
+    SerdeJsonSerializer::new().deserialize_value(&serialized).map_err(|err| format!("decode: {err}"))?;
+  assert_eq!(reply, Reply { length: 5 });
+  Ok(())
+}
+
+#[test]
+fn actor_ref_rejects_local_pids() -> TestResult {
+  let transport = RecordingTransport::default();
+  let system = new_system(node(2551));
+  let outbound = outbound_for(&system, &transport, node(2551))?;
+
+  let local = Pid::parse("actor://cellex@127.0.0.1:2551/1").map_err(|err| format!("pid: {err:?}"))?;
+  let nodeless = Pid::new(SystemId::new("cellex"), local.path().clone());
+  assert!(matches!(outbound.actor_ref::<Greeting>(local), Err(RemoteSendError::NotRemote)));
+  assert!(matches!(outbound.actor_ref::<Greeting>(nodeless), Err(RemoteSendError::NotRemote)));
+  Ok(())
+}
+
+#[test]
+fn tell_fails_without_serializer_binding() -> TestResult {
+  let transport = RecordingTransport::default();
+  let system = new_system::<Greeting>(node(2551));
+  let endpoints =
+    ArcShared::new(EndpointManager::new(transport, system.process_registry().system().clone(), node(2551)));
+  let outbound = RemoteOutbound::new(endpoints, &SerializerRegistryExtension::new());
+
+  let target = Pid::parse("actor://cellex@127.0.0.1:2552/1").map_err(|err| format!("pid: {err:?}"))?;
+  let remote = outbound.actor_ref::<Greeting>(target).map_err(|err| format!("actor ref: {err}"))?;
+  let result = remote.tell(Greeting { text: "lost".to_string() });
+
+  assert_eq!(result, Err(RemoteSendError::MissingSerializer("test.Greeting".to_string())));
+  Ok(())
+}
@@ -0,0 +1,121 @@
+//! Fixtures shared by the remote-core unit tests.
+
+extern crate std;
+
+use std::{
+  format,
+  string::{String, ToString},
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+  },
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::api::{
+  actor_runtime::GenericActorRuntime,
+  actor_system::{GenericActorSystem, GenericActorSystemConfig},
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  process::pid::NodeId,
+  test_support::TestMailboxFactory,
+};
+use cellex_utils_core_rs::{collections::Element, sync::ArcShared};
+
+use crate::{
+  codec::{decode_wire_frame, RemoteDelivery, RemoteWireFrame},
+  endpoint::EndpointManager,
+  outbound::RemoteOutbound,
+  transport::{InboundFrameHandler, RemoteTransport, TransportConnection, TransportError},
+};
+
+pub(crate) type TestResult<T = ()> = Result<T, String>;
+pub(crate) type TestRuntime = GenericActorRuntime<TestMailboxFactory>;
+type Frames = Arc<Mutex<Vec<(NodeId, Vec<u8>)>>>;
+
+/// Transport recording every frame written to any node, optionally refusing every connection.
+#[derive(Clone, Default)]
+pub(crate) struct RecordingTransport {
+  frames:      Frames,
+  unreachable: Arc<AtomicBool>,
+}
+
+pub(crate) struct RecordingConnection {
+  node:   NodeId,
+  frames: Frames,
+  closed: AtomicBool,
+}
+
+impl RemoteTransport for RecordingTransport {
+  type Connection = RecordingConnection;
+
+  fn listen(&self, _local: &NodeId, _handler: InboundFrameHandler) -> Result<(), TransportError> {
+    Ok(())
+  }
+
+  fn connect(&self, remote: &NodeId) -> Result<Self::Connection, TransportError> {
+    if self.unreachable.load(Ordering::SeqCst) {
+      return Err(TransportError::Unreachable(remote.clone()));
+    }
+    Ok(RecordingConnection { node: remote.clone(), frames: self.frames.clone(), closed: AtomicBool::new(false) })
+  }
+}
+
+impl TransportConnection for RecordingConnection {
+  fn send(&self, frame: Vec<u8>) -> Result<(), TransportError> {
+    self.frames.lock().unwrap_or_else(|err| err.into_inner()).push((self.node.clone(), frame));
+    Ok(())
+  }
+
+  fn close(&self) {
+    self.closed.store(true, Ordering::SeqCst);
+  }
+
+  fn is_closed(&self) -> bool {
+    self.closed.load(Ordering::SeqCst)
+  }
+}
+
+impl RecordingTransport {
+  /// Makes every later connection attempt fail as unreachable.
+  pub(crate) fn refuse_connections(&self) {
+    self.unreachable.store(true, Ordering::SeqCst);
+  }
+
+  /// Drains the recorded deliveries along with the node each was written to.
+  pub(crate) fn take(&self) -> TestResult<Vec<(NodeId, RemoteDelivery)>> {
+    let frames = core::mem::take(&mut *self.frames.lock().unwrap_or_else(|err| err.into_inner()));
+    // Handshakes opening each association are not part of the traffic under test.
+    frames
+      .into_iter()
+      .filter_map(|(node, frame)| match decode_wire_frame(&frame) {
+        | Ok(RemoteWireFrame::Delivery(delivery)) => Some(Ok((node, *delivery))),
+        | Ok(_) => None,
+        | Err(err) => Some(Err(format!("decode: {err}"))),
+      })
+      .collect()
+  }
+}
+
+pub(crate) fn node(port: u16) -> NodeId {
+  NodeId::new("127.0.0.1", Some(port))
+}
+
+pub(crate) fn new_system<U: Element>(node: NodeId) -> GenericActorSystem<U, TestRuntime> {
+  let config = GenericActorSystemConfig::default().with_node_id(node);
+  GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TestMailboxFactory::unbounded()), config)
+}
+
+/// Builds the outbound side of `system` writing through `transport` as `node`.
+pub(crate) fn outbound_for<U: Element>(
+  system: &GenericActorSystem<U, TestRuntime>,
+  transport: &RecordingTransport,
+  node: NodeId,
+) -> TestResult<RemoteOutbound<RecordingTransport>> {
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(transport.clone(), system_id, node));
+  system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      RemoteOutbound::new(endpoints, extension)
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())
+}
@@ -157,7 +157,9 @@ fn remote_failure_notifier_emit_calls_hub_and_handler() -> TestResult {
   let events = lock(&hub_events)?;
   assert_eq!(events.len(), 1);
 
-  let FailureEvent::RootEscalated(received_info) = &events[0];
+  let FailureEvent::RootEscalated(received_info) = &events[0] else {
+    return Err("root escalation expected".to_string());
+  };
   assert_eq!(received_info.actor, info.actor);
   assert_eq!(received_info.description(), info.description());
   Ok(())
@@ -184,7 +186,9 @@ fn remote_failure_notifier_preserves_behavior_failure() -> TestResult {
   let captured: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
   let captured_clone = Arc::clone(&captured);
   let _subscription = hub.subscribe(FailureEventListener::new(move |event: FailureEvent| {
-    let FailureEvent::RootEscalated(info) = event;
+    let FailureEvent::RootEscalated(info) = event else {
+      return;
+    };
     if let Some(failure) = info.behavior_failure().as_any().downcast_ref::<SampleBehaviorFailure>() {
       captured_clone.lock().unwrap_or_else(|err| err.into_inner()).replace(failure.0.to_owned());
     }
@@ -193,7 +197,9 @@ fn remote_failure_notifier_preserves_behavior_failure() -> TestResult {
   let handler_called = Arc::new(Mutex::new(false));
   let handler_called_clone = Arc::clone(&handler_called);
   notifier.set_handler(FailureEventListener::new(move |event: FailureEvent| {
-    let FailureEvent::RootEscalated(info) = event;
+    let FailureEvent::RootEscalated(info) = event else {
+      return;
+    };
     if info.behavior_failure().as_any().is::<SampleBehaviorFailure>() {
       *handler_called_clone.lock().unwrap_or_else(|err| err.into_inner()) = true;
     }
@@ -269,7 +275,9 @@ fn remote_failure_notifier_triggers_telemetry_metrics() {
   let sink = Arc::new(Mutex::new(root_sink));
   let sink_clone: Arc<Mutex<RootEscalationSink<TestMailboxFactory>>> = Arc::clone(&sink);
   let _subscription = hub.subscribe(FailureEventListener::new(move |event: FailureEvent| {
-    let FailureEvent::RootEscalated(info) = event;
+    let FailureEvent::RootEscalated(info) = event else {
+      return;
+    };
     let handle_result = sink_clone.lock().unwrap_or_else(|err| err.into_inner()).handle(info, false);
     assert!(handle_result.is_ok(), "root sink should handle failure: {:?}", handle_result.err());
   }));
@@ -311,7 +319,7 @@ fn remote_serialization_router_roundtrip_with_fallback() -> TestResult {
   let envelope = control_remote_envelope_with_reply(serialized, 4, None);
   let frame = frame_from_serialized_envelope(envelope).map_err(|err| format!("フレーム生成に失敗しました: {err:?}"))?;
 
-  let decoded = envelope_from_frame(frame);
+  let decoded = envelope_from_frame(frame).ok_or_else(|| "envelope expected".to_string())?;
   let (envelope, priority, channel) = decoded.into_parts_with_channel();
   assert_eq!(priority, 4);
   assert_eq!(channel, PriorityChannel::Control);
@@ -431,7 +439,7 @@ fn frame_roundtrip_preserves_channel_and_priority_for_system_message() -> TestRe
     | _ => return Err("expected system payload".to_string()),
   }
 
-  let decoded = envelope_from_frame(frame);
+  let decoded = envelope_from_frame(frame).ok_or_else(|| "envelope expected".to_string())?;
   let (decoded_message, decoded_priority, decoded_channel) = decoded.into_parts_with_channel();
   assert_eq!(decoded_priority, priority);
   assert_eq!(decoded_channel, PriorityChannel::Control);
@@ -461,7 +469,7 @@ fn frame_roundtrip_preserves_serialized_user_payload() -> TestResult {
   assert_eq!(frame_payload.serializer_id, serialized.serializer_id);
   assert_eq!(frame_payload.payload, serialized.payload);
 
-  let decoded = envelope_from_frame(frame);
+  let decoded = envelope_from_frame(frame).ok_or_else(|| "envelope expected".to_string())?;
   let (decoded_envelope, priority, channel) = decoded.into_parts_with_channel();
   assert_eq!(priority, 9);
   assert_eq!(channel, PriorityChannel::Control);
@@ -491,7 +499,7 @@ fn frame_roundtrip_preserves_reply_to_pid() -> TestResult {
 
   let RemoteMessageFrame { priority, channel, payload, reply_to: reply_to_frame } = frame;
   let decoded_frame = RemoteMessageFrame::new(priority, channel, payload, reply_to_frame);
-  let decoded = envelope_from_frame(decoded_frame);
+  let decoded = envelope_from_frame(decoded_frame).ok_or_else(|| "envelope expected".to_string())?;
   let (envelope, _, _) = decoded.into_parts_with_channel();
   match envelope {
     | MessageEnvelope::User(user) => {
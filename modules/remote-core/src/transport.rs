@@ -0,0 +1,11 @@
+mod inbound_frame_handler;
+mod remote_transport;
+mod transport_connection;
+mod transport_error;
+mod undelivered_frame_handler;
+
+pub use inbound_frame_handler::InboundFrameHandler;
+pub use remote_transport::RemoteTransport;
+pub use transport_connection::TransportConnection;
+pub use transport_error::TransportError;
+pub use undelivered_frame_handler::UndeliveredFrameHandler;
@@ -0,0 +1,43 @@
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use cellex_utils_core_rs::sync::{shared::SharedBound, ArcShared};
+
+#[cfg(target_has_atomic = "ptr")]
+type InboundFrameFn = dyn Fn(&mut Option<NodeId>, &[u8]) + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type InboundFrameFn = dyn Fn(&mut Option<NodeId>, &[u8]);
+
+/// Callback invoked by a transport for every complete frame body received from a peer.
+///
+/// Transports keep one peer slot per inbound connection, empty when the connection opens, and
+/// hand it to the handler along with every frame read from that connection. The handler records
+/// there the node the connection belongs to once it knows it, typically from the handshake.
+#[derive(Clone)]
+pub struct InboundFrameHandler {
+  inner: ArcShared<InboundFrameFn>,
+}
+
+impl InboundFrameHandler {
+  /// Creates a handler from a closure ignoring which connection the frames come from.
+  #[must_use]
+  pub fn new<F>(f: F) -> Self
+  where
+    F: Fn(&[u8]) + SharedBound + 'static, {
+    Self::with_peer(move |_: &mut Option<NodeId>, frame: &[u8]| f(frame))
+  }
+
+  /// Creates a handler from a closure also given the peer slot of the connection.
+  #[must_use]
+  pub fn with_peer<F>(f: F) -> Self
+  where
+    F: Fn(&mut Option<NodeId>, &[u8]) + SharedBound + 'static, {
+    let shared = ArcShared::new(f);
+    Self { inner: shared.into_dyn(|func| func as &InboundFrameFn) }
+  }
+
+  /// Hands a frame body (without length prefix) read from the connection owning `peer` to the
+  /// handler.
+  pub fn handle(&self, peer: &mut Option<NodeId>, frame: &[u8]) {
+    (self.inner)(peer, frame);
+  }
+}
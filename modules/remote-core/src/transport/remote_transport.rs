@@ -0,0 +1,34 @@
+use cellex_actor_core_rs::api::process::pid::NodeId;
+use cellex_utils_core_rs::sync::shared::SharedBound;
+
+use super::{InboundFrameHandler, TransportConnection, TransportError, UndeliveredFrameHandler};
+
+/// Byte-level transport used by the endpoint manager to reach other nodes.
+///
+/// Implementations only move opaque frame bodies; encoding and PID resolution stay in the
+/// endpoint layer so that every transport behaves identically.
+pub trait RemoteTransport: SharedBound {
+  /// Connection handle returned for an outbound association.
+  type Connection: TransportConnection;
+
+  /// Starts accepting inbound connections for `local` and forwards every frame to `handler`.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the local address cannot be bound.
+  fn listen(&self, local: &NodeId, handler: InboundFrameHandler) -> Result<(), TransportError>;
+
+  /// Opens an outbound connection to `remote`.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the connection cannot be initiated.
+  fn connect(&self, remote: &NodeId) -> Result<Self::Connection, TransportError>;
+
+  /// Reports to `handler` every frame accepted by a connection that is given up before being
+  /// written to the peer, replacing any previous handler.
+  ///
+  /// Transports that write synchronously report failures from [`TransportConnection::send`]
+  /// instead and keep the default, which ignores `handler`.
+  fn report_undelivered(&self, handler: UndeliveredFrameHandler) {
+    let _ = handler;
+  }
+}
@@ -0,0 +1,28 @@
+use alloc::vec::Vec;
+
+use cellex_utils_core_rs::sync::shared::SharedBound;
+
+use super::TransportError;
+
+/// Outbound half of an association with a single remote node.
+pub trait TransportConnection: SharedBound {
+  /// Queues a frame body for delivery to the peer.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the connection is closed or the frame is rejected.
+  fn send(&self, frame: Vec<u8>) -> Result<(), TransportError>;
+
+  /// Returns how many frames were accepted by [`TransportConnection::send`] but are not written
+  /// to the peer yet.
+  ///
+  /// Transports that write synchronously keep the default of zero.
+  fn backlog(&self) -> usize {
+    0
+  }
+
+  /// Closes the connection. Pending frames may be discarded.
+  fn close(&self);
+
+  /// Returns `true` once the connection can no longer carry frames.
+  fn is_closed(&self) -> bool;
+}
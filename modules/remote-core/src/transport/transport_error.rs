@@ -0,0 +1,36 @@
+use alloc::string::String;
+
+use cellex_actor_core_rs::api::process::pid::NodeId;
+
+use crate::codec::{HandshakeRejection, RemoteCodecError};
+
+/// Errors reported by remote transports and the endpoint manager.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum TransportError {
+  /// The target PID carries no node and therefore cannot be routed.
+  #[error("pid has no node to route to")]
+  MissingNode,
+  /// The peer could not be reached.
+  #[error("node {0:?} is unreachable")]
+  Unreachable(NodeId),
+  /// The node has been quarantined and is no longer connected to.
+  #[error("node {0:?} is quarantined")]
+  Quarantined(NodeId),
+  /// The node refused the association handshake.
+  #[error("node {0:?} rejected the association: {1}")]
+  Rejected(NodeId, HandshakeRejection),
+  /// The outbound queue to the node is full and its overflow policy is
+  /// [`MailboxOverflowPolicy::Block`](cellex_actor_core_rs::api::mailbox::MailboxOverflowPolicy::Block);
+  /// the message was not sent and is published as a dead letter.
+  #[error("outbound queue to node {0:?} is full")]
+  Backpressure(NodeId),
+  /// The connection has already been closed.
+  #[error("connection closed")]
+  Closed,
+  /// An I/O error reported by the underlying transport.
+  #[error("transport i/o error: {0}")]
+  Io(String),
+  /// The frame could not be encoded or decoded.
+  #[error(transparent)]
+  Codec(#[from] RemoteCodecError),
+}
@@ -0,0 +1,31 @@
+use cellex_actor_core_rs::api::process::dead_letter::DeadLetterReason;
+use cellex_utils_core_rs::sync::{shared::SharedBound, ArcShared};
+
+#[cfg(target_has_atomic = "ptr")]
+type UndeliveredFrameFn = dyn Fn(&[u8], DeadLetterReason) + Send + Sync;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+type UndeliveredFrameFn = dyn Fn(&[u8], DeadLetterReason);
+
+/// Callback invoked by a transport for every frame body it accepted but could not write.
+#[derive(Clone)]
+pub struct UndeliveredFrameHandler {
+  inner: ArcShared<UndeliveredFrameFn>,
+}
+
+impl UndeliveredFrameHandler {
+  /// Creates a handler from a closure.
+  #[must_use]
+  pub fn new<F>(f: F) -> Self
+  where
+    F: Fn(&[u8], DeadLetterReason) + SharedBound + 'static, {
+    let shared = ArcShared::new(f);
+    Self { inner: shared.into_dyn(|func| func as &UndeliveredFrameFn) }
+  }
+
+  /// Hands a frame body (without length prefix) that never reached the peer to the handler,
+  /// along with the reason it was given up.
+  pub fn handle(&self, frame: &[u8], reason: DeadLetterReason) {
+    (self.inner)(frame, reason);
+  }
+}
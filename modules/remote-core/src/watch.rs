@@ -0,0 +1,9 @@
+mod remote_death_watch;
+mod remote_terminated;
+mod remote_watcher;
+
+#[cfg(test)]
+mod tests;
+
+pub use remote_death_watch::RemoteDeathWatch;
+pub use remote_terminated::RemoteTerminated;
@@ -0,0 +1,308 @@
+use alloc::{
+  collections::BTreeMap,
+  string::{String, ToString},
+  vec::Vec,
+};
+
+use cellex_actor_core_rs::{
+  api::{
+    actor::ActorId,
+    mailbox::{messages::SystemMessage, ThreadSafe},
+    messaging::MessageSender,
+    process::{
+      dead_letter::DeadLetterReason,
+      pid::{NodeId, Pid},
+      process_registry::{ProcessResolution, ProcessTerminationListener},
+    },
+  },
+  shared::mailbox::MailboxFactory,
+};
+use cellex_utils_core_rs::sync::{shared::SharedBound, ArcShared};
+use spin::RwLock;
+
+use super::{remote_watcher::RemoteWatcher, RemoteTerminated};
+use crate::{
+  codec::{RemoteDelivery, RemotePayloadFrame},
+  delivery::{RemoteFrameInterceptor, RemoteInboundDispatcher, RemoteProcessRegistry},
+  outbound::{RemoteOutbound, RemoteSendError},
+  transport::{RemoteTransport, TransportError},
+};
+
+/// Watchers grouped by the node of the watched actor, then by the watched PID.
+type WatchingTable = BTreeMap<String, BTreeMap<String, Vec<RemoteWatcher>>>;
+
+/// Remote watchers grouped by the local PID they watch.
+type WatchedByTable = BTreeMap<String, Vec<Pid>>;
+
+/// Death watch spanning node boundaries.
+///
+/// Watching a remote actor sends [`SystemMessage::Watch`] to its node with the watcher as reply-to
+/// PID. The watched node records the watcher and answers with a termination notice once the actor
+/// is removed from its process registry. Watchers are tracked per endpoint so that
+/// [`RemoteDeathWatch::node_unreachable`] can synthesize a [`RemoteTerminated`] tagged with
+/// [`DeadLetterReason::NetworkUnreachable`] for every actor on a lost node.
+pub struct RemoteDeathWatch<T>
+where
+  T: RemoteTransport, {
+  outbound:   RemoteOutbound<T>,
+  watching:   ArcShared<RwLock<WatchingTable>>,
+  watched_by: ArcShared<RwLock<WatchedByTable>>,
+}
+
+impl<T> Clone for RemoteDeathWatch<T>
+where
+  T: RemoteTransport,
+{
+  fn clone(&self) -> Self {
+    Self { outbound: self.outbound.clone(), watching: self.watching.clone(), watched_by: self.watched_by.clone() }
+  }
+}
+
+impl<T> RemoteDeathWatch<T>
+where
+  T: RemoteTransport,
+{
+  /// Creates a death watch sending its control frames through `outbound`.
+  #[must_use]
+  pub fn new(outbound: RemoteOutbound<T>) -> Self {
+    Self {
+      outbound,
+      watching: ArcShared::new(RwLock::new(BTreeMap::new())),
+      watched_by: ArcShared::new(RwLock::new(BTreeMap::new())),
+    }
+  }
+
+  /// Returns the outbound side used to reach other nodes.
+  #[must_use]
+  pub const fn outbound(&self) -> &RemoteOutbound<T> {
+    &self.outbound
+  }
+
+  /// Starts watching the remote actor `watchee` on behalf of the local actor `watcher`.
+  ///
+  /// `notify` receives a [`RemoteTerminated`] once the watchee stops or its node becomes
+  /// unreachable. When the watch request cannot reach the node at all, the notice is delivered
+  /// immediately with [`DeadLetterReason::NetworkUnreachable`].
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError::NotRemote`] when `watchee` lives on the local node.
+  pub fn watch(
+    &self,
+    watchee: &Pid,
+    watcher: &Pid,
+    notify: MessageSender<RemoteTerminated, ThreadSafe>,
+  ) -> Result<(), RemoteSendError> {
+    let Some(node) = watchee.node().filter(|_| self.outbound.is_remote(watchee)) else {
+      return Err(RemoteSendError::NotRemote);
+    };
+    {
+      let mut watching = self.watching.write();
+      let watchers = watching.entry(node.to_string()).or_default().entry(watchee.to_string()).or_default();
+      watchers.retain(|existing| existing.watcher() != watcher);
+      watchers.push(RemoteWatcher::new(watchee.clone(), watcher.clone(), notify));
+    }
+    let message = SystemMessage::Watch(Self::actor_id(watcher));
+    match self.send_system(watchee, message, watcher.clone()) {
+      | Ok(()) => Ok(()),
+      | Err(RemoteSendError::Transport(_)) => {
+        if let Some(entry) = self.remove_watcher(watchee, watcher) {
+          entry.notify(DeadLetterReason::NetworkUnreachable);
+        }
+        Ok(())
+      },
+      | Err(error) => {
+        drop(self.remove_watcher(watchee, watcher));
+        Err(error)
+      },
+    }
+  }
+
+  /// Stops watching `watchee` on behalf of `watcher`.
+  ///
+  /// # Errors
+  /// Returns [`RemoteSendError`] when the unwatch request cannot be sent. The local registration
+  /// is removed regardless.
+  pub fn unwatch(&self, watchee: &Pid, watcher: &Pid) -> Result<(), RemoteSendError> {
+    if !self.outbound.is_remote(watchee) {
+      return Err(RemoteSendError::NotRemote);
+    }
+    if self.remove_watcher(watchee, watcher).is_none() {
+      return Ok(());
+    }
+    self.send_system(watchee, SystemMessage::Unwatch(Self::actor_id(watcher)), watcher.clone())
+  }
+
+  /// Returns `true` when at least one local actor watches `watchee`.
+  #[must_use]
+  pub fn is_watching(&self, watchee: &Pid) -> bool {
+    let Some(node) = watchee.node() else {
+      return false;
+    };
+    self.watching.read().get(&node.to_string()).is_some_and(|watchees| watchees.contains_key(&watchee.to_string()))
+  }
+
+  /// Returns the number of remote watchers observing the local actor `watchee`.
+  #[must_use]
+  pub fn remote_watcher_count(&self, watchee: &Pid) -> usize {
+    self.watched_by.read().get(&watchee.to_string()).map_or(0, Vec::len)
+  }
+
+  /// Notifies every watcher of actors on `node` that they are unreachable and forgets the
+  /// watches in both directions.
+  pub fn node_unreachable(&self, node: &NodeId) {
+    let node_key = node.to_string();
+    let lost = self.watching.write().remove(&node_key).unwrap_or_default();
+    self.watched_by.write().retain(|_, watchers| {
+      watchers.retain(|watcher| watcher.node() != Some(node));
+      !watchers.is_empty()
+    });
+    for watcher in lost.into_values().flatten() {
+      watcher.notify(DeadLetterReason::NetworkUnreachable);
+    }
+  }
+
+  /// Sends a termination notice to every remote watcher of the local actor `pid`.
+  pub fn local_terminated(&self, pid: &Pid) {
+    let watchers = self.watched_by.write().remove(&pid.to_string()).unwrap_or_default();
+    for watcher in watchers {
+      let _ = self.send_terminated(&watcher, pid, DeadLetterReason::Terminated);
+    }
+  }
+
+  /// Handles watch protocol frames, returning the delivery when it belongs to regular traffic.
+  ///
+  /// `resolve` reports whether a local PID is currently registered.
+  pub fn handle_frame<R>(&self, delivery: RemoteDelivery, resolve: R) -> Option<RemoteDelivery>
+  where
+    R: Fn(&Pid) -> bool, {
+    let RemoteDelivery { target, frame } = delivery;
+    match (&frame.payload, frame.reply_to.as_ref()) {
+      | (RemotePayloadFrame::System(SystemMessage::Watch(_)), Some(watcher)) if self.outbound.is_remote(watcher) => {
+        self.remote_watch(&target, watcher, resolve);
+        None
+      },
+      | (RemotePayloadFrame::System(SystemMessage::Unwatch(_)), Some(watcher)) if self.outbound.is_remote(watcher) => {
+        self.remote_unwatch(&target, watcher);
+        None
+      },
+      | (RemotePayloadFrame::Terminated { pid, reason }, _) => {
+        self.remote_terminated(pid, &target, reason);
+        None
+      },
+      | _ => Some(RemoteDelivery::new(target, frame)),
+    }
+  }
+
+  fn remote_watch<R>(&self, watchee: &Pid, watcher: &Pid, resolve: R)
+  where
+    R: Fn(&Pid) -> bool, {
+    // Record before resolving so that a concurrent termination either sees the watcher or the
+    // watchee is already gone.
+    {
+      let mut watched_by = self.watched_by.write();
+      let watchers = watched_by.entry(watchee.to_string()).or_default();
+      if !watchers.contains(watcher) {
+        watchers.push(watcher.clone());
+      }
+    }
+    if !resolve(watchee) && self.remove_remote_watcher(watchee, watcher) {
+      let _ = self.send_terminated(watcher, watchee, DeadLetterReason::UnregisteredPid);
+    }
+  }
+
+  fn remote_unwatch(&self, watchee: &Pid, watcher: &Pid) {
+    let _ = self.remove_remote_watcher(watchee, watcher);
+  }
+
+  fn remote_terminated(&self, watchee: &Pid, watcher: &Pid, reason: &DeadLetterReason) {
+    if let Some(entry) = self.remove_watcher(watchee, watcher) {
+      entry.notify(reason.clone());
+    }
+  }
+
+  fn remove_watcher(&self, watchee: &Pid, watcher: &Pid) -> Option<RemoteWatcher> {
+    let node_key = watchee.node()?.to_string();
+    let watchee_key = watchee.to_string();
+    let mut watching = self.watching.write();
+    let watchees = watching.get_mut(&node_key)?;
+    let watchers = watchees.get_mut(&watchee_key)?;
+    let index = watchers.iter().position(|entry| entry.watcher() == watcher)?;
+    let removed = watchers.remove(index);
+    if watchers.is_empty() {
+      watchees.remove(&watchee_key);
+    }
+    if watchees.is_empty() {
+      watching.remove(&node_key);
+    }
+    Some(removed)
+  }
+
+  fn remove_remote_watcher(&self, watchee: &Pid, watcher: &Pid) -> bool {
+    let key = watchee.to_string();
+    let mut watched_by = self.watched_by.write();
+    let Some(watchers) = watched_by.get_mut(&key) else {
+      return false;
+    };
+    let before = watchers.len();
+    watchers.retain(|existing| existing != watcher);
+    let removed = watchers.len() != before;
+    if watchers.is_empty() {
+      watched_by.remove(&key);
+    }
+    removed
+  }
+
+  fn send_system(&self, watchee: &Pid, message: SystemMessage, watcher: Pid) -> Result<(), RemoteSendError> {
+    let priority = message.priority();
+    self.outbound.send_control(watchee, RemotePayloadFrame::System(message), priority, Some(watcher))
+  }
+
+  fn send_terminated(&self, watcher: &Pid, watchee: &Pid, reason: DeadLetterReason) -> Result<(), RemoteSendError> {
+    let payload = RemotePayloadFrame::Terminated { pid: watchee.clone(), reason };
+    let priority = SystemMessage::Watch(ActorId::ROOT).priority();
+    self.outbound.send_control(watcher, payload, priority, None)
+  }
+
+  fn actor_id(pid: &Pid) -> ActorId {
+    pid.path().last().unwrap_or(ActorId::ROOT)
+  }
+}
+
+impl<T> RemoteDeathWatch<T>
+where
+  T: RemoteTransport + 'static,
+  Self: SharedBound,
+{
+  /// Hooks the death watch into `dispatcher`.
+  ///
+  /// Watch protocol frames are consumed before regular delivery, and removals from the
+  /// dispatcher's process registry notify remote watchers.
+  #[must_use]
+  pub fn attach<MF>(&self, dispatcher: RemoteInboundDispatcher<MF>) -> RemoteInboundDispatcher<MF>
+  where
+    MF: MailboxFactory + 'static,
+    ArcShared<RemoteProcessRegistry<MF>>: SharedBound, {
+    let registry = dispatcher.registry().clone();
+    let watch = self.clone();
+    let listener =
+      ArcShared::new(move |pid: &Pid| watch.local_terminated(pid)).into_dyn(|f| f as &ProcessTerminationListener);
+    registry.subscribe_terminations(listener);
+    let watch = self.clone();
+    dispatcher.with_interceptor(RemoteFrameInterceptor::new(move |delivery: RemoteDelivery| {
+      watch.handle_frame(delivery, |pid| matches!(registry.resolve_pid(pid), ProcessResolution::Local(_)))
+    }))
+  }
+
+  /// Starts listening on the local node with the death watch attached to inbound delivery.
+  ///
+  /// # Errors
+  /// Returns [`TransportError`] when the transport cannot listen on the local node.
+  pub fn start<MF>(&self, registry: ArcShared<RemoteProcessRegistry<MF>>) -> Result<(), TransportError>
+  where
+    MF: MailboxFactory + 'static,
+    ArcShared<RemoteProcessRegistry<MF>>: SharedBound,
+    RemoteInboundDispatcher<MF>: SharedBound, {
+    let dispatcher = self.attach(self.outbound.inbound_dispatcher(registry));
+    self.outbound.endpoints().start(dispatcher)
+  }
+}
@@ -0,0 +1,31 @@
+use cellex_actor_core_rs::api::process::{dead_letter::DeadLetterReason, pid::Pid};
+
+/// Termination notice delivered to a watcher of an actor on another node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteTerminated {
+  pid:    Pid,
+  reason: DeadLetterReason,
+}
+
+impl RemoteTerminated {
+  /// Creates a notice reporting that `pid` terminated for `reason`.
+  #[must_use]
+  pub const fn new(pid: Pid, reason: DeadLetterReason) -> Self {
+    Self { pid, reason }
+  }
+
+  /// Returns the PID of the terminated actor.
+  #[must_use]
+  pub const fn pid(&self) -> &Pid {
+    &self.pid
+  }
+
+  /// Returns why the actor is considered terminated.
+  ///
+  /// [`DeadLetterReason::Terminated`] means the actor stopped,
+  /// [`DeadLetterReason::NetworkUnreachable`] that its node can no longer be reached.
+  #[must_use]
+  pub const fn reason(&self) -> &DeadLetterReason {
+    &self.reason
+  }
+}
@@ -0,0 +1,29 @@
+use cellex_actor_core_rs::api::{
+  mailbox::ThreadSafe,
+  messaging::MessageSender,
+  process::{dead_letter::DeadLetterReason, pid::Pid},
+};
+
+use super::RemoteTerminated;
+
+/// Local watcher of an actor on another node.
+pub(crate) struct RemoteWatcher {
+  watchee: Pid,
+  watcher: Pid,
+  notify:  MessageSender<RemoteTerminated, ThreadSafe>,
+}
+
+impl RemoteWatcher {
+  pub(crate) const fn new(watchee: Pid, watcher: Pid, notify: MessageSender<RemoteTerminated, ThreadSafe>) -> Self {
+    Self { watchee, watcher, notify }
+  }
+
+  pub(crate) const fn watcher(&self) -> &Pid {
+    &self.watcher
+  }
+
+  /// Delivers the termination notice; a watcher that already stopped simply misses it.
+  pub(crate) fn notify(&self, reason: DeadLetterReason) {
+    let _ = self.notify.dispatch_user(RemoteTerminated::new(self.watchee.clone(), reason));
+  }
+}
@@ -0,0 +1,193 @@
+extern crate std;
+
+use std::{format, string::ToString};
+
+use cellex_actor_core_rs::api::{
+  actor::{ask::create_ask_handles, ActorId},
+  actor_system::GenericActorSystem,
+  mailbox::{
+    messages::{PriorityChannel, SystemMessage},
+    ThreadSafe,
+  },
+  process::{
+    dead_letter::DeadLetterReason,
+    pid::{NodeId, Pid},
+  },
+};
+use futures::executor::block_on;
+
+use super::{RemoteDeathWatch, RemoteTerminated};
+use crate::{
+  codec::{RemoteDelivery, RemoteMessageFrame, RemotePayloadFrame},
+  outbound::RemoteSendError,
+  test_support::{new_system, node, outbound_for, RecordingTransport, TestResult, TestRuntime},
+};
+
+fn pid(input: &str) -> TestResult<Pid> {
+  Pid::parse(input).map_err(|err| format!("pid: {err:?}"))
+}
+
+fn watch_for(
+  system: &GenericActorSystem<u32, TestRuntime>,
+  transport: &RecordingTransport,
+  node: NodeId,
+) -> TestResult<RemoteDeathWatch<RecordingTransport>> {
+  let outbound = outbound_for(system, transport, node)?;
+  Ok(RemoteDeathWatch::new(outbound))
+}
+
+fn control_frame(payload: RemotePayloadFrame, reply_to: Option<Pid>) -> RemoteMessageFrame {
+  RemoteMessageFrame::new(0, PriorityChannel::Control, payload, reply_to)
+}
+
+#[test]
+fn watch_sends_request_and_node_loss_notifies_watcher() -> TestResult {
+  let transport = RecordingTransport::default();
+  let system = new_system(node(2551));
+  let watch = watch_for(&system, &transport, node(2551))?;
+
+  let watchee = pid("actor://cellex@127.0.0.1:2552/3")?;
+  let watcher = pid("actor://cellex@127.0.0.1:2551/7")?;
+  let (future, notify) = create_ask_handles::<RemoteTerminated, ThreadSafe>();
+  watch.watch(&watchee, &watcher, notify).map_err(|err| format!("watch: {err}"))?;
+  assert!(watch.is_watching(&watchee));
+
+  let (node_id, request) = transport.take()?.pop().ok_or_else(|| "watch frame expected".to_string())?;
+  assert_eq!(node_id, node(2552));
+  assert_eq!(request.target, watchee);
+  assert_eq!(request.frame.channel, PriorityChannel::Control);
+  assert_eq!(request.frame.reply_to, Some(watcher));
+  assert_eq!(request.frame.payload, RemotePayloadFrame::System(SystemMessage::Watch(ActorId(7))));
+
+  watch.node_unreachable(&node(2552));
+  let terminated = block_on(future).map_err(|err| format!("await: {err:?}"))?;
+  assert_eq!(terminated, RemoteTerminated::new(watchee.clone(), DeadLetterReason::NetworkUnreachable));
+  assert!(!watch.is_watching(&watchee));
+  Ok(())
+}
+
+#[test]
+fn termination_notice_completes_watcher_and_unwatch_stops_tracking() -> TestResult {
+  let transport = RecordingTransport::default();
+  let system = new_system(node(2551));
+  let watch = watch_for(&system, &transport, node(2551))?;
+
+  let watchee = pid("actor://cellex@127.0.0.1:2552/3")?;
+  let first = pid("actor://cellex@127.0.0.1:2551/7")?;
+  let second = pid("actor://cellex@127.0.0.1:2551/8")?;
+  let (future, notify) = create_ask_handles::<RemoteTerminated, ThreadSafe>();
+  watch.watch(&watchee, &first, notify).map_err(|err| format!("watch: {err}"))?;
+  let (_, notify) = create_ask_handles::<RemoteTerminated, ThreadSafe>();
+  watch.watch(&watchee, &second, notify).map_err(|err| format!("watch: {err}"))?;
+  watch.unwatch(&watchee, &second).map_err(|err| format!("unwatch: {err}"))?;
+
+  let (_, unwatch) = transport.take()?.pop().ok_or_else(|| "unwatch frame expected".to_string())?;
+  assert_eq!(unwatch.frame.payload, RemotePayloadFrame::System(SystemMessage::Unwatch(ActorId(8))));
+
+  let notice = RemotePayloadFrame::Terminated { pid: watchee.clone(), reason: DeadLetterReason::Terminated };
+  let unconsumed = watch.handle_frame(RemoteDelivery::new(first, control_frame(notice, None)), |_| true);
+  assert!(unconsumed.is_none());
+
+  let terminated = block_on(future).map_err(|err| format!("await: {err:?}"))?;
+  assert_eq!(terminated.pid(), &watchee);
+  assert_eq!(terminated.reason(), &DeadLetterReason::Terminated);
+  assert!(!watch.is_watching(&watchee));
+  Ok(())
+}
+
+#[test]
+fn watching_unreachable_node_notifies_immediately() -> TestResult {
+  let transport = RecordingTransport::default();
+  transport.refuse_connections();
+  let system = new_system(node(2551));
+  let watch = watch_for(&system, &transport, node(2551))?;
+
+  let watchee = pid("actor://cellex@127.0.0.1:2552/3")?;
+  let watcher = pid("actor://cellex@127.0.0.1:2551/7")?;
+  let (future, notify) = create_ask_handles::<RemoteTerminated, ThreadSafe>();
+  watch.watch(&watchee, &watcher, notify).map_err(|err| format!("watch: {err}"))?;
+
+  let terminated = block_on(future).map_err(|err| format!("await: {err:?}"))?;
+  assert_eq!(terminated.reason(), &DeadLetterReason::NetworkUnreachable);
+  assert!(!watch.is_watching(&watchee));
+  Ok(())
+}
+
+#[test]
+fn watch_rejects_local_pids() -> TestResult {
+  let transport = RecordingTransport::default();
+  let system = new_system(node(2551));
+  let watch = watch_for(&system, &transport, node(2551))?;
+
+  let local = pid("actor://cellex@127.0.0.1:2551/3")?;
+  let (_, notify) = create_ask_handles::<RemoteTerminated, ThreadSafe>();
+  let result = watch.watch(&local, &local, notify);
+  assert!(matches!(result, Err(RemoteSendError::NotRemote)));
+  assert!(transport.take()?.is_empty());
+  Ok(())
+}
+
+#[test]
+fn watch_request_for_unknown_actor_is_answered_immediately() -> TestResult {
+  let transport = RecordingTransport::default();
+  let system = new_system(node(2552));
+  let watch = watch_for(&system, &transport, node(2552))?;
+
+  let watchee = pid("actor://cellex@127.0.0.1:2552/3")?;
+  let watcher = pid("actor://cellex@127.0.0.1:2551/7")?;
+  let request = control_frame(RemotePayloadFrame::System(SystemMessage::Watch(ActorId(7))), Some(watcher.clone()));
+  let unconsumed = watch.handle_frame(RemoteDelivery::new(watchee.clone(), request), |_| false);
+  assert!(unconsumed.is_none());
+  assert_eq!(watch.remote_watcher_count(&watchee), 0);
+
+  let (node_id, notice) = transport.take()?.pop().ok_or_else(|| "notice expected".to_string())?;
+  assert_eq!(node_id, node(2551));
+  assert_eq!(notice.target, watcher);
+  assert_eq!(notice.frame.payload, RemotePayloadFrame::Terminated {
+    pid:    watchee,
+    reason: DeadLetterReason::UnregisteredPid,
+  });
+  Ok(())
+}
+
+#[test]
+fn local_termination_notifies_remote_watchers_once() -> TestResult {
+  let transport = RecordingTransport::default();
+  let system = new_system(node(2552));
+  let watch = watch_for(&system, &transport, node(2552))?;
+
+  let watchee = pid("actor://cellex@127.0.0.1:2552/3")?;
+  let watcher = pid("actor://cellex@127.0.0.1:2551/7")?;
+  let request = control_frame(RemotePayloadFrame::System(SystemMessage::Watch(ActorId(7))), Some(watcher.clone()));
+  assert!(watch.handle_frame(RemoteDelivery::new(watchee.clone(), request), |_| true).is_none());
+  assert_eq!(watch.remote_watcher_count(&watchee), 1);
+  assert!(transport.take()?.is_empty());
+
+  watch.local_terminated(&watchee);
+  watch.local_terminated(&watchee);
+
+  let mut notices = transport.take()?;
+  assert_eq!(notices.len(), 1);
+  let (node_id, notice) = notices.pop().ok_or_else(|| "notice expected".to_string())?;
+  assert_eq!(node_id, node(2551));
+  assert_eq!(notice.target, watcher);
+  assert_eq!(notice.frame.payload, RemotePayloadFrame::Terminated {
+    pid:    watchee.clone(),
+    reason: DeadLetterReason::Terminated,
+  });
+  assert_eq!(watch.remote_watcher_count(&watchee), 0);
+  Ok(())
+}
+
+#[test]
+fn regular_frames_pass_through() -> TestResult {
+  let transport = RecordingTransport::default();
+  let system = new_system(node(2552));
+  let watch = watch_for(&system, &transport, node(2552))?;
+
+  let target = pid("actor://cellex@127.0.0.1:2552/3")?;
+  let delivery = RemoteDelivery::new(target, control_frame(RemotePayloadFrame::System(SystemMessage::Stop), None));
+  let unconsumed = watch.handle_frame(delivery.clone(), |_| true);
+  assert_eq!(unconsumed, Some(delivery));
+  Ok(())
+}
@@ -0,0 +1,52 @@
+use std::time::{Duration, Instant};
+
+use cellex_remote_core_rs::{failure_detector::RemoteHeartbeat, transport::RemoteTransport};
+use tokio::{
+  runtime::Handle,
+  task::JoinHandle,
+  time::{self, MissedTickBehavior},
+};
+
+/// Tokio task ticking a [`RemoteHeartbeat`] at its configured heartbeat interval.
+///
+/// The task is aborted when the handle is dropped.
+pub struct HeartbeatTask {
+  handle: JoinHandle<()>,
+}
+
+impl HeartbeatTask {
+  /// Spawns the heartbeat loop on `handle`.
+  #[must_use]
+  pub fn spawn<T>(handle: &Handle, heartbeat: RemoteHeartbeat<T>) -> Self
+  where
+    T: RemoteTransport + 'static,
+    RemoteHeartbeat<T>: Send + 'static, {
+    let period = heartbeat.config().heartbeat_interval();
+    let handle = handle.spawn(async move {
+      let mut interval = time::interval(period);
+      interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+      loop {
+        interval.tick().await;
+        heartbeat.tick();
+      }
+    });
+    Self { handle }
+  }
+
+  /// Returns a monotonic clock suitable for [`RemoteHeartbeat::new`].
+  pub fn monotonic_clock() -> impl Fn() -> Duration + Send + Sync + 'static {
+    let origin = Instant::now();
+    move || origin.elapsed()
+  }
+
+  /// Stops ticking.
+  pub fn abort(&self) {
+    self.handle.abort();
+  }
+}
+
+impl Drop for HeartbeatTask {
+  fn drop(&mut self) {
+    self.handle.abort();
+  }
+}
@@ -0,0 +1,53 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use cellex_remote_core_rs::transport::{TransportConnection, TransportError};
+use cellex_utils_core_rs::sync::ArcShared;
+use tokio::sync::{mpsc, Notify};
+
+/// Outbound TCP association created by [`TcpTransport`](crate::TcpTransport).
+///
+/// Frames are queued to a writer task that owns the socket, so `send` never blocks the caller;
+/// the frames the task has not written yet make up the connection backlog.
+pub struct TcpConnection {
+  sender:   mpsc::UnboundedSender<Vec<u8>>,
+  backlog:  ArcShared<AtomicUsize>,
+  closed:   ArcShared<AtomicBool>,
+  shutdown: ArcShared<Notify>,
+}
+
+impl TcpConnection {
+  pub(crate) const fn new(
+    sender: mpsc::UnboundedSender<Vec<u8>>,
+    backlog: ArcShared<AtomicUsize>,
+    closed: ArcShared<AtomicBool>,
+    shutdown: ArcShared<Notify>,
+  ) -> Self {
+    Self { sender, backlog, closed, shutdown }
+  }
+}
+
+impl TransportConnection for TcpConnection {
+  fn send(&self, frame: Vec<u8>) -> Result<(), TransportError> {
+    if self.is_closed() {
+      return Err(TransportError::Closed);
+    }
+    self.backlog.fetch_add(1, Ordering::SeqCst);
+    self.sender.send(frame).map_err(|_| {
+      self.backlog.fetch_sub(1, Ordering::SeqCst);
+      TransportError::Closed
+    })
+  }
+
+  fn close(&self) {
+    self.closed.store(true, Ordering::SeqCst);
+    self.shutdown.notify_one();
+  }
+
+  fn backlog(&self) -> usize {
+    self.backlog.load(Ordering::SeqCst)
+  }
+
+  fn is_closed(&self) -> bool {
+    self.closed.load(Ordering::SeqCst) || self.sender.is_closed()
+  }
+}
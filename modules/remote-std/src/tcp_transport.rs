@@ -0,0 +1,163 @@
+use std::{
+  net::TcpListener as StdTcpListener,
+  sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    RwLock,
+  },
+};
+
+use cellex_actor_core_rs::api::process::{dead_letter::DeadLetterReason, pid::NodeId};
+use cellex_remote_core_rs::{
+  codec::{frame_length, length_prefixed, FRAME_LENGTH_PREFIX_SIZE},
+  transport::{InboundFrameHandler, RemoteTransport, TransportError, UndeliveredFrameHandler},
+};
+use cellex_utils_core_rs::sync::ArcShared;
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  net::{TcpListener, TcpStream},
+  runtime::Handle,
+  sync::{mpsc, Notify},
+};
+
+use crate::TcpConnection;
+
+/// [`RemoteTransport`] exchanging length-prefixed frames over TCP.
+///
+/// Every [`NodeId`] is interpreted as `host:port`; nodes without a port cannot be reached.
+///
+/// Frames queued to a connection that cannot be opened, or still queued when it closes, are
+/// reported undelivered as [`DeadLetterReason::NetworkUnreachable`]; frames too large to be
+/// written as [`DeadLetterReason::DeliveryRejected`].
+#[derive(Clone)]
+pub struct TcpTransport {
+  handle:      Handle,
+  undelivered: ArcShared<RwLock<Option<UndeliveredFrameHandler>>>,
+}
+
+impl TcpTransport {
+  /// Creates a transport spawning its I/O tasks on `handle`.
+  #[must_use]
+  pub fn new(handle: Handle) -> Self {
+    Self { handle, undelivered: ArcShared::new(RwLock::new(None)) }
+  }
+
+  /// Creates a transport bound to the Tokio runtime of the calling context.
+  ///
+  /// # Errors
+  /// Returns [`TransportError::Io`] when called outside a Tokio runtime.
+  pub fn current() -> Result<Self, TransportError> {
+    Handle::try_current().map(Self::new).map_err(|err| TransportError::Io(err.to_string()))
+  }
+
+  fn address(node: &NodeId) -> Result<String, TransportError> {
+    let port = node.port().ok_or_else(|| TransportError::Unreachable(node.clone()))?;
+    Ok(format!("{}:{}", node.host(), port))
+  }
+
+  async fn accept_loop(listener: StdTcpListener, handler: InboundFrameHandler) {
+    let Ok(listener) = TcpListener::from_std(listener) else {
+      return;
+    };
+    while let Ok((stream, _)) = listener.accept().await {
+      let handler = handler.clone();
+      tokio::spawn(Self::read_loop(stream, handler));
+    }
+  }
+
+  async fn read_loop(mut stream: TcpStream, handler: InboundFrameHandler) {
+    let mut peer = None;
+    let mut prefix = [0_u8; FRAME_LENGTH_PREFIX_SIZE];
+    loop {
+      if stream.read_exact(&mut prefix).await.is_err() {
+        return;
+      }
+      let Ok(len) = frame_length(prefix) else {
+        return;
+      };
+      let mut body = vec![0_u8; len];
+      if stream.read_exact(&mut body).await.is_err() {
+        return;
+      }
+      handler.handle(&mut peer, &body);
+    }
+  }
+
+  async fn write_loop(
+    address: String,
+    mut frames: mpsc::UnboundedReceiver<Vec<u8>>,
+    backlog: ArcShared<AtomicUsize>,
+    closed: ArcShared<AtomicBool>,
+    shutdown: ArcShared<Notify>,
+    undelivered: ArcShared<RwLock<Option<UndeliveredFrameHandler>>>,
+  ) {
+    let report = |frame: &[u8], reason: DeadLetterReason| {
+      if let Some(handler) = undelivered.read().unwrap_or_else(|err| err.into_inner()).as_ref() {
+        handler.handle(frame, reason);
+      }
+    };
+    if let Ok(mut stream) = TcpStream::connect(address).await {
+      let _ = stream.set_nodelay(true);
+      loop {
+        tokio::select! {
+          frame = frames.recv() => {
+            let Some(frame) = frame else {
+              break;
+            };
+            backlog.fetch_sub(1, Ordering::SeqCst);
+            match length_prefixed(&frame) {
+              | Ok(bytes) => {
+                if stream.write_all(&bytes).await.is_err() {
+                  report(&frame, DeadLetterReason::NetworkUnreachable);
+                  break;
+                }
+              },
+              | Err(_) => report(&frame, DeadLetterReason::DeliveryRejected),
+            }
+          },
+          () = shutdown.notified() => break,
+        }
+      }
+      let _ = stream.shutdown().await;
+    }
+    closed.store(true, Ordering::SeqCst);
+    // Frames queued after the last write are never written; draining them empties the backlog.
+    frames.close();
+    while let Ok(frame) = frames.try_recv() {
+      backlog.fetch_sub(1, Ordering::SeqCst);
+      report(&frame, DeadLetterReason::NetworkUnreachable);
+    }
+  }
+}
+
+impl RemoteTransport for TcpTransport {
+  type Connection = TcpConnection;
+
+  fn listen(&self, local: &NodeId, handler: InboundFrameHandler) -> Result<(), TransportError> {
+    let listener = StdTcpListener::bind(Self::address(local)?).map_err(|err| TransportError::Io(err.to_string()))?;
+    listener.set_nonblocking(true).map_err(|err| TransportError::Io(err.to_string()))?;
+    self.handle.spawn(Self::accept_loop(listener, handler));
+    Ok(())
+  }
+
+  fn connect(&self, remote: &NodeId) -> Result<Self::Connection, TransportError> {
+    let address = Self::address(remote)?;
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let backlog = ArcShared::new(AtomicUsize::new(0));
+    let closed = ArcShared::new(AtomicBool::new(false));
+    let shutdown = ArcShared::new(Notify::new());
+    let undelivered = self.undelivered.clone();
+    self.handle.spawn(Self::write_loop(
+      address,
+      receiver,
+      backlog.clone(),
+      closed.clone(),
+      shutdown.clone(),
+      undelivered,
+    ));
+    Ok(TcpConnection::new(sender, backlog, closed, shutdown))
+  }
+
+  fn report_undelivered(&self, handler: UndeliveredFrameHandler) {
+    *self.undelivered.write().unwrap_or_else(|err| err.into_inner()) = Some(handler);
+  }
+}
@@ -0,0 +1,404 @@
+use core::time::Duration;
+use std::sync::{
+  atomic::{AtomicBool, Ordering},
+  Arc, Mutex,
+};
+
+use cellex_actor_core_rs::api::{
+  actor::{actor_failure::ActorFailure, ask::create_ask_handles, ActorId, ActorPath, Props},
+  actor_runtime::GenericActorRuntime,
+  actor_system::{GenericActorSystem, GenericActorSystemConfig},
+  extensions::{serializer_extension_id, SerializerRegistryExtension},
+  failure::{failure_event_stream::FailureEventListener, FailureEvent},
+  mailbox::{
+    messages::{PriorityChannel, SystemMessage},
+    ThreadSafe,
+  },
+  process::{
+    dead_letter::DeadLetterReason,
+    pid::{NodeId, Pid},
+  },
+};
+use cellex_actor_std_rs::{tokio_mailbox::TokioMailboxFactory, TokioActorRuntime};
+use cellex_remote_core_rs::{
+  codec::{RemoteDelivery, RemoteMessageFrame, RemotePayloadFrame, MAX_FRAME_SIZE},
+  delivery::{RemoteInboundDispatcher, RemoteMessage},
+  endpoint::EndpointManager,
+  failure_detector::{FailureDetectorConfig, NodeReachability, RemoteHeartbeat},
+  outbound::RemoteOutbound,
+  transport::{RemoteTransport, TransportConnection, TransportError, UndeliveredFrameHandler},
+  watch::{RemoteDeathWatch, RemoteTerminated},
+};
+use cellex_serialization_core_rs::{
+  error::{DeserializationError, SerializationError},
+  impl_type_key, InMemorySerializerRegistry, TypeKey,
+};
+use cellex_serialization_json_rs::{shared_json_serializer, SerdeJsonSerializer, SERDE_JSON_SERIALIZER_ID};
+use cellex_utils_core_rs::sync::ArcShared;
+use serde::{Deserialize, Serialize};
+use tokio::runtime::Handle;
+
+use super::*;
+
+type TestResult<T = ()> = Result<T, String>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Greeting {
+  text: String,
+}
+
+impl_type_key!(Greeting, "test.Greeting");
+
+impl RemoteMessage for Greeting {
+  fn encode_payload(&self) -> Result<Vec<u8>, SerializationError> {
+    serde_json::to_vec(self).map_err(|err| SerializationError::custom(err.to_string()))
+  }
+
+  fn decode_payload(bytes: &[u8]) -> Result<Self, DeserializationError> {
+    serde_json::from_slice(bytes).map_err(|err| DeserializationError::custom(err.to_string()))
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Reply {
+  length: usize,
+}
+
+impl_type_key!(Reply, "test.Reply");
+
+impl RemoteMessage for Reply {
+  fn encode_payload(&self) -> Result<Vec<u8>, SerializationError> {
+    serde_json::to_vec(self).map_err(|err| SerializationError::custom(err.to_string()))
+  }
+
+  fn decode_payload(bytes: &[u8]) -> Result<Self, DeserializationError> {
+    serde_json::from_slice(bytes).map_err(|err| DeserializationError::custom(err.to_string()))
+  }
+}
+
+fn local_node() -> TestResult<NodeId> {
+  let listener = std::net::TcpListener::bind("127.0.0.1:0").map_err(|err| format!("bind: {err}"))?;
+  let port = listener.local_addr().map_err(|err| format!("local addr: {err}"))?.port();
+  Ok(NodeId::new("127.0.0.1", Some(port)))
+}
+
+fn new_system(node: NodeId) -> GenericActorSystem<Greeting, TokioActorRuntime> {
+  let config = GenericActorSystemConfig::default().with_node_id(node);
+  GenericActorSystem::new_with_actor_runtime(GenericActorRuntime::new(TokioMailboxFactory), config)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn tcp_transport_delivers_frames_to_remote_actor() -> TestResult {
+  let node = local_node()?;
+  let mut system = new_system(node.clone());
+  let received: Arc<Mutex<Vec<Greeting>>> = Arc::new(Mutex::new(Vec::new()));
+  let received_clone = received.clone();
+  let actor_ref = system
+    .root_context()
+    .spawn(Props::new(move |_, message: Greeting| {
+      received_clone.lock().unwrap_or_else(|err| err.into_inner()).push(message);
+      Ok(())
+    }))
+    .map_err(|err| format!("spawn: {err:?}"))?;
+  let target = actor_ref.pid().ok_or_else(|| "pid expected".to_string())?;
+
+  let serializers = InMemorySerializerRegistry::new();
+  serializers.register(shared_json_serializer()).map_err(|err| format!("register serializer: {err}"))?;
+  let dispatcher = RemoteInboundDispatcher::new(system.process_registry(), serializers);
+  dispatcher.register_message::<Greeting>();
+  let system_id = system.process_registry().system().clone();
+  let receiver = EndpointManager::new(
+    TcpTransport::current().map_err(|err| format!("transport: {err}"))?,
+    system_id.clone(),
+    node.clone(),
+  );
+  receiver.start(dispatcher).map_err(|err| format!("listen: {err}"))?;
+
+  let sender =
+    EndpointManager::new(TcpTransport::current().map_err(|err| format!("transport: {err}"))?, system_id, local_node()?);
+  let greeting = Greeting { text: "over tcp".to_string() };
+  let serialized = SerdeJsonSerializer::new()
+    .serialize_value(Some(<Greeting as TypeKey>::type_key()), &greeting)
+    .map_err(|err| format!("serialize: {err}"))?;
+  let frame = RemoteMessageFrame::new(0, PriorityChannel::Regular, RemotePayloadFrame::User { serialized }, None);
+  sender.send(&RemoteDelivery::new(target, frame)).map_err(|err| format!("send: {err}"))?;
+  assert_eq!(sender.connected_nodes(), vec![node]);
+
+  let delivered = received.clone();
+  tokio::time::timeout(
+    Duration::from_secs(5),
+    system.run_until(move || delivered.lock().unwrap_or_else(|err| err.into_inner()).is_empty()),
+  )
+  .await
+  .map_err(|_| "timed out waiting for remote frame".to_string())?
+  .map_err(|err| format!("dispatch: {err:?}"))?;
+
+  assert_eq!(received.lock().unwrap_or_else(|err| err.into_inner()).as_slice(), &[greeting]);
+  Ok(())
+}
+
+fn new_outbound(
+  system: &GenericActorSystem<Greeting, TokioActorRuntime>,
+  node: NodeId,
+) -> TestResult<RemoteOutbound<TcpTransport>> {
+  let transport = TcpTransport::current().map_err(|err| format!("transport: {err}"))?;
+  let system_id = system.process_registry().system().clone();
+  let endpoints = ArcShared::new(EndpointManager::new(transport, system_id, node));
+  let outbound = system
+    .extension(serializer_extension_id(), |extension: &SerializerRegistryExtension| {
+      let _ = extension.register_serializer(shared_json_serializer());
+      extension.bind_type::<Greeting>(SERDE_JSON_SERIALIZER_ID).map_err(|err| format!("bind: {err:?}"))?;
+      extension.bind_type::<Reply>(SERDE_JSON_SERIALIZER_ID).map_err(|err| format!("bind: {err:?}"))?;
+      Ok::<_, String>(RemoteOutbound::new(endpoints, extension))
+    })
+    .ok_or_else(|| "serializer extension expected".to_string())??;
+  outbound.register_message::<Greeting>();
+  outbound.register_message::<Reply>();
+  Ok(outbound)
+}
+
+fn outbound_for(
+  system: &GenericActorSystem<Greeting, TokioActorRuntime>,
+  node: NodeId,
+) -> TestResult<RemoteOutbound<TcpTransport>> {
+  let outbound = new_outbound(system, node)?;
+  outbound.start(system.process_registry()).map_err(|err| format!("listen: {err}"))?;
+  Ok(outbound)
+}
+
+fn death_watch_for(
+  system: &GenericActorSystem<Greeting, TokioActorRuntime>,
+  node: NodeId,
+) -> TestResult<RemoteDeathWatch<TcpTransport>> {
+  let watch = RemoteDeathWatch::new(new_outbound(system, node)?);
+  watch.start(system.process_registry()).map_err(|err| format!("listen: {err}"))?;
+  Ok(watch)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn remote_actor_ref_asks_actor_on_other_node_over_tcp() -> TestResult {
+  let client_node = local_node()?;
+  let client_system = new_system(client_node.clone());
+  let client = outbound_for(&client_system, client_node)?;
+
+  let server_node = local_node()?;
+  let mut server_system = new_system(server_node.clone());
+  let _server = outbound_for(&server_system, server_node.clone())?;
+  let handled = Arc::new(AtomicBool::new(false));
+  let handled_clone = handled.clone();
+  let actor_ref = server_system
+    .root_context()
+    .spawn(Props::new(move |ctx, message: Greeting| {
+      ctx
+        .respond(Reply { length: message.text.len() })
+        .map_err(|err| ActorFailure::from_message(format!("respond: {err:?}")))?;
+      handled_clone.store(true, Ordering::SeqCst);
+      Ok(())
+    }))
+    .map_err(|err| format!("spawn: {err:?}"))?;
+  let pid = actor_ref.pid().ok_or_else(|| "pid expected".to_string())?;
+
+  let remote = client.actor_ref::<Greeting>(pid).map_err(|err| format!("actor ref: {err}"))?;
+  let future = remote.ask::<Reply>(Greeting { text: "ping".to_string() }).map_err(|err| format!("ask: {err}"))?;
+
+  let pending = handled.clone();
+  tokio::time::timeout(Duration::from_secs(5), server_system.run_until(move || !pending.load(Ordering::SeqCst)))
+    .await
+    .map_err(|_| "timed out waiting for request".to_string())?
+    .map_err(|err| format!("dispatch: {err:?}"))?;
+  let reply = tokio::time::timeout(Duration::from_secs(5), future)
+    .await
+    .map_err(|_| "timed out waiting for reply".to_string())?
+    .map_err(|err| format!("ask: {err:?}"))?;
+
+  assert_eq!(reply, Reply { length: 4 });
+  assert!(client.replies().is_empty());
+  let association = client.endpoints().association(&server_node).ok_or_else(|| "association expected".to_string())?;
+  assert_eq!(&association.system, client.system());
+  Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn remote_death_watch_reports_actor_stopped_on_other_node() -> TestResult {
+  let client_node = local_node()?;
+  let client_system = new_system(client_node.clone());
+  let client = death_watch_for(&client_system, client_node.clone())?;
+
+  let server_node = local_node()?;
+  let mut server_system = new_system(server_node.clone());
+  let server = death_watch_for(&server_system, server_node)?;
+  let actor_ref =
+    server_system.root_context().spawn(Props::new(|_, _: Greeting| Ok(()))).map_err(|err| format!("spawn: {err:?}"))?;
+  let watchee = actor_ref.pid().ok_or_else(|| "pid expected".to_string())?;
+
+  let watcher = Pid::new(watchee.system().clone(), ActorPath::new().push_child(ActorId(7))).with_node(client_node);
+  let (future, notify) = create_ask_handles::<RemoteTerminated, ThreadSafe>();
+  client.watch(&watchee, &watcher, notify).map_err(|err| format!("watch: {err}"))?;
+
+  for _ in 0..250 {
+    if server.remote_watcher_count(&watchee) == 1 {
+      break;
+    }
+    tokio::time::sleep(Duration::from_millis(20)).await;
+  }
+  assert_eq!(server.remote_watcher_count(&watchee), 1);
+
+  actor_ref.send_system(SystemMessage::Stop).map_err(|err| format!("stop: {err:?}"))?;
+  let watching = server.clone();
+  let pending = watchee.clone();
+  tokio::time::timeout(
+    Duration::from_secs(5),
+    server_system.run_until(move || watching.remote_watcher_count(&pending) > 0),
+  )
+  .await
+  .map_err(|_| "timed out waiting for stop".to_string())?
+  .map_err(|err| format!("dispatch: {err:?}"))?;
+
+  let terminated = tokio::time::timeout(Duration::from_secs(5), future)
+    .await
+    .map_err(|_| "timed out waiting for termination".to_string())?
+    .map_err(|err| format!("await: {err:?}"))?;
+  assert_eq!(terminated, RemoteTerminated::new(watchee.clone(), DeadLetterReason::Terminated));
+  assert!(!client.is_watching(&watchee));
+  Ok(())
+}
+
+fn heartbeat_for(
+  system: &GenericActorSystem<Greeting, TokioActorRuntime>,
+  node: &NodeId,
+) -> TestResult<RemoteHeartbeat<TcpTransport>> {
+  let config = FailureDetectorConfig::new()
+    .with_heartbeat_interval(Duration::from_millis(50))
+    .with_acceptable_heartbeat_pause(Duration::from_millis(150));
+  let outbound = new_outbound(system, node.clone())?;
+  let heartbeat = RemoteHeartbeat::new(outbound.clone(), config, HeartbeatTask::monotonic_clock());
+  let dispatcher = heartbeat.attach(outbound.inbound_dispatcher(system.process_registry()));
+  outbound.endpoints().start(dispatcher).map_err(|err| format!("listen: {err}"))?;
+  Ok(heartbeat)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn heartbeat_task_reports_silent_node_unreachable() -> TestResult {
+  let live_node = local_node()?;
+  let live_system = new_system(live_node.clone());
+  let _live = heartbeat_for(&live_system, &live_node)?;
+
+  let local = local_node()?;
+  let system = new_system(local.clone());
+  let events: Arc<Mutex<Vec<NodeId>>> = Arc::new(Mutex::new(Vec::new()));
+  let events_clone = events.clone();
+  let heartbeat = heartbeat_for(&system, &local)?.with_failure_listener(FailureEventListener::new(move |event| {
+    if let FailureEvent::NodeUnreachable(node) = event {
+      events_clone.lock().unwrap_or_else(|err| err.into_inner()).push(node);
+    }
+  }));
+  let silent_node = local_node()?;
+  heartbeat.monitor(&live_node);
+  heartbeat.monitor(&silent_node);
+  let _task = HeartbeatTask::spawn(&Handle::current(), heartbeat.clone());
+
+  for _ in 0..250 {
+    if heartbeat.reachability(&silent_node) == Some(NodeReachability::Unreachable) {
+      break;
+    }
+    tokio::time::sleep(Duration::from_millis(20)).await;
+  }
+
+  assert_eq!(heartbeat.reachability(&silent_node), Some(NodeReachability::Unreachable));
+  assert_eq!(heartbeat.reachability(&live_node), Some(NodeReachability::Reachable));
+  assert_eq!(events.lock().unwrap_or_else(|err| err.into_inner()).as_slice(), &[silent_node]);
+  Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn tcp_connection_closes_when_peer_is_unreachable() -> TestResult {
+  let transport = TcpTransport::current().map_err(|err| format!("transport: {err}"))?;
+  let connection = transport.connect(&local_node()?).map_err(|err| format!("connect: {err}"))?;
+
+  for _ in 0..50 {
+    if connection.is_closed() {
+      break;
+    }
+    tokio::time::sleep(Duration::from_millis(20)).await;
+  }
+
+  assert!(connection.is_closed());
+  assert_eq!(connection.send(vec![1]), Err(TransportError::Closed));
+  Ok(())
+}
+
+type Undelivered = Arc<Mutex<Vec<(usize, DeadLetterReason)>>>;
+
+fn record_undelivered(transport: &TcpTransport) -> Undelivered {
+  let undelivered: Undelivered = Arc::new(Mutex::new(Vec::new()));
+  let recorded = undelivered.clone();
+  transport.report_undelivered(UndeliveredFrameHandler::new(move |frame: &[u8], reason: DeadLetterReason| {
+    recorded.lock().unwrap_or_else(|err| err.into_inner()).push((frame.len(), reason));
+  }));
+  undelivered
+}
+
+#[test]
+fn tcp_connection_reports_frames_queued_for_an_unreachable_peer() -> TestResult {
+  let runtime =
+    tokio::runtime::Builder::new_current_thread().enable_all().build().map_err(|err| format!("runtime: {err}"))?;
+  let transport = TcpTransport::new(runtime.handle().clone());
+  let undelivered = record_undelivered(&transport);
+  let connection = transport.connect(&local_node()?).map_err(|err| format!("connect: {err}"))?;
+  // The writer task only runs once the runtime is driven, so both frames are queued.
+  connection.send(vec![1]).map_err(|err| format!("send: {err}"))?;
+  connection.send(vec![2, 2]).map_err(|err| format!("send: {err}"))?;
+  assert_eq!(connection.backlog(), 2);
+
+  runtime.block_on(async {
+    for _ in 0..50 {
+      if connection.is_closed() {
+        break;
+      }
+      tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+  });
+
+  assert!(connection.is_closed());
+  assert_eq!(connection.backlog(), 0);
+  assert_eq!(*undelivered.lock().unwrap_or_else(|err| err.into_inner()), vec![
+    (1, DeadLetterReason::NetworkUnreachable),
+    (2, DeadLetterReason::NetworkUnreachable)
+  ]);
+  Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn tcp_connection_reports_frames_too_large_to_write() -> TestResult {
+  let listener = std::net::TcpListener::bind("127.0.0.1:0").map_err(|err| format!("bind: {err}"))?;
+  let port = listener.local_addr().map_err(|err| format!("local addr: {err}"))?.port();
+  let transport = TcpTransport::current().map_err(|err| format!("transport: {err}"))?;
+  let undelivered = record_undelivered(&transport);
+  let connection = transport.connect(&NodeId::new("127.0.0.1", Some(port))).map_err(|err| format!("connect: {err}"))?;
+  connection.send(vec![0; MAX_FRAME_SIZE + 1]).map_err(|err| format!("send: {err}"))?;
+
+  for _ in 0..50 {
+    if connection.backlog() == 0 {
+      break;
+    }
+    tokio::time::sleep(Duration::from_millis(20)).await;
+  }
+
+  assert!(!connection.is_closed());
+  assert_eq!(*undelivered.lock().unwrap_or_else(|err| err.into_inner()), vec![(
+    MAX_FRAME_SIZE + 1,
+    DeadLetterReason::DeliveryRejected
+  )]);
+  drop(listener);
+  Ok(())
+}
+
+#[test]
+fn tcp_transport_rejects_nodes_without_port() -> TestResult {
+  let runtime = tokio::runtime::Builder::new_current_thread().build().map_err(|err| format!("runtime: {err}"))?;
+  let transport = TcpTransport::new(runtime.handle().clone());
+  let node = NodeId::new("127.0.0.1", None);
+
+  assert!(matches!(transport.connect(&node), Err(TransportError::Unreachable(_))));
+  Ok(())
+}